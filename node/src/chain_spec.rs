@@ -185,6 +185,9 @@ fn testnet_genesis(
                 ])
                 .collect::<Vec<_>>(),
         },
+        "marketplace": {
+            "acceptedAssets": vec![(1337, 1, false), (1984, 1, false)], // Accepted payment assets: id, min_amount, uses_exchange_rate
+        },
     })
 }
 
@@ -0,0 +1,168 @@
+//! RPC interface for the marketplace pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use pallet_marketplace::{
+    types::{ListingFunding, ListingInfo, OfferInfo, PurchaseInfo, TokenListingInfo},
+    ListingId,
+};
+
+pub use pallet_marketplace::MarketplaceApi as MarketplaceRuntimeApi;
+
+#[rpc(client, server)]
+pub trait MarketplaceApi<BlockHash, AccountId, Balance, BlockNumber> {
+    /// Returns the ongoing property listings after the listing `start`.
+    #[method(name = "marketplace_ongoingListings")]
+    fn ongoing_listings(
+        &self,
+        start: Option<ListingId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ListingInfo<AccountId, Balance, BlockNumber>>>;
+
+    /// Returns the relisted token listings after the listing `start`.
+    #[method(name = "marketplace_tokenListings")]
+    fn token_listings(
+        &self,
+        start: Option<ListingId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<TokenListingInfo<AccountId, Balance>>>;
+
+    /// Returns the funding progress of a property listing per payment asset.
+    #[method(name = "marketplace_listingFunding")]
+    fn listing_funding(
+        &self,
+        listing_id: ListingId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ListingFunding<Balance>>>;
+
+    /// Returns the open offers on a token listing after the offer of the account `start`.
+    #[method(name = "marketplace_listingOffers")]
+    fn listing_offers(
+        &self,
+        listing_id: ListingId,
+        start: Option<AccountId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<OfferInfo<AccountId, Balance>>>;
+
+    /// Returns the unclaimed purchases of an account after the listing `start`.
+    #[method(name = "marketplace_accountPurchases")]
+    fn account_purchases(
+        &self,
+        account: AccountId,
+        start: Option<ListingId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<PurchaseInfo<Balance>>>;
+}
+
+/// Provides RPC methods to query the marketplace.
+pub struct Marketplace<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Marketplace<C, Block> {
+    /// Creates a new instance of the marketplace RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error code returned when a runtime api call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query the marketplace.",
+        Some(format!("{:?}", err)),
+    )
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber>
+    MarketplaceApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber>
+    for Marketplace<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: MarketplaceRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn ongoing_listings(
+        &self,
+        start: Option<ListingId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ListingInfo<AccountId, Balance, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.ongoing_listings(at, start, limit)
+            .map_err(runtime_error)
+    }
+
+    fn token_listings(
+        &self,
+        start: Option<ListingId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<TokenListingInfo<AccountId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.token_listings(at, start, limit).map_err(runtime_error)
+    }
+
+    fn listing_funding(
+        &self,
+        listing_id: ListingId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ListingFunding<Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.listing_funding(at, listing_id).map_err(runtime_error)
+    }
+
+    fn listing_offers(
+        &self,
+        listing_id: ListingId,
+        start: Option<AccountId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<OfferInfo<AccountId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.listing_offers(at, listing_id, start, limit)
+            .map_err(runtime_error)
+    }
+
+    fn account_purchases(
+        &self,
+        account: AccountId,
+        start: Option<ListingId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<PurchaseInfo<Balance>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.account_purchases(at, account, start, limit)
+            .map_err(runtime_error)
+    }
+}
@@ -113,6 +113,28 @@ fn list_property_helper<T: Config>(
     token_amount: u32,
     token_price: <T as pallet::Config>::Balance,
     tax_paid_by_developer: bool,
+) -> u32 {
+    list_property_with_sale_mode::<T>(
+        seller,
+        region_id,
+        location,
+        token_amount,
+        token_price,
+        crate::SaleTerms {
+            tax_paid_by_developer,
+            sale_mode: crate::SaleMode::FixedPrice,
+            soft_cap: None,
+        },
+    )
+}
+
+fn list_property_with_sale_mode<T: Config>(
+    seller: T::AccountId,
+    region_id: u16,
+    location: LocationId<T>,
+    token_amount: u32,
+    token_price: <T as pallet::Config>::Balance,
+    sale_terms: crate::SaleTerms<<T as pallet::Config>::Balance>,
 ) -> u32 {
     let property_price = token_price.saturating_mul((token_amount as u128).into());
     let deposit_amount = property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
@@ -134,7 +156,7 @@ fn list_property_helper<T: Config>(
         token_price,
         token_amount,
         metadata,
-        tax_paid_by_developer,
+        sale_terms
     ));
     let listing_id = 0;
     assert!(OngoingObjectListing::<T>::contains_key(listing_id));
@@ -170,11 +192,15 @@ fn list_and_sell_property<T: Config>(
         token_price,
         token_amount,
         metadata,
-        tax_paid_by_developer,
+        crate::SaleTerms {
+            tax_paid_by_developer,
+            sale_mode: crate::SaleMode::FixedPrice,
+            soft_cap: None
+        }
     ));
     let listing_id = 0;
     assert!(OngoingObjectListing::<T>::contains_key(listing_id));
-    let payment_asset = T::AcceptedAssets::get()[0];
+    let payment_asset = Marketplace::<T>::accepted_assets()[0];
     let buyer: T::AccountId = account("buyer", 0, 0);
     assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
         &buyer,
@@ -202,6 +228,7 @@ fn list_and_sell_property<T: Config>(
         listing_id,
         1,
         payment_asset,
+        None,
     ));
     assert_ok!(Marketplace::<T>::claim_property_token(
         RawOrigin::Signed(buyer.clone()).into(),
@@ -315,7 +342,7 @@ fn add_buyers_to_listing<T: Config>(
 
     for i in 1..=buyers {
         let buyer: T::AccountId = account("buyer", i, i);
-        let payment_asset_buyers = T::AcceptedAssets::get()[0];
+        let payment_asset_buyers = Marketplace::<T>::accepted_assets()[0];
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
             &buyer,
             deposit_amount.saturating_mul(20u32.into())
@@ -334,7 +361,52 @@ fn add_buyers_to_listing<T: Config>(
             RawOrigin::Signed(buyer).into(),
             0,
             1,
-            payment_asset_buyers
+            payment_asset_buyers,
+            None
+        ));
+    }
+}
+
+fn add_auction_bidders<T: Config>(
+    bidders: u32,
+    listing_id: ListingId,
+    token_price: <T as pallet::Config>::Balance,
+    token_amount: u32,
+    admin: T::AccountId,
+) {
+    let payment_asset = Marketplace::<T>::accepted_assets()[0];
+    let base_amount = token_amount / bidders;
+    let remainder = token_amount % bidders;
+
+    for i in 1..=bidders {
+        let bidder: T::AccountId = account("bidder", i, i);
+        let amount = if i <= remainder {
+            base_amount + 1
+        } else {
+            base_amount
+        };
+        let bid_price = token_price.saturating_mul((amount as u128).into());
+        assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
+            &bidder,
+            bid_price.saturating_mul(T::ListingDeposit::get())
+        ));
+        assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
+            payment_asset,
+            &bidder,
+            bid_price.saturating_mul(2u32.into())
+        ));
+        assert_ok!(Whitelist::<T>::assign_role(
+            RawOrigin::Signed(admin.clone()).into(),
+            bidder.clone(),
+            Role::RealEstateInvestor
+        ));
+        assert_ok!(Marketplace::<T>::place_auction_bid(
+            RawOrigin::Signed(bidder).into(),
+            listing_id,
+            token_price,
+            amount,
+            payment_asset,
+            None
         ));
     }
 }
@@ -466,7 +538,11 @@ mod benchmarks {
             token_price,
             token_amount,
             metadata,
-            tax_paid_by_developer,
+            crate::SaleTerms {
+                tax_paid_by_developer,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None,
+            },
         );
 
         let listing_id = 0;
@@ -491,7 +567,7 @@ mod benchmarks {
         let property_price = token_price.saturating_mul((token_amount as u128).into());
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         add_buyers_to_listing::<T>(b, payment_asset, property_price, admin.clone());
 
         let buyer: T::AccountId = account("buyer_final", 0, 0);
@@ -535,6 +611,7 @@ mod benchmarks {
                     listing_id,
                     base,
                     payment_asset,
+                    None,
                 ));
             }
             base + remainder
@@ -548,6 +625,7 @@ mod benchmarks {
             listing_id,
             buyer_amount,
             payment_asset,
+            None,
         );
 
         assert_eq!(
@@ -564,7 +642,7 @@ mod benchmarks {
     #[benchmark]
     fn buy_property_token_all_token(
         b: Linear<1, { <T as pallet::Config>::MaxPropertyToken::get() }>,
-        n: Linear<1, { <T as pallet::Config>::AcceptedAssets::get().len() as u32 }>,
+        n: Linear<1, { <T as pallet::Config>::MaxAcceptedAssets::get() }>,
     ) {
         let (seller, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
         let (region_id, location) = create_a_new_region::<T>(seller.clone(), admin.clone());
@@ -575,7 +653,7 @@ mod benchmarks {
         let property_price = token_price.saturating_mul((token_amount as u128).into());
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         add_buyers_to_listing::<T>(b - 1, payment_asset, property_price, admin.clone());
 
         let buyer: T::AccountId = account("buyer_final", 0, 0);
@@ -618,6 +696,7 @@ mod benchmarks {
                     listing_id,
                     base,
                     payment_asset,
+                    None,
                 ));
             }
             base + remainder
@@ -631,6 +710,7 @@ mod benchmarks {
             listing_id,
             buyer_amount,
             payment_asset,
+            None,
         );
 
         assert_eq!(
@@ -662,7 +742,7 @@ mod benchmarks {
         let property_price = token_price.saturating_mul((token_amount as u128).into());
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let buyer: T::AccountId = account("buyer", 0, 0);
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
             &buyer,
@@ -685,6 +765,7 @@ mod benchmarks {
             listing_id,
             1,
             payment_asset,
+            None,
         ));
         claim_buyers_property_token::<T>(token_amount - 1, listing_id);
 
@@ -717,7 +798,7 @@ mod benchmarks {
         let property_price = token_price.saturating_mul((token_amount as u128).into());
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let buyer: T::AccountId = account("buyer", 0, 0);
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
             &buyer,
@@ -740,6 +821,7 @@ mod benchmarks {
             listing_id,
             1,
             payment_asset,
+            None,
         ));
         claim_buyers_property_token::<T>(token_amount - 1, listing_id);
 
@@ -759,6 +841,7 @@ mod benchmarks {
             listing_id,
             1,
             payment_asset,
+            None,
         ));
         let expiry =
             frame_system::Pallet::<T>::block_number() + T::ClaimWindow::get() + 1u32.into();
@@ -791,7 +874,7 @@ mod benchmarks {
         let property_price = token_price.saturating_mul((token_amount as u128).into());
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let buyer: T::AccountId = account("buyer", 0, 0);
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
             &buyer,
@@ -819,6 +902,7 @@ mod benchmarks {
             listing_id,
             1,
             payment_asset,
+            None,
         ));
         claim_buyers_property_token::<T>(token_amount - 1, listing_id);
         assert_ok!(Marketplace::<T>::claim_property_token(
@@ -887,7 +971,7 @@ mod benchmarks {
             amount
         ));
 
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let relist_buyer: T::AccountId = account("relist_buyer", 0, 0);
         let deposit_amount = price.saturating_mul(T::ListingDeposit::get());
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
@@ -911,6 +995,7 @@ mod benchmarks {
             1,
             amount,
             payment_asset,
+            None,
         );
 
         assert!(!TokenListings::<T>::contains_key(1));
@@ -937,7 +1022,7 @@ mod benchmarks {
         let property_price = token_price.saturating_mul((token_amount as u128).into());
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         add_buyers_to_listing::<T>(
             token_amount - 2,
             payment_asset,
@@ -967,6 +1052,7 @@ mod benchmarks {
             listing_id,
             amount,
             payment_asset,
+            None,
         ));
 
         assert_eq!(
@@ -978,7 +1064,7 @@ mod benchmarks {
         //assert!(TokenBuyer::<T>::get(listing_id).contains(&buyer));
 
         #[extrinsic_call]
-        cancel_property_purchase(RawOrigin::Signed(buyer.clone()), 0);
+        cancel_property_purchase(RawOrigin::Signed(buyer.clone()), 0, amount);
 
         assert_eq!(
             OngoingObjectListing::<T>::get(listing_id)
@@ -1007,7 +1093,7 @@ mod benchmarks {
             amount
         ));
 
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let offerer: T::AccountId = account("offerer", 0, 0);
         let deposit_amount = price.saturating_mul(T::ListingDeposit::get());
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
@@ -1060,7 +1146,7 @@ mod benchmarks {
             amount
         ));
 
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let offerer: T::AccountId = account("offerer", 0, 0);
         let deposit_amount = price.saturating_mul(T::ListingDeposit::get());
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
@@ -1117,7 +1203,7 @@ mod benchmarks {
             amount
         ));
 
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let offerer: T::AccountId = account("offerer", 0, 0);
         let deposit_amount = price.saturating_mul(T::ListingDeposit::get());
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
@@ -1170,7 +1256,7 @@ mod benchmarks {
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
 
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let buyer: T::AccountId = account("buyer", 0, 0);
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
             &buyer,
@@ -1209,6 +1295,7 @@ mod benchmarks {
                 listing_id,
                 base,
                 payment_asset,
+                None,
             ));
         }
         let buyer_amount = base + remainder;
@@ -1217,6 +1304,7 @@ mod benchmarks {
             listing_id,
             buyer_amount,
             payment_asset,
+            None,
         ));
         assert_ok!(Marketplace::<T>::claim_property_token(
             RawOrigin::Signed(buyer.clone()).into(),
@@ -1295,7 +1383,7 @@ mod benchmarks {
         let property_price = token_price.saturating_mul((token_amount as u128).into());
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let buyer: T::AccountId = account("buyer", 0, 0);
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
             &buyer,
@@ -1334,6 +1422,7 @@ mod benchmarks {
                 listing_id,
                 base,
                 payment_asset,
+                None,
             ));
         }
         let buyer_amount = base + remainder;
@@ -1342,6 +1431,7 @@ mod benchmarks {
             listing_id,
             buyer_amount,
             payment_asset,
+            None,
         ));
         assert_ok!(Marketplace::<T>::claim_property_token(
             RawOrigin::Signed(buyer.clone()).into(),
@@ -1408,7 +1498,7 @@ mod benchmarks {
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
 
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let buyer: T::AccountId = account("buyer", 0, 0);
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
             &buyer,
@@ -1430,6 +1520,7 @@ mod benchmarks {
             listing_id,
             10,
             payment_asset,
+            None,
         ));
 
         let expiry =
@@ -1484,7 +1575,7 @@ mod benchmarks {
         let property_price = token_price.saturating_mul((token_amount as u128).into());
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let buyer: T::AccountId = account("buyer", 0, 0);
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
             &buyer,
@@ -1523,6 +1614,7 @@ mod benchmarks {
                 listing_id,
                 base,
                 payment_asset,
+                None,
             ));
         }
         let buyer_amount = base + remainder;
@@ -1531,6 +1623,7 @@ mod benchmarks {
             listing_id,
             buyer_amount,
             payment_asset,
+            None,
         ));
 
         assert_ok!(Marketplace::<T>::claim_property_token(
@@ -1558,6 +1651,7 @@ mod benchmarks {
                 listing_id,
                 base,
                 payment_asset,
+                None,
             ));
         }
 
@@ -1589,7 +1683,7 @@ mod benchmarks {
         let property_price = token_price.saturating_mul((token_amount as u128).into());
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let buyer: T::AccountId = account("buyer", 0, 0);
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
             &buyer,
@@ -1628,6 +1722,7 @@ mod benchmarks {
                 listing_id,
                 base,
                 payment_asset,
+                None,
             ));
         }
         let buyer_amount = base + remainder;
@@ -1636,6 +1731,7 @@ mod benchmarks {
             listing_id,
             buyer_amount,
             payment_asset,
+            None,
         ));
 
         let expiry =
@@ -2078,7 +2174,7 @@ mod benchmarks {
         let deposit_amount =
             property_price.saturating_mul(T::ListingDeposit::get()) / 100u128.into();
 
-        let payment_asset = T::AcceptedAssets::get()[0];
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
         let token_holder: T::AccountId = account("buyer", 0, 0);
         assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
             &token_holder,
@@ -2120,6 +2216,7 @@ mod benchmarks {
                     listing_id,
                     base,
                     payment_asset,
+                    None,
                 ));
             }
             base + remainder
@@ -2131,6 +2228,7 @@ mod benchmarks {
             listing_id,
             buyer_amount,
             payment_asset,
+            None,
         ));
         assert_ok!(Marketplace::<T>::claim_property_token(
             RawOrigin::Signed(token_holder.clone()).into(),
@@ -2221,5 +2319,365 @@ mod benchmarks {
         assert!(!pallet_real_estate_asset::PropertyOwner::<T>::get(asset_id).contains(&seller));
     }
 
+    #[benchmark]
+    fn place_bid(n: Linear<1, { <T as pallet::Config>::MaxOrdersPerAsset::get() }>) {
+        let (seller, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(seller.clone(), admin.clone());
+        create_registered_property::<T>(seller.clone(), region_id, location, admin.clone());
+
+        let asset_id = 0;
+        let price: <T as pallet::Config>::Balance = 5_000u32.into();
+        for i in 1..=n {
+            let token_owner: T::AccountId = account("buyer", i, i);
+            assert_ok!(Marketplace::<T>::unfreeze_spv_lawyer_token(
+                RawOrigin::Signed(token_owner.clone()).into(),
+                0,
+            ));
+            assert_ok!(Marketplace::<T>::place_ask(
+                RawOrigin::Signed(token_owner).into(),
+                asset_id,
+                price,
+                1
+            ));
+        }
+
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
+        let bidder: T::AccountId = account("bidder", 0, 0);
+        let deposit_amount = price.saturating_mul(T::ListingDeposit::get());
+        assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
+            &bidder,
+            deposit_amount.saturating_mul(20u32.into())
+        ));
+        assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
+            payment_asset,
+            &bidder,
+            price.saturating_mul((n as u128 * 100).into())
+        ));
+        assert_ok!(Whitelist::<T>::assign_role(
+            RawOrigin::Signed(admin).into(),
+            bidder.clone(),
+            Role::RealEstateInvestor
+        ));
+
+        #[extrinsic_call]
+        place_bid(
+            RawOrigin::Signed(bidder.clone()),
+            asset_id,
+            price,
+            n,
+            payment_asset,
+        );
+
+        assert!(AssetAsks::<T>::get(asset_id).is_empty());
+        assert!(AssetBids::<T>::get(asset_id).is_empty());
+        assert_eq!(
+            pallet_real_estate_asset::PropertyOwnerToken::<T>::get(asset_id, &bidder),
+            n
+        );
+    }
+
+    #[benchmark]
+    fn place_ask(n: Linear<1, { <T as pallet::Config>::MaxOrdersPerAsset::get() }>) {
+        let (seller, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(seller.clone(), admin.clone());
+        let token_owner =
+            create_registered_property::<T>(seller.clone(), region_id, location, admin.clone());
+
+        let asset_id = 0;
+        let price: <T as pallet::Config>::Balance = 5_000u32.into();
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
+        let deposit_amount = price.saturating_mul(T::ListingDeposit::get());
+        for i in 1..n {
+            let buyer: T::AccountId = account("buyer", i, i);
+            assert_ok!(Marketplace::<T>::unfreeze_spv_lawyer_token(
+                RawOrigin::Signed(buyer.clone()).into(),
+                0,
+            ));
+            assert_ok!(Marketplace::<T>::send_property_token(
+                RawOrigin::Signed(buyer).into(),
+                asset_id,
+                token_owner.clone(),
+                1
+            ));
+        }
+        for i in 0..n {
+            let bidder: T::AccountId = account("bidder", i, i);
+            assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
+                &bidder,
+                deposit_amount.saturating_mul(20u32.into())
+            ));
+            assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
+                payment_asset,
+                &bidder,
+                price.saturating_mul(100u32.into())
+            ));
+            assert_ok!(Whitelist::<T>::assign_role(
+                RawOrigin::Signed(admin.clone()).into(),
+                bidder.clone(),
+                Role::RealEstateInvestor
+            ));
+            assert_ok!(Marketplace::<T>::place_bid(
+                RawOrigin::Signed(bidder).into(),
+                asset_id,
+                price,
+                1,
+                payment_asset
+            ));
+        }
+
+        #[extrinsic_call]
+        place_ask(RawOrigin::Signed(token_owner.clone()), asset_id, price, n);
+
+        assert!(AssetBids::<T>::get(asset_id).is_empty());
+        assert!(AssetAsks::<T>::get(asset_id).is_empty());
+        assert_eq!(
+            pallet_real_estate_asset::PropertyOwnerToken::<T>::get(asset_id, &token_owner),
+            0
+        );
+    }
+
+    #[benchmark]
+    fn cancel_order() {
+        let (seller, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(seller.clone(), admin.clone());
+        create_registered_property::<T>(seller.clone(), region_id, location, admin.clone());
+
+        let asset_id = 0;
+        let price: <T as pallet::Config>::Balance = 5_000u32.into();
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
+        let bidder: T::AccountId = account("bidder", 0, 0);
+        let deposit_amount = price.saturating_mul(T::ListingDeposit::get());
+        assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
+            &bidder,
+            deposit_amount.saturating_mul(20u32.into())
+        ));
+        assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
+            payment_asset,
+            &bidder,
+            price.saturating_mul(100u32.into())
+        ));
+        assert_ok!(Whitelist::<T>::assign_role(
+            RawOrigin::Signed(admin).into(),
+            bidder.clone(),
+            Role::RealEstateInvestor
+        ));
+        assert_ok!(Marketplace::<T>::place_bid(
+            RawOrigin::Signed(bidder.clone()).into(),
+            asset_id,
+            price,
+            1,
+            payment_asset
+        ));
+
+        #[extrinsic_call]
+        cancel_order(RawOrigin::Signed(bidder), 0);
+
+        assert!(!Orders::<T>::contains_key(0));
+        assert!(AssetBids::<T>::get(asset_id).is_empty());
+    }
+
+    #[benchmark]
+    fn place_auction_bid(n: Linear<1, { <T as pallet::Config>::MaxPropertyToken::get() }>) {
+        let (seller, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(seller.clone(), admin.clone());
+        let token_amount: u32 = <T as pallet::Config>::MaxPropertyToken::get();
+        let token_price: <T as pallet::Config>::Balance = 1_000u32.into();
+        let listing_id = list_property_with_sale_mode::<T>(
+            seller,
+            region_id,
+            location,
+            token_amount,
+            token_price,
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::EnglishAuction,
+                soft_cap: None,
+            },
+        );
+        if n > 1 {
+            add_auction_bidders::<T>(n - 1, listing_id, token_price, n - 1, admin.clone());
+        }
+
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
+        let bidder: T::AccountId = account("bidder", 0, 0);
+        let bid_price = token_price.saturating_mul(2u32.into());
+        assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
+            &bidder,
+            bid_price.saturating_mul(T::ListingDeposit::get())
+        ));
+        assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
+            payment_asset,
+            &bidder,
+            bid_price.saturating_mul(2u32.into())
+        ));
+        assert_ok!(Whitelist::<T>::assign_role(
+            RawOrigin::Signed(admin).into(),
+            bidder.clone(),
+            Role::RealEstateInvestor
+        ));
+
+        #[extrinsic_call]
+        place_auction_bid(
+            RawOrigin::Signed(bidder.clone()),
+            listing_id,
+            bid_price,
+            1,
+            payment_asset,
+            None,
+        );
+
+        let bids = AuctionBids::<T>::get(listing_id);
+        assert_eq!(bids.len() as u32, n);
+        assert_eq!(bids[0].bidder, bidder);
+    }
+
+    #[benchmark]
+    fn settle_auction(n: Linear<3, { <T as pallet::Config>::MaxPropertyToken::get() }>) {
+        let (seller, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(seller.clone(), admin.clone());
+        let token_amount: u32 = <T as pallet::Config>::MaxPropertyToken::get();
+        let token_price: <T as pallet::Config>::Balance = 1_000u32.into();
+        let listing_id = list_property_with_sale_mode::<T>(
+            seller,
+            region_id,
+            location,
+            token_amount,
+            token_price,
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::EnglishAuction,
+                soft_cap: None,
+            },
+        );
+        add_auction_bidders::<T>(n, listing_id, token_price, token_amount, admin);
+
+        let listing = OngoingObjectListing::<T>::get(listing_id).unwrap();
+        frame_system::Pallet::<T>::set_block_number(listing.listing_expiry + 1u32.into());
+        let caller: T::AccountId = account("bidder", 1, 1);
+
+        #[extrinsic_call]
+        settle_auction(RawOrigin::Signed(caller), listing_id);
+
+        assert!(AuctionBids::<T>::get(listing_id).is_empty());
+        let listing = OngoingObjectListing::<T>::get(listing_id).unwrap();
+        assert_eq!(listing.listed_token_amount, 0);
+        assert_eq!(listing.unclaimed_token_amount, token_amount);
+    }
+
+    #[benchmark]
+    fn finalize_soft_cap() {
+        let (seller, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(seller.clone(), admin.clone());
+        let token_amount: u32 = <T as pallet::Config>::MaxPropertyToken::get();
+        let token_price: <T as pallet::Config>::Balance = 1_000u32.into();
+        let listing_id = list_property_with_sale_mode::<T>(
+            seller,
+            region_id,
+            location,
+            token_amount,
+            token_price,
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: Some(1u32.into()),
+            },
+        );
+        let listing = OngoingObjectListing::<T>::get(listing_id).unwrap();
+        OwnershipCap::<T>::insert(listing.asset_id, Permill::one());
+        let property_price = token_price.saturating_mul((token_amount as u128).into());
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
+        add_buyers_to_listing::<T>(1, payment_asset, property_price, admin);
+
+        let listing = OngoingObjectListing::<T>::get(listing_id).unwrap();
+        frame_system::Pallet::<T>::set_block_number(listing.listing_expiry + 1u32.into());
+        let caller: T::AccountId = account("buyer", 1, 1);
+
+        #[extrinsic_call]
+        finalize_soft_cap(RawOrigin::Signed(caller), listing_id);
+
+        let listing = OngoingObjectListing::<T>::get(listing_id).unwrap();
+        assert_eq!(listing.listed_token_amount, 0);
+        assert_eq!(listing.token_amount, 1);
+        assert!(listing.claim_expiry.is_some());
+    }
+
+    #[benchmark]
+    fn set_accepted_asset() {
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
+        assert_ok!(Marketplace::<T>::remove_accepted_asset(
+            RawOrigin::Root.into(),
+            payment_asset
+        ));
+        let min_amount: <T as pallet::Config>::Balance = 1_000u32.into();
+
+        #[extrinsic_call]
+        set_accepted_asset(RawOrigin::Root, payment_asset, min_amount, false);
+
+        assert_eq!(
+            AcceptedPaymentAssets::<T>::get()
+                .get(&payment_asset)
+                .copied(),
+            Some(AcceptedAssetDetails {
+                min_amount,
+                uses_exchange_rate: false
+            })
+        );
+    }
+
+    #[benchmark]
+    fn remove_accepted_asset() {
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
+
+        #[extrinsic_call]
+        remove_accepted_asset(RawOrigin::Root, payment_asset);
+
+        assert!(!AcceptedPaymentAssets::<T>::get().contains_key(&payment_asset));
+    }
+
+    #[benchmark]
+    fn process_refunds(n: Linear<1, { <T as pallet::Config>::MaxPropertyToken::get() - 1 }>) {
+        // A sold out listing is not refunded, so one token stays listed.
+        let (seller, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(seller.clone(), admin.clone());
+        let token_amount: u32 = <T as pallet::Config>::MaxPropertyToken::get();
+        let token_price: <T as pallet::Config>::Balance = 1_000u32.into();
+        let listing_id =
+            list_property_helper::<T>(seller, region_id, location, token_amount, token_price, true);
+        let property_price = token_price.saturating_mul((token_amount as u128).into());
+        let payment_asset = Marketplace::<T>::accepted_assets()[0];
+        add_buyers_to_listing::<T>(n, payment_asset, property_price, admin);
+
+        let listing = OngoingObjectListing::<T>::get(listing_id).unwrap();
+        frame_system::Pallet::<T>::set_block_number(listing.listing_expiry + 1u32.into());
+        let caller: T::AccountId = account("caller", 0, 0);
+
+        #[extrinsic_call]
+        process_refunds(RawOrigin::Signed(caller), listing_id, n);
+
+        assert!(OngoingObjectListing::<T>::get(listing_id).is_none());
+        assert!(ListingDeposits::<T>::get(listing_id).is_none());
+        assert_eq!(ListingTokenOwners::<T>::iter_prefix(listing_id).count(), 0);
+    }
+
+    #[benchmark]
+    fn set_ownership_cap() -> Result<(), BenchmarkError> {
+        let (seller, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(seller.clone(), admin);
+        let token_amount: u32 = <T as pallet::Config>::MaxPropertyToken::get();
+        let token_price: <T as pallet::Config>::Balance = 1_000u32.into();
+        let listing_id =
+            list_property_helper::<T>(seller, region_id, location, token_amount, token_price, true);
+        let asset_id = OngoingObjectListing::<T>::get(listing_id).unwrap().asset_id;
+        let origin = <T as pallet::Config>::OwnershipCapOrigin::try_successful_origin(&asset_id)
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let cap = Permill::from_percent(25);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, Some(cap));
+
+        assert_eq!(OwnershipCap::<T>::get(asset_id), Some(cap));
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(Marketplace, crate::mock::new_test_ext(), crate::mock::Test);
 }
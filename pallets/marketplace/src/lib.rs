@@ -13,6 +13,7 @@ pub mod types;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
@@ -31,12 +32,18 @@ use frame_support::{
 };
 
 use frame_support::sp_runtime::{
-    traits::{AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero},
-    Percent, Permill, Saturating,
+    helpers_128bit::multiply_by_rational_with_rounding,
+    traits::{
+        AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub,
+        MaybeSerializeDeserialize, One, Zero,
+    },
+    FixedPointNumber, FixedU128, Percent, Permill, Rounding, SaturatedConversion, Saturating,
 };
 
 use codec::Codec;
 
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
 use primitives::{MarketplaceFreezeReason, MarketplaceHoldReason};
 
 use types::*;
@@ -45,10 +52,12 @@ use pallet_real_estate_asset::traits::{
     PropertyTokenInspect, PropertyTokenManage, PropertyTokenOwnership, PropertyTokenSpvControl,
 };
 
-use pallet_xcavate_whitelist::{RolePermission, Role};
+use pallet_xcavate_whitelist::{InvestmentEligibility, RolePermission, Role};
 
 use pallet_regions::{LawyerManagement, Pallet as PalletRegions};
 
+use pallet_price_oracle::ExchangeRateProvider;
+
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
 pub type LocalAssetIdOf<T> = <<T as Config>::LocalCurrency as fungibles::Inspect<
@@ -65,13 +74,19 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::composite_enum]
     pub enum HoldReason {
         #[codec(index = 0)]
         ListingDepositReserve,
+        #[codec(index = 1)]
+        OrderDepositReserve,
     }
 
     /// The module configuration trait.
@@ -93,6 +108,7 @@ pub mod pallet {
             + TypeInfo
             + From<u128>
             + Into<<Self as pallet_real_estate_asset::Config>::Balance>
+            + MaybeSerializeDeserialize
             + Default;
 
         type NativeCurrency: fungible::Inspect<AccountIdOf<Self>>
@@ -171,13 +187,21 @@ pub mod pallet {
         #[pallet::constant]
         type MarketplaceFeePercentage: Get<<Self as pallet::Config>::Balance>;
 
-        #[pallet::constant]
-        type AcceptedAssets: Get<[u32; 2]>;
+        /// The origin that is allowed to manage the accepted payment assets.
+        type AssetRegistryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
         /// The maximum amount of accepted assets.
         #[pallet::constant]
         type MaxAcceptedAssets: Get<u32>;
 
+        /// The decimals of the reference unit in which token prices are quoted.
+        #[pallet::constant]
+        type ReferenceDecimals: Get<u8>;
+
+        /// The provider of the exchange rates for payment assets that are not pegged to the
+        /// reference unit.
+        type ExchangeRates: ExchangeRateProvider;
+
         type PropertyToken: PropertyTokenManage<Self>
             + PropertyTokenOwnership<Self>
             + PropertyTokenSpvControl<Self>
@@ -199,11 +223,12 @@ pub mod pallet {
             Success = Self::AccountId,
         >;
 
-        type CompliantOrigin: EnsureOriginWithArg<
-            Self::RuntimeOrigin,
-            Role,
-            Success = Self::AccountId,
-        >;
+        type CompliantOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, Role, Success = Self::AccountId>
+            + EnsureOriginWithArg<
+                Self::RuntimeOrigin,
+                InvestmentEligibility,
+                Success = Self::AccountId,
+            >;
 
         #[pallet::constant]
         type MinVotingQuorum: Get<Percent>;
@@ -212,11 +237,41 @@ pub mod pallet {
         type ClaimWindow: Get<BlockNumberFor<Self>>;
         #[pallet::constant]
         type MaxRelistAttempts: Get<u8>;
+
+        /// The maximum amount of open orders per side in the order book of a property.
+        #[pallet::constant]
+        type MaxOrdersPerAsset: Get<u32>;
+
+        /// The maximum amount of open orders of an account in the order book of a property.
+        #[pallet::constant]
+        type MaxOrdersPerAccount: Get<u32>;
+
+        /// A deposit for an open order in the order book.
+        #[pallet::constant]
+        type OrderDeposit: Get<<Self as pallet::Config>::Balance>;
+
+        /// The maximum amount of listings expiring per block for each round type.
+        #[pallet::constant]
+        type MaxListingsForBlock: Get<u32>;
+
+        /// The maximum weight that the processing of expired rounds may use in a block.
+        /// Listings that do not fit are carried over to the next block.
+        #[pallet::constant]
+        type ExpiryProcessingWeight: Get<Weight>;
+
+        /// The default maximum share of a property's token that a single investor, together
+        /// with the accounts linked to them, may hold.
+        #[pallet::constant]
+        type DefaultOwnershipCap: Get<Permill>;
+
+        /// The origin that is allowed to adjust the ownership cap of a property.
+        type OwnershipCapOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, u32>;
     }
 
     pub type RegionId = u16;
     pub type ListingId = u32;
     pub type ProposalId = u64;
+    pub type OrderId = u64;
     pub type LocationId<T> = BoundedVec<u8, <T as pallet_regions::Config>::PostcodeLimit>;
 
     pub(super) type PropertyListingDetailsType<T> = PropertyListingDetails<
@@ -231,6 +286,20 @@ pub mod pallet {
         T,
     >;
 
+    /// Amounts per payment asset.
+    pub(super) type PaymentAssetAmounts<T> = BoundedBTreeMap<
+        u32,
+        <T as pallet::Config>::Balance,
+        <T as pallet::Config>::MaxAcceptedAssets,
+    >;
+
+    /// The price, the tax and the total amount of a purchase.
+    pub(super) type PurchasePrice<T> = (
+        <T as pallet::Config>::Balance,
+        <T as pallet::Config>::Balance,
+        <T as pallet::Config>::Balance,
+    );
+
     /// The Id for the next token listing.
     #[pallet::storage]
     pub(super) type NextListingId<T: Config> = StorageValue<_, ListingId, ValueQuery>;
@@ -253,6 +322,34 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Index of the investors that own token of a listing, mirrors `TokenOwner`.
+    #[pallet::storage]
+    pub(super) type ListingTokenOwners<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ListingId,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        (),
+        OptionQuery,
+    >;
+
+    /// The funds paid in a payment asset for the token of a listing and their value in the
+    /// reference unit at the exchange rates of the purchases.
+    #[pallet::storage]
+    pub(super) type ListingPaymentValues<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ListingId,
+        Blake2_128Concat,
+        u32,
+        (
+            <T as pallet::Config>::Balance,
+            <T as pallet::Config>::Balance,
+        ),
+        ValueQuery,
+    >;
+
     /// Mapping of the listing id to the listing details of a token listing.
     #[pallet::storage]
     pub(super) type TokenListings<T: Config> =
@@ -334,6 +431,139 @@ pub mod pallet {
     #[pallet::storage]
     pub type ProposalCounter<T: Config> = StorageValue<_, ProposalId, ValueQuery>;
 
+    /// Mapping of the listing id to the bids of an english auction, sorted by price-time priority.
+    #[pallet::storage]
+    pub type AuctionBids<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ListingId,
+        BoundedVec<AuctionBid<T>, <T as pallet::Config>::MaxPropertyToken>,
+        ValueQuery,
+    >;
+
+    /// Mapping of the accepted payment assets to their details.
+    #[pallet::storage]
+    pub type AcceptedPaymentAssets<T: Config> = StorageValue<
+        _,
+        BoundedBTreeMap<
+            u32,
+            AcceptedAssetDetails<<T as pallet::Config>::Balance>,
+            <T as pallet::Config>::MaxAcceptedAssets,
+        >,
+        ValueQuery,
+    >;
+
+    /// Mapping of the asset id of a property to its ownership cap.
+    /// Properties without an entry use the `DefaultOwnershipCap`.
+    #[pallet::storage]
+    pub type OwnershipCap<T: Config> = StorageMap<_, Blake2_128Concat, u32, Permill, OptionQuery>;
+
+    /// Stores the listing ids that get processed on a given block after the listing expired.
+    #[pallet::storage]
+    pub type ListingRoundsExpiring<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ListingId, <T as pallet::Config>::MaxListingsForBlock>,
+        ValueQuery,
+    >;
+
+    /// Stores the listing ids that get processed on a given block after the claim window
+    /// expired.
+    #[pallet::storage]
+    pub type ClaimRoundsExpiring<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ListingId, <T as pallet::Config>::MaxListingsForBlock>,
+        ValueQuery,
+    >;
+
+    /// Stores the listing ids that get processed on a given block after the legal process
+    /// expired.
+    #[pallet::storage]
+    pub type LegalProcessRoundsExpiring<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ListingId, <T as pallet::Config>::MaxListingsForBlock>,
+        ValueQuery,
+    >;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// The accepted payment assets: asset id, minimum payment amount and whether the asset
+        /// is priced with an exchange rate.
+        pub accepted_assets: Vec<(u32, <T as pallet::Config>::Balance, bool)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            let mut accepted_assets = BoundedBTreeMap::new();
+            for &(asset_id, min_amount, uses_exchange_rate) in self.accepted_assets.iter() {
+                accepted_assets
+                    .try_insert(
+                        asset_id,
+                        AcceptedAssetDetails {
+                            min_amount,
+                            uses_exchange_rate,
+                        },
+                    )
+                    .expect("Too many accepted payment assets");
+            }
+            AcceptedPaymentAssets::<T>::put(accepted_assets);
+        }
+    }
+
+    /// The Id for the next order of the order book.
+    #[pallet::storage]
+    pub(super) type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+    /// Mapping of the order id to the order details.
+    #[pallet::storage]
+    pub type Orders<T: Config> =
+        StorageMap<_, Blake2_128Concat, OrderId, OrderDetails<T>, OptionQuery>;
+
+    /// Mapping of the asset id to the open bids, sorted by price-time priority.
+    #[pallet::storage]
+    pub type AssetBids<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<OrderId, <T as pallet::Config>::MaxOrdersPerAsset>,
+        ValueQuery,
+    >;
+
+    /// Mapping of the asset id to the open asks, sorted by price-time priority.
+    #[pallet::storage]
+    pub type AssetAsks<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<OrderId, <T as pallet::Config>::MaxOrdersPerAsset>,
+        ValueQuery,
+    >;
+
+    /// Mapping of the account and the asset id to the amount of open orders of the account in
+    /// the order book of the property.
+    #[pallet::storage]
+    pub type AccountOpenOrders<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        u32,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Stores the deposit information of an open order.
+    #[pallet::storage]
+    pub type OrderDeposits<T: Config> =
+        StorageMap<_, Blake2_128Concat, OrderId, (AccountIdOf<T>, <T as pallet::Config>::Balance)>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -350,6 +580,8 @@ pub mod pallet {
             tax_paid_by_developer: bool,
             listing_expiry: BlockNumberFor<T>,
             metadata_blob: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>,
+            sale_mode: SaleMode<<T as pallet::Config>::Balance>,
+            soft_cap: Option<<T as pallet::Config>::Balance>,
         },
         /// A token has been bought.
         RelistedTokenBought {
@@ -462,10 +694,16 @@ pub mod pallet {
             investor: AccountIdOf<T>,
             amount_returned: u32,
             new_tokens_remaining: u32,
-            principal_refunded_usdc: <T as pallet::Config>::Balance,
-            tax_refunded_usdc: <T as pallet::Config>::Balance,
-            principal_refunded_usdt: <T as pallet::Config>::Balance,
-            tax_refunded_usdt: <T as pallet::Config>::Balance,
+            principal_refunded: BoundedBTreeMap<
+                u32,
+                <T as pallet::Config>::Balance,
+                <T as pallet::Config>::MaxAcceptedAssets,
+            >,
+            tax_refunded: BoundedBTreeMap<
+                u32,
+                <T as pallet::Config>::Balance,
+                <T as pallet::Config>::MaxAcceptedAssets,
+            >,
         },
         /// Property token have been sent to another account.
         PropertyTokenSend {
@@ -537,8 +775,11 @@ pub mod pallet {
             listing_id: ListingId,
             lawyer_account: AccountIdOf<T>,
             lawyer_type: LegalProperty,
-            cost_in_usdc: <T as pallet::Config>::Balance,
-            cost_in_usdt: <T as pallet::Config>::Balance,
+            costs: BoundedBTreeMap<
+                u32,
+                <T as pallet::Config>::Balance,
+                <T as pallet::Config>::MaxAcceptedAssets,
+            >,
         },
         UnclaimedRelisted {
             listing_id: ListingId,
@@ -548,16 +789,115 @@ pub mod pallet {
         UnclaimedTokenWithdrawn {
             listing_id: ListingId,
             investor: AccountIdOf<T>,
-            principal_refunded_usdc: <T as pallet::Config>::Balance,
-            tax_refunded_usdc: <T as pallet::Config>::Balance,
-            principal_refunded_usdt: <T as pallet::Config>::Balance,
-            tax_refunded_usdt: <T as pallet::Config>::Balance,
+            principal_refunded: BoundedBTreeMap<
+                u32,
+                <T as pallet::Config>::Balance,
+                <T as pallet::Config>::MaxAcceptedAssets,
+            >,
+            tax_refunded: BoundedBTreeMap<
+                u32,
+                <T as pallet::Config>::Balance,
+                <T as pallet::Config>::MaxAcceptedAssets,
+            >,
         },
         /// A sale has been cancelled due to not all token have been claimed.
         SaleCancelledUnclaimed {
             listing_id: ListingId,
             unclaimed_amount: u32,
         },
+        /// A bid has been placed in an english auction.
+        AuctionBidPlaced {
+            listing_id: ListingId,
+            bidder: AccountIdOf<T>,
+            token_price: <T as pallet::Config>::Balance,
+            amount: u32,
+            payment_asset: u32,
+        },
+        /// A bid has been outbid and the held funds have been released.
+        AuctionBidOutbid {
+            listing_id: ListingId,
+            bidder: AccountIdOf<T>,
+            amount: u32,
+        },
+        /// The soft cap of a listing has been reached and the sale proceeds with the sold token.
+        SoftCapReached {
+            listing_id: ListingId,
+            asset_id: u32,
+            sold_token_amount: u32,
+            unsold_token_amount: u32,
+            claim_deadline: BlockNumberFor<T>,
+        },
+        /// A winning auction bid could not be filled and the held funds have been released.
+        AuctionBidRejected {
+            listing_id: ListingId,
+            bidder: AccountIdOf<T>,
+            amount: u32,
+            error: DispatchResult,
+        },
+        /// An english auction has been settled.
+        AuctionSettled {
+            listing_id: ListingId,
+            asset_id: u32,
+            sold_token_amount: u32,
+            successful: bool,
+        },
+        /// A payment asset has been added to the accepted assets or its details were updated.
+        AcceptedAssetSet {
+            asset_id: u32,
+            min_amount: <T as pallet::Config>::Balance,
+            uses_exchange_rate: bool,
+        },
+        /// A payment asset has been removed from the accepted assets.
+        AcceptedAssetRemoved { asset_id: u32 },
+        /// A new order has been placed in the order book.
+        OrderPlaced {
+            order_id: OrderId,
+            asset_id: u32,
+            owner: AccountIdOf<T>,
+            side: OrderSide,
+            token_price: <T as pallet::Config>::Balance,
+            amount: u32,
+            payment_asset: Option<u32>,
+        },
+        /// An order has been (partially) filled.
+        OrderFilled {
+            maker_order_id: OrderId,
+            taker_order_id: OrderId,
+            asset_id: u32,
+            buyer: AccountIdOf<T>,
+            seller: AccountIdOf<T>,
+            token_price: <T as pallet::Config>::Balance,
+            amount: u32,
+            payment_asset: u32,
+            maker_amount_remaining: u32,
+        },
+        /// An order has been cancelled.
+        OrderCancelled {
+            order_id: OrderId,
+            asset_id: u32,
+            owner: AccountIdOf<T>,
+            amount_returned: u32,
+        },
+        /// A listing expired without selling all token and the investors can withdraw their funds.
+        ListingExpired {
+            listing_id: ListingId,
+            unsold_token_amount: u32,
+        },
+        /// The legal process of a listing expired and the investors can withdraw their funds.
+        LegalProcessExpired { listing_id: ListingId },
+        /// Processing of an expired listing round failed.
+        ListingProcessingFailed {
+            listing_id: ListingId,
+            error: DispatchResult,
+        },
+        /// A batch of refunds of a listing has been processed.
+        RefundsProcessed {
+            listing_id: ListingId,
+            refunded_investors: u32,
+            completed: bool,
+        },
+        /// The ownership cap of a property has been set.
+        OwnershipCapSet { asset_id: u32, cap: Permill },
     }
 
     // Errors inform users that something went wrong.
@@ -663,6 +1003,68 @@ pub mod pallet {
         InsufficientRefundableTokens,
         /// The amount for voting has to be higher than 0.
         ZeroVoteAmount,
+        /// There is no vote to withdraw.
+        NoVoteToWithdraw,
+        /// The order could not be found.
+        OrderNotFound,
+        /// The order book of this property is full.
+        OrderBookFull,
+        /// The account has reached the maximum amount of open orders for this property.
+        TooManyOpenOrders,
+        /// The action is not possible with the sale mode of the listing.
+        InvalidSaleMode,
+        /// The bid is too low.
+        BidTooLow,
+        /// A user can only place one bid per auction.
+        OnlyOneBidPerUser,
+        /// The auction has not been settled yet.
+        AuctionNotSettled,
+        /// No bids have been placed in the auction.
+        NoAuctionBids,
+        /// The soft cap must be between one and the token amount of the property.
+        InvalidSoftCap,
+        /// The soft cap of the listing has not been reached.
+        SoftCapNotReached,
+        /// The soft cap of the listing has been reached.
+        SoftCapReached,
+        /// The maximum amount of accepted payment assets has been reached.
+        TooManyAcceptedAssets,
+        /// The payment is below the minimum amount of the payment asset.
+        PaymentBelowMinimum,
+        /// There is no exchange rate available for the payment asset.
+        ExchangeRateUnavailable,
+        /// The price exceeds the maximum payment of the investor.
+        SlippageExceeded,
+        /// The listing has no pending refunds.
+        NoRefundsPending,
+        /// The ownership cap must be greater than zero.
+        InvalidOwnershipCap,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(3, 3);
+            // Processes the listings whose rounds expired in the previous block.
+            for round in [
+                ExpiryRound::Listing,
+                ExpiryRound::ClaimWindow,
+                ExpiryRound::LegalProcess,
+            ] {
+                Self::process_expiring_round(round, n, &mut weight);
+            }
+            weight
+        }
+
+        fn integrity_test() {
+            assert!(
+                <T as pallet::Config>::WeightInfo::settle_auction(
+                    <T as pallet::Config>::MaxPropertyToken::get()
+                )
+                .all_lte(T::ExpiryProcessingWeight::get()),
+                "The expiry processing weight must fit the settlement of a full auction."
+            );
+        }
     }
 
     #[pallet::call]
@@ -678,7 +1080,8 @@ pub mod pallet {
         /// - `token_price`: The price of a single token.
         /// - `token_amount`: The amount of tokens for a object.
         /// - `data`: The Metadata of the nft.
-        /// - `tax_paid_by_developer`: Bool if the tax is paid by the real estate developer or not.
+        /// - `sale_terms`: Whether the developer pays the tax, the sale mode and the soft cap of the
+        /// primary sale.
         ///
         /// Emits `ObjectListed` event when successful
         #[pallet::call_index(0)]
@@ -692,12 +1095,17 @@ pub mod pallet {
             token_price: <T as pallet::Config>::Balance,
             token_amount: u32,
             data: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>,
-            tax_paid_by_developer: bool,
+            sale_terms: SaleTerms<<T as pallet::Config>::Balance>,
         ) -> DispatchResult {
             let signer = <T as pallet::Config>::PermissionOrigin::ensure_origin(
                 origin,
                 &Role::RealEstateDeveloper,
             )?;
+            let SaleTerms {
+                tax_paid_by_developer,
+                sale_mode,
+                soft_cap,
+            } = sale_terms;
             ensure!(token_amount > 0, Error::<T>::AmountCannotBeZero);
             ensure!(
                 token_amount <= <T as pallet::Config>::MaxPropertyToken::get(),
@@ -708,7 +1116,17 @@ pub mod pallet {
                 Error::<T>::TokenAmountTooLow
             );
             ensure!(!token_price.is_zero(), Error::<T>::InvalidTokenPrice);
-
+            if let SaleMode::DutchAuction {
+                floor_price,
+                price_decrement,
+            } = sale_mode
+            {
+                ensure!(
+                    !floor_price.is_zero() && floor_price <= token_price,
+                    Error::<T>::InvalidTokenPrice
+                );
+                ensure!(!price_decrement.is_zero(), Error::<T>::InvalidTokenPrice);
+            }
             let region_info =
                 pallet_regions::RegionDetails::<T>::get(region).ok_or(Error::<T>::RegionUnknown)?;
             ensure!(
@@ -720,17 +1138,18 @@ pub mod pallet {
             let listing_duration = region_info.listing_duration;
             let listing_expiry = current_block_number.saturating_add(listing_duration);
 
-            let mut collected_funds = BoundedBTreeMap::default();
-            for &asset_id in T::AcceptedAssets::get().iter() {
-                collected_funds
-                    .try_insert(asset_id, Default::default())
-                    .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
-            }
+            let collected_funds = Self::create_initial_funds()?;
 
             // Calculate listing deposit
             let property_price = token_price
                 .checked_mul(&((token_amount as u128).into()))
                 .ok_or(Error::<T>::MultiplyError)?;
+            if let Some(soft_cap) = soft_cap {
+                ensure!(
+                    !soft_cap.is_zero() && soft_cap <= property_price,
+                    Error::<T>::InvalidSoftCap
+                );
+            }
             let deposit_amount = T::ListingDeposit::get();
 
             // Check signer balance before doing anything
@@ -766,8 +1185,12 @@ pub mod pallet {
                 claim_expiry: None,
                 relist_count: Zero::zero(),
                 unclaimed_token_amount: Zero::zero(),
+                sale_mode: sale_mode.clone(),
+                listed_at: current_block_number,
+                soft_cap,
             };
             OngoingObjectListing::<T>::insert(listing_id, property_details);
+            Self::schedule_expiry(ExpiryRound::Listing, listing_id, listing_expiry)?;
 
             <T as pallet::Config>::NativeCurrency::hold(
                 &HoldReason::ListingDepositReserve.into(),
@@ -793,6 +1216,8 @@ pub mod pallet {
                 tax_paid_by_developer,
                 listing_expiry,
                 metadata_blob: data,
+                sale_mode,
+                soft_cap,
             });
             Ok(())
         }
@@ -805,32 +1230,38 @@ pub mod pallet {
         /// - `listing_id`: The listing that the investor wants to buy token from.
         /// - `amount`: The amount of token that the investor wants to buy.
         /// - `payment_asset`: Asset in which the investor wants to pay.
+        /// - `max_payment`: The maximum amount of the payment asset including fees and taxes
+        ///   the investor is willing to pay.
         ///
         /// Emits `PropertyTokenBought` event when successful.
         #[pallet::call_index(1)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::buy_property_token_all_token(
             <T as pallet::Config>::MaxPropertyToken::get(),
-            <T as pallet::Config>::AcceptedAssets::get().len() as u32
+            <T as pallet::Config>::MaxAcceptedAssets::get()
         ))]
         pub fn buy_property_token(
             origin: OriginFor<T>,
             listing_id: ListingId,
             amount: u32,
             payment_asset: u32,
+            max_payment: Option<<T as pallet::Config>::Balance>,
         ) -> DispatchResult {
             let signer = <T as pallet::Config>::CompliantOrigin::ensure_origin(
-                origin,
+                origin.clone(),
                 &Role::RealEstateInvestor,
             )?;
             ensure!(amount > 0, Error::<T>::AmountCannotBeZero);
-            let accepted_payment_assets = T::AcceptedAssets::get();
             ensure!(
-                accepted_payment_assets.contains(&payment_asset),
+                AcceptedPaymentAssets::<T>::get().contains_key(&payment_asset),
                 Error::<T>::PaymentAssetNotSupported
             );
 
             let mut property_details =
                 OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::TokenNotForSale)?;
+            ensure!(
+                property_details.sale_mode != SaleMode::EnglishAuction,
+                Error::<T>::InvalidSaleMode
+            );
             ensure!(
                 property_details.listed_token_amount >= amount,
                 Error::<T>::NotEnoughTokenAvailable
@@ -844,45 +1275,36 @@ pub mod pallet {
             let region_info = pallet_regions::RegionDetails::<T>::get(asset_details.region)
                 .ok_or(Error::<T>::RegionUnknown)?;
 
-            let fee_percent = T::MarketplaceFeePercentage::get();
-            ensure!(
-                fee_percent < 100u128.into(),
-                Error::<T>::InvalidFeePercentage
-            );
-            let tax_percent = region_info.tax;
-            ensure!(
-                tax_percent < Permill::from_percent(100),
-                Error::<T>::InvalidTaxPercentage
+            let token_price = Self::current_token_price(&property_details);
+            let pending_token = TokenOwner::<T>::get(&signer, listing_id)
+                .filter(|token_details| token_details.relist_count == property_details.relist_count)
+                .map_or(0, |token_details| token_details.token_amount);
+            let owned_token = <T as pallet::Config>::PropertyToken::get_token_balance(
+                property_details.asset_id,
+                &signer,
             );
-            let total_supply = property_details.token_amount;
-            let max_tokens = total_supply
-                .checked_mul(50)
-                .ok_or(Error::<T>::MultiplyError)?
-                .checked_div(100)
-                .ok_or(Error::<T>::DivisionError)?;
-
-            let transfer_price = property_details
-                .token_price
-                .checked_mul(&((amount as u128).into()))
-                .ok_or(Error::<T>::MultiplyError)?;
-            let fee = transfer_price
-                .checked_mul(&fee_percent)
-                .ok_or(Error::<T>::MultiplyError)?
-                .checked_div(&100u128.into())
-                .ok_or(Error::<T>::DivisionError)?;
-            let tax = tax_percent.mul_floor(transfer_price);
-
-            let base_price = transfer_price
-                .checked_add(&fee)
-                .ok_or(Error::<T>::ArithmeticOverflow)?;
-
-            let total_transfer_price = if property_details.tax_paid_by_developer {
-                base_price
-            } else {
-                base_price
-                    .checked_add(&tax)
-                    .ok_or(Error::<T>::ArithmeticOverflow)?
-            };
+            Self::ensure_investment_eligible(
+                origin,
+                asset_details.region,
+                pending_token
+                    .saturating_add(owned_token)
+                    .saturating_add(amount),
+                token_price,
+            )?;
+            let (transfer_price, tax, total_transfer_price) = Self::calculate_purchase_price(
+                token_price,
+                amount,
+                payment_asset,
+                region_info.tax,
+                property_details.tax_paid_by_developer,
+            )?;
+            Self::ensure_minimum_payment(payment_asset, transfer_price)?;
+            if let Some(max_payment) = max_payment {
+                ensure!(
+                    total_transfer_price <= max_payment,
+                    Error::<T>::SlippageExceeded
+                );
+            }
 
             T::ForeignAssetsHolder::hold(
                 payment_asset,
@@ -899,53 +1321,21 @@ pub mod pallet {
                 .unclaimed_token_amount
                 .checked_add(amount)
                 .ok_or(Error::<T>::ArithmeticOverflow)?;
-            TokenOwner::<T>::try_mutate_exists(&signer, listing_id, |maybe_token_owner_details| {
-                if maybe_token_owner_details.is_none() {
-                    let initial_funds = Self::create_initial_funds()?;
-                    *maybe_token_owner_details = Some(TokenOwnerDetails {
-                        token_amount: 0,
-                        paid_funds: initial_funds.clone(),
-                        paid_tax: initial_funds,
-                        relist_count: property_details.relist_count,
-                    });
-                }
-
-                let token_owner_details = maybe_token_owner_details
-                    .as_mut()
-                    .ok_or(Error::<T>::TokenOwnerNotFound)?;
-                ensure!(
-                    token_owner_details.relist_count == property_details.relist_count,
-                    Error::<T>::StillHasUnclaimedToken
-                );
-                let claimed_token_amount = <T as pallet::Config>::PropertyToken::get_token_balance(
-                    property_details.asset_id,
-                    &signer,
-                );
-                let new_token_amount = token_owner_details
-                    .token_amount
-                    .checked_add(amount)
-                    .ok_or(Error::<T>::ArithmeticOverflow)?;
-                let total_investor_token_amount = new_token_amount
-                    .checked_add(claimed_token_amount)
-                    .ok_or(Error::<T>::ArithmeticOverflow)?;
-                ensure!(
-                    total_investor_token_amount < max_tokens,
-                    Error::<T>::ExceedsMaxOwnership
-                );
-                token_owner_details.token_amount = new_token_amount;
-
-                Self::update_map(
-                    &mut token_owner_details.paid_funds,
-                    payment_asset,
-                    transfer_price,
-                )?;
-
-                if !property_details.tax_paid_by_developer {
-                    Self::update_map(&mut token_owner_details.paid_tax, payment_asset, tax)?;
-                }
-
-                Ok::<(), DispatchError>(())
-            })?;
+            Self::add_token_purchase(
+                &signer,
+                listing_id,
+                &property_details,
+                amount,
+                payment_asset,
+                transfer_price,
+                tax,
+            )?;
+            Self::add_listing_payment(
+                listing_id,
+                payment_asset,
+                transfer_price,
+                Self::to_reference_units(transfer_price, payment_asset)?,
+            )?;
 
             let asset_id = property_details.asset_id;
             let tax_paid_by_developer = property_details.tax_paid_by_developer;
@@ -954,6 +1344,7 @@ pub mod pallet {
                 let current_block_number = <frame_system::Pallet<T>>::block_number();
                 let expiry_block = current_block_number.saturating_add(T::ClaimWindow::get());
                 property_details.claim_expiry = Some(expiry_block);
+                Self::schedule_expiry(ExpiryRound::ClaimWindow, listing_id, expiry_block)?;
                 Self::deposit_event(Event::<T>::PrimarySaleSoldOut {
                     listing_id,
                     asset_id,
@@ -1005,6 +1396,7 @@ pub mod pallet {
             );
             let token_details =
                 TokenOwner::<T>::take(&signer, listing_id).ok_or(Error::<T>::TokenOwnerNotFound)?;
+            ListingTokenOwners::<T>::remove(listing_id, &signer);
             ensure!(
                 token_details.relist_count == property_details.relist_count,
                 Error::<T>::NoValidTokenToClaim
@@ -1150,6 +1542,7 @@ pub mod pallet {
                 };
                 property_details.claim_expiry = None;
                 PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
+                Self::schedule_expiry(ExpiryRound::LegalProcess, listing_id, expiry_block)?;
                 Self::deposit_event(Event::<T>::AllPropertyTokenClaimed {
                     listing_id,
                     asset_id,
@@ -1185,59 +1578,7 @@ pub mod pallet {
                 origin,
                 &Role::RealEstateInvestor,
             )?;
-            let mut property_details =
-                OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
-            let claim_expiry = property_details
-                .claim_expiry
-                .ok_or(Error::<T>::NoClaimWindow)?;
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            ensure!(
-                current_block > claim_expiry,
-                Error::<T>::ClaimWindowNotExpired
-            );
-
-            let unclaimed_amount = property_details.unclaimed_token_amount;
-            ensure!(unclaimed_amount > 0, Error::<T>::AllTokensClaimed);
-            if property_details.relist_count >= T::MaxRelistAttempts::get() {
-                property_details.relist_count = property_details
-                    .relist_count
-                    .checked_add(1)
-                    .ok_or(Error::<T>::ArithmeticOverflow)?;
-                property_details.claim_expiry = None;
-                OngoingObjectListing::<T>::insert(listing_id, &property_details);
-                RefundClaimedToken::<T>::insert(
-                    listing_id,
-                    property_details
-                        .token_amount
-                        .saturating_sub(unclaimed_amount),
-                );
-                Self::deposit_event(Event::<T>::SaleCancelledUnclaimed {
-                    listing_id,
-                    unclaimed_amount,
-                });
-            } else {
-                property_details.listed_token_amount = property_details
-                    .listed_token_amount
-                    .checked_add(unclaimed_amount)
-                    .ok_or(Error::<T>::ArithmeticOverflow)?;
-                property_details.relist_count = property_details
-                    .relist_count
-                    .checked_add(1)
-                    .ok_or(Error::<T>::ArithmeticOverflow)?;
-                property_details.unclaimed_token_amount = 0;
-                property_details.claim_expiry = None;
-                let possible_listing_expiry = current_block.saturating_add(T::ClaimWindow::get());
-                if property_details.listing_expiry < possible_listing_expiry {
-                    property_details.listing_expiry = possible_listing_expiry;
-                }
-                OngoingObjectListing::<T>::insert(listing_id, &property_details);
-                Self::deposit_event(Event::<T>::UnclaimedRelisted {
-                    listing_id,
-                    amount: unclaimed_amount,
-                    relist_count: property_details.relist_count,
-                });
-            }
-            Ok(())
+            Self::do_finalize_claim_window(listing_id)
         }
 
         /// Confirm that a spv has been created.
@@ -1339,6 +1680,8 @@ pub mod pallet {
         /// - `listing_id`: The listing that the investor wants to buy from.
         /// - `amount`: The amount of token the investor wants to buy.
         /// - `payment_asset`: Asset in which the investor wants to pay.
+        /// - `max_payment`: The maximum amount of the payment asset the investor is willing to
+        ///   pay.
         ///
         /// Emits `RelistedTokenBought` event when successful.
         #[pallet::call_index(6)]
@@ -1348,13 +1691,14 @@ pub mod pallet {
             listing_id: ListingId,
             amount: u32,
             payment_asset: u32,
+            max_payment: Option<<T as pallet::Config>::Balance>,
         ) -> DispatchResult {
             let buyer = <T as pallet::Config>::CompliantOrigin::ensure_origin(
-                origin,
+                origin.clone(),
                 &Role::RealEstateInvestor,
             )?;
             ensure!(
-                T::AcceptedAssets::get().contains(&payment_asset),
+                AcceptedPaymentAssets::<T>::get().contains_key(&payment_asset),
                 Error::<T>::PaymentAssetNotSupported
             );
             ensure!(amount > 0, Error::<T>::AmountCannotBeZero);
@@ -1365,10 +1709,22 @@ pub mod pallet {
                 Error::<T>::NotEnoughTokenAvailable
             );
             Self::restrict_ownership(listing_details.asset_id, &buyer, amount)?;
-            let price = listing_details
+            Self::ensure_relisted_investment_eligible(
+                origin,
+                &buyer,
+                &listing_details,
+                amount,
+                listing_details.token_price,
+            )?;
+            let reference_price = listing_details
                 .token_price
                 .checked_mul(&((amount as u128).into()))
                 .ok_or(Error::<T>::MultiplyError)?;
+            let price = Self::to_payment_asset_units(reference_price, payment_asset)?;
+            Self::ensure_minimum_payment(payment_asset, price)?;
+            if let Some(max_payment) = max_payment {
+                ensure!(price <= max_payment, Error::<T>::SlippageExceeded);
+            }
             Self::buying_token_process(
                 listing_id,
                 &buyer,
@@ -1382,11 +1738,13 @@ pub mod pallet {
         }
 
         /// Lets a investor cancel the property token purchase.
+        /// The investor can cancel all or only a part of the purchased token.
         ///
         /// The origin must be Signed and the sender must have sufficient funds free.
         ///
         /// Parameters:
         /// - `listing_id`: The listing that the investor wants to buy from.
+        /// - `amount`: The amount of purchased token that the investor wants to cancel.
         ///
         /// Emits `InvestmentCancelled` event when successful.
         #[pallet::call_index(7)]
@@ -1394,13 +1752,19 @@ pub mod pallet {
         pub fn cancel_property_purchase(
             origin: OriginFor<T>,
             listing_id: ListingId,
+            amount: u32,
         ) -> DispatchResult {
             let signer = <T as pallet::Config>::PermissionOrigin::ensure_origin(
                 origin,
                 &Role::RealEstateInvestor,
             )?;
+            ensure!(amount > 0, Error::<T>::AmountCannotBeZero);
             let mut property_details =
                 OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
+            ensure!(
+                property_details.sale_mode != SaleMode::EnglishAuction,
+                Error::<T>::InvalidSaleMode
+            );
             ensure!(
                 property_details.listing_expiry > <frame_system::Pallet<T>>::block_number(),
                 Error::<T>::ListingExpired
@@ -1410,36 +1774,52 @@ pub mod pallet {
                 Error::<T>::PropertyAlreadySold
             );
 
-            let token_details: TokenOwnerDetails<T> =
-                TokenOwner::<T>::take(&signer, listing_id).ok_or(Error::<T>::TokenOwnerNotFound)?;
+            let mut token_details: TokenOwnerDetails<T> =
+                TokenOwner::<T>::get(&signer, listing_id).ok_or(Error::<T>::TokenOwnerNotFound)?;
+            ensure!(
+                token_details.relist_count == property_details.relist_count,
+                Error::<T>::NoPermission
+            );
             ensure!(
                 !token_details.token_amount.is_zero(),
                 Error::<T>::NoTokenBought
             );
+            ensure!(
+                token_details.token_amount >= amount,
+                Error::<T>::NotEnoughToken
+            );
+
+            // Split off the cancelled share of the purchase
+            let refund_details = Self::split_token_owner_details(&mut token_details, amount)?;
 
             // Process refunds
-            let (
-                principal_refunded_usdc,
-                tax_refunded_usdc,
-                principal_refunded_usdt,
-                tax_refunded_usdt,
-            ) = Self::unfreeze_token_with_refunds(&token_details, &signer)?;
+            let (principal_refunded, tax_refunded) =
+                Self::unfreeze_token_with_refunds(&refund_details, Some(&token_details), &signer)?;
+            Self::remove_listing_payments(listing_id, &refund_details.paid_funds)?;
             property_details.listed_token_amount = property_details
                 .listed_token_amount
-                .checked_add(token_details.token_amount)
+                .checked_add(amount)
                 .ok_or(Error::<T>::ArithmeticOverflow)?;
+            property_details.unclaimed_token_amount = property_details
+                .unclaimed_token_amount
+                .checked_sub(amount)
+                .ok_or(Error::<T>::ArithmeticUnderflow)?;
 
+            if token_details.token_amount.is_zero() {
+                TokenOwner::<T>::remove(&signer, listing_id);
+                ListingTokenOwners::<T>::remove(listing_id, &signer);
+            } else {
+                TokenOwner::<T>::insert(&signer, listing_id, token_details);
+            }
             OngoingObjectListing::<T>::insert(listing_id, &property_details);
 
             Self::deposit_event(Event::<T>::InvestmentCancelled {
                 listing_id,
                 investor: signer,
-                amount_returned: token_details.token_amount,
+                amount_returned: amount,
                 new_tokens_remaining: property_details.listed_token_amount,
-                principal_refunded_usdc,
-                tax_refunded_usdc,
-                principal_refunded_usdt,
-                tax_refunded_usdt,
+                principal_refunded,
+                tax_refunded,
             });
             Ok(())
         }
@@ -1465,11 +1845,11 @@ pub mod pallet {
             payment_asset: u32,
         ) -> DispatchResult {
             let signer = <T as pallet::Config>::CompliantOrigin::ensure_origin(
-                origin,
+                origin.clone(),
                 &Role::RealEstateInvestor,
             )?;
             ensure!(
-                T::AcceptedAssets::get().contains(&payment_asset),
+                AcceptedPaymentAssets::<T>::get().contains_key(&payment_asset),
                 Error::<T>::PaymentAssetNotSupported
             );
             ensure!(
@@ -1484,9 +1864,17 @@ pub mod pallet {
             );
             ensure!(amount > 0, Error::<T>::AmountCannotBeZero);
             ensure!(!offer_price.is_zero(), Error::<T>::InvalidTokenPrice);
+            Self::ensure_relisted_investment_eligible(
+                origin,
+                &signer,
+                &listing_details,
+                amount,
+                Self::to_reference_units(offer_price, payment_asset)?,
+            )?;
             let price = offer_price
                 .checked_mul(&((amount as u128).into()))
                 .ok_or(Error::<T>::MultiplyError)?;
+            Self::ensure_minimum_payment(payment_asset, price)?;
 
             T::ForeignAssetsHolder::hold(
                 payment_asset,
@@ -1636,7 +2024,6 @@ pub mod pallet {
                 RefundToken::<T>::get(listing_id).ok_or(Error::<T>::TokenNotRefunded)?;
             let property_details =
                 OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
-            let property_account = Self::property_account_id(property_details.asset_id);
             let token_amount = <T as pallet::Config>::PropertyToken::get_token_balance(
                 property_details.asset_id,
                 &signer,
@@ -1647,52 +2034,17 @@ pub mod pallet {
                 .checked_sub(token_amount)
                 .ok_or(Error::<T>::InsufficientRefundableTokens)?;
 
-            for &asset in T::AcceptedAssets::get().iter() {
-                if let Some(investor_funds) = property_details.investor_funds.get(&signer).cloned()
-                {
-                    if let Some(paid_funds) = investor_funds.paid_funds.get(&asset).copied() {
-                        // Transfer USDT funds to owner account
-                        Self::transfer_funds(&property_account, &signer, paid_funds, asset)?;
-                    }
-                }
-            }
-            <T as pallet::Config>::LocalCurrency::transfer(
-                property_details.asset_id,
-                &signer,
-                &property_account,
-                token_amount.into(),
-                Preservation::Expendable,
-            )?;
+            Self::refund_investor(&property_details, &signer, token_amount, false)?;
             if refund_infos.refund_amount == 0 {
-                T::PropertyToken::burn_property_token(property_details.asset_id)?;
                 Self::refund_investors_with_fees(
                     &property_details,
                     refund_infos.property_lawyer_details,
                 )?;
-                let (depositor, deposit_amount) =
-                    ListingDeposits::<T>::take(listing_id).ok_or(Error::<T>::ListingNotFound)?;
-                <T as pallet::Config>::NativeCurrency::release(
-                    &HoldReason::ListingDepositReserve.into(),
-                    &depositor,
-                    deposit_amount,
-                    Precision::Exact,
-                )?;
-                let native_balance =
-                    <T as pallet::Config>::NativeCurrency::balance(&property_account);
-                if !native_balance.is_zero() {
-                    <T as pallet::Config>::NativeCurrency::transfer(
-                        &property_account,
-                        &property_details.real_estate_developer,
-                        native_balance,
-                        Preservation::Expendable,
-                    )?;
-                }
-                OngoingObjectListing::<T>::remove(listing_id);
+                Self::close_failed_listing(listing_id, &property_details)?;
                 RefundToken::<T>::remove(listing_id);
             } else {
                 RefundToken::<T>::insert(listing_id, refund_infos);
             }
-            T::PropertyToken::remove_property_token_ownership(property_details.asset_id, &signer)?;
             Self::deposit_event(Event::<T>::RejectedFundsWithdrawn { signer, listing_id });
             Ok(())
         }
@@ -1717,7 +2069,6 @@ pub mod pallet {
             )?;
             let property_details =
                 OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
-            let property_account = Self::property_account_id(property_details.asset_id);
             let token_amount = <T as pallet::Config>::PropertyToken::get_token_balance(
                 property_details.asset_id,
                 &signer,
@@ -1734,18 +2085,11 @@ pub mod pallet {
                         property_lawyer_details.legal_process_expiry < current_block_number,
                         Error::<T>::LegalProcessOngoing
                     );
-                    if let Some(real_estate_developer_lawyer_id) =
-                        property_lawyer_details.real_estate_developer_lawyer
-                    {
-                        PalletRegions::<T>::decrement_active_cases(
-                            &real_estate_developer_lawyer_id,
-                        )?;
-                    }
-                    if let Some(spv_lawyer_id) = property_lawyer_details.spv_lawyer {
-                        PalletRegions::<T>::decrement_active_cases(&spv_lawyer_id)?;
-                    }
-                    PropertyLawyer::<T>::remove(listing_id);
-                    RefundLegalExpired::<T>::insert(listing_id, property_details.token_amount);
+                    Self::expire_legal_process(
+                        listing_id,
+                        &property_details,
+                        &property_lawyer_details,
+                    )?;
                     property_details.token_amount
                 }
             };
@@ -1754,60 +2098,14 @@ pub mod pallet {
                 .checked_sub(token_amount)
                 .ok_or(Error::<T>::InsufficientRefundableTokens)?;
 
-            for &asset in T::AcceptedAssets::get().iter() {
-                if let Some(investor_funds) = property_details.investor_funds.get(&signer).cloned()
-                {
-                    if let Some(paid_funds) = investor_funds.paid_funds.get(&asset).copied() {
-                        if let Some(paid_fee) = investor_funds.paid_fee.get(&asset).copied() {
-                            let transfer_amount = paid_funds
-                                .checked_add(&paid_fee)
-                                .ok_or(Error::<T>::ArithmeticOverflow)?;
-                            Self::transfer_funds(
-                                &property_account,
-                                &signer,
-                                transfer_amount,
-                                asset,
-                            )?;
-                        } else {
-                            Self::transfer_funds(&property_account, &signer, paid_funds, asset)?;
-                        }
-                    }
-                }
-            }
-            <T as pallet::Config>::LocalCurrency::transfer(
-                property_details.asset_id,
-                &signer,
-                &property_account,
-                token_amount.into(),
-                Preservation::Expendable,
-            )?;
+            Self::refund_investor(&property_details, &signer, token_amount, true)?;
             if refund_infos == 0 {
-                T::PropertyToken::burn_property_token(property_details.asset_id)?;
                 T::PropertyToken::clear_token_owners(property_details.asset_id)?;
-                let (depositor, deposit_amount) =
-                    ListingDeposits::<T>::take(listing_id).ok_or(Error::<T>::ListingNotFound)?;
-                <T as pallet::Config>::NativeCurrency::release(
-                    &HoldReason::ListingDepositReserve.into(),
-                    &depositor,
-                    deposit_amount,
-                    Precision::Exact,
-                )?;
-                let native_balance =
-                    <T as pallet::Config>::NativeCurrency::balance(&property_account);
-                if !native_balance.is_zero() {
-                    <T as pallet::Config>::NativeCurrency::transfer(
-                        &property_account,
-                        &property_details.real_estate_developer,
-                        native_balance,
-                        Preservation::Expendable,
-                    )?;
-                }
-                OngoingObjectListing::<T>::remove(listing_id);
+                Self::close_failed_listing(listing_id, &property_details)?;
                 RefundLegalExpired::<T>::remove(listing_id);
             } else {
                 RefundLegalExpired::<T>::insert(listing_id, refund_infos);
             }
-            T::PropertyToken::remove_property_token_ownership(property_details.asset_id, &signer)?;
             Self::deposit_event(Event::<T>::ExpiredFundsWithdrawn { signer, listing_id });
             Ok(())
         }
@@ -1838,9 +2136,14 @@ pub mod pallet {
                 !property_details.listed_token_amount.is_zero(),
                 Error::<T>::PropertyAlreadySold
             );
+            ensure!(
+                !Self::soft_cap_reached(listing_id, &property_details)?,
+                Error::<T>::SoftCapReached
+            );
 
             let token_details =
                 TokenOwner::<T>::take(&signer, listing_id).ok_or(Error::<T>::TokenOwnerNotFound)?;
+            ListingTokenOwners::<T>::remove(listing_id, &signer);
             ensure!(
                 !token_details.token_amount.is_zero(),
                 Error::<T>::NoTokenBought,
@@ -1857,27 +2160,7 @@ pub mod pallet {
             // Check if all tokens are returned
             if property_details.listed_token_amount >= property_details.token_amount {
                 // Listing is over, burn and clean everything
-                T::PropertyToken::burn_property_token(property_details.asset_id)?;
-                let (depositor, deposit_amount) =
-                    ListingDeposits::<T>::take(listing_id).ok_or(Error::<T>::ListingNotFound)?;
-                <T as pallet::Config>::NativeCurrency::release(
-                    &HoldReason::ListingDepositReserve.into(),
-                    &depositor,
-                    deposit_amount,
-                    Precision::Exact,
-                )?;
-                let property_account = Self::property_account_id(property_details.asset_id);
-                let native_balance =
-                    <T as pallet::Config>::NativeCurrency::balance(&property_account);
-                if !native_balance.is_zero() {
-                    <T as pallet::Config>::NativeCurrency::transfer(
-                        &property_account,
-                        &property_details.real_estate_developer,
-                        native_balance,
-                        Preservation::Expendable,
-                    )?;
-                }
-                OngoingObjectListing::<T>::remove(listing_id);
+                Self::close_failed_listing(listing_id, &property_details)?;
             } else {
                 OngoingObjectListing::<T>::insert(listing_id, &property_details);
             }
@@ -1922,6 +2205,10 @@ pub mod pallet {
                 property_details.listed_token_amount >= property_details.token_amount,
                 Error::<T>::TokenNotReturned
             );
+            ensure!(
+                AuctionBids::<T>::get(listing_id).is_empty(),
+                Error::<T>::AuctionNotSettled
+            );
             // Listing is over, burn and clean everything
             T::PropertyToken::burn_property_token(property_details.asset_id)?;
             let (depositor, deposit_amount) =
@@ -1943,6 +2230,11 @@ pub mod pallet {
                 )?;
             }
             OngoingObjectListing::<T>::remove(listing_id);
+            let _ = ListingPaymentValues::<T>::clear_prefix(
+                listing_id,
+                <T as pallet::Config>::MaxAcceptedAssets::get(),
+                None,
+            );
             Self::deposit_event(Event::<T>::DeveloperDepositReturned {
                 listing_id,
                 developer: signer,
@@ -1973,7 +2265,6 @@ pub mod pallet {
                 RefundClaimedToken::<T>::take(listing_id).ok_or(Error::<T>::TokenNotRefunded)?;
             let property_details =
                 OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
-            let property_account = Self::property_account_id(property_details.asset_id);
             let token_amount = <T as pallet::Config>::PropertyToken::get_token_balance(
                 property_details.asset_id,
                 &signer,
@@ -1983,55 +2274,13 @@ pub mod pallet {
                 .checked_sub(token_amount)
                 .ok_or(Error::<T>::InsufficientRefundableTokens)?;
 
-            if let Some(investor_funds) = property_details.investor_funds.get(&signer) {
-                for (asset, paid_funds) in investor_funds.paid_funds.iter() {
-                    let paid_fees = investor_funds
-                        .paid_fee
-                        .get(asset)
-                        .copied()
-                        .unwrap_or_default();
-
-                    let transfer_amount = paid_funds
-                        .checked_add(&paid_fees)
-                        .ok_or(Error::<T>::ArithmeticOverflow)?;
-
-                    // Transfer funds to owner account
-                    Self::transfer_funds(&property_account, &signer, transfer_amount, *asset)?;
-                }
-            }
-            <T as pallet::Config>::LocalCurrency::transfer(
-                property_details.asset_id,
-                &signer,
-                &property_account,
-                token_amount.into(),
-                Preservation::Expendable,
-            )?;
+            Self::refund_investor(&property_details, &signer, token_amount, true)?;
             if refund_amount == 0 {
-                T::PropertyToken::burn_property_token(property_details.asset_id)?;
                 T::PropertyToken::clear_token_owners(property_details.asset_id)?;
-                let (depositor, deposit_amount) =
-                    ListingDeposits::<T>::take(listing_id).ok_or(Error::<T>::ListingNotFound)?;
-                <T as pallet::Config>::NativeCurrency::release(
-                    &HoldReason::ListingDepositReserve.into(),
-                    &depositor,
-                    deposit_amount,
-                    Precision::Exact,
-                )?;
-                let native_balance =
-                    <T as pallet::Config>::NativeCurrency::balance(&property_account);
-                if !native_balance.is_zero() {
-                    <T as pallet::Config>::NativeCurrency::transfer(
-                        &property_account,
-                        &property_details.real_estate_developer,
-                        native_balance,
-                        Preservation::Expendable,
-                    )?;
-                }
-                OngoingObjectListing::<T>::remove(listing_id);
+                Self::close_failed_listing(listing_id, &property_details)?;
             } else {
                 RefundClaimedToken::<T>::insert(listing_id, refund_amount);
             }
-            T::PropertyToken::remove_property_token_ownership(property_details.asset_id, &signer)?;
             Self::deposit_event(Event::<T>::RejectedFundsWithdrawn { signer, listing_id });
             Ok(())
         }
@@ -2053,6 +2302,7 @@ pub mod pallet {
             )?;
             let token_details: TokenOwnerDetails<T> =
                 TokenOwner::<T>::take(&signer, listing_id).ok_or(Error::<T>::TokenOwnerNotFound)?;
+            ListingTokenOwners::<T>::remove(listing_id, &signer);
             if let Some(property_details) = OngoingObjectListing::<T>::get(listing_id) {
                 ensure!(
                     property_details.relist_count > token_details.relist_count,
@@ -2064,20 +2314,14 @@ pub mod pallet {
                 Error::<T>::NoTokenBought
             );
 
-            let (
-                principal_refunded_usdc,
-                tax_refunded_usdc,
-                principal_refunded_usdt,
-                tax_refunded_usdt,
-            ) = Self::unfreeze_token_with_refunds(&token_details, &signer)?;
+            let (principal_refunded, tax_refunded) =
+                Self::unfreeze_token_with_refunds(&token_details, None, &signer)?;
 
             Self::deposit_event(Event::<T>::UnclaimedTokenWithdrawn {
                 listing_id,
                 investor: signer,
-                principal_refunded_usdc,
-                tax_refunded_usdc,
-                principal_refunded_usdt,
-                tax_refunded_usdt,
+                principal_refunded,
+                tax_refunded,
             });
             Ok(())
         }
@@ -2122,6 +2366,10 @@ pub mod pallet {
                     !property_details.listed_token_amount.is_zero(),
                     Error::<T>::PropertyAlreadySold
                 );
+                ensure!(
+                    property_details.sale_mode != SaleMode::EnglishAuction,
+                    Error::<T>::InvalidSaleMode
+                );
                 property_details.token_price = new_price;
                 Ok::<(), DispatchError>(())
             })?;
@@ -2207,18 +2455,7 @@ pub mod pallet {
                 Error::<T>::WrongRegion
             );
 
-            let [asset_id_usdc, asset_id_usdt] = T::AcceptedAssets::get();
-            let collected_fee_usdt = property_details
-                .collected_fees
-                .get(&asset_id_usdt)
-                .ok_or(Error::<T>::AssetNotSupported)?;
-            let collected_fee_usdc = property_details
-                .collected_fees
-                .get(&asset_id_usdc)
-                .ok_or(Error::<T>::AssetNotSupported)?;
-            let collected_fees = collected_fee_usdt
-                .checked_add(collected_fee_usdc)
-                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            let collected_fees = Self::total_collected_fees(listing_id, &property_details)?;
             ensure!(collected_fees >= costs, Error::<T>::CostsTooHigh);
             match legal_side {
                 LegalProperty::RealEstateDeveloperSide => {
@@ -2315,7 +2552,7 @@ pub mod pallet {
         ///
         /// Parameters:
         /// - `listing_id`: The listing from the property.
-        /// - `vote`: Must be either a Yes vote or a No vote.
+        /// - `vote`: A Yes or No vote, or Withdraw to take back a previous vote.
         /// - `amount`: The amount of property token that the investor is using for voting.
         ///
         /// Emits `VotedOnLawyer` event when successful.
@@ -2340,10 +2577,20 @@ pub mod pallet {
                 proposal_details.expiry_block > current_block_number,
                 Error::<T>::VotingExpired
             );
-            ensure!(amount > 0, Error::<T>::ZeroVoteAmount);
+            ensure!(
+                vote == Vote::Withdraw || amount > 0,
+                Error::<T>::ZeroVoteAmount
+            );
             let voting_power =
                 T::PropertyToken::get_token_balance(proposal_details.asset_id, &signer);
-            ensure!(voting_power >= amount, Error::<T>::NotEnoughToken);
+            ensure!(
+                vote == Vote::Withdraw || voting_power >= amount,
+                Error::<T>::NotEnoughToken
+            );
+            ensure!(
+                vote != Vote::Withdraw || UserLawyerVote::<T>::contains_key(proposal_id, &signer),
+                Error::<T>::NoVoteToWithdraw
+            );
 
             let mut new_yes_power = 0u32;
             let mut new_no_power = 0u32;
@@ -2373,16 +2620,10 @@ pub mod pallet {
                                     .no_voting_power
                                     .saturating_sub(previous_vote.power)
                             }
+                            Vote::Withdraw => {}
                         }
                     }
 
-                    T::AssetsFreezer::increase_frozen(
-                        proposal_details.asset_id,
-                        &MarketplaceFreezeReason::SpvLawyerVoting,
-                        &signer,
-                        amount.into(),
-                    )?;
-
                     match vote {
                         Vote::Yes => {
                             current_vote.yes_voting_power =
@@ -2392,20 +2633,28 @@ pub mod pallet {
                             current_vote.no_voting_power =
                                 current_vote.no_voting_power.saturating_add(amount)
                         }
+                        Vote::Withdraw => return Ok(()),
                     }
 
+                    T::AssetsFreezer::increase_frozen(
+                        proposal_details.asset_id,
+                        &MarketplaceFreezeReason::SpvLawyerVoting,
+                        &signer,
+                        amount.into(),
+                    )?;
+
                     *maybe_vote_record = Some(VoteRecord {
                         vote: vote.clone(),
                         asset_id: proposal_details.asset_id,
                         power: amount,
                     });
 
-                    new_yes_power = current_vote.yes_voting_power;
-                    new_no_power = current_vote.no_voting_power;
-
                     Ok::<(), DispatchError>(())
                 })?;
 
+                new_yes_power = current_vote.yes_voting_power;
+                new_no_power = current_vote.no_voting_power;
+
                 Ok::<(), DispatchError>(())
             })?;
             Self::deposit_event(Event::VotedOnLawyer {
@@ -2457,36 +2706,22 @@ pub mod pallet {
             if approve && !expired {
                 property_lawyer_details.real_estate_developer_lawyer =
                     Some(proposal.lawyer.clone());
-                let [asset_id_usdc, asset_id_usdt] = T::AcceptedAssets::get();
-                let collected_fee_usdt = property_details
-                    .collected_fees
-                    .get(&asset_id_usdt)
-                    .copied()
-                    .ok_or(Error::<T>::AssetNotSupported)?;
-                let collected_fee_usdc = property_details
-                    .collected_fees
-                    .get(&asset_id_usdc)
-                    .copied()
-                    .ok_or(Error::<T>::AssetNotSupported)?;
-                let collected_fees = collected_fee_usdt
-                    .checked_add(&collected_fee_usdc)
-                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                let collected_fees = Self::total_collected_fees(listing_id, &property_details)?;
                 ensure!(collected_fees >= proposal.costs, Error::<T>::CostsTooHigh);
 
-                let (usdc_cost, usdt_cost) = Self::allocate_fees(
+                Self::allocate_fees(
+                    listing_id,
                     &mut property_lawyer_details.real_estate_developer_lawyer_costs,
-                    asset_id_usdt,
-                    collected_fee_usdt,
-                    asset_id_usdc,
-                    collected_fee_usdc,
+                    &property_details.collected_fees,
                     proposal.costs,
                 )?;
                 Self::deposit_event(Event::<T>::LawyerCostsAllocated {
                     listing_id,
                     lawyer_account: proposal.lawyer.clone(),
                     lawyer_type: LegalProperty::RealEstateDeveloperSide,
-                    cost_in_usdc: usdc_cost,
-                    cost_in_usdt: usdt_cost,
+                    costs: property_lawyer_details
+                        .real_estate_developer_lawyer_costs
+                        .clone(),
                 });
                 PalletRegions::<T>::increment_active_cases(&proposal.lawyer)?;
                 PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
@@ -2532,14 +2767,10 @@ pub mod pallet {
                 OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
             let mut property_lawyer_details =
                 PropertyLawyer::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
-            let asset_details = <T as pallet::Config>::PropertyToken::get_property_asset_info(
-                property_details.asset_id,
-            )
-            .ok_or(Error::<T>::NoObjectFound)?;
             let total_votes = voting_result
                 .yes_voting_power
                 .saturating_add(voting_result.no_voting_power);
-            let total_supply = asset_details.token_amount;
+            let total_supply = property_details.token_amount;
 
             ensure!(total_supply > Zero::zero(), Error::<T>::NoObjectFound);
 
@@ -2555,37 +2786,20 @@ pub mod pallet {
 
             if is_approved && !expired {
                 property_lawyer_details.spv_lawyer = Some(proposal.lawyer.clone());
-                let [asset_id_usdc, asset_id_usdt] = T::AcceptedAssets::get();
-
-                let collected_fee_usdt = property_details
-                    .collected_fees
-                    .get(&asset_id_usdt)
-                    .copied()
-                    .ok_or(Error::<T>::AssetNotSupported)?;
-                let collected_fee_usdc = property_details
-                    .collected_fees
-                    .get(&asset_id_usdc)
-                    .copied()
-                    .ok_or(Error::<T>::AssetNotSupported)?;
-                let collected_fees = collected_fee_usdt
-                    .checked_add(&collected_fee_usdc)
-                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                let collected_fees = Self::total_collected_fees(listing_id, &property_details)?;
                 ensure!(collected_fees >= proposal.costs, Error::<T>::CostsTooHigh);
 
-                let (usdc_cost, usdt_cost) = Self::allocate_fees(
+                Self::allocate_fees(
+                    listing_id,
                     &mut property_lawyer_details.spv_lawyer_costs,
-                    asset_id_usdt,
-                    collected_fee_usdt,
-                    asset_id_usdc,
-                    collected_fee_usdc,
+                    &property_details.collected_fees,
                     proposal.costs,
                 )?;
                 Self::deposit_event(Event::<T>::LawyerCostsAllocated {
                     listing_id,
                     lawyer_account: proposal.lawyer.clone(),
                     lawyer_type: LegalProperty::SpvSide,
-                    cost_in_usdc: usdc_cost,
-                    cost_in_usdt: usdt_cost,
+                    costs: property_lawyer_details.spv_lawyer_costs.clone(),
                 });
                 PalletRegions::<T>::increment_active_cases(&proposal.lawyer)?;
                 PropertyLawyer::<T>::insert(listing_id, property_lawyer_details.clone());
@@ -2805,7 +3019,8 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Lets the sender send property token to another account.
+        /// Lets the sender send property token to another account. Sender and receiver have to be
+        /// compliant investors.
         ///
         /// The origin must be Signed and the sender must have sufficient funds free.
         ///
@@ -2823,12 +3038,12 @@ pub mod pallet {
             receiver: AccountIdOf<T>,
             token_amount: u32,
         ) -> DispatchResult {
-            let sender = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+            let sender = <T as pallet::Config>::CompliantOrigin::ensure_origin(
                 origin,
                 &Role::RealEstateInvestor,
             )?;
             ensure!(
-                <T as pallet::Config>::Whitelist::has_role(
+                <T as pallet::Config>::Whitelist::is_compliant(
                     &receiver,
                     Role::RealEstateInvestor
                 ),
@@ -2851,553 +3066,2731 @@ pub mod pallet {
             });
             Ok(())
         }
-    }
-
-    impl<T: Config> Pallet<T> {
-        /// Get the account id of the pallet
-        pub fn account_id() -> AccountIdOf<T> {
-            <T as pallet::Config>::PalletId::get().into_account_truncating()
-        }
 
-        pub fn property_account_id(asset_id: u32) -> AccountIdOf<T> {
-            <T as pallet::Config>::PalletId::get().into_sub_account_truncating(("pr", asset_id))
-        }
+        /// Places a bid for property token in the order book of a property.
+        /// The bid gets matched against the open asks with price-time priority and
+        /// the remaining amount stays in the order book.
+        /// An order that stays in the order book holds the order deposit of the investor.
+        ///
+        /// The origin must be Signed and the sender must have sufficient funds free.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset id of the property.
+        /// - `token_price`: The maximum price in the payment asset the investor wants to pay for
+        ///   a single token.
+        /// - `amount`: The amount of token the investor wants to buy.
+        /// - `payment_asset`: Asset in which the investor wants to pay.
+        ///
+        /// Emits `OrderPlaced` event when successful.
+        /// Emits `OrderFilled` event for every order that has been matched.
+        #[pallet::call_index(27)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::place_bid(
+            <T as pallet::Config>::MaxOrdersPerAsset::get()
+        ))]
+        pub fn place_bid(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            token_price: <T as pallet::Config>::Balance,
+            amount: u32,
+            payment_asset: u32,
+        ) -> DispatchResult {
+            let buyer = <T as pallet::Config>::CompliantOrigin::ensure_origin(
+                origin.clone(),
+                &Role::RealEstateInvestor,
+            )?;
+            ensure!(
+                AcceptedPaymentAssets::<T>::get().contains_key(&payment_asset),
+                Error::<T>::PaymentAssetNotSupported
+            );
+            ensure!(amount > 0, Error::<T>::AmountCannotBeZero);
+            ensure!(!token_price.is_zero(), Error::<T>::InvalidTokenPrice);
+            let price = token_price
+                .checked_mul(&((amount as u128).into()))
+                .ok_or(Error::<T>::MultiplyError)?;
+            Self::ensure_minimum_payment(payment_asset, price)?;
+            let asset_details = T::PropertyToken::get_if_property_finalized(asset_id)?;
+            Self::restrict_ownership(asset_id, &buyer, amount)?;
+            let owned_token =
+                <T as pallet::Config>::PropertyToken::get_token_balance(asset_id, &buyer);
+            Self::ensure_investment_eligible(
+                origin,
+                asset_details.region,
+                owned_token.saturating_add(amount),
+                Self::to_reference_units(token_price, payment_asset)?,
+            )?;
 
-        /// Get the account id of the treasury pallet
-        pub fn treasury_account_id() -> AccountIdOf<T> {
-            <T as pallet::Config>::TreasuryId::get().into_account_truncating()
+            let order_id = NextOrderId::<T>::get();
+            let mut order = OrderDetails {
+                owner: buyer.clone(),
+                asset_id,
+                side: OrderSide::Bid,
+                token_price,
+                amount,
+                payment_asset: Some(payment_asset),
+                created_at: <frame_system::Pallet<T>>::block_number(),
+            };
+            Self::deposit_event(Event::<T>::OrderPlaced {
+                order_id,
+                asset_id,
+                owner: buyer.clone(),
+                side: OrderSide::Bid,
+                token_price,
+                amount,
+                payment_asset: Some(payment_asset),
+            });
+            Self::match_bid(order_id, &mut order, payment_asset)?;
+            if order.amount > 0 {
+                T::ForeignAssetsHolder::hold(
+                    payment_asset,
+                    &MarketplaceHoldReason::Marketplace,
+                    &buyer,
+                    order.get_total_amount()?,
+                )?;
+                Self::insert_order(order_id, order)?;
+            }
+            let next_order_id = order_id
+                .checked_add(1)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            NextOrderId::<T>::put(next_order_id);
+            Ok(())
         }
 
-        pub fn next_listing_id(listing_id: ListingId) -> Result<ListingId, Error<T>> {
-            listing_id
+        /// Places an ask for property token in the order book of a property.
+        /// The ask gets matched against the open bids with price-time priority and
+        /// the remaining amount stays in the order book.
+        /// An order that stays in the order book holds the order deposit of the investor.
+        ///
+        /// The origin must be Signed and the sender must have sufficient funds free.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset id of the property.
+        /// - `token_price`: The minimum price in the reference unit the investor wants to receive
+        ///   for a single token.
+        /// - `amount`: The amount of token the investor wants to sell.
+        ///
+        /// Emits `OrderPlaced` event when successful.
+        /// Emits `OrderFilled` event for every order that has been matched.
+        #[pallet::call_index(28)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::place_ask(
+            <T as pallet::Config>::MaxOrdersPerAsset::get()
+        ))]
+        pub fn place_ask(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            token_price: <T as pallet::Config>::Balance,
+            amount: u32,
+        ) -> DispatchResult {
+            let seller = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                origin,
+                &Role::RealEstateInvestor,
+            )?;
+            ensure!(amount > 0, Error::<T>::AmountCannotBeZero);
+            ensure!(!token_price.is_zero(), Error::<T>::InvalidTokenPrice);
+            T::PropertyToken::get_if_property_finalized(asset_id)?;
+            T::PropertyToken::escrow_property_token(asset_id, &seller, amount)?;
+
+            let order_id = NextOrderId::<T>::get();
+            let mut order = OrderDetails {
+                owner: seller.clone(),
+                asset_id,
+                side: OrderSide::Ask,
+                token_price,
+                amount,
+                payment_asset: None,
+                created_at: <frame_system::Pallet<T>>::block_number(),
+            };
+            Self::deposit_event(Event::<T>::OrderPlaced {
+                order_id,
+                asset_id,
+                owner: seller,
+                side: OrderSide::Ask,
+                token_price,
+                amount,
+                payment_asset: None,
+            });
+            Self::match_ask(order_id, &mut order)?;
+            if order.amount > 0 {
+                Self::insert_order(order_id, order)?;
+            }
+            let next_order_id = order_id
                 .checked_add(1)
-                .ok_or(Error::<T>::ArithmeticOverflow)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            NextOrderId::<T>::put(next_order_id);
+            Ok(())
         }
 
-        /// Sends the token to the new owners and the funds to the real estate developer once all 100 token
-        /// of a collection are sold.
-        fn execute_deal(
-            listing_id: u32,
-            property_lawyer_details: PropertyLawyerDetails<T>,
+        /// Cancels an open order of the order book.
+        /// The held funds of a bid or the listed token of an ask are returned to the owner and the
+        /// order deposit is released.
+        ///
+        /// The origin must be Signed and the sender must have sufficient funds free.
+        ///
+        /// Parameters:
+        /// - `order_id`: The order that the investor wants to cancel.
+        ///
+        /// Emits `OrderCancelled` event when successful.
+        #[pallet::call_index(29)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_order())]
+        pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+            let signer = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                origin,
+                &Role::RealEstateInvestor,
+            )?;
+            let order = Orders::<T>::take(order_id).ok_or(Error::<T>::OrderNotFound)?;
+            ensure!(order.owner == signer, Error::<T>::NoPermission);
+            match order.side {
+                OrderSide::Bid => {
+                    AssetBids::<T>::mutate(order.asset_id, |bids| {
+                        bids.retain(|id| *id != order_id)
+                    });
+                    let payment_asset = order
+                        .payment_asset
+                        .ok_or(Error::<T>::PaymentAssetNotSupported)?;
+                    T::ForeignAssetsHolder::release(
+                        payment_asset,
+                        &MarketplaceHoldReason::Marketplace,
+                        &signer,
+                        order.get_total_amount()?,
+                        Precision::Exact,
+                    )?;
+                }
+                OrderSide::Ask => {
+                    AssetAsks::<T>::mutate(order.asset_id, |asks| {
+                        asks.retain(|id| *id != order_id)
+                    });
+                    T::PropertyToken::release_escrowed_property_token(
+                        order.asset_id,
+                        &signer,
+                        order.amount,
+                    )?;
+                }
+            }
+            Self::release_order_slot(order_id, &signer, order.asset_id)?;
+            Self::deposit_event(Event::<T>::OrderCancelled {
+                order_id,
+                asset_id: order.asset_id,
+                owner: signer,
+                amount_returned: order.amount,
+            });
+            Ok(())
+        }
+
+        /// Places a bid on token of a primary sale in english auction mode.
+        /// The funds of the bid are held until the auction is settled or the bid has been outbid.
+        ///
+        /// The origin must be Signed and the sender must have sufficient funds free.
+        ///
+        /// Parameters:
+        /// - `listing_id`: The listing that the investor wants to bid on.
+        /// - `token_price`: The price the investor offers for a single token.
+        /// - `amount`: The amount of token the investor wants to buy.
+        /// - `payment_asset`: Asset in which the investor wants to pay.
+        /// - `max_payment`: The maximum amount of the payment asset including fees and taxes
+        ///   the investor is willing to pay.
+        ///
+        /// Emits `AuctionBidPlaced` event when successful.
+        #[pallet::call_index(30)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::place_auction_bid(
+            <T as pallet::Config>::MaxPropertyToken::get()
+        ))]
+        pub fn place_auction_bid(
+            origin: OriginFor<T>,
+            listing_id: ListingId,
+            token_price: <T as pallet::Config>::Balance,
+            amount: u32,
+            payment_asset: u32,
+            max_payment: Option<<T as pallet::Config>::Balance>,
         ) -> DispatchResult {
+            let signer = <T as pallet::Config>::CompliantOrigin::ensure_origin(
+                origin.clone(),
+                &Role::RealEstateInvestor,
+            )?;
+            ensure!(amount > 0, Error::<T>::AmountCannotBeZero);
+            ensure!(
+                AcceptedPaymentAssets::<T>::get().contains_key(&payment_asset),
+                Error::<T>::PaymentAssetNotSupported
+            );
             let property_details =
-                OngoingObjectListing::<T>::take(listing_id).ok_or(Error::<T>::ListingNotFound)?;
+                OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::TokenNotForSale)?;
+            ensure!(
+                property_details.sale_mode == SaleMode::EnglishAuction,
+                Error::<T>::InvalidSaleMode
+            );
+            ensure!(
+                property_details.listed_token_amount >= amount,
+                Error::<T>::NotEnoughTokenAvailable
+            );
+            ensure!(
+                property_details.listing_expiry > <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::ListingExpired
+            );
+            ensure!(
+                token_price >= property_details.token_price,
+                Error::<T>::BidTooLow
+            );
             let asset_details =
-                T::PropertyToken::get_property_asset_info(property_details.asset_id)
-                    .ok_or(Error::<T>::NoObjectFound)?;
-            let treasury_id = Self::treasury_account_id();
-            let property_account = Self::property_account_id(property_details.asset_id);
-            let region = pallet_regions::RegionDetails::<T>::get(asset_details.region)
+                T::PropertyToken::get_if_spv_not_created(property_details.asset_id)?;
+            let region_info = pallet_regions::RegionDetails::<T>::get(asset_details.region)
                 .ok_or(Error::<T>::RegionUnknown)?;
 
-            // Get lawyer accounts
-            let real_estate_developer_lawyer_id = property_lawyer_details
-                .real_estate_developer_lawyer
-                .ok_or(Error::<T>::LawyerNotFound)?;
-            let spv_lawyer_id = property_lawyer_details
-                .spv_lawyer
-                .ok_or(Error::<T>::LawyerNotFound)?;
-            PalletRegions::<T>::decrement_active_cases(&real_estate_developer_lawyer_id)?;
-            PalletRegions::<T>::decrement_active_cases(&spv_lawyer_id)?;
-
-            let mut developer_payout = Payout::default();
-            let mut spv_lawyer_payout = Payout::default();
-            let mut treasury_payout = Payout::default();
-            let mut region_owner_payout = Payout::default();
+            let max_tokens =
+                Self::max_token_ownership(property_details.asset_id, property_details.token_amount);
+            let owned_token_amount = <T as pallet::Config>::PropertyToken::get_token_balance(
+                property_details.asset_id,
+                &signer,
+            )
+            .checked_add(
+                TokenOwner::<T>::get(&signer, listing_id)
+                    .map(|token_details| token_details.token_amount)
+                    .unwrap_or_default(),
+            )
+            .ok_or(Error::<T>::ArithmeticOverflow)?
+            .checked_add(Self::linked_token_amount(
+                property_details.asset_id,
+                Some(listing_id),
+                &signer,
+            )?)
+            .ok_or(Error::<T>::ArithmeticOverflow)?;
+            ensure!(
+                owned_token_amount
+                    .checked_add(amount)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?
+                    <= max_tokens,
+                Error::<T>::ExceedsMaxOwnership
+            );
+            Self::ensure_investment_eligible(
+                origin,
+                asset_details.region,
+                owned_token_amount.saturating_add(amount),
+                token_price,
+            )?;
 
-            // Distribute funds from property account for each asset
-            for &asset in T::AcceptedAssets::get().iter() {
-                // Get total collected amounts and lawyer costs
-                let total_collected_funds = property_details
-                    .collected_funds
-                    .get(&asset)
-                    .copied()
-                    .ok_or(Error::<T>::AssetNotSupported)?;
-                let spv_lawyer_costs = property_lawyer_details
-                    .spv_lawyer_costs
-                    .get(&asset)
-                    .copied()
-                    .ok_or(Error::<T>::AssetNotSupported)?;
-                let tax = property_details
-                    .collected_tax
-                    .get(&asset)
-                    .copied()
-                    .ok_or(Error::<T>::AssetNotSupported)?;
-                let collected_fees = property_details
-                    .collected_fees
-                    .get(&asset)
-                    .copied()
-                    .ok_or(Error::<T>::AssetNotSupported)?;
+            let (transfer_price, tax, held_amount) = Self::calculate_purchase_price(
+                token_price,
+                amount,
+                payment_asset,
+                region_info.tax,
+                property_details.tax_paid_by_developer,
+            )?;
+            Self::ensure_minimum_payment(payment_asset, transfer_price)?;
+            if let Some(max_payment) = max_payment {
+                ensure!(held_amount <= max_payment, Error::<T>::SlippageExceeded);
+            }
 
-                let fee_percentage = T::MarketplaceFeePercentage::get();
+            AuctionBids::<T>::try_mutate(listing_id, |bids| {
                 ensure!(
-                    fee_percentage <= 100u128.into(),
-                    Error::<T>::InvalidFeePercentage
+                    !bids.iter().any(|bid| bid.bidder == signer),
+                    Error::<T>::OnlyOneBidPerUser
+                );
+                let position = bids
+                    .iter()
+                    .position(|bid| bid.token_price < token_price)
+                    .unwrap_or(bids.len());
+                let preceding_amount = bids
+                    .iter()
+                    .take(position)
+                    .fold(0u32, |total, bid| total.saturating_add(bid.amount));
+                ensure!(
+                    preceding_amount < property_details.listed_token_amount,
+                    Error::<T>::BidTooLow
                 );
 
-                let developer_percentage = <T as pallet::Config>::Balance::from(100u128)
-                    .checked_sub(&fee_percentage)
-                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
+                T::ForeignAssetsHolder::hold(
+                    payment_asset,
+                    &MarketplaceHoldReason::Marketplace,
+                    &signer,
+                    held_amount,
+                )?;
+                bids.try_insert(
+                    position,
+                    AuctionBid {
+                        bidder: signer.clone(),
+                        token_price,
+                        amount,
+                        payment_asset,
+                        held_amount,
+                        transfer_price,
+                        tax,
+                    },
+                )
+                .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
+
+                // Bids that can not get any token anymore are removed and refunded.
+                let mut covered_amount = 0u32;
+                let mut winning_bids = 0usize;
+                for bid in bids.iter() {
+                    if covered_amount >= property_details.listed_token_amount {
+                        break;
+                    }
+                    covered_amount = covered_amount.saturating_add(bid.amount);
+                    winning_bids = winning_bids.saturating_add(1);
+                }
+                let outbid: Vec<AuctionBid<T>> = bids.iter().skip(winning_bids).cloned().collect();
+                bids.truncate(winning_bids);
+                for bid in outbid {
+                    T::ForeignAssetsHolder::release(
+                        bid.payment_asset,
+                        &MarketplaceHoldReason::Marketplace,
+                        &bid.bidder,
+                        bid.held_amount,
+                        Precision::Exact,
+                    )?;
+                    Self::deposit_event(Event::<T>::AuctionBidOutbid {
+                        listing_id,
+                        bidder: bid.bidder,
+                        amount: bid.amount,
+                    });
+                }
+                Ok::<(), DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::<T>::AuctionBidPlaced {
+                listing_id,
+                bidder: signer,
+                token_price,
+                amount,
+                payment_asset,
+            });
+            Ok(())
+        }
+
+        /// Settles an english auction once the listing has expired.
+        /// If the bids reach the soft cap of the listing, the winning bids get the token at their
+        /// bid price and the claim window starts. Otherwise all bids are refunded.
+        ///
+        /// The origin must be Signed and the sender must have sufficient funds free.
+        ///
+        /// Parameters:
+        /// - `listing_id`: The listing of the auction that should be settled.
+        ///
+        /// Emits `AuctionSettled` event when successful.
+        #[pallet::call_index(31)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::settle_auction(
+            <T as pallet::Config>::MaxPropertyToken::get()
+        ))]
+        pub fn settle_auction(origin: OriginFor<T>, listing_id: ListingId) -> DispatchResult {
+            let _ = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                origin,
+                &Role::RealEstateInvestor,
+            )?;
+            Self::do_settle_auction(listing_id)
+        }
+
+        /// Lets the primary sale proceed with the sold token once the listing has expired
+        /// and the soft cap has been reached.
+        /// The unsold token are returned to the real estate developer when the sale is completed.
+        ///
+        /// The origin must be Signed and the sender must have sufficient funds free.
+        ///
+        /// Parameters:
+        /// - `listing_id`: The listing that reached the soft cap.
+        ///
+        /// Emits `SoftCapReached` event when successful.
+        #[pallet::call_index(32)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::finalize_soft_cap())]
+        pub fn finalize_soft_cap(origin: OriginFor<T>, listing_id: ListingId) -> DispatchResult {
+            let _ = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                origin,
+                &Role::RealEstateInvestor,
+            )?;
+            let mut property_details =
+                OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
+            ensure!(
+                property_details.listing_expiry < <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::ListingNotExpired
+            );
+            ensure!(
+                !property_details.listed_token_amount.is_zero(),
+                Error::<T>::PropertyAlreadySold
+            );
+            ensure!(
+                AuctionBids::<T>::get(listing_id).is_empty(),
+                Error::<T>::AuctionNotSettled
+            );
+            ensure!(
+                Self::soft_cap_reached(listing_id, &property_details)?,
+                Error::<T>::SoftCapNotReached
+            );
+            Self::finalize_with_soft_cap(listing_id, &mut property_details)?;
+            OngoingObjectListing::<T>::insert(listing_id, &property_details);
+            Ok(())
+        }
+
+        /// Adds a payment asset to the accepted assets or updates its details.
+        ///
+        /// The origin must be the AssetRegistryOrigin.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The foreign asset that should be accepted as payment.
+        /// - `min_amount`: The minimum amount of a single payment in this asset.
+        /// - `uses_exchange_rate`: Whether the asset is priced with an exchange rate instead of
+        ///   being pegged to the reference unit.
+        ///
+        /// Emits `AcceptedAssetSet` event when successful.
+        #[pallet::call_index(33)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_accepted_asset())]
+        pub fn set_accepted_asset(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            min_amount: <T as pallet::Config>::Balance,
+            uses_exchange_rate: bool,
+        ) -> DispatchResult {
+            T::AssetRegistryOrigin::ensure_origin(origin)?;
+            ensure!(
+                <<T as pallet::Config>::ForeignCurrency as fungibles::Inspect<
+                    AccountIdOf<T>,
+                >>::asset_exists(asset_id),
+                Error::<T>::AssetNotSupported
+            );
+            AcceptedPaymentAssets::<T>::try_mutate(|accepted_assets| {
+                accepted_assets
+                    .try_insert(
+                        asset_id,
+                        AcceptedAssetDetails {
+                            min_amount,
+                            uses_exchange_rate,
+                        },
+                    )
+                    .map_err(|_| Error::<T>::TooManyAcceptedAssets)?;
+                Ok::<(), DispatchError>(())
+            })?;
+            Self::deposit_event(Event::<T>::AcceptedAssetSet {
+                asset_id,
+                min_amount,
+                uses_exchange_rate,
+            });
+            Ok(())
+        }
+
+        /// Removes a payment asset from the accepted assets.
+        /// Funds that have already been paid in this asset are still paid out and refunded.
+        ///
+        /// The origin must be the AssetRegistryOrigin.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The payment asset that should no longer be accepted.
+        ///
+        /// Emits `AcceptedAssetRemoved` event when successful.
+        #[pallet::call_index(34)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_accepted_asset())]
+        pub fn remove_accepted_asset(origin: OriginFor<T>, asset_id: u32) -> DispatchResult {
+            T::AssetRegistryOrigin::ensure_origin(origin)?;
+            AcceptedPaymentAssets::<T>::try_mutate(|accepted_assets| {
+                accepted_assets
+                    .remove(&asset_id)
+                    .ok_or(Error::<T>::PaymentAssetNotSupported)?;
+                Ok::<(), DispatchError>(())
+            })?;
+            Self::deposit_event(Event::<T>::AcceptedAssetRemoved { asset_id });
+            Ok(())
+        }
+
+        /// Pushes the pending refunds of a failed listing to the investors in batches.
+        /// Cleans up the listing once all investors have been refunded.
+        ///
+        /// The origin must be Signed.
+        ///
+        /// Parameters:
+        /// - `listing_id`: The listing that the refunds should be processed for.
+        /// - `max_refunds`: The maximum amount of investors that get refunded in this call.
+        ///
+        /// Emits `RefundsProcessed` event when successful.
+        #[pallet::call_index(35)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::process_refunds(
+            (*max_refunds).min(<T as pallet::Config>::MaxPropertyToken::get())
+        ))]
+        pub fn process_refunds(
+            origin: OriginFor<T>,
+            listing_id: ListingId,
+            max_refunds: u32,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let max_refunds = max_refunds.min(<T as pallet::Config>::MaxPropertyToken::get());
+            let mut property_details =
+                OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
+
+            let (refunded_investors, completed) =
+                if let Some(mut refund_infos) = RefundToken::<T>::get(listing_id) {
+                    let (refunded_investors, remaining) = Self::refund_claimed_investors(
+                        &property_details,
+                        refund_infos.refund_amount,
+                        max_refunds,
+                        false,
+                    )?;
+                    refund_infos.refund_amount = remaining;
+                    if remaining.is_zero() {
+                        Self::refund_investors_with_fees(
+                            &property_details,
+                            refund_infos.property_lawyer_details,
+                        )?;
+                        RefundToken::<T>::remove(listing_id);
+                    } else {
+                        RefundToken::<T>::insert(listing_id, refund_infos);
+                    }
+                    (refunded_investors, remaining.is_zero())
+                } else if let Some(refund_amount) = RefundLegalExpired::<T>::get(listing_id) {
+                    let (refunded_investors, remaining) = Self::refund_claimed_investors(
+                        &property_details,
+                        refund_amount,
+                        max_refunds,
+                        true,
+                    )?;
+                    if remaining.is_zero() {
+                        T::PropertyToken::clear_token_owners(property_details.asset_id)?;
+                        RefundLegalExpired::<T>::remove(listing_id);
+                    } else {
+                        RefundLegalExpired::<T>::insert(listing_id, remaining);
+                    }
+                    (refunded_investors, remaining.is_zero())
+                } else if let Some(refund_amount) = RefundClaimedToken::<T>::get(listing_id) {
+                    let (refunded_investors, remaining) = Self::refund_claimed_investors(
+                        &property_details,
+                        refund_amount,
+                        max_refunds,
+                        true,
+                    )?;
+                    if remaining.is_zero() {
+                        T::PropertyToken::clear_token_owners(property_details.asset_id)?;
+                        RefundClaimedToken::<T>::remove(listing_id);
+                    } else {
+                        RefundClaimedToken::<T>::insert(listing_id, remaining);
+                    }
+                    (refunded_investors, remaining.is_zero())
+                } else {
+                    ensure!(
+                        property_details.listing_expiry < <frame_system::Pallet<T>>::block_number(),
+                        Error::<T>::ListingNotExpired
+                    );
+                    ensure!(
+                        !property_details.listed_token_amount.is_zero(),
+                        Error::<T>::PropertyAlreadySold
+                    );
+                    ensure!(
+                        !Self::soft_cap_reached(listing_id, &property_details)?,
+                        Error::<T>::SoftCapReached
+                    );
+                    let investors: Vec<AccountIdOf<T>> =
+                        ListingTokenOwners::<T>::iter_key_prefix(listing_id)
+                            .take(max_refunds as usize)
+                            .collect();
+                    ensure!(!investors.is_empty(), Error::<T>::NoRefundsPending);
+                    for investor in investors.iter() {
+                        ListingTokenOwners::<T>::remove(listing_id, investor);
+                        let token_details = match TokenOwner::<T>::take(investor, listing_id) {
+                            Some(token_details) => token_details,
+                            None => continue,
+                        };
+                        if token_details.relist_count != property_details.relist_count {
+                            // Token of a previous round that have never been claimed
+                            Self::unfreeze_token_with_refunds(&token_details, None, investor)?;
+                            continue;
+                        }
+                        Self::unfreeze_token(&token_details, investor)?;
+                        property_details.listed_token_amount = property_details
+                            .listed_token_amount
+                            .checked_add(token_details.token_amount)
+                            .ok_or(Error::<T>::ArithmeticOverflow)?;
+                    }
+                    (
+                        investors.len() as u32,
+                        property_details.listed_token_amount >= property_details.token_amount,
+                    )
+                };
+
+            if completed {
+                Self::close_failed_listing(listing_id, &property_details)?;
+            } else {
+                OngoingObjectListing::<T>::insert(listing_id, &property_details);
+            }
+            Self::deposit_event(Event::<T>::RefundsProcessed {
+                listing_id,
+                refunded_investors,
+                completed,
+            });
+            Ok(())
+        }
+
+        /// Sets the maximum share of a property's token that a single investor, together with
+        /// the accounts linked to them, may hold.
+        ///
+        /// The origin must be the OwnershipCapOrigin of the property.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset id of the property.
+        /// - `cap`: The new ownership cap. `None` resets the cap to the default.
+        ///
+        /// Emits `OwnershipCapSet` event when successful.
+        #[pallet::call_index(36)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_ownership_cap())]
+        pub fn set_ownership_cap(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            cap: Option<Permill>,
+        ) -> DispatchResult {
+            <T as pallet::Config>::OwnershipCapOrigin::ensure_origin(origin, &asset_id)?;
+            ensure!(
+                <T as pallet::Config>::PropertyToken::get_property_asset_info(asset_id).is_some(),
+                Error::<T>::NoObjectFound
+            );
+            match cap {
+                Some(cap) => {
+                    ensure!(!cap.is_zero(), Error::<T>::InvalidOwnershipCap);
+                    OwnershipCap::<T>::insert(asset_id, cap);
+                }
+                None => OwnershipCap::<T>::remove(asset_id),
+            }
+            let cap = Self::ownership_cap(asset_id);
+            Self::deposit_event(Event::<T>::OwnershipCapSet { asset_id, cap });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Get the account id of the pallet
+        pub fn account_id() -> AccountIdOf<T> {
+            <T as pallet::Config>::PalletId::get().into_account_truncating()
+        }
+
+        pub fn property_account_id(asset_id: u32) -> AccountIdOf<T> {
+            <T as pallet::Config>::PalletId::get().into_sub_account_truncating(("pr", asset_id))
+        }
+
+        /// Get the account id of the treasury pallet
+        pub fn treasury_account_id() -> AccountIdOf<T> {
+            <T as pallet::Config>::TreasuryId::get().into_account_truncating()
+        }
+
+        pub fn next_listing_id(listing_id: ListingId) -> Result<ListingId, Error<T>> {
+            listing_id
+                .checked_add(1)
+                .ok_or(Error::<T>::ArithmeticOverflow)
+        }
+
+        /// Sends the token to the new owners and the funds to the real estate developer once all 100 token
+        /// of a collection are sold.
+        fn execute_deal(
+            listing_id: u32,
+            property_lawyer_details: PropertyLawyerDetails<T>,
+        ) -> DispatchResult {
+            let property_details =
+                OngoingObjectListing::<T>::take(listing_id).ok_or(Error::<T>::ListingNotFound)?;
+            let _ = ListingPaymentValues::<T>::clear_prefix(
+                listing_id,
+                <T as pallet::Config>::MaxAcceptedAssets::get(),
+                None,
+            );
+            let asset_details =
+                T::PropertyToken::get_property_asset_info(property_details.asset_id)
+                    .ok_or(Error::<T>::NoObjectFound)?;
+            let treasury_id = Self::treasury_account_id();
+            let property_account = Self::property_account_id(property_details.asset_id);
+            let region = pallet_regions::RegionDetails::<T>::get(asset_details.region)
+                .ok_or(Error::<T>::RegionUnknown)?;
+
+            // Get lawyer accounts
+            let real_estate_developer_lawyer_id = property_lawyer_details
+                .real_estate_developer_lawyer
+                .ok_or(Error::<T>::LawyerNotFound)?;
+            let spv_lawyer_id = property_lawyer_details
+                .spv_lawyer
+                .ok_or(Error::<T>::LawyerNotFound)?;
+            PalletRegions::<T>::decrement_active_cases(&real_estate_developer_lawyer_id)?;
+            PalletRegions::<T>::decrement_active_cases(&spv_lawyer_id)?;
+
+            let mut developer_payout = Payout::default();
+            let mut spv_lawyer_payout = Payout::default();
+            let mut treasury_payout = Payout::default();
+            let mut region_owner_payout = Payout::default();
+
+            // Distribute funds from property account for each asset
+            for (&asset, &total_collected_funds) in property_details.collected_funds.iter() {
+                // Get collected amounts and lawyer costs
+                let spv_lawyer_costs = property_lawyer_details
+                    .spv_lawyer_costs
+                    .get(&asset)
+                    .copied()
+                    .unwrap_or_default();
+                let tax = property_details
+                    .collected_tax
+                    .get(&asset)
+                    .copied()
+                    .unwrap_or_default();
+                let collected_fees = property_details
+                    .collected_fees
+                    .get(&asset)
+                    .copied()
+                    .unwrap_or_default();
+
+                let fee_percentage = T::MarketplaceFeePercentage::get();
+                ensure!(
+                    fee_percentage <= 100u128.into(),
+                    Error::<T>::InvalidFeePercentage
+                );
+
+                let developer_percentage = <T as pallet::Config>::Balance::from(100u128)
+                    .checked_sub(&fee_percentage)
+                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
+
+                // Calculate amounts to distribute
+                let mut developer_amount = total_collected_funds
+                    .checked_mul(&developer_percentage)
+                    .ok_or(Error::<T>::MultiplyError)?
+                    .checked_div(&(100u128.into()))
+                    .ok_or(Error::<T>::DivisionError)?;
+
+                let (developer_lawyer_tax, spv_lawyer_tax) =
+                    if property_details.tax_paid_by_developer {
+                        developer_amount = developer_amount
+                            .checked_sub(&tax)
+                            .ok_or(Error::<T>::ArithmeticUnderflow)?;
+                        (tax, Zero::zero())
+                    } else {
+                        (Zero::zero(), tax)
+                    };
+
+                let spv_lawyer_amount = spv_lawyer_costs
+                    .checked_add(&spv_lawyer_tax)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+
+                let protocol_fees = total_collected_funds
+                    .checked_div(&(100u128.into()))
+                    .ok_or(Error::<T>::DivisionError)?
+                    .checked_add(&collected_fees)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?
+                    .saturating_sub(spv_lawyer_costs);
+
+                let region_owner_amount = protocol_fees
+                    .checked_div(&(2u128.into()))
+                    .ok_or(Error::<T>::DivisionError)?;
+                let treasury_amount = protocol_fees.saturating_sub(region_owner_amount);
+
+                // Transfer funds from property account
+                Self::transfer_funds(
+                    &property_account,
+                    &property_details.real_estate_developer,
+                    developer_amount,
+                    asset,
+                )?;
+                if !developer_lawyer_tax.is_zero() {
+                    Self::transfer_funds(
+                        &property_account,
+                        &real_estate_developer_lawyer_id,
+                        developer_lawyer_tax,
+                        asset,
+                    )?;
+                }
+                Self::transfer_funds(&property_account, &spv_lawyer_id, spv_lawyer_amount, asset)?;
+                Self::transfer_funds(&property_account, &treasury_id, treasury_amount, asset)?;
+                Self::transfer_funds(&property_account, &region.owner, region_owner_amount, asset)?;
+
+                Self::update_map(&mut developer_payout.amounts, asset, developer_amount)?;
+                Self::update_map(&mut spv_lawyer_payout.amounts, asset, spv_lawyer_costs)?;
+                Self::update_map(&mut treasury_payout.amounts, asset, treasury_amount)?;
+                Self::update_map(&mut region_owner_payout.amounts, asset, region_owner_amount)?;
+            }
+            T::PropertyToken::finalize_property(property_details.asset_id)?;
+            // Return unsold token of a soft cap sale to the developer
+            let unsold_token_amount = asset_details
+                .token_amount
+                .saturating_sub(property_details.token_amount);
+            if !unsold_token_amount.is_zero() {
+                T::PropertyToken::distribute_property_token_to_owner(
+                    property_details.asset_id,
+                    &property_details.real_estate_developer,
+                    unsold_token_amount,
+                )?;
+            }
+            // Release deposit
+            if let Some((depositor, deposit_amount)) = ListingDeposits::<T>::take(listing_id) {
+                <T as pallet::Config>::NativeCurrency::release(
+                    &HoldReason::ListingDepositReserve.into(),
+                    &depositor,
+                    deposit_amount,
+                    Precision::Exact,
+                )?;
+            }
+
+            let payouts = FinalSettlementPayouts {
+                developer_payout,
+                developer_account: property_details.real_estate_developer.clone(),
+                spv_lawyer_payout,
+                spv_lawyer_account: spv_lawyer_id.clone(),
+                treasury_payout,
+                treasury_account: treasury_id.clone(),
+                region_owner_payout,
+                region_owner_account: region.owner.clone(),
+            };
+
+            Self::deposit_event(Event::<T>::PrimarySaleCompleted {
+                listing_id,
+                asset_id: property_details.asset_id,
+                payouts,
+            });
+            Ok(())
+        }
+
+        fn reject_and_refund(
+            listing_id: u32,
+            property_lawyer_details: &PropertyLawyerDetails<T>,
+        ) -> DispatchResult {
+            let property_details =
+                OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
+            let real_estate_developer_lawyer_id = property_lawyer_details
+                .real_estate_developer_lawyer
+                .clone()
+                .ok_or(Error::<T>::LawyerNotFound)?;
+            let spv_lawyer_id = property_lawyer_details
+                .spv_lawyer
+                .clone()
+                .ok_or(Error::<T>::LawyerNotFound)?;
+            PalletRegions::<T>::decrement_active_cases(&real_estate_developer_lawyer_id)?;
+            PalletRegions::<T>::decrement_active_cases(&spv_lawyer_id)?;
+            RefundToken::<T>::insert(
+                listing_id,
+                RefundInfos {
+                    refund_amount: property_details.token_amount,
+                    property_lawyer_details: property_lawyer_details.clone(),
+                },
+            );
+            Ok(())
+        }
+
+        /// Refunds the paid funds of an investor and returns the property token to the property
+        /// account.
+        fn refund_investor(
+            property_details: &PropertyListingDetailsType<T>,
+            investor: &AccountIdOf<T>,
+            token_amount: u32,
+            with_fees: bool,
+        ) -> DispatchResult {
+            let property_account = Self::property_account_id(property_details.asset_id);
+            if let Some(investor_funds) = property_details.investor_funds.get(investor) {
+                for (&asset, &paid_funds) in investor_funds.paid_funds.iter() {
+                    let transfer_amount = if with_fees {
+                        let paid_fee = investor_funds
+                            .paid_fee
+                            .get(&asset)
+                            .copied()
+                            .unwrap_or_default();
+                        paid_funds
+                            .checked_add(&paid_fee)
+                            .ok_or(Error::<T>::ArithmeticOverflow)?
+                    } else {
+                        paid_funds
+                    };
+                    // Transfer funds to owner account
+                    Self::transfer_funds(&property_account, investor, transfer_amount, asset)?;
+                }
+            }
+            <T as pallet::Config>::LocalCurrency::transfer(
+                property_details.asset_id,
+                investor,
+                &property_account,
+                token_amount.into(),
+                Preservation::Expendable,
+            )?;
+            T::PropertyToken::remove_property_token_ownership(property_details.asset_id, investor)
+        }
+
+        /// Refunds up to `max_refunds` investors that still hold property token and returns the
+        /// amount of refunded investors and the remaining refundable token.
+        fn refund_claimed_investors(
+            property_details: &PropertyListingDetailsType<T>,
+            refund_amount: u32,
+            max_refunds: u32,
+            with_fees: bool,
+        ) -> Result<(u32, u32), DispatchError> {
+            let mut refunded_investors: u32 = 0;
+            let mut remaining = refund_amount;
+            let owners = T::PropertyToken::get_property_owner(property_details.asset_id);
+            for investor in owners.iter() {
+                if refunded_investors >= max_refunds {
+                    break;
+                }
+                let token_amount =
+                    T::PropertyToken::get_token_balance(property_details.asset_id, investor);
+                if token_amount.is_zero() {
+                    continue;
+                }
+                remaining = remaining
+                    .checked_sub(token_amount)
+                    .ok_or(Error::<T>::InsufficientRefundableTokens)?;
+                Self::refund_investor(property_details, investor, token_amount, with_fees)?;
+                refunded_investors = refunded_investors.saturating_add(1);
+            }
+            Ok((refunded_investors, remaining))
+        }
+
+        /// Burns the property token of a failed listing, releases the listing deposit and removes
+        /// the listing.
+        fn close_failed_listing(
+            listing_id: ListingId,
+            property_details: &PropertyListingDetailsType<T>,
+        ) -> DispatchResult {
+            T::PropertyToken::burn_property_token(property_details.asset_id)?;
+            let (depositor, deposit_amount) =
+                ListingDeposits::<T>::take(listing_id).ok_or(Error::<T>::ListingNotFound)?;
+            <T as pallet::Config>::NativeCurrency::release(
+                &HoldReason::ListingDepositReserve.into(),
+                &depositor,
+                deposit_amount,
+                Precision::Exact,
+            )?;
+            let property_account = Self::property_account_id(property_details.asset_id);
+            let native_balance = <T as pallet::Config>::NativeCurrency::balance(&property_account);
+            if !native_balance.is_zero() {
+                <T as pallet::Config>::NativeCurrency::transfer(
+                    &property_account,
+                    &property_details.real_estate_developer,
+                    native_balance,
+                    Preservation::Expendable,
+                )?;
+            }
+            OngoingObjectListing::<T>::remove(listing_id);
+            let _ = ListingPaymentValues::<T>::clear_prefix(
+                listing_id,
+                <T as pallet::Config>::MaxAcceptedAssets::get(),
+                None,
+            );
+            Ok(())
+        }
+
+        fn refund_investors_with_fees(
+            property_details: &PropertyListingDetailsType<T>,
+            property_lawyer_details: PropertyLawyerDetails<T>,
+        ) -> DispatchResult {
+            let property_account = Self::property_account_id(property_details.asset_id);
+            let treasury_id = Self::treasury_account_id();
+            let spv_lawyer_id = property_lawyer_details
+                .spv_lawyer
+                .ok_or(Error::<T>::LawyerNotFound)?;
+
+            // Process fees and transfers for each asset
+            for (asset, &fees) in property_details.collected_fees.iter() {
+                // Fetch lawyer costs
+                let lawyer_costs = property_lawyer_details
+                    .spv_lawyer_costs
+                    .get(asset)
+                    .copied()
+                    .unwrap_or_default();
+
+                // Calculate treasury amount
+                let treasury_amount = fees
+                    .checked_sub(&lawyer_costs)
+                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
+
+                // Perform fund transfers
+                Self::transfer_funds(&property_account, &treasury_id, treasury_amount, *asset)?;
+                Self::transfer_funds(&property_account, &spv_lawyer_id, lawyer_costs, *asset)?;
+            }
+            T::PropertyToken::clear_token_owners(property_details.asset_id)?;
+            Ok(())
+        }
+
+        fn buying_token_process(
+            listing_id: u32,
+            transfer_from: &AccountIdOf<T>,
+            account: &AccountIdOf<T>,
+            mut listing_details: ListingDetailsType<T>,
+            price: <T as pallet::Config>::Balance,
+            amount: u32,
+            payment_asset: u32,
+        ) -> DispatchResult {
+            Self::calculate_fees(price, transfer_from, &listing_details.seller, payment_asset)?;
+            let property_account = Self::property_account_id(listing_details.asset_id);
+            T::PropertyToken::transfer_property_token(
+                listing_details.asset_id,
+                &listing_details.seller,
+                &property_account,
+                account,
+                amount,
+            )?;
+            listing_details.amount = listing_details
+                .amount
+                .checked_sub(amount)
+                .ok_or(Error::<T>::ArithmeticUnderflow)?;
+            if listing_details.amount > 0 {
+                TokenListings::<T>::insert(listing_id, listing_details.clone());
+            }
+            Self::deposit_event(Event::<T>::RelistedTokenBought {
+                listing_index: listing_id,
+                asset_id: listing_details.asset_id,
+                buyer: account.clone(),
+                seller: listing_details.seller,
+                price: listing_details.token_price,
+                amount,
+                payment_asset,
+                new_amount_remaining: listing_details.amount,
+            });
+            Ok(())
+        }
+
+        fn unfreeze_token(
+            token_details: &TokenOwnerDetails<T>,
+            signer: &AccountIdOf<T>,
+        ) -> DispatchResult {
+            for (asset, &paid_funds) in token_details.paid_funds.iter() {
+                if paid_funds.is_zero() {
+                    continue;
+                }
+
+                let default = Default::default();
+                let paid_tax = token_details
+                    .paid_tax
+                    .get(asset)
+                    .copied()
+                    .unwrap_or(default);
+
+                // Calculate refund and investor fee
+                let refund_amount = paid_funds
+                    .checked_add(&paid_tax)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                let investor_fee = Self::marketplace_fee(paid_funds)?;
+                let total_investor_amount = refund_amount
+                    .checked_add(&investor_fee)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+
+                // Release funds
+                T::ForeignAssetsHolder::release(
+                    *asset,
+                    &MarketplaceHoldReason::Marketplace,
+                    signer,
+                    total_investor_amount,
+                    Precision::Exact,
+                )?;
+            }
+            Ok(())
+        }
+
+        /// Releases the held funds of a purchase to the investor.
+        /// For a partial refund the fee is taken from the remaining purchase, so that the fees
+        /// released over all cancellations add up to the fee held for the purchase.
+        fn unfreeze_token_with_refunds(
+            token_details: &TokenOwnerDetails<T>,
+            remaining_details: Option<&TokenOwnerDetails<T>>,
+            signer: &AccountIdOf<T>,
+        ) -> Result<(PaymentAssetAmounts<T>, PaymentAssetAmounts<T>), DispatchError> {
+            let mut principal_refunded = BoundedBTreeMap::new();
+            let mut tax_refunded = BoundedBTreeMap::new();
+
+            for (asset, &paid_funds) in token_details.paid_funds.iter() {
+                if paid_funds.is_zero() {
+                    continue;
+                }
+
+                let default = Default::default();
+                let paid_tax = token_details
+                    .paid_tax
+                    .get(asset)
+                    .copied()
+                    .unwrap_or(default);
+
+                // Calculate refund and investor fee
+                let refund_amount = paid_funds
+                    .checked_add(&paid_tax)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                let remaining_funds = remaining_details
+                    .and_then(|details| details.paid_funds.get(asset).copied())
+                    .unwrap_or_default();
+                let investor_fee = Self::marketplace_fee(
+                    paid_funds
+                        .checked_add(&remaining_funds)
+                        .ok_or(Error::<T>::ArithmeticOverflow)?,
+                )?
+                .checked_sub(&Self::marketplace_fee(remaining_funds)?)
+                .ok_or(Error::<T>::ArithmeticUnderflow)?;
+                let total_investor_amount = refund_amount
+                    .checked_add(&investor_fee)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+
+                // Release funds
+                T::ForeignAssetsHolder::release(
+                    *asset,
+                    &MarketplaceHoldReason::Marketplace,
+                    signer,
+                    total_investor_amount,
+                    Precision::Exact,
+                )?;
+
+                Self::update_map(&mut principal_refunded, *asset, paid_funds)?;
+                Self::update_map(&mut tax_refunded, *asset, paid_tax)?;
+            }
+            Ok((principal_refunded, tax_refunded))
+        }
+
+        /// Splits the share of `amount` token off the purchase of an investor.
+        /// The paid funds and taxes are split proportionally per payment asset.
+        fn split_token_owner_details(
+            token_details: &mut TokenOwnerDetails<T>,
+            amount: u32,
+        ) -> Result<TokenOwnerDetails<T>, DispatchError> {
+            let token_amount = token_details.token_amount;
+            ensure!(token_amount >= amount, Error::<T>::NotEnoughToken);
+            let mut paid_funds = Self::create_initial_funds()?;
+            let mut paid_tax = Self::create_initial_funds()?;
+
+            for (asset, funds) in token_details.paid_funds.iter_mut() {
+                let share = Self::proportional_share(*funds, amount, token_amount)?;
+                *funds = funds
+                    .checked_sub(&share)
+                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
+                Self::update_map(&mut paid_funds, *asset, share)?;
+            }
+            for (asset, tax) in token_details.paid_tax.iter_mut() {
+                let share = Self::proportional_share(*tax, amount, token_amount)?;
+                *tax = tax
+                    .checked_sub(&share)
+                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
+                Self::update_map(&mut paid_tax, *asset, share)?;
+            }
+            token_details.token_amount = token_amount
+                .checked_sub(amount)
+                .ok_or(Error::<T>::ArithmeticUnderflow)?;
+
+            Ok(TokenOwnerDetails {
+                token_amount: amount,
+                paid_funds,
+                paid_tax,
+                relist_count: token_details.relist_count,
+            })
+        }
+
+        /// Calculates the share of `part` out of `total` of a value.
+        fn proportional_share(
+            value: <T as pallet::Config>::Balance,
+            part: u32,
+            total: u32,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            if part == total {
+                return Ok(value);
+            }
+            let share = value
+                .checked_mul(&((part as u128).into()))
+                .ok_or(Error::<T>::MultiplyError)?
+                .checked_div(&((total as u128).into()))
+                .ok_or(Error::<T>::DivisionError)?;
+            Ok(share)
+        }
+
+        fn calculate_fees(
+            price: <T as pallet::Config>::Balance,
+            sender: &AccountIdOf<T>,
+            receiver: &AccountIdOf<T>,
+            asset: u32,
+        ) -> DispatchResult {
+            let fee_percent = T::MarketplaceFeePercentage::get();
+            ensure!(
+                fee_percent < 100u128.into(),
+                Error::<T>::InvalidFeePercentage
+            );
+
+            let fees = price
+                .checked_mul(&fee_percent)
+                .ok_or(Error::<T>::MultiplyError)?
+                .checked_div(&(100u128.into()))
+                .ok_or(Error::<T>::DivisionError)?;
+            let treasury_id = Self::treasury_account_id();
+            let seller_part = price
+                .checked_sub(&fees)
+                .ok_or(Error::<T>::ArithmeticUnderflow)?;
+
+            Self::transfer_funds(sender, &treasury_id, fees, asset)?;
+            Self::transfer_funds(sender, receiver, seller_part, asset)?;
+            Ok(())
+        }
+
+        fn transfer_funds(
+            from: &AccountIdOf<T>,
+            to: &AccountIdOf<T>,
+            amount: <T as pallet::Config>::Balance,
+            asset: u32,
+        ) -> DispatchResult {
+            if !amount.is_zero() {
+                T::ForeignCurrency::transfer(asset, from, to, amount, Preservation::Expendable)
+                    .map_err(|_| Error::<T>::NotEnoughFunds)?;
+            }
+            Ok(())
+        }
+
+        fn create_initial_funds() -> Result<
+            BoundedBTreeMap<
+                u32,
+                <T as pallet::Config>::Balance,
+                <T as pallet::Config>::MaxAcceptedAssets,
+            >,
+            DispatchError,
+        > {
+            let mut map = BoundedBTreeMap::default();
+            for &asset in AcceptedPaymentAssets::<T>::get().keys() {
+                map.try_insert(asset, Default::default())
+                    .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
+            }
+            Ok(map)
+        }
+
+        fn update_map(
+            map: &mut BoundedBTreeMap<
+                u32,
+                <T as pallet::Config>::Balance,
+                <T as pallet::Config>::MaxAcceptedAssets,
+            >,
+            asset: u32,
+            value: <T as pallet::Config>::Balance,
+        ) -> DispatchResult {
+            if let Some(existing) = map.get_mut(&asset) {
+                *existing = existing
+                    .checked_add(&value)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+            } else {
+                if map.len() >= <T as pallet::Config>::MaxAcceptedAssets::get() as usize {
+                    // Make room by dropping empty entries of assets that are no longer accepted
+                    let accepted_assets = AcceptedPaymentAssets::<T>::get();
+                    map.retain(|asset, value| {
+                        !value.is_zero() || accepted_assets.contains_key(asset)
+                    });
+                }
+                map.try_insert(asset, value)
+                    .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
+            }
+            Ok(())
+        }
+
+        /// Returns the sum of the collected fees of a listing over all payment assets in the
+        /// reference unit.
+        fn total_collected_fees(
+            listing_id: ListingId,
+            property_details: &PropertyListingDetailsType<T>,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            property_details.collected_fees.iter().try_fold(
+                Zero::zero(),
+                |total: <T as pallet::Config>::Balance, (&asset, &fee)| {
+                    total
+                        .checked_add(&Self::listing_funds_to_reference_units(
+                            listing_id, fee, asset,
+                        )?)
+                        .ok_or(Error::<T>::ArithmeticOverflow.into())
+                },
+            )
+        }
+
+        /// Allocates the lawyer costs, quoted in the reference unit, to the collected fees.
+        /// A single payment asset covering the costs is preferred, otherwise the costs are split
+        /// across the payment assets, starting with the highest asset id.
+        fn allocate_fees(
+            listing_id: ListingId,
+            costs_map: &mut BoundedBTreeMap<
+                u32,
+                <T as pallet::Config>::Balance,
+                <T as pallet::Config>::MaxAcceptedAssets,
+            >,
+            collected_fees: &BoundedBTreeMap<
+                u32,
+                <T as pallet::Config>::Balance,
+                <T as pallet::Config>::MaxAcceptedAssets,
+            >,
+            costs: <T as pallet::Config>::Balance,
+        ) -> DispatchResult {
+            for (&asset, &fee) in collected_fees.iter().rev() {
+                let asset_costs = Self::listing_reference_to_funds(listing_id, costs, asset)?;
+                if fee >= asset_costs {
+                    costs_map
+                        .try_insert(asset, asset_costs)
+                        .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
+                    return Ok(());
+                }
+            }
+            let mut remaining_costs = costs;
+            for (&asset, &fee) in collected_fees.iter().rev() {
+                let covered_costs = Self::listing_funds_to_reference_units(listing_id, fee, asset)?
+                    .min(remaining_costs);
+                let asset_costs =
+                    Self::listing_reference_to_funds(listing_id, covered_costs, asset)?.min(fee);
+                costs_map
+                    .try_insert(asset, asset_costs)
+                    .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
+                remaining_costs = remaining_costs
+                    .checked_sub(&covered_costs)
+                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
+            }
+            ensure!(remaining_costs.is_zero(), Error::<T>::CostsTooHigh);
+            Ok(())
+        }
+
+        /// Ensures that the payment asset is accepted and the payment reaches its minimum amount.
+        fn ensure_minimum_payment(
+            payment_asset: u32,
+            amount: <T as pallet::Config>::Balance,
+        ) -> DispatchResult {
+            let asset_details = AcceptedPaymentAssets::<T>::get()
+                .get(&payment_asset)
+                .copied()
+                .ok_or(Error::<T>::PaymentAssetNotSupported)?;
+            ensure!(
+                amount >= asset_details.min_amount,
+                Error::<T>::PaymentBelowMinimum
+            );
+            Ok(())
+        }
+
+        /// Returns the decimals of a payment asset from its metadata.
+        fn asset_decimals(payment_asset: u32) -> u8 {
+            <<T as pallet::Config>::ForeignCurrency as fungibles::metadata::Inspect<
+                AccountIdOf<T>,
+            >>::decimals(payment_asset)
+        }
+
+        /// Returns the factor between the reference unit and the units of a payment asset.
+        fn decimals_factor(
+            decimals_difference: u8,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            Ok(10u128
+                .checked_pow(decimals_difference.into())
+                .ok_or(Error::<T>::MultiplyError)?
+                .into())
+        }
+
+        /// Returns the exchange rate of a payment asset, which is one for assets pegged to the
+        /// reference unit. Assets that are no longer accepted need an exchange rate.
+        fn exchange_rate(payment_asset: u32) -> Result<FixedU128, DispatchError> {
+            match AcceptedPaymentAssets::<T>::get().get(&payment_asset) {
+                Some(asset_details) if !asset_details.uses_exchange_rate => Ok(FixedU128::one()),
+                _ => T::ExchangeRates::exchange_rate(payment_asset)
+                    .ok_or(Error::<T>::ExchangeRateUnavailable.into()),
+            }
+        }
+
+        /// Applies the exchange rate of the payment asset to an amount in the reference decimals.
+        /// Converting into the payment asset divides by the rate, converting back multiplies.
+        fn apply_exchange_rate(
+            amount: <T as pallet::Config>::Balance,
+            payment_asset: u32,
+            into_payment_asset: bool,
+            rounding: Rounding,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            let rate = Self::exchange_rate(payment_asset)?.into_inner();
+            let (numerator, denominator) = if into_payment_asset {
+                (FixedU128::DIV, rate)
+            } else {
+                (rate, FixedU128::DIV)
+            };
+            let converted_amount = multiply_by_rational_with_rounding(
+                amount.saturated_into::<u128>(),
+                numerator,
+                denominator,
+                rounding,
+            )
+            .ok_or(Error::<T>::MultiplyError)?;
+            Ok(converted_amount.into())
+        }
+
+        /// Converts an amount quoted in the reference unit into the units of the payment asset.
+        /// The amount is rounded up, so a payment never falls short of the quoted price.
+        fn to_payment_asset_units(
+            amount: <T as pallet::Config>::Balance,
+            payment_asset: u32,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            let amount = Self::apply_exchange_rate(amount, payment_asset, true, Rounding::Up)?;
+            let asset_decimals = Self::asset_decimals(payment_asset);
+            let reference_decimals = T::ReferenceDecimals::get();
+            if asset_decimals >= reference_decimals {
+                let factor = Self::decimals_factor(asset_decimals - reference_decimals)?;
+                return amount
+                    .checked_mul(&factor)
+                    .ok_or(Error::<T>::MultiplyError.into());
+            }
+            let factor = Self::decimals_factor(reference_decimals - asset_decimals)?;
+            let converted_amount = amount
+                .checked_div(&factor)
+                .ok_or(Error::<T>::DivisionError)?;
+            let rounded_amount = converted_amount
+                .checked_mul(&factor)
+                .ok_or(Error::<T>::MultiplyError)?;
+            if rounded_amount < amount {
+                converted_amount
+                    .checked_add(&One::one())
+                    .ok_or(Error::<T>::ArithmeticOverflow.into())
+            } else {
+                Ok(converted_amount)
+            }
+        }
+
+        /// Converts an amount in the units of the payment asset into the reference unit.
+        /// The amount is rounded down.
+        fn to_reference_units(
+            amount: <T as pallet::Config>::Balance,
+            payment_asset: u32,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            let asset_decimals = Self::asset_decimals(payment_asset);
+            let reference_decimals = T::ReferenceDecimals::get();
+            let amount = if asset_decimals >= reference_decimals {
+                let factor = Self::decimals_factor(asset_decimals - reference_decimals)?;
+                amount
+                    .checked_div(&factor)
+                    .ok_or(Error::<T>::DivisionError)?
+            } else {
+                let factor = Self::decimals_factor(reference_decimals - asset_decimals)?;
+                amount
+                    .checked_mul(&factor)
+                    .ok_or(Error::<T>::MultiplyError)?
+            };
+            Self::apply_exchange_rate(amount, payment_asset, false, Rounding::Down)
+        }
+
+        /// Converts funds collected by a listing into the reference unit at the average exchange
+        /// rate of the purchases in the payment asset.
+        fn listing_funds_to_reference_units(
+            listing_id: ListingId,
+            amount: <T as pallet::Config>::Balance,
+            payment_asset: u32,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            let (funds, value) = ListingPaymentValues::<T>::get(listing_id, payment_asset);
+            if funds.is_zero() {
+                return Self::to_reference_units(amount, payment_asset);
+            }
+            let converted_amount = multiply_by_rational_with_rounding(
+                amount.saturated_into::<u128>(),
+                value.saturated_into::<u128>(),
+                funds.saturated_into::<u128>(),
+                Rounding::Down,
+            )
+            .ok_or(Error::<T>::MultiplyError)?;
+            Ok(converted_amount.into())
+        }
+
+        /// Converts an amount in the reference unit into the funds collected by a listing in the
+        /// payment asset at the average exchange rate of the purchases.
+        fn listing_reference_to_funds(
+            listing_id: ListingId,
+            amount: <T as pallet::Config>::Balance,
+            payment_asset: u32,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            let (funds, value) = ListingPaymentValues::<T>::get(listing_id, payment_asset);
+            if value.is_zero() {
+                return Self::to_payment_asset_units(amount, payment_asset);
+            }
+            let converted_amount = multiply_by_rational_with_rounding(
+                amount.saturated_into::<u128>(),
+                funds.saturated_into::<u128>(),
+                value.saturated_into::<u128>(),
+                Rounding::Up,
+            )
+            .ok_or(Error::<T>::MultiplyError)?;
+            Ok(converted_amount.into())
+        }
+
+        /// Schedules a listing to be processed in the block after the given round expires.
+        /// The listing is processed in the next block with room if that block is full.
+        fn schedule_expiry(
+            round: ExpiryRound,
+            listing_id: ListingId,
+            expiry_block: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let mut process_block = expiry_block.saturating_add(One::one());
+            let append = |listings: &mut BoundedVec<ListingId, T::MaxListingsForBlock>| {
+                listings.try_push(listing_id)
+            };
+            loop {
+                let scheduled = match round {
+                    ExpiryRound::Listing => {
+                        ListingRoundsExpiring::<T>::try_mutate(process_block, append)
+                    }
+                    ExpiryRound::ClaimWindow => {
+                        ClaimRoundsExpiring::<T>::try_mutate(process_block, append)
+                    }
+                    ExpiryRound::LegalProcess => {
+                        LegalProcessRoundsExpiring::<T>::try_mutate(process_block, append)
+                    }
+                };
+                if scheduled.is_ok() {
+                    return Ok(());
+                }
+                process_block = process_block.saturating_add(One::one());
+            }
+        }
+
+        /// Processes the listings of a round that expired in block `n` as long as they fit the
+        /// expiry processing weight and carries the remaining listings over to the next block.
+        fn process_expiring_round(round: ExpiryRound, n: BlockNumberFor<T>, weight: &mut Weight) {
+            let listings = match round {
+                ExpiryRound::Listing => ListingRoundsExpiring::<T>::take(n),
+                ExpiryRound::ClaimWindow => ClaimRoundsExpiring::<T>::take(n),
+                ExpiryRound::LegalProcess => LegalProcessRoundsExpiring::<T>::take(n),
+            };
+            let budget = T::ExpiryProcessingWeight::get();
+            for (index, listing_id) in listings.iter().enumerate() {
+                let listing_weight = Self::expiry_weight(round, *listing_id);
+                if weight.saturating_add(listing_weight).any_gt(budget) {
+                    for listing_id in &listings[index..] {
+                        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+                        if let Err(e) = Self::schedule_expiry(round, *listing_id, n) {
+                            Self::deposit_event(Event::ListingProcessingFailed {
+                                listing_id: *listing_id,
+                                error: Err(e),
+                            });
+                        }
+                    }
+                    return;
+                }
+                weight.saturating_accrue(listing_weight);
+                if let Err(e) = frame_support::storage::with_storage_layer(|| match round {
+                    ExpiryRound::Listing => Self::process_expired_listing(*listing_id),
+                    ExpiryRound::ClaimWindow => Self::process_expired_claim_window(*listing_id),
+                    ExpiryRound::LegalProcess => Self::process_expired_legal_process(*listing_id),
+                }) {
+                    Self::deposit_event(Event::ListingProcessingFailed {
+                        listing_id: *listing_id,
+                        error: Err(e),
+                    });
+                };
+            }
+        }
+
+        /// Returns the weight of processing a listing whose round expired.
+        fn expiry_weight(round: ExpiryRound, listing_id: ListingId) -> Weight {
+            match round {
+                ExpiryRound::Listing => {
+                    let bidders = AuctionBids::<T>::decode_len(listing_id).unwrap_or_default();
+                    let process_weight = if bidders.is_zero() {
+                        <T as pallet::Config>::WeightInfo::finalize_soft_cap()
+                    } else {
+                        <T as pallet::Config>::WeightInfo::settle_auction(bidders as u32)
+                            .max(<T as pallet::Config>::WeightInfo::finalize_soft_cap())
+                    };
+                    process_weight.saturating_add(T::DbWeight::get().reads(1))
+                }
+                ExpiryRound::ClaimWindow => {
+                    <T as pallet::Config>::WeightInfo::finalize_claim_window()
+                }
+                ExpiryRound::LegalProcess => {
+                    <T as pallet::Config>::WeightInfo::withdraw_legal_process_expired()
+                }
+            }
+        }
+
+        /// Settles the auction, finalizes a reached soft cap or marks the listing as expired.
+        fn process_expired_listing(listing_id: ListingId) -> DispatchResult {
+            let Some(mut property_details) = OngoingObjectListing::<T>::get(listing_id) else {
+                return Ok(());
+            };
+            if property_details.listing_expiry >= <frame_system::Pallet<T>>::block_number()
+                || property_details.listed_token_amount.is_zero()
+            {
+                return Ok(());
+            }
+            if property_details.sale_mode == SaleMode::EnglishAuction
+                && !AuctionBids::<T>::get(listing_id).is_empty()
+            {
+                return Self::do_settle_auction(listing_id);
+            }
+            if Self::soft_cap_reached(listing_id, &property_details)? {
+                Self::finalize_with_soft_cap(listing_id, &mut property_details)?;
+                OngoingObjectListing::<T>::insert(listing_id, &property_details);
+            } else {
+                Self::deposit_event(Event::<T>::ListingExpired {
+                    listing_id,
+                    unsold_token_amount: property_details.listed_token_amount,
+                });
+            }
+            Ok(())
+        }
+
+        /// Finalizes the claim window of a listing if there are unclaimed token left.
+        fn process_expired_claim_window(listing_id: ListingId) -> DispatchResult {
+            let Some(property_details) = OngoingObjectListing::<T>::get(listing_id) else {
+                return Ok(());
+            };
+            match property_details.claim_expiry {
+                Some(claim_expiry)
+                    if claim_expiry < <frame_system::Pallet<T>>::block_number()
+                        && property_details.unclaimed_token_amount > 0 =>
+                {
+                    Self::do_finalize_claim_window(listing_id)
+                }
+                _ => Ok(()),
+            }
+        }
+
+        /// Ends the legal process of a listing if it has not been completed in time.
+        fn process_expired_legal_process(listing_id: ListingId) -> DispatchResult {
+            let (Some(property_details), Some(property_lawyer_details)) = (
+                OngoingObjectListing::<T>::get(listing_id),
+                PropertyLawyer::<T>::get(listing_id),
+            ) else {
+                return Ok(());
+            };
+            if property_lawyer_details.legal_process_expiry
+                >= <frame_system::Pallet<T>>::block_number()
+                || RefundLegalExpired::<T>::contains_key(listing_id)
+            {
+                return Ok(());
+            }
+            Self::expire_legal_process(listing_id, &property_details, &property_lawyer_details)
+        }
+
+        /// Releases the lawyers of a listing, drops the open lawyer proposals and lets the
+        /// investors withdraw their funds.
+        fn expire_legal_process(
+            listing_id: ListingId,
+            property_details: &PropertyListingDetailsType<T>,
+            property_lawyer_details: &PropertyLawyerDetails<T>,
+        ) -> DispatchResult {
+            if let Some(real_estate_developer_lawyer_id) =
+                &property_lawyer_details.real_estate_developer_lawyer
+            {
+                PalletRegions::<T>::decrement_active_cases(real_estate_developer_lawyer_id)?;
+            }
+            if let Some(spv_lawyer_id) = &property_lawyer_details.spv_lawyer {
+                PalletRegions::<T>::decrement_active_cases(spv_lawyer_id)?;
+            }
+            PropertyLawyer::<T>::remove(listing_id);
+            ProposedLawyers::<T>::remove(listing_id);
+            if let Some(proposal_id) = ListingSpvProposal::<T>::take(listing_id) {
+                SpvLawyerProposal::<T>::remove(proposal_id);
+                OngoingLawyerVoting::<T>::remove(proposal_id);
+            }
+            RefundLegalExpired::<T>::insert(listing_id, property_details.token_amount);
+            Self::deposit_event(Event::<T>::LegalProcessExpired { listing_id });
+            Ok(())
+        }
+
+        /// Finalizes the claim window of a listing and relists or refunds the unclaimed token.
+        fn do_finalize_claim_window(listing_id: ListingId) -> DispatchResult {
+            let mut property_details =
+                OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
+            let claim_expiry = property_details
+                .claim_expiry
+                .ok_or(Error::<T>::NoClaimWindow)?;
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                current_block > claim_expiry,
+                Error::<T>::ClaimWindowNotExpired
+            );
+
+            let unclaimed_amount = property_details.unclaimed_token_amount;
+            ensure!(unclaimed_amount > 0, Error::<T>::AllTokensClaimed);
+            if property_details.relist_count >= T::MaxRelistAttempts::get() {
+                property_details.relist_count = property_details
+                    .relist_count
+                    .checked_add(1)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                property_details.claim_expiry = None;
+                OngoingObjectListing::<T>::insert(listing_id, &property_details);
+                RefundClaimedToken::<T>::insert(
+                    listing_id,
+                    property_details
+                        .token_amount
+                        .saturating_sub(unclaimed_amount),
+                );
+                Self::deposit_event(Event::<T>::SaleCancelledUnclaimed {
+                    listing_id,
+                    unclaimed_amount,
+                });
+            } else {
+                property_details.listed_token_amount = property_details
+                    .listed_token_amount
+                    .checked_add(unclaimed_amount)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                property_details.relist_count = property_details
+                    .relist_count
+                    .checked_add(1)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                property_details.unclaimed_token_amount = 0;
+                property_details.claim_expiry = None;
+                let possible_listing_expiry = current_block.saturating_add(T::ClaimWindow::get());
+                if property_details.listing_expiry < possible_listing_expiry {
+                    property_details.listing_expiry = possible_listing_expiry;
+                    Self::schedule_expiry(
+                        ExpiryRound::Listing,
+                        listing_id,
+                        possible_listing_expiry,
+                    )?;
+                }
+                OngoingObjectListing::<T>::insert(listing_id, &property_details);
+                Self::deposit_event(Event::<T>::UnclaimedRelisted {
+                    listing_id,
+                    amount: unclaimed_amount,
+                    relist_count: property_details.relist_count,
+                });
+            }
+            Ok(())
+        }
+
+        /// Settles the english auction of an expired listing.
+        fn do_settle_auction(listing_id: ListingId) -> DispatchResult {
+            let mut property_details =
+                OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
+            ensure!(
+                property_details.sale_mode == SaleMode::EnglishAuction,
+                Error::<T>::InvalidSaleMode
+            );
+            let current_block_number = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                property_details.listing_expiry < current_block_number,
+                Error::<T>::ListingNotExpired
+            );
+            let bids = AuctionBids::<T>::take(listing_id);
+            ensure!(!bids.is_empty(), Error::<T>::NoAuctionBids);
+            T::PropertyToken::get_if_spv_not_created(property_details.asset_id)?;
+
+            let listed_token_amount = property_details.listed_token_amount;
+            let bid_token_amount = bids
+                .iter()
+                .fold(0u32, |total, bid| total.saturating_add(bid.amount));
+            let soft_cap_bid = if property_details.soft_cap.is_some() {
+                let mut bid_token_left = listed_token_amount;
+                let mut bid_value = Self::raised_amount(listing_id)?;
+                for bid in bids.iter() {
+                    let bid_amount = bid.amount.min(bid_token_left);
+                    bid_token_left = bid_token_left.saturating_sub(bid_amount);
+                    bid_value = bid_value.saturating_add(
+                        bid.token_price.saturating_mul((bid_amount as u128).into()),
+                    );
+                }
+                Self::soft_cap_met(&property_details, bid_value)
+            } else {
+                bid_token_amount >= listed_token_amount
+            };
+            let mut sold_token_amount: u32 = 0;
+
+            for bid in bids {
+                let mut release_amount = bid.held_amount;
+                let allocated_amount = if soft_cap_bid {
+                    listed_token_amount
+                        .saturating_sub(sold_token_amount)
+                        .min(bid.amount)
+                } else {
+                    0
+                };
+                if allocated_amount > 0 {
+                    // The bid is settled at the exchange rate of the time it was placed.
+                    let transfer_price =
+                        Self::proportional_share(bid.transfer_price, allocated_amount, bid.amount)?;
+                    let tax = Self::proportional_share(bid.tax, allocated_amount, bid.amount)?;
+                    let mut total_transfer_price = transfer_price
+                        .checked_add(&Self::marketplace_fee(transfer_price)?)
+                        .ok_or(Error::<T>::ArithmeticOverflow)?;
+                    if !property_details.tax_paid_by_developer {
+                        total_transfer_price = total_transfer_price
+                            .checked_add(&tax)
+                            .ok_or(Error::<T>::ArithmeticOverflow)?;
+                    }
+                    let total_transfer_price = total_transfer_price.min(bid.held_amount);
+                    let reference_value = bid
+                        .token_price
+                        .checked_mul(&((allocated_amount as u128).into()))
+                        .ok_or(Error::<T>::MultiplyError)?;
+                    let purchase = frame_support::storage::with_storage_layer(|| {
+                        Self::add_token_purchase(
+                            &bid.bidder,
+                            listing_id,
+                            &property_details,
+                            allocated_amount,
+                            bid.payment_asset,
+                            transfer_price,
+                            tax,
+                        )?;
+                        Self::add_listing_payment(
+                            listing_id,
+                            bid.payment_asset,
+                            transfer_price,
+                            reference_value,
+                        )
+                    });
+                    if let Err(e) = purchase {
+                        Self::deposit_event(Event::<T>::AuctionBidRejected {
+                            listing_id,
+                            bidder: bid.bidder.clone(),
+                            amount: allocated_amount,
+                            error: Err(e),
+                        });
+                    } else {
+                        release_amount = release_amount.saturating_sub(total_transfer_price);
+                        sold_token_amount = sold_token_amount
+                            .checked_add(allocated_amount)
+                            .ok_or(Error::<T>::ArithmeticOverflow)?;
+                        Self::deposit_event(Event::<T>::PropertyTokenBought {
+                            listing_index: listing_id,
+                            asset_id: property_details.asset_id,
+                            buyer: bid.bidder.clone(),
+                            amount_purchased: allocated_amount,
+                            price_paid: transfer_price,
+                            tax_paid: if !property_details.tax_paid_by_developer {
+                                tax
+                            } else {
+                                0u128.into()
+                            },
+                            payment_asset: bid.payment_asset,
+                            new_tokens_remaining: listed_token_amount
+                                .saturating_sub(sold_token_amount),
+                        });
+                    }
+                }
+                if !release_amount.is_zero() {
+                    T::ForeignAssetsHolder::release(
+                        bid.payment_asset,
+                        &MarketplaceHoldReason::Marketplace,
+                        &bid.bidder,
+                        release_amount,
+                        Precision::Exact,
+                    )?;
+                }
+            }
+
+            property_details.listed_token_amount = listed_token_amount
+                .checked_sub(sold_token_amount)
+                .ok_or(Error::<T>::ArithmeticUnderflow)?;
+            property_details.unclaimed_token_amount = property_details
+                .unclaimed_token_amount
+                .checked_add(sold_token_amount)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            let asset_id = property_details.asset_id;
+            if property_details.listed_token_amount.is_zero() {
+                let expiry_block = current_block_number.saturating_add(T::ClaimWindow::get());
+                property_details.claim_expiry = Some(expiry_block);
+                Self::schedule_expiry(ExpiryRound::ClaimWindow, listing_id, expiry_block)?;
+                Self::deposit_event(Event::<T>::PrimarySaleSoldOut {
+                    listing_id,
+                    asset_id,
+                    claim_deadline: expiry_block,
+                });
+            } else if !sold_token_amount.is_zero()
+                && Self::soft_cap_reached(listing_id, &property_details)?
+            {
+                Self::finalize_with_soft_cap(listing_id, &mut property_details)?;
+            }
+            let successful = property_details.claim_expiry.is_some();
+            OngoingObjectListing::<T>::insert(listing_id, &property_details);
+            Self::deposit_event(Event::<T>::AuctionSettled {
+                listing_id,
+                asset_id,
+                sold_token_amount,
+                successful,
+            });
+            Ok(())
+        }
+
+        /// Returns if the amount raised by a listing reached its soft cap.
+        fn soft_cap_reached(
+            listing_id: ListingId,
+            property_details: &PropertyListingDetailsType<T>,
+        ) -> Result<bool, DispatchError> {
+            Ok(Self::soft_cap_met(
+                property_details,
+                Self::raised_amount(listing_id)?,
+            ))
+        }
+
+        /// Returns if the raised amount in the reference unit reaches the soft cap of a listing.
+        /// The unsold token go to the real estate developer regardless of the ownership cap.
+        fn soft_cap_met(
+            property_details: &PropertyListingDetailsType<T>,
+            raised_amount: <T as pallet::Config>::Balance,
+        ) -> bool {
+            property_details.relist_count.is_zero()
+                && property_details
+                    .soft_cap
+                    .is_some_and(|soft_cap| raised_amount >= soft_cap)
+        }
+
+        /// Returns the amount in the reference unit raised by the purchases of a listing.
+        fn raised_amount(
+            listing_id: ListingId,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            ListingPaymentValues::<T>::iter_prefix_values(listing_id).try_fold(
+                Zero::zero(),
+                |total: <T as pallet::Config>::Balance, (_, value)| {
+                    total
+                        .checked_add(&value)
+                        .ok_or(Error::<T>::ArithmeticOverflow.into())
+                },
+            )
+        }
+
+        /// Removes refunded funds from the payments of a listing together with their share of
+        /// the value in the reference unit.
+        fn remove_listing_payments(
+            listing_id: ListingId,
+            refunded_funds: &BoundedBTreeMap<
+                u32,
+                <T as pallet::Config>::Balance,
+                <T as pallet::Config>::MaxAcceptedAssets,
+            >,
+        ) -> DispatchResult {
+            for (asset, &refunded) in refunded_funds.iter() {
+                ListingPaymentValues::<T>::try_mutate(listing_id, asset, |(funds, value)| {
+                    let refunded_value = if refunded >= *funds {
+                        *value
+                    } else {
+                        multiply_by_rational_with_rounding(
+                            refunded.saturated_into::<u128>(),
+                            (*value).saturated_into::<u128>(),
+                            (*funds).saturated_into::<u128>(),
+                            Rounding::Down,
+                        )
+                        .ok_or(Error::<T>::MultiplyError)?
+                        .into()
+                    };
+                    *funds = funds.saturating_sub(refunded);
+                    *value = value.saturating_sub(refunded_value);
+                    Ok::<(), DispatchError>(())
+                })?;
+            }
+            Ok(())
+        }
+
+        /// Reduces the listing to the sold token and starts the claim window.
+        /// The unsold token stay in the property account until the sale is completed.
+        fn finalize_with_soft_cap(
+            listing_id: ListingId,
+            property_details: &mut PropertyListingDetailsType<T>,
+        ) -> DispatchResult {
+            let unsold_token_amount = property_details.listed_token_amount;
+            property_details.token_amount = property_details
+                .token_amount
+                .checked_sub(unsold_token_amount)
+                .ok_or(Error::<T>::ArithmeticUnderflow)?;
+            property_details.listed_token_amount = 0;
+            let expiry_block =
+                <frame_system::Pallet<T>>::block_number().saturating_add(T::ClaimWindow::get());
+            property_details.claim_expiry = Some(expiry_block);
+            Self::schedule_expiry(ExpiryRound::ClaimWindow, listing_id, expiry_block)?;
+            Self::deposit_event(Event::<T>::SoftCapReached {
+                listing_id,
+                asset_id: property_details.asset_id,
+                sold_token_amount: property_details.token_amount,
+                unsold_token_amount,
+                claim_deadline: expiry_block,
+            });
+            Ok(())
+        }
+
+        /// Returns the current token price of a primary sale listing.
+        fn current_token_price(
+            property_details: &PropertyListingDetailsType<T>,
+        ) -> <T as pallet::Config>::Balance {
+            match property_details.sale_mode {
+                SaleMode::DutchAuction {
+                    floor_price,
+                    price_decrement,
+                } => {
+                    let elapsed_blocks: u128 = <frame_system::Pallet<T>>::block_number()
+                        .saturating_sub(property_details.listed_at)
+                        .saturated_into();
+                    let price_decline = price_decrement.saturating_mul(elapsed_blocks.into());
+                    property_details
+                        .token_price
+                        .saturating_sub(price_decline)
+                        .max(floor_price)
+                }
+                _ => property_details.token_price,
+            }
+        }
+
+        /// Calculates the price, the tax and the total amount an investor has to pay
+        /// for token of a primary sale.
+        /// The price is converted into the payment asset first, so the fee and the tax are
+        /// derived from the amount that is actually paid.
+        fn calculate_purchase_price(
+            token_price: <T as pallet::Config>::Balance,
+            amount: u32,
+            payment_asset: u32,
+            tax_percent: Permill,
+            tax_paid_by_developer: bool,
+        ) -> Result<PurchasePrice<T>, DispatchError> {
+            let fee_percent = T::MarketplaceFeePercentage::get();
+            ensure!(
+                fee_percent < 100u128.into(),
+                Error::<T>::InvalidFeePercentage
+            );
+            ensure!(
+                tax_percent < Permill::from_percent(100),
+                Error::<T>::InvalidTaxPercentage
+            );
+
+            let reference_price = token_price
+                .checked_mul(&((amount as u128).into()))
+                .ok_or(Error::<T>::MultiplyError)?;
+            let transfer_price = Self::to_payment_asset_units(reference_price, payment_asset)?;
+            let fee = Self::marketplace_fee(transfer_price)?;
+            let tax = tax_percent.mul_floor(transfer_price);
+
+            let base_price = transfer_price
+                .checked_add(&fee)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+
+            let total_transfer_price = if tax_paid_by_developer {
+                base_price
+            } else {
+                base_price
+                    .checked_add(&tax)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?
+            };
+            Ok((transfer_price, tax, total_transfer_price))
+        }
 
-                // Calculate amounts to distribute
-                let mut developer_amount = total_collected_funds
-                    .checked_mul(&developer_percentage)
-                    .ok_or(Error::<T>::MultiplyError)?
-                    .checked_div(&(100u128.into()))
-                    .ok_or(Error::<T>::DivisionError)?;
+        /// Returns the marketplace fee for an amount paid in a payment asset.
+        fn marketplace_fee(
+            amount: <T as pallet::Config>::Balance,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            Ok(amount
+                .checked_mul(&T::MarketplaceFeePercentage::get())
+                .ok_or(Error::<T>::MultiplyError)?
+                .checked_div(&100u128.into())
+                .ok_or(Error::<T>::DivisionError)?)
+        }
 
-                let (developer_lawyer_tax, spv_lawyer_tax) =
-                    if property_details.tax_paid_by_developer {
-                        developer_amount = developer_amount
-                            .checked_sub(&tax)
-                            .ok_or(Error::<T>::ArithmeticUnderflow)?;
-                        (tax, Zero::zero())
-                    } else {
-                        (Zero::zero(), tax)
-                    };
+        /// Adds purchased token of a primary sale to the token owner details of an investor.
+        fn add_token_purchase(
+            signer: &AccountIdOf<T>,
+            listing_id: ListingId,
+            property_details: &PropertyListingDetailsType<T>,
+            amount: u32,
+            payment_asset: u32,
+            transfer_price: <T as pallet::Config>::Balance,
+            tax: <T as pallet::Config>::Balance,
+        ) -> DispatchResult {
+            let max_tokens =
+                Self::max_token_ownership(property_details.asset_id, property_details.token_amount);
+            let linked_token_amount =
+                Self::linked_token_amount(property_details.asset_id, Some(listing_id), signer)?;
+            TokenOwner::<T>::try_mutate_exists(signer, listing_id, |maybe_token_owner_details| {
+                if maybe_token_owner_details.is_none() {
+                    let initial_funds = Self::create_initial_funds()?;
+                    *maybe_token_owner_details = Some(TokenOwnerDetails {
+                        token_amount: 0,
+                        paid_funds: initial_funds.clone(),
+                        paid_tax: initial_funds,
+                        relist_count: property_details.relist_count,
+                    });
+                }
 
-                let spv_lawyer_amount = spv_lawyer_costs
-                    .checked_add(&spv_lawyer_tax)
+                let token_owner_details = maybe_token_owner_details
+                    .as_mut()
+                    .ok_or(Error::<T>::TokenOwnerNotFound)?;
+                ensure!(
+                    token_owner_details.relist_count == property_details.relist_count,
+                    Error::<T>::StillHasUnclaimedToken
+                );
+                let claimed_token_amount = <T as pallet::Config>::PropertyToken::get_token_balance(
+                    property_details.asset_id,
+                    signer,
+                );
+                let new_token_amount = token_owner_details
+                    .token_amount
+                    .checked_add(amount)
                     .ok_or(Error::<T>::ArithmeticOverflow)?;
-
-                let protocol_fees = total_collected_funds
-                    .checked_div(&(100u128.into()))
-                    .ok_or(Error::<T>::DivisionError)?
-                    .checked_add(&collected_fees)
+                let total_investor_token_amount = new_token_amount
+                    .checked_add(claimed_token_amount)
                     .ok_or(Error::<T>::ArithmeticOverflow)?
-                    .saturating_sub(spv_lawyer_costs);
-
-                let region_owner_amount = protocol_fees
-                    .checked_div(&(2u128.into()))
-                    .ok_or(Error::<T>::DivisionError)?;
-                let treasury_amount = protocol_fees.saturating_sub(region_owner_amount);
+                    .checked_add(linked_token_amount)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                ensure!(
+                    total_investor_token_amount <= max_tokens,
+                    Error::<T>::ExceedsMaxOwnership
+                );
+                token_owner_details.token_amount = new_token_amount;
 
-                // Transfer funds from property account
-                Self::transfer_funds(
-                    &property_account,
-                    &property_details.real_estate_developer,
-                    developer_amount,
-                    asset,
+                Self::update_map(
+                    &mut token_owner_details.paid_funds,
+                    payment_asset,
+                    transfer_price,
                 )?;
-                if !developer_lawyer_tax.is_zero() {
-                    Self::transfer_funds(
-                        &property_account,
-                        &real_estate_developer_lawyer_id,
-                        developer_lawyer_tax,
-                        asset,
-                    )?;
-                }
-                Self::transfer_funds(&property_account, &spv_lawyer_id, spv_lawyer_amount, asset)?;
-                Self::transfer_funds(&property_account, &treasury_id, treasury_amount, asset)?;
-                Self::transfer_funds(&property_account, &region.owner, region_owner_amount, asset)?;
 
-                match asset {
-                    1337 => {
-                        // USDC ID
-                        developer_payout.amount_in_usdc = developer_amount;
-                        spv_lawyer_payout.amount_in_usdc = spv_lawyer_costs;
-                        treasury_payout.amount_in_usdc = treasury_amount;
-                        region_owner_payout.amount_in_usdc = region_owner_amount;
-                    }
-                    1984 => {
-                        // USDT ID
-                        developer_payout.amount_in_usdt = developer_amount;
-                        spv_lawyer_payout.amount_in_usdt = spv_lawyer_costs;
-                        treasury_payout.amount_in_usdt = treasury_amount;
-                        region_owner_payout.amount_in_usdt = region_owner_amount;
-                    }
-                    _ => {}
+                if !property_details.tax_paid_by_developer {
+                    Self::update_map(&mut token_owner_details.paid_tax, payment_asset, tax)?;
                 }
-            }
-            T::PropertyToken::finalize_property(property_details.asset_id)?;
-            // Release deposit
-            if let Some((depositor, deposit_amount)) = ListingDeposits::<T>::take(listing_id) {
-                <T as pallet::Config>::NativeCurrency::release(
-                    &HoldReason::ListingDepositReserve.into(),
-                    &depositor,
-                    deposit_amount,
-                    Precision::Exact,
-                )?;
-            }
 
-            let payouts = FinalSettlementPayouts {
-                developer_payout,
-                developer_account: property_details.real_estate_developer.clone(),
-                spv_lawyer_payout,
-                spv_lawyer_account: spv_lawyer_id.clone(),
-                treasury_payout,
-                treasury_account: treasury_id.clone(),
-                region_owner_payout,
-                region_owner_account: region.owner.clone(),
-            };
+                Ok::<(), DispatchError>(())
+            })?;
+            ListingTokenOwners::<T>::insert(listing_id, signer, ());
+            Ok(())
+        }
 
-            Self::deposit_event(Event::<T>::PrimarySaleCompleted {
-                listing_id,
-                asset_id: property_details.asset_id,
-                payouts,
-            });
+        /// Adds the funds paid for token of a listing and their value in the reference unit to
+        /// the payments of the listing.
+        fn add_listing_payment(
+            listing_id: ListingId,
+            payment_asset: u32,
+            transfer_price: <T as pallet::Config>::Balance,
+            reference_value: <T as pallet::Config>::Balance,
+        ) -> DispatchResult {
+            ListingPaymentValues::<T>::try_mutate(listing_id, payment_asset, |(funds, value)| {
+                *funds = funds
+                    .checked_add(&transfer_price)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                *value = value
+                    .checked_add(&reference_value)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                Ok::<(), DispatchError>(())
+            })
+        }
+
+        /// Ensures through the `CompliantOrigin` that the investor is eligible to hold the token
+        /// amount of a property in the region at the given token price.
+        fn ensure_investment_eligible(
+            origin: OriginFor<T>,
+            region: RegionId,
+            token_amount: u32,
+            token_price: <T as pallet::Config>::Balance,
+        ) -> DispatchResult {
+            let investment = token_price
+                .saturated_into::<u128>()
+                .saturating_mul(token_amount as u128);
+            <T as pallet::Config>::CompliantOrigin::ensure_origin(
+                origin,
+                &InvestmentEligibility { region, investment },
+            )?;
             Ok(())
         }
 
-        fn reject_and_refund(
-            listing_id: u32,
-            property_lawyer_details: &PropertyLawyerDetails<T>,
+        /// Ensures that the investor is eligible to buy token of a relisting at the given token
+        /// price in the reference unit.
+        fn ensure_relisted_investment_eligible(
+            origin: OriginFor<T>,
+            investor: &AccountIdOf<T>,
+            listing_details: &ListingDetailsType<T>,
+            amount: u32,
+            token_price: <T as pallet::Config>::Balance,
         ) -> DispatchResult {
-            let property_details =
-                OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::ListingNotFound)?;
-            let real_estate_developer_lawyer_id = property_lawyer_details
-                .real_estate_developer_lawyer
-                .clone()
-                .ok_or(Error::<T>::LawyerNotFound)?;
-            let spv_lawyer_id = property_lawyer_details
-                .spv_lawyer
-                .clone()
-                .ok_or(Error::<T>::LawyerNotFound)?;
-            PalletRegions::<T>::decrement_active_cases(&real_estate_developer_lawyer_id)?;
-            PalletRegions::<T>::decrement_active_cases(&spv_lawyer_id)?;
-            RefundToken::<T>::insert(
-                listing_id,
-                RefundInfos {
-                    refund_amount: property_details.token_amount,
-                    property_lawyer_details: property_lawyer_details.clone(),
-                },
+            let asset_details = <T as pallet::Config>::PropertyToken::get_property_asset_info(
+                listing_details.asset_id,
+            )
+            .ok_or(Error::<T>::NoObjectFound)?;
+            let owned_token = <T as pallet::Config>::PropertyToken::get_token_balance(
+                listing_details.asset_id,
+                investor,
             );
-            Ok(())
+            Self::ensure_investment_eligible(
+                origin,
+                asset_details.region,
+                owned_token.saturating_add(amount),
+                token_price,
+            )
         }
 
-        fn refund_investors_with_fees(
-            property_details: &PropertyListingDetailsType<T>,
-            property_lawyer_details: PropertyLawyerDetails<T>,
+        fn restrict_ownership(
+            asset_id: u32,
+            account: &AccountIdOf<T>,
+            amount: u32,
         ) -> DispatchResult {
-            let property_account = Self::property_account_id(property_details.asset_id);
-            let treasury_id = Self::treasury_account_id();
-            let spv_lawyer_id = property_lawyer_details
-                .spv_lawyer
-                .ok_or(Error::<T>::LawyerNotFound)?;
+            let property_info =
+                <T as pallet::Config>::PropertyToken::get_property_asset_info(asset_id)
+                    .ok_or(Error::<T>::NoObjectFound)?;
+            let max_tokens = Self::max_token_ownership(asset_id, property_info.token_amount);
+            let owned_token =
+                <T as pallet::Config>::PropertyToken::get_token_balance(asset_id, account);
+            let new_token_amount = owned_token
+                .checked_add(amount)
+                .ok_or(Error::<T>::ArithmeticOverflow)?
+                .checked_add(Self::linked_token_amount(asset_id, None, account)?)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            ensure!(
+                new_token_amount <= max_tokens,
+                Error::<T>::ExceedsMaxOwnership
+            );
+            Ok(())
+        }
 
-            // Process fees and transfers for each asset
-            for asset in T::AcceptedAssets::get().iter() {
-                // Fetch fees and lawyer costs
-                let fees = property_details
-                    .collected_fees
-                    .get(asset)
-                    .copied()
-                    .ok_or(Error::<T>::AssetNotSupported)?;
-                let lawyer_costs = property_lawyer_details
-                    .spv_lawyer_costs
-                    .get(asset)
-                    .copied()
-                    .ok_or(Error::<T>::AssetNotSupported)?;
+        /// Returns the ownership cap of a property.
+        pub fn ownership_cap(asset_id: u32) -> Permill {
+            OwnershipCap::<T>::get(asset_id)
+                .unwrap_or_else(<T as pallet::Config>::DefaultOwnershipCap::get)
+        }
 
-                // Calculate treasury amount
-                let treasury_amount = fees
-                    .checked_sub(&lawyer_costs)
+        /// Returns the maximum token amount of a property that an investor can hold under the
+        /// ownership cap.
+        fn max_token_ownership(asset_id: u32, token_amount: u32) -> u32 {
+            Self::ownership_cap(asset_id).mul_floor(token_amount)
+        }
+
+        /// Returns the token of a property held by the accounts linked to an investor,
+        /// including token bought in the given listing that have not been claimed yet.
+        fn linked_token_amount(
+            asset_id: u32,
+            listing_id: Option<ListingId>,
+            account: &AccountIdOf<T>,
+        ) -> Result<u32, DispatchError> {
+            <T as pallet::Config>::Whitelist::linked_accounts(account)
+                .iter()
+                .filter(|linked_account| *linked_account != account)
+                .try_fold(0u32, |total, linked_account| {
+                    let pending_token = listing_id
+                        .and_then(|listing_id| TokenOwner::<T>::get(linked_account, listing_id))
+                        .map(|token_details| token_details.token_amount)
+                        .unwrap_or_default();
+                    total
+                        .checked_add(<T as pallet::Config>::PropertyToken::get_token_balance(
+                            asset_id,
+                            linked_account,
+                        ))
+                        .and_then(|total| total.checked_add(pending_token))
+                        .ok_or(Error::<T>::ArithmeticOverflow.into())
+                })
+        }
+
+        /// Matches a new bid against the open asks of the property.
+        /// Fills are executed at the price of the ask converted into the payment asset of the bid.
+        fn match_bid(
+            order_id: OrderId,
+            order: &mut OrderDetails<T>,
+            payment_asset: u32,
+        ) -> DispatchResult {
+            let mut asks = AssetAsks::<T>::get(order.asset_id);
+            let mut index = 0;
+            while index < asks.len() && order.amount > 0 {
+                let ask_id = asks[index];
+                let mut ask = Orders::<T>::get(ask_id).ok_or(Error::<T>::OrderNotFound)?;
+                if ask.owner == order.owner {
+                    index = index.saturating_add(1);
+                    continue;
+                }
+                let amount = order.amount.min(ask.amount);
+                let amount_in_balance: <T as pallet::Config>::Balance = (amount as u128).into();
+                let price = Self::to_payment_asset_units(
+                    ask.token_price
+                        .checked_mul(&amount_in_balance)
+                        .ok_or(Error::<T>::MultiplyError)?,
+                    payment_asset,
+                )?;
+                let max_price = order
+                    .token_price
+                    .checked_mul(&amount_in_balance)
+                    .ok_or(Error::<T>::MultiplyError)?;
+                if price > max_price {
+                    break;
+                }
+                Self::settle_order_fill(
+                    order.asset_id,
+                    &order.owner,
+                    &ask.owner,
+                    price,
+                    amount,
+                    payment_asset,
+                )?;
+                ask.amount = ask
+                    .amount
+                    .checked_sub(amount)
+                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
+                order.amount = order
+                    .amount
+                    .checked_sub(amount)
+                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
+                Self::deposit_event(Event::<T>::OrderFilled {
+                    maker_order_id: ask_id,
+                    taker_order_id: order_id,
+                    asset_id: order.asset_id,
+                    buyer: order.owner.clone(),
+                    seller: ask.owner.clone(),
+                    token_price: ask.token_price,
+                    amount,
+                    payment_asset,
+                    maker_amount_remaining: ask.amount,
+                });
+                if ask.amount == 0 {
+                    Orders::<T>::remove(ask_id);
+                    Self::release_order_slot(ask_id, &ask.owner, order.asset_id)?;
+                    asks.remove(index);
+                } else {
+                    Orders::<T>::insert(ask_id, ask);
+                    index = index.saturating_add(1);
+                }
+            }
+            AssetAsks::<T>::insert(order.asset_id, asks);
+            Ok(())
+        }
+
+        /// Matches a new ask against the open bids of the property.
+        /// Fills are executed at the price of the bid, if its value in the reference unit reaches
+        /// the price of the ask.
+        fn match_ask(order_id: OrderId, order: &mut OrderDetails<T>) -> DispatchResult {
+            let mut bids = AssetBids::<T>::get(order.asset_id);
+            let mut index = 0;
+            while index < bids.len() && order.amount > 0 {
+                let bid_id = bids[index];
+                let mut bid = Orders::<T>::get(bid_id).ok_or(Error::<T>::OrderNotFound)?;
+                let amount = order.amount.min(bid.amount);
+                let amount_in_balance: <T as pallet::Config>::Balance = (amount as u128).into();
+                let payment_asset = bid
+                    .payment_asset
+                    .ok_or(Error::<T>::PaymentAssetNotSupported)?;
+                let price = bid
+                    .token_price
+                    .checked_mul(&amount_in_balance)
+                    .ok_or(Error::<T>::MultiplyError)?;
+                let min_price = order
+                    .token_price
+                    .checked_mul(&amount_in_balance)
+                    .ok_or(Error::<T>::MultiplyError)?;
+                // Bids in different payment assets are only ordered by the exchange rates at the
+                // time they were placed, so every bid is checked.
+                let reaches_ask = Self::to_reference_units(price, payment_asset)
+                    .is_ok_and(|reference_price| reference_price >= min_price);
+                if !reaches_ask
+                    || bid.owner == order.owner
+                    || Self::restrict_ownership(order.asset_id, &bid.owner, amount).is_err()
+                {
+                    index = index.saturating_add(1);
+                    continue;
+                }
+                T::ForeignAssetsHolder::release(
+                    payment_asset,
+                    &MarketplaceHoldReason::Marketplace,
+                    &bid.owner,
+                    price,
+                    Precision::Exact,
+                )?;
+                Self::settle_order_fill(
+                    order.asset_id,
+                    &bid.owner,
+                    &order.owner,
+                    price,
+                    amount,
+                    payment_asset,
+                )?;
+                bid.amount = bid
+                    .amount
+                    .checked_sub(amount)
+                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
+                order.amount = order
+                    .amount
+                    .checked_sub(amount)
                     .ok_or(Error::<T>::ArithmeticUnderflow)?;
-
-                // Perform fund transfers
-                Self::transfer_funds(&property_account, &treasury_id, treasury_amount, *asset)?;
-                Self::transfer_funds(&property_account, &spv_lawyer_id, lawyer_costs, *asset)?;
+                Self::deposit_event(Event::<T>::OrderFilled {
+                    maker_order_id: bid_id,
+                    taker_order_id: order_id,
+                    asset_id: order.asset_id,
+                    buyer: bid.owner.clone(),
+                    seller: order.owner.clone(),
+                    token_price: bid.token_price,
+                    amount,
+                    payment_asset,
+                    maker_amount_remaining: bid.amount,
+                });
+                if bid.amount == 0 {
+                    Orders::<T>::remove(bid_id);
+                    Self::release_order_slot(bid_id, &bid.owner, order.asset_id)?;
+                    bids.remove(index);
+                } else {
+                    Orders::<T>::insert(bid_id, bid);
+                    index = index.saturating_add(1);
+                }
             }
-            T::PropertyToken::clear_token_owners(property_details.asset_id)?;
+            AssetBids::<T>::insert(order.asset_id, bids);
             Ok(())
         }
 
-        fn buying_token_process(
-            listing_id: u32,
-            transfer_from: &AccountIdOf<T>,
-            account: &AccountIdOf<T>,
-            mut listing_details: ListingDetailsType<T>,
+        /// Pays the seller and the treasury and sends the listed token to the buyer.
+        fn settle_order_fill(
+            asset_id: u32,
+            buyer: &AccountIdOf<T>,
+            seller: &AccountIdOf<T>,
             price: <T as pallet::Config>::Balance,
             amount: u32,
             payment_asset: u32,
         ) -> DispatchResult {
-            Self::calculate_fees(price, transfer_from, &listing_details.seller, payment_asset)?;
-            let property_account = Self::property_account_id(listing_details.asset_id);
+            Self::calculate_fees(price, buyer, seller, payment_asset)?;
+            let property_account = Self::property_account_id(asset_id);
             T::PropertyToken::transfer_property_token(
-                listing_details.asset_id,
-                &listing_details.seller,
+                asset_id,
+                seller,
                 &property_account,
-                account,
+                buyer,
                 amount,
             )?;
-            listing_details.amount = listing_details
-                .amount
-                .checked_sub(amount)
-                .ok_or(Error::<T>::ArithmeticUnderflow)?;
-            if listing_details.amount > 0 {
-                TokenListings::<T>::insert(listing_id, listing_details.clone());
-            }
-            Self::deposit_event(Event::<T>::RelistedTokenBought {
-                listing_index: listing_id,
-                asset_id: listing_details.asset_id,
-                buyer: account.clone(),
-                seller: listing_details.seller,
-                price: listing_details.token_price,
-                amount,
-                payment_asset,
-                new_amount_remaining: listing_details.amount,
-            });
             Ok(())
         }
 
-        fn unfreeze_token(
-            token_details: &TokenOwnerDetails<T>,
-            signer: &AccountIdOf<T>,
-        ) -> DispatchResult {
-            for asset in T::AcceptedAssets::get().iter() {
-                if let Some(paid_funds) = token_details.paid_funds.get(asset).copied() {
-                    if paid_funds.is_zero() {
-                        continue;
-                    }
-
-                    let default = Default::default();
-                    let paid_tax = token_details
-                        .paid_tax
-                        .get(asset)
-                        .copied()
-                        .unwrap_or(default);
-
-                    // Calculate refund and investor fee (1% of paid funds)
-                    let refund_amount = paid_funds
-                        .checked_add(&paid_tax)
-                        .ok_or(Error::<T>::ArithmeticOverflow)?;
-                    let investor_fee = paid_funds
-                        .checked_div(&(100u128.into()))
-                        .ok_or(Error::<T>::DivisionError)?;
-                    let total_investor_amount = refund_amount
-                        .checked_add(&investor_fee)
-                        .ok_or(Error::<T>::ArithmeticOverflow)?;
-
-                    // Release funds
-                    T::ForeignAssetsHolder::release(
-                        *asset,
-                        &MarketplaceHoldReason::Marketplace,
-                        signer,
-                        total_investor_amount,
-                        Precision::Exact,
-                    )?;
-                }
+        /// Returns the price of a single token of an order in the reference unit.
+        fn order_reference_price(
+            order: &OrderDetails<T>,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            match order.payment_asset {
+                Some(payment_asset) => Self::to_reference_units(order.token_price, payment_asset),
+                None => Ok(order.token_price),
             }
-            Ok(())
         }
 
-        fn unfreeze_token_with_refunds(
-            token_details: &TokenOwnerDetails<T>,
-            signer: &AccountIdOf<T>,
-        ) -> Result<
-            (
-                <T as pallet::Config>::Balance,
-                <T as pallet::Config>::Balance,
-                <T as pallet::Config>::Balance,
-                <T as pallet::Config>::Balance,
-            ),
-            DispatchError,
-        > {
-            let mut principal_usdc = Default::default();
-            let mut tax_usdc = Default::default();
-            let mut principal_usdt = Default::default();
-            let mut tax_usdt = Default::default();
-
-            for asset in T::AcceptedAssets::get().iter() {
-                if let Some(paid_funds) = token_details.paid_funds.get(asset).copied() {
-                    if paid_funds.is_zero() {
+        /// Inserts an order into the order book behind all orders with the same or a better price
+        /// and holds the order deposit of the owner.
+        /// Prices are compared in the reference unit, orders whose price can no longer be
+        /// converted are skipped.
+        fn insert_order(order_id: OrderId, order: OrderDetails<T>) -> DispatchResult {
+            let reference_price = Self::order_reference_price(&order)?;
+            AccountOpenOrders::<T>::try_mutate(&order.owner, order.asset_id, |open_orders| {
+                ensure!(
+                    *open_orders < T::MaxOrdersPerAccount::get(),
+                    Error::<T>::TooManyOpenOrders
+                );
+                *open_orders = open_orders.saturating_add(1);
+                Ok::<(), DispatchError>(())
+            })?;
+            let deposit_amount = T::OrderDeposit::get();
+            <T as pallet::Config>::NativeCurrency::hold(
+                &HoldReason::OrderDepositReserve.into(),
+                &order.owner,
+                deposit_amount,
+            )?;
+            OrderDeposits::<T>::insert(order_id, (&order.owner, deposit_amount));
+            let insert = |book: &mut BoundedVec<OrderId, T::MaxOrdersPerAsset>| {
+                let mut position = book.len();
+                for (index, existing_id) in book.iter().enumerate() {
+                    let existing =
+                        Orders::<T>::get(existing_id).ok_or(Error::<T>::OrderNotFound)?;
+                    let Ok(existing_price) = Self::order_reference_price(&existing) else {
                         continue;
-                    }
-
-                    let default = Default::default();
-                    let paid_tax = token_details
-                        .paid_tax
-                        .get(asset)
-                        .copied()
-                        .unwrap_or(default);
-
-                    // Calculate refund and investor fee (1% of paid funds)
-                    let refund_amount = paid_funds
-                        .checked_add(&paid_tax)
-                        .ok_or(Error::<T>::ArithmeticOverflow)?;
-                    let investor_fee = paid_funds
-                        .checked_div(&(100u128.into()))
-                        .ok_or(Error::<T>::DivisionError)?;
-                    let total_investor_amount = refund_amount
-                        .checked_add(&investor_fee)
-                        .ok_or(Error::<T>::ArithmeticOverflow)?;
-
-                    // Release funds
-                    T::ForeignAssetsHolder::release(
-                        *asset,
-                        &MarketplaceHoldReason::Marketplace,
-                        signer,
-                        total_investor_amount,
-                        Precision::Exact,
-                    )?;
-
-                    if *asset == 1337u32 {
-                        principal_usdc = paid_funds;
-                        tax_usdc = paid_tax;
-                    } else if *asset == 1984u32 {
-                        principal_usdt = paid_funds;
-                        tax_usdt = paid_tax;
+                    };
+                    let has_priority = match order.side {
+                        OrderSide::Bid => reference_price > existing_price,
+                        OrderSide::Ask => reference_price < existing_price,
+                    };
+                    if has_priority {
+                        position = index;
+                        break;
                     }
                 }
+                book.try_insert(position, order_id)
+                    .map_err(|_| Error::<T>::OrderBookFull)?;
+                Ok::<(), DispatchError>(())
+            };
+            match order.side {
+                OrderSide::Bid => AssetBids::<T>::try_mutate(order.asset_id, insert)?,
+                OrderSide::Ask => AssetAsks::<T>::try_mutate(order.asset_id, insert)?,
             }
-            Ok((principal_usdc, tax_usdc, principal_usdt, tax_usdt))
-        }
-
-        fn calculate_fees(
-            price: <T as pallet::Config>::Balance,
-            sender: &AccountIdOf<T>,
-            receiver: &AccountIdOf<T>,
-            asset: u32,
-        ) -> DispatchResult {
-            let fee_percent = T::MarketplaceFeePercentage::get();
-            ensure!(
-                fee_percent < 100u128.into(),
-                Error::<T>::InvalidFeePercentage
-            );
-
-            let fees = price
-                .checked_mul(&fee_percent)
-                .ok_or(Error::<T>::MultiplyError)?
-                .checked_div(&(100u128.into()))
-                .ok_or(Error::<T>::DivisionError)?;
-            let treasury_id = Self::treasury_account_id();
-            let seller_part = price
-                .checked_sub(&fees)
-                .ok_or(Error::<T>::ArithmeticUnderflow)?;
-
-            Self::transfer_funds(sender, &treasury_id, fees, asset)?;
-            Self::transfer_funds(sender, receiver, seller_part, asset)?;
+            Orders::<T>::insert(order_id, order);
             Ok(())
         }
 
-        fn transfer_funds(
-            from: &AccountIdOf<T>,
-            to: &AccountIdOf<T>,
-            amount: <T as pallet::Config>::Balance,
-            asset: u32,
+        /// Frees the slot of a closed order of the owner and releases the order deposit.
+        fn release_order_slot(
+            order_id: OrderId,
+            owner: &AccountIdOf<T>,
+            asset_id: u32,
         ) -> DispatchResult {
-            if !amount.is_zero() {
-                T::ForeignCurrency::transfer(asset, from, to, amount, Preservation::Expendable)
-                    .map_err(|_| Error::<T>::NotEnoughFunds)?;
+            AccountOpenOrders::<T>::mutate_exists(owner, asset_id, |open_orders| {
+                *open_orders = open_orders
+                    .map(|open_orders| open_orders.saturating_sub(1))
+                    .filter(|open_orders| !open_orders.is_zero());
+            });
+            if let Some((depositor, deposit_amount)) = OrderDeposits::<T>::take(order_id) {
+                <T as pallet::Config>::NativeCurrency::release(
+                    &HoldReason::OrderDepositReserve.into(),
+                    &depositor,
+                    deposit_amount,
+                    Precision::Exact,
+                )?;
             }
             Ok(())
         }
 
-        fn create_initial_funds() -> Result<
-            BoundedBTreeMap<
-                u32,
-                <T as pallet::Config>::Balance,
-                <T as pallet::Config>::MaxAcceptedAssets,
-            >,
-            DispatchError,
-        > {
-            let mut map = BoundedBTreeMap::default();
-            for &asset in T::AcceptedAssets::get().iter() {
-                map.try_insert(asset, Default::default())
-                    .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
-            }
-            Ok(map)
+        /// Returns up to `limit` ongoing property listings in storage order, starting after the
+        /// listing `start`.
+        pub fn ongoing_listings(
+            start: Option<ListingId>,
+            limit: u32,
+        ) -> Vec<ListingInfo<AccountIdOf<T>, <T as pallet::Config>::Balance, BlockNumberFor<T>>>
+        {
+            let listings = match start {
+                Some(listing_id) => OngoingObjectListing::<T>::iter_from(
+                    OngoingObjectListing::<T>::hashed_key_for(listing_id),
+                ),
+                None => OngoingObjectListing::<T>::iter(),
+            };
+            listings
+                .take(limit as usize)
+                .map(|(listing_id, details)| ListingInfo {
+                    listing_id,
+                    token_price: Self::current_token_price(&details),
+                    real_estate_developer: details.real_estate_developer,
+                    asset_id: details.asset_id,
+                    token_amount: details.token_amount,
+                    listed_token_amount: details.listed_token_amount,
+                    tax_paid_by_developer: details.tax_paid_by_developer,
+                    tax: details.tax,
+                    listing_expiry: details.listing_expiry,
+                    claim_expiry: details.claim_expiry,
+                    sale_mode: details.sale_mode,
+                    soft_cap: details.soft_cap,
+                })
+                .collect()
         }
 
-        fn update_map(
-            map: &mut BoundedBTreeMap<
-                u32,
-                <T as pallet::Config>::Balance,
-                <T as pallet::Config>::MaxAcceptedAssets,
-            >,
-            asset: u32,
-            value: <T as pallet::Config>::Balance,
-        ) -> DispatchResult {
-            if let Some(existing) = map.get_mut(&asset) {
-                *existing = existing
-                    .checked_add(&value)
-                    .ok_or(Error::<T>::ArithmeticOverflow)?;
-            } else {
-                map.try_insert(asset, value)
-                    .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
-            }
-            Ok(())
+        /// Returns up to `limit` relisted token listings in storage order, starting after the
+        /// listing `start`.
+        pub fn token_listings(
+            start: Option<ListingId>,
+            limit: u32,
+        ) -> Vec<TokenListingInfo<AccountIdOf<T>, <T as pallet::Config>::Balance>> {
+            let listings = match start {
+                Some(listing_id) => {
+                    TokenListings::<T>::iter_from(TokenListings::<T>::hashed_key_for(listing_id))
+                }
+                None => TokenListings::<T>::iter(),
+            };
+            listings
+                .take(limit as usize)
+                .map(|(listing_id, details)| TokenListingInfo {
+                    listing_id,
+                    seller: details.seller,
+                    asset_id: details.asset_id,
+                    token_price: details.token_price,
+                    amount: details.amount,
+                })
+                .collect()
         }
 
-        fn allocate_fees(
-            costs_map: &mut BoundedBTreeMap<
-                u32,
-                <T as pallet::Config>::Balance,
-                <T as pallet::Config>::MaxAcceptedAssets,
-            >,
-            asset_id_usdt: u32,
-            collected_fee_usdt: <T as pallet::Config>::Balance,
-            asset_id_usdc: u32,
-            collected_fee_usdc: <T as pallet::Config>::Balance,
-            costs: <T as pallet::Config>::Balance,
-        ) -> Result<
-            (
-                <T as pallet::Config>::Balance,
-                <T as pallet::Config>::Balance,
-            ),
-            DispatchError,
-        > {
-            let mut usdt_cost = 0u32.into();
-            let mut usdc_cost = 0u32.into();
+        /// Returns the funds raised by an ongoing property listing per payment asset.
+        /// Includes the funds of purchases that have not been claimed yet.
+        pub fn listing_funding(
+            listing_id: ListingId,
+        ) -> Option<ListingFunding<<T as pallet::Config>::Balance>> {
+            let details = OngoingObjectListing::<T>::get(listing_id)?;
+            let fee_percent = T::MarketplaceFeePercentage::get();
+            let mut payment_assets: BTreeSet<u32> =
+                AcceptedPaymentAssets::<T>::get().keys().copied().collect();
+            payment_assets.extend(details.collected_funds.keys().copied());
+            let mut funding: Vec<_> = payment_assets
+                .iter()
+                .map(|asset| PaymentAssetFunding {
+                    payment_asset: *asset,
+                    funds: details
+                        .collected_funds
+                        .get(asset)
+                        .copied()
+                        .unwrap_or_default(),
+                    fees: details
+                        .collected_fees
+                        .get(asset)
+                        .copied()
+                        .unwrap_or_default(),
+                    tax: details
+                        .collected_tax
+                        .get(asset)
+                        .copied()
+                        .unwrap_or_default(),
+                })
+                .collect();
+
+            ListingTokenOwners::<T>::iter_key_prefix(listing_id)
+                .filter_map(|investor| TokenOwner::<T>::get(investor, listing_id))
+                .filter(|token_details| token_details.relist_count == details.relist_count)
+                .for_each(|token_details| {
+                    for entry in funding.iter_mut() {
+                        let paid_funds = token_details
+                            .paid_funds
+                            .get(&entry.payment_asset)
+                            .copied()
+                            .unwrap_or_default();
+                        let paid_tax = if details.tax_paid_by_developer {
+                            details.tax.mul_floor(paid_funds)
+                        } else {
+                            token_details
+                                .paid_tax
+                                .get(&entry.payment_asset)
+                                .copied()
+                                .unwrap_or_default()
+                        };
+                        let fee = paid_funds.saturating_mul(fee_percent) / 100u128.into();
+                        entry.funds = entry.funds.saturating_add(paid_funds);
+                        entry.fees = entry.fees.saturating_add(fee);
+                        entry.tax = entry.tax.saturating_add(paid_tax);
+                    }
+                });
 
-            if collected_fee_usdt >= costs {
-                costs_map
-                    .try_insert(asset_id_usdt, costs)
-                    .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
-                usdt_cost = costs;
-            } else if collected_fee_usdc >= costs {
-                costs_map
-                    .try_insert(asset_id_usdc, costs)
-                    .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
-                usdc_cost = costs;
-            } else {
-                let remaining_costs = costs
-                    .checked_sub(&collected_fee_usdt)
-                    .ok_or(Error::<T>::ArithmeticUnderflow)?;
-                ensure!(
-                    collected_fee_usdc >= remaining_costs,
-                    Error::<T>::CostsTooHigh
-                );
-                costs_map
-                    .try_insert(asset_id_usdt, collected_fee_usdt)
-                    .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
-                costs_map
-                    .try_insert(asset_id_usdc, remaining_costs)
-                    .map_err(|_| Error::<T>::ExceedsMaxEntries)?;
-                usdt_cost = collected_fee_usdt;
-                usdc_cost = remaining_costs;
-            }
-            Ok((usdc_cost, usdt_cost))
+            Some(ListingFunding {
+                listing_id,
+                token_amount: details.token_amount,
+                sold_token_amount: details
+                    .token_amount
+                    .saturating_sub(details.listed_token_amount),
+                unclaimed_token_amount: details.unclaimed_token_amount,
+                funding,
+            })
         }
 
-        fn restrict_ownership(
-            asset_id: u32,
-            account: &AccountIdOf<T>,
-            amount: u32,
-        ) -> DispatchResult {
-            let property_info =
-                <T as pallet::Config>::PropertyToken::get_property_asset_info(asset_id)
-                    .ok_or(Error::<T>::NoObjectFound)?;
-            let max_tokens = property_info
-                .token_amount
-                .checked_mul(50)
-                .ok_or(Error::<T>::MultiplyError)?
-                .checked_div(100)
-                .ok_or(Error::<T>::DivisionError)?;
-            let owned_token =
-                <T as pallet::Config>::PropertyToken::get_token_balance(asset_id, account);
-            let new_token_amount = owned_token
-                .checked_add(amount)
-                .ok_or(Error::<T>::ArithmeticOverflow)?;
-            ensure!(
-                new_token_amount < max_tokens,
-                Error::<T>::ExceedsMaxOwnership
-            );
-            Ok(())
+        /// Returns up to `limit` open offers on a token listing in storage order, starting after
+        /// the offer of the account `start`.
+        pub fn listing_offers(
+            listing_id: ListingId,
+            start: Option<AccountIdOf<T>>,
+            limit: u32,
+        ) -> Vec<OfferInfo<AccountIdOf<T>, <T as pallet::Config>::Balance>> {
+            let offers = match start {
+                Some(offeror) => OngoingOffers::<T>::iter_prefix_from(
+                    listing_id,
+                    OngoingOffers::<T>::hashed_key_for(listing_id, offeror),
+                ),
+                None => OngoingOffers::<T>::iter_prefix(listing_id),
+            };
+            offers
+                .take(limit as usize)
+                .map(|(offeror, offer)| OfferInfo {
+                    offeror,
+                    token_price: offer.token_price,
+                    amount: offer.amount,
+                    payment_asset: offer.payment_assets,
+                })
+                .collect()
+        }
+
+        /// Returns up to `limit` unclaimed purchases of an account in storage order, starting
+        /// after the purchase of the listing `start`.
+        pub fn account_purchases(
+            account: AccountIdOf<T>,
+            start: Option<ListingId>,
+            limit: u32,
+        ) -> Vec<PurchaseInfo<<T as pallet::Config>::Balance>> {
+            let purchases = match start {
+                Some(listing_id) => TokenOwner::<T>::iter_prefix_from(
+                    &account,
+                    TokenOwner::<T>::hashed_key_for(&account, listing_id),
+                ),
+                None => TokenOwner::<T>::iter_prefix(&account),
+            };
+            purchases
+                .take(limit as usize)
+                .map(|(listing_id, token_details)| PurchaseInfo {
+                    listing_id,
+                    token_amount: token_details.token_amount,
+                    paid_funds: token_details
+                        .paid_funds
+                        .into_iter()
+                        .filter(|(_, amount)| !amount.is_zero())
+                        .collect(),
+                    paid_tax: token_details
+                        .paid_tax
+                        .into_iter()
+                        .filter(|(_, amount)| !amount.is_zero())
+                        .collect(),
+                })
+                .collect()
         }
     }
 }
 
+pub trait AcceptedAssetRegistry<Balance> {
+    fn is_accepted(asset_id: u32) -> bool;
+
+    fn asset_details(asset_id: u32) -> Option<AcceptedAssetDetails<Balance>>;
+
+    fn accepted_assets() -> Vec<u32>;
+}
+
+impl<T: Config> AcceptedAssetRegistry<<T as pallet::Config>::Balance> for Pallet<T> {
+    fn is_accepted(asset_id: u32) -> bool {
+        AcceptedPaymentAssets::<T>::get().contains_key(&asset_id)
+    }
+
+    fn asset_details(
+        asset_id: u32,
+    ) -> Option<AcceptedAssetDetails<<T as pallet::Config>::Balance>> {
+        AcceptedPaymentAssets::<T>::get().get(&asset_id).copied()
+    }
+
+    fn accepted_assets() -> Vec<u32> {
+        AcceptedPaymentAssets::<T>::get().keys().copied().collect()
+    }
+}
+
 sp_api::decl_runtime_apis! {
     pub trait NftMarketplaceApi<AccountId>
     where
@@ -3405,4 +5798,40 @@ sp_api::decl_runtime_apis! {
     {
         fn get_marketplace_account_id() -> AccountId;
     }
+
+    pub trait MarketplaceApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns the ongoing property listings after the listing `start`.
+        fn ongoing_listings(
+            start: Option<ListingId>,
+            limit: u32,
+        ) -> Vec<ListingInfo<AccountId, Balance, BlockNumber>>;
+
+        /// Returns the relisted token listings after the listing `start`.
+        fn token_listings(
+            start: Option<ListingId>,
+            limit: u32,
+        ) -> Vec<TokenListingInfo<AccountId, Balance>>;
+
+        /// Returns the funding progress of a property listing per payment asset.
+        fn listing_funding(listing_id: ListingId) -> Option<ListingFunding<Balance>>;
+
+        /// Returns the open offers on a token listing after the offer of the account `start`.
+        fn listing_offers(
+            listing_id: ListingId,
+            start: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<OfferInfo<AccountId, Balance>>;
+
+        /// Returns the unclaimed purchases of an account after the listing `start`.
+        fn account_purchases(
+            account: AccountId,
+            start: Option<ListingId>,
+            limit: u32,
+        ) -> Vec<PurchaseInfo<Balance>>;
+    }
 }
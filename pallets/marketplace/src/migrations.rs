@@ -0,0 +1,148 @@
+//! Storage migrations for the marketplace pallet.
+
+use super::*;
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+pub mod v1 {
+    use super::*;
+
+    /// Moves the accepted payment assets from the runtime configuration into
+    /// `AcceptedPaymentAssets`.
+    /// Assets beyond `MaxAcceptedAssets` are skipped.
+    pub struct InnerMigrateV0ToV1<T, InitialAssets>(PhantomData<(T, InitialAssets)>);
+
+    impl<T, InitialAssets> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T, InitialAssets>
+    where
+        T: Config,
+        InitialAssets: Get<Vec<(u32, <T as Config>::Balance, bool)>>,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let mut accepted_assets = AcceptedPaymentAssets::<T>::get();
+            for (asset_id, min_amount, uses_exchange_rate) in InitialAssets::get() {
+                let _ = accepted_assets.try_insert(
+                    asset_id,
+                    AcceptedAssetDetails {
+                        min_amount,
+                        uses_exchange_rate,
+                    },
+                );
+            }
+            AcceptedPaymentAssets::<T>::put(accepted_assets);
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+    }
+
+    /// Migrates the marketplace from storage version 0 to 1.
+    pub type MigrateV0ToV1<T, InitialAssets> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T, InitialAssets>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v2 {
+    use super::*;
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    /// The listing details before sale modes and soft caps were introduced.
+    #[derive(Encode, Decode)]
+    pub struct OldPropertyListingDetails<NftId, NftCollectionId, T: Config> {
+        pub real_estate_developer: AccountIdOf<T>,
+        pub token_price: <T as pallet::Config>::Balance,
+        pub collected_funds: BoundedBTreeMap<
+            u32,
+            <T as pallet::Config>::Balance,
+            <T as pallet::Config>::MaxAcceptedAssets,
+        >,
+        pub collected_tax: BoundedBTreeMap<
+            u32,
+            <T as pallet::Config>::Balance,
+            <T as pallet::Config>::MaxAcceptedAssets,
+        >,
+        pub collected_fees: BoundedBTreeMap<
+            u32,
+            <T as pallet::Config>::Balance,
+            <T as pallet::Config>::MaxAcceptedAssets,
+        >,
+        pub asset_id: u32,
+        pub item_id: NftId,
+        pub collection_id: NftCollectionId,
+        pub token_amount: u32,
+        pub listed_token_amount: u32,
+        pub tax_paid_by_developer: bool,
+        pub tax: Permill,
+        pub listing_expiry: BlockNumberFor<T>,
+        pub investor_funds: BoundedBTreeMap<
+            AccountIdOf<T>,
+            TokenOwnerFunds<T>,
+            <T as pallet::Config>::MaxPropertyToken,
+        >,
+        pub claim_expiry: Option<BlockNumberFor<T>>,
+        pub relist_count: u8,
+        pub unclaimed_token_amount: u32,
+    }
+
+    type OldPropertyListingDetailsType<T> = OldPropertyListingDetails<
+        <T as pallet_real_estate_asset::Config>::NftId,
+        <T as pallet_regions::Config>::NftCollectionId,
+        T,
+    >;
+
+    /// Translates the ongoing property listings into fixed price sales without a soft cap.
+    /// The listing block is derived from the listing duration of the region of the property.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            OngoingObjectListing::<T>::translate::<OldPropertyListingDetailsType<T>, _>(
+                |_, old| {
+                    translated.saturating_inc();
+                    let listing_duration =
+                        pallet_real_estate_asset::PropertyAssetInfo::<T>::get(old.asset_id)
+                            .and_then(|asset_details| {
+                                pallet_regions::RegionDetails::<T>::get(asset_details.region)
+                            })
+                            .map(|region_info| region_info.listing_duration)
+                            .unwrap_or_default();
+                    Some(PropertyListingDetails {
+                        real_estate_developer: old.real_estate_developer,
+                        token_price: old.token_price,
+                        collected_funds: old.collected_funds,
+                        collected_tax: old.collected_tax,
+                        collected_fees: old.collected_fees,
+                        asset_id: old.asset_id,
+                        item_id: old.item_id,
+                        collection_id: old.collection_id,
+                        token_amount: old.token_amount,
+                        listed_token_amount: old.listed_token_amount,
+                        tax_paid_by_developer: old.tax_paid_by_developer,
+                        tax: old.tax,
+                        listing_expiry: old.listing_expiry,
+                        investor_funds: old.investor_funds,
+                        claim_expiry: old.claim_expiry,
+                        relist_count: old.relist_count,
+                        unclaimed_token_amount: old.unclaimed_token_amount,
+                        sale_mode: SaleMode::FixedPrice,
+                        listed_at: old.listing_expiry.saturating_sub(listing_duration),
+                        soft_cap: None,
+                    })
+                },
+            );
+            T::DbWeight::get().reads_writes(translated.saturating_mul(3), translated)
+        }
+    }
+
+    /// Migrates the marketplace from storage version 1 to 2.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -5,12 +5,13 @@ use frame_support::{
     derive_impl, parameter_types,
     traits::AsEnsureOriginWithArg,
     traits::{EnsureOriginWithArg, OriginTrait},
+    weights::Weight,
     BoundedVec,
 };
-use sp_core::{ConstU128, ConstU32};
+use sp_core::{ConstU128, ConstU32, ConstU64};
 use sp_runtime::{
     traits::{AccountIdLookup, BlakeTwo256, IdentifyAccount, Verify},
-    MultiSignature, Percent,
+    MultiSignature, Percent, Permill,
 };
 
 use frame_system::EnsureRoot;
@@ -52,6 +53,7 @@ frame_support::construct_runtime!(
         AssetsFreezer: pallet_assets_freezer::<Instance1>,
         Regions: pallet_regions,
         RealEstateAsset: pallet_real_estate_asset,
+        PriceOracle: pallet_price_oracle,
     }
 );
 
@@ -145,6 +147,21 @@ impl pallet_xcavate_whitelist::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_xcavate_whitelist::weights::SubstrateWeight<Test>;
     type WhitelistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type RoleGrantPeriod = ConstU64<100_000>;
+    type ExpiryNoticePeriod = ConstU64<10>;
+    type MaxExpiriesPerBlock = ConstU32<100>;
+    type MaxJurisdictions = ConstU32<10>;
+    type PendingActionLifetime = ConstU64<20>;
+    type MaxApprovals = ConstU32<5>;
+    type MaxRoleAssignments = ConstU32<100>;
+    type MaxLinkedAccounts = ConstU32<5>;
+}
+
+impl pallet_price_oracle::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_price_oracle::weights::SubstrateWeight<Test>;
+    type FeederOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type MaxRateAge = ConstU64<10>;
 }
 
 use pallet_xcavate_whitelist::{self as whitelist, RolePermission};
@@ -205,6 +222,34 @@ impl<T: whitelist::Config> EnsureOriginWithArg<T::RuntimeOrigin, whitelist::Role
     }
 }
 
+impl<T: whitelist::Config> EnsureOriginWithArg<T::RuntimeOrigin, whitelist::InvestmentEligibility>
+    for EnsureCompliant<T>
+{
+    type Success = T::AccountId;
+
+    fn try_origin(
+        origin: T::RuntimeOrigin,
+        eligibility: &whitelist::InvestmentEligibility,
+    ) -> Result<Self::Success, T::RuntimeOrigin> {
+        let Some(who) = origin.clone().into_signer() else {
+            return Err(origin);
+        };
+        if whitelist::Pallet::<T>::is_eligible(&who, eligibility) {
+            Ok(who)
+        } else {
+            Err(origin)
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(
+        _eligibility: &whitelist::InvestmentEligibility,
+    ) -> Result<T::RuntimeOrigin, ()> {
+        let account = frame_benchmarking::whitelisted_caller();
+        Ok(frame_system::RawOrigin::Signed(account).into())
+    }
+}
+
 parameter_types! {
     pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 
@@ -347,6 +392,7 @@ impl pallet_real_estate_asset::Config for Test {
     type AssetId = <Self as pallet_assets::Config<Instance1>>::AssetId;
     type PropertyAccountFundingAmount = ConstU128<100>;
     type MaxPropertyToken = MaxPropertyTokens;
+    type OnBalanceChange = ();
 }
 
 parameter_types! {
@@ -355,13 +401,19 @@ parameter_types! {
     pub const MaxPropertyTokens: u32 = 250;
     pub const MaxNftsInCollection: u32 = 100;
     pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
-    pub const AcceptedPaymentAssets: [u32; 2] = [1337, 1984];
-    pub const MaximumAcceptedAssets: u32 = 2;
+    pub const MaximumAcceptedAssets: u32 = 3;
+    pub const PriceReferenceDecimals: u8 = 6;
     pub const LawyerVotingDuration: BlockNumber = 30;
     pub const LegalProcessDuration: BlockNumber = 100;
     pub const MinimumVotingQuorum: Percent = Percent::from_percent(50);
     pub const ClaimWindowTime: BlockNumber = 100;
     pub const MaximumRelistAttempts: u8 = 1;
+    pub const MaxOrdersPerProperty: u32 = 100;
+    pub const MaxOrdersPerInvestor: u32 = 5;
+    pub const MaxListingForBlock: u32 = 100;
+    pub static ExpiryProcessingWeight: Weight = Weight::MAX;
+    pub static MarketplaceFeePercentage: u128 = 1;
+    pub const DefaultPropertyOwnershipCap: Permill = Permill::from_percent(50);
 }
 
 /// Configure the pallet-xcavate-staking in pallets/xcavate-staking.
@@ -381,9 +433,11 @@ impl pallet_marketplace::Config for Test {
     type TreasuryId = TreasuryPalletId;
     type AssetId = <Self as pallet_assets::Config<Instance1>>::AssetId;
     type ListingDeposit = ConstU128<200_000>;
-    type MarketplaceFeePercentage = ConstU128<1>;
-    type AcceptedAssets = AcceptedPaymentAssets;
+    type MarketplaceFeePercentage = MarketplaceFeePercentage;
+    type AssetRegistryOrigin = EnsureRoot<Self::AccountId>;
     type MaxAcceptedAssets = MaximumAcceptedAssets;
+    type ReferenceDecimals = PriceReferenceDecimals;
+    type ExchangeRates = PriceOracle;
     type PropertyToken = RealEstateAsset;
     type LawyerVotingTime = LawyerVotingDuration;
     type LegalProcessTime = LegalProcessDuration;
@@ -393,6 +447,13 @@ impl pallet_marketplace::Config for Test {
     type MinVotingQuorum = MinimumVotingQuorum;
     type ClaimWindow = ClaimWindowTime;
     type MaxRelistAttempts = MaximumRelistAttempts;
+    type MaxOrdersPerAsset = MaxOrdersPerProperty;
+    type MaxOrdersPerAccount = MaxOrdersPerInvestor;
+    type OrderDeposit = ConstU128<100>;
+    type MaxListingsForBlock = MaxListingForBlock;
+    type ExpiryProcessingWeight = ExpiryProcessingWeight;
+    type DefaultOwnershipCap = DefaultPropertyOwnershipCap;
+    type OwnershipCapOrigin = AsEnsureOriginWithArg<EnsureRoot<Self::AccountId>>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -433,7 +494,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
             true,
             1,
         )], // Genesis assets: id, owner, is_sufficient, min_balance
-        metadata: vec![(1984, "USDT".into(), "USDT".into(), 0)], // Genesis metadata: id, name, symbol, decimals
+        metadata: vec![(1984, "USDT".into(), "USDT".into(), 6)], // Genesis metadata: id, name, symbol, decimals
         accounts: vec![
             (1984, [0; 32].into(), 20_000_000),
             (1984, [1; 32].into(), 1_500_000),
@@ -456,7 +517,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
             true,
             1,
         )], // Genesis assets: id, owner, is_sufficient, min_balance
-        metadata: vec![(1337, "USDC".into(), "USDC".into(), 0)], // Genesis metadata: id, name, symbol, decimals
+        metadata: vec![(1337, "USDC".into(), "USDC".into(), 6)], // Genesis metadata: id, name, symbol, decimals
         accounts: vec![
             (1337, [0; 32].into(), 20_000_000),
             (1337, [1; 32].into(), 1_500_000),
@@ -475,5 +536,11 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     .assimilate_storage(&mut test)
     .unwrap();
 
+    pallet_marketplace::GenesisConfig::<Test> {
+        accepted_assets: vec![(1337, 1, false), (1984, 1, false)], // Accepted payment assets: id, min_amount, uses_exchange_rate
+    }
+    .assimilate_storage(&mut test)
+    .unwrap();
+
     test.into()
 }
@@ -10,7 +10,7 @@ use frame_support::{
         fungible::InspectHold,
         fungibles::InspectHold as FungiblesInspectHold,
         fungibles::{Inspect, InspectFreeze},
-        OnFinalize, OnInitialize,
+        GetStorageVersion, OnFinalize, OnInitialize, OnRuntimeUpgrade, StorageVersion,
     },
 };
 use pallet_real_estate_asset::{
@@ -19,6 +19,7 @@ use pallet_real_estate_asset::{
 };
 use pallet_regions::{RealEstateLawyer, RegionDetails, RegionIdentifier};
 use sp_runtime::{traits::BadOrigin, Permill, TokenError};
+use std::collections::BTreeMap;
 
 macro_rules! bvec {
 	($( $x:tt )*) => {
@@ -70,6 +71,196 @@ fn new_region_helper() {
     ));
 }
 
+fn registered_property_helper() {
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [8; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    new_region_helper();
+    assert_ok!(Regions::create_new_location(
+        RuntimeOrigin::signed([8; 32].into()),
+        3,
+        bvec![10, 10]
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [0; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateDeveloper
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [1; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [2; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [3; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [30; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [31; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [10; 32].into(),
+        pallet_xcavate_whitelist::Role::Lawyer
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [11; 32].into(),
+        pallet_xcavate_whitelist::Role::Lawyer
+    ));
+    assert_ok!(Regions::register_lawyer(
+        RuntimeOrigin::signed([10; 32].into()),
+        3,
+    ));
+    assert_ok!(Regions::register_lawyer(
+        RuntimeOrigin::signed([11; 32].into()),
+        3,
+    ));
+    assert_ok!(Marketplace::list_property(
+        RuntimeOrigin::signed([0; 32].into()),
+        3,
+        bvec![10, 10],
+        10_000,
+        100,
+        bvec![22, 22],
+        crate::SaleTerms {
+            tax_paid_by_developer: false,
+            sale_mode: crate::SaleMode::FixedPrice,
+            soft_cap: None
+        }
+    ));
+    assert_ok!(Marketplace::buy_property_token(
+        RuntimeOrigin::signed([1; 32].into()),
+        0,
+        3,
+        1984,
+        None
+    ));
+    assert_ok!(Marketplace::buy_property_token(
+        RuntimeOrigin::signed([2; 32].into()),
+        0,
+        47,
+        1984,
+        None
+    ));
+    assert_ok!(Marketplace::buy_property_token(
+        RuntimeOrigin::signed([30; 32].into()),
+        0,
+        30,
+        1984,
+        None
+    ));
+    assert_ok!(Marketplace::buy_property_token(
+        RuntimeOrigin::signed([31; 32].into()),
+        0,
+        20,
+        1984,
+        None
+    ));
+    assert_ok!(Marketplace::claim_property_token(
+        RuntimeOrigin::signed([1; 32].into()),
+        0,
+    ));
+    assert_ok!(Marketplace::claim_property_token(
+        RuntimeOrigin::signed([2; 32].into()),
+        0,
+    ));
+    assert_ok!(Marketplace::claim_property_token(
+        RuntimeOrigin::signed([30; 32].into()),
+        0,
+    ));
+    assert_ok!(Marketplace::claim_property_token(
+        RuntimeOrigin::signed([31; 32].into()),
+        0,
+    ));
+    assert_ok!(Marketplace::lawyer_claim_property(
+        RuntimeOrigin::signed([10; 32].into()),
+        0,
+        crate::LegalProperty::RealEstateDeveloperSide,
+        4_000,
+    ));
+    assert_ok!(Marketplace::approve_developer_lawyer(
+        RuntimeOrigin::signed([0; 32].into()),
+        0,
+        true
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [5; 32].into(),
+        pallet_xcavate_whitelist::Role::SpvConfirmation
+    ));
+    assert_ok!(Marketplace::create_spv(
+        RuntimeOrigin::signed([5; 32].into()),
+        0,
+    ));
+    assert_ok!(Marketplace::lawyer_claim_property(
+        RuntimeOrigin::signed([11; 32].into()),
+        0,
+        crate::LegalProperty::SpvSide,
+        4_000,
+    ));
+    assert_ok!(Marketplace::vote_on_spv_lawyer(
+        RuntimeOrigin::signed([1; 32].into()),
+        0,
+        crate::Vote::Yes,
+        3
+    ));
+    assert_ok!(Marketplace::vote_on_spv_lawyer(
+        RuntimeOrigin::signed([2; 32].into()),
+        0,
+        crate::Vote::Yes,
+        44
+    ));
+    assert_ok!(Marketplace::vote_on_spv_lawyer(
+        RuntimeOrigin::signed([30; 32].into()),
+        0,
+        crate::Vote::Yes,
+        4
+    ));
+    run_to_block(91);
+    assert_ok!(Marketplace::finalize_spv_lawyer(
+        RuntimeOrigin::signed([1; 32].into()),
+        0,
+    ));
+    assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+        RuntimeOrigin::signed([1; 32].into()),
+        0,
+    ));
+    assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+        RuntimeOrigin::signed([2; 32].into()),
+        0,
+    ));
+    assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+        RuntimeOrigin::signed([30; 32].into()),
+        0,
+    ));
+    assert_ok!(Marketplace::lawyer_confirm_documents(
+        RuntimeOrigin::signed([10; 32].into()),
+        0,
+        true,
+    ));
+    assert_ok!(Marketplace::lawyer_confirm_documents(
+        RuntimeOrigin::signed([11; 32].into()),
+        0,
+        true,
+    ));
+}
+
 #[test]
 fn adjust_listing_duration_works() {
     new_test_ext().execute_with(|| {
@@ -107,7 +298,11 @@ fn adjust_listing_duration_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Regions::adjust_listing_duration(
             RuntimeOrigin::signed([8; 32].into()),
@@ -121,7 +316,11 @@ fn adjust_listing_duration_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_eq!(
             OngoingObjectListing::<Test>::get(0).unwrap().listing_expiry,
@@ -133,14 +332,21 @@ fn adjust_listing_duration_works() {
         );
         run_to_block(92);
         assert_noop!(
-            Marketplace::buy_property_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, 1984),
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                30,
+                1984,
+                None
+            ),
             Error::<Test>::ListingExpired
         );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             1,
             30,
-            1984
+            1984,
+            None
         ));
     })
 }
@@ -177,7 +383,11 @@ fn list_property_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_eq!(
             Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
@@ -227,7 +437,11 @@ fn list_property_fails() {
                 10_000,
                 100,
                 bvec![22, 22],
-                false
+                crate::SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: crate::SaleMode::FixedPrice,
+                    soft_cap: None
+                }
             ),
             Error::<Test>::RegionUnknown
         );
@@ -240,7 +454,11 @@ fn list_property_fails() {
                 10_000,
                 100,
                 bvec![22, 22],
-                false
+                crate::SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: crate::SaleMode::FixedPrice,
+                    soft_cap: None
+                }
             ),
             Error::<Test>::LocationUnknown
         );
@@ -257,7 +475,11 @@ fn list_property_fails() {
                 10_000,
                 251,
                 bvec![22, 22],
-                false
+                crate::SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: crate::SaleMode::FixedPrice,
+                    soft_cap: None
+                }
             ),
             Error::<Test>::TooManyToken
         );
@@ -269,7 +491,11 @@ fn list_property_fails() {
                 10_000,
                 99,
                 bvec![22, 22],
-                false
+                crate::SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: crate::SaleMode::FixedPrice,
+                    soft_cap: None
+                }
             ),
             Error::<Test>::TokenAmountTooLow
         );
@@ -281,7 +507,11 @@ fn list_property_fails() {
                 10_000,
                 0,
                 bvec![22, 22],
-                false
+                crate::SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: crate::SaleMode::FixedPrice,
+                    soft_cap: None
+                }
             ),
             Error::<Test>::AmountCannotBeZero
         );
@@ -325,13 +555,18 @@ fn buy_property_token_works() {
             10_000_000_000_000_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([6; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_eq!(
             OngoingObjectListing::<Test>::get(0)
@@ -420,27 +655,44 @@ fn buy_property_token_works_2() {
             10_000,
             200,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_noop!(
-            Marketplace::buy_property_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, 1984),
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                101,
+                1984,
+                None
+            ),
             Error::<Test>::ExceedsMaxOwnership
         );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            99,
-            1984
+            100,
+            1984,
+            None
         ));
         assert_noop!(
-            Marketplace::buy_property_token(RuntimeOrigin::signed([1; 32].into()), 0, 1, 1984),
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                1,
+                1984,
+                None
+            ),
             Error::<Test>::ExceedsMaxOwnership
         );
         assert_eq!(
             TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0)
                 .unwrap()
                 .token_amount,
-            99
+            100
         );
     })
 }
@@ -481,13 +733,18 @@ fn buy_property_token_works_developer_covers_fees() {
             10_000,
             100,
             bvec![22, 22],
-            true
+            crate::SaleTerms {
+                tax_paid_by_developer: true,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_eq!(
             OngoingObjectListing::<Test>::get(0)
@@ -552,7 +809,13 @@ fn buy_property_token_doesnt_work() {
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_noop!(
-            Marketplace::buy_property_token(RuntimeOrigin::signed([0; 32].into()), 1, 1, 1984),
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([0; 32].into()),
+                1,
+                1,
+                1984,
+                None
+            ),
             Error::<Test>::TokenNotForSale
         );
     })
@@ -594,18 +857,40 @@ fn buy_property_token_doesnt_work_2() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_noop!(
-            Marketplace::buy_property_token(RuntimeOrigin::signed([1; 32].into()), 0, 101, 1984),
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                101,
+                1984,
+                None
+            ),
             Error::<Test>::NotEnoughTokenAvailable
         );
         assert_noop!(
-            Marketplace::buy_property_token(RuntimeOrigin::signed([1; 32].into()), 0, 50, 1984),
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                51,
+                1984,
+                None
+            ),
             Error::<Test>::ExceedsMaxOwnership
         );
         assert_noop!(
-            Marketplace::buy_property_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, 1985),
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                30,
+                1985,
+                None
+            ),
             Error::<Test>::PaymentAssetNotSupported
         );
         assert_ok!(XcavateWhitelist::set_permission(
@@ -615,7 +900,13 @@ fn buy_property_token_doesnt_work_2() {
             pallet_xcavate_whitelist::AccessPermission::Revoked,
         ));
         assert_noop!(
-            Marketplace::buy_property_token(RuntimeOrigin::signed([1; 32].into()), 0, 40, 1984),
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                40,
+                1984,
+                None
+            ),
             BadOrigin
         );
         assert_ok!(XcavateWhitelist::set_permission(
@@ -626,7 +917,13 @@ fn buy_property_token_doesnt_work_2() {
         ));
         run_to_block(92);
         assert_noop!(
-            Marketplace::buy_property_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, 1984),
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                30,
+                1984,
+                None
+            ),
             Error::<Test>::ListingExpired
         );
         assert_ok!(Marketplace::list_property(
@@ -636,17 +933,28 @@ fn buy_property_token_doesnt_work_2() {
             1_000,
             250,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_noop!(
-            Marketplace::buy_property_token(RuntimeOrigin::signed([1; 32].into()), 1, 125, 1984),
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                1,
+                126,
+                1984,
+                None
+            ),
             Error::<Test>::ExceedsMaxOwnership
         );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             1,
-            124,
-            1984
+            125,
+            1984,
+            None
         ));
     })
 }
@@ -687,10 +995,20 @@ fn buy_property_token_fails_insufficient_balance() {
             10_000_000_000_000_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_noop!(
-            Marketplace::buy_property_token(RuntimeOrigin::signed([4; 32].into()), 0, 30, 1984),
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([4; 32].into()),
+                0,
+                30,
+                1984,
+                None
+            ),
             TokenError::FundsUnavailable
         );
         assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 50);
@@ -702,53 +1020,77 @@ fn buy_property_token_fails_insufficient_balance() {
 }
 
 #[test]
-fn listing_and_selling_multiple_objects() {
+fn secondary_purchases_respect_region_policy() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [8; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        registered_property_helper();
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            1_000,
+            5
         ));
-        new_region_helper();
-        assert_ok!(Regions::create_new_location(
-            RuntimeOrigin::signed([8; 32].into()),
+        assert_ok!(XcavateWhitelist::set_region_policy(
+            RuntimeOrigin::root(),
             3,
-            bvec![10, 10]
+            bvec![*b"DE"],
+            false,
+            Some(10_000)
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
+        assert_ok!(XcavateWhitelist::set_investor_profile(
             RuntimeOrigin::signed([20; 32].into()),
-            [15; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+            [1; 32].into(),
+            *b"DE",
+            false
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        // The offer price is checked instead of the price of the listing.
+        assert_noop!(
+            Marketplace::make_offer(RuntimeOrigin::signed([1; 32].into()), 1, 5_000, 2, 1984),
+            BadOrigin
+        );
+        assert_ok!(Marketplace::make_offer(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+            1_000,
+            2,
+            1984
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        assert_noop!(
+            Marketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 5_000, 2, 1984),
+            BadOrigin
+        );
+        assert_ok!(Marketplace::place_bid(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            2_000,
+            2,
+            1984
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+    })
+}
+
+#[test]
+fn buy_property_token_respects_region_policy() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [8; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
@@ -757,92 +1099,2780 @@ fn listing_and_selling_multiple_objects() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([15; 32].into()),
+            RuntimeOrigin::signed([0; 32].into()),
             3,
             bvec![10, 10],
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([2; 32].into()),
+        assert_ok!(XcavateWhitelist::set_region_policy(
+            RuntimeOrigin::root(),
             3,
-            bvec![10, 10],
-            10_000,
-            100,
-            bvec![22, 22],
-            false
+            bvec![*b"DE"],
+            false,
+            Some(200_000)
         ));
-        assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([0; 32].into()),
-            3,
-            bvec![10, 10],
-            10_000,
-            100,
-            bvec![22, 22],
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                10,
+                1984,
+                None
+            ),
+            BadOrigin
+        );
+        assert_ok!(XcavateWhitelist::set_investor_profile(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            *b"DE",
             false
         ));
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                21,
+                1984,
+                None
+            ),
+            BadOrigin
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            20,
+            1984,
+            None
+        ));
+        // Pending purchases count towards the investment cap
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                1,
+                1984,
+                None
+            ),
+            BadOrigin
+        );
+        assert_ok!(XcavateWhitelist::remove_region_policy(
+            RuntimeOrigin::root(),
+            3
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            10,
+            1984,
+            None
+        ));
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0)
+                .unwrap()
+                .token_amount,
+            30
+        );
+    })
+}
+
+fn ownership_cap_setup() {
+    System::set_block_number(1);
+    assert_ok!(XcavateWhitelist::add_admin(
+        RuntimeOrigin::root(),
+        [20; 32].into(),
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [8; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    new_region_helper();
+    assert_ok!(Regions::create_new_location(
+        RuntimeOrigin::signed([8; 32].into()),
+        3,
+        bvec![10, 10]
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [0; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateDeveloper
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [1; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [2; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    assert_ok!(Marketplace::list_property(
+        RuntimeOrigin::signed([0; 32].into()),
+        3,
+        bvec![10, 10],
+        10_000,
+        100,
+        bvec![22, 22],
+        crate::SaleTerms {
+            tax_paid_by_developer: false,
+            sale_mode: crate::SaleMode::FixedPrice,
+            soft_cap: None
+        }
+    ));
+}
+
+#[test]
+fn set_ownership_cap_works() {
+    new_test_ext().execute_with(|| {
+        ownership_cap_setup();
+        assert_eq!(Marketplace::ownership_cap(0), Permill::from_percent(50));
+        assert_ok!(Marketplace::set_ownership_cap(
+            RuntimeOrigin::root(),
+            0,
+            Some(Permill::from_percent(20))
+        ));
+        assert_eq!(
+            OwnershipCap::<Test>::get(0),
+            Some(Permill::from_percent(20))
+        );
+        System::assert_last_event(
+            Event::OwnershipCapSet {
+                asset_id: 0,
+                cap: Permill::from_percent(20),
+            }
+            .into(),
+        );
+        assert_ok!(Marketplace::set_ownership_cap(
+            RuntimeOrigin::root(),
+            0,
+            None
+        ));
+        assert_eq!(OwnershipCap::<Test>::get(0), None);
+        assert_eq!(Marketplace::ownership_cap(0), Permill::from_percent(50));
+        System::assert_last_event(
+            Event::OwnershipCapSet {
+                asset_id: 0,
+                cap: Permill::from_percent(50),
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn set_ownership_cap_fails() {
+    new_test_ext().execute_with(|| {
+        ownership_cap_setup();
+        assert_noop!(
+            Marketplace::set_ownership_cap(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                Some(Permill::from_percent(20))
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            Marketplace::set_ownership_cap(
+                RuntimeOrigin::root(),
+                1,
+                Some(Permill::from_percent(20))
+            ),
+            Error::<Test>::NoObjectFound
+        );
+        assert_noop!(
+            Marketplace::set_ownership_cap(RuntimeOrigin::root(), 0, Some(Permill::zero())),
+            Error::<Test>::InvalidOwnershipCap
+        );
+    })
+}
+
+#[test]
+fn buy_property_token_respects_ownership_cap() {
+    new_test_ext().execute_with(|| {
+        ownership_cap_setup();
+        assert_ok!(Marketplace::set_ownership_cap(
+            RuntimeOrigin::root(),
+            0,
+            Some(Permill::from_percent(20))
+        ));
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                21,
+                1984,
+                None
+            ),
+            Error::<Test>::ExceedsMaxOwnership
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            20,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                1,
+                1984,
+                None
+            ),
+            Error::<Test>::ExceedsMaxOwnership
+        );
+        assert_ok!(Marketplace::set_ownership_cap(
+            RuntimeOrigin::root(),
+            0,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0)
+                .unwrap()
+                .token_amount,
+            50
+        );
+    })
+}
+
+#[test]
+fn ownership_cap_covers_linked_accounts() {
+    new_test_ext().execute_with(|| {
+        ownership_cap_setup();
+        assert_ok!(XcavateWhitelist::link_account(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            [2; 32].into()
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([2; 32].into()),
+                0,
+                21,
+                1984,
+                None
+            ),
+            Error::<Test>::ExceedsMaxOwnership
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            20,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                1,
+                1984,
+                None
+            ),
+            Error::<Test>::ExceedsMaxOwnership
+        );
+        assert_ok!(XcavateWhitelist::unlink_account(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into()
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            1,
+            1984,
+            None
+        ));
+    })
+}
+
+#[test]
+fn listing_and_selling_multiple_objects() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [15; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([15; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([2; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+            20,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            1,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            1,
+            30,
+            1984,
+            None
+        ));
+        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+            20,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            1
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            1
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            1
+        ));
+        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), true);
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            1,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            1,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            1,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+            crate::Vote::Yes,
+            40,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            1,
+            crate::Vote::Yes,
+            20,
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            2,
+            10,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            2,
+            10,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            2,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([15; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            33,
+            1984,
+            None
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+        ),);
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            1,
+            true,
+        ));
+        System::assert_last_event(
+            Event::DocumentsConfirmed {
+                signer: [10; 32].into(),
+                listing_id: 1,
+                legal_side: LegalProperty::RealEstateDeveloperSide,
+                approve: true,
+            }
+            .into(),
+        );
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            1,
+            true,
+        ));
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .listed_token_amount,
+            67
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(2)
+                .unwrap()
+                .listed_token_amount,
+            50
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(3)
+                .unwrap()
+                .listed_token_amount,
+            100
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([2; 32].into(), 2)
+                .unwrap()
+                .token_amount,
+            30
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 1),
+            None
+        );
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(1, [1; 32].into()),
+            40
+        );
+    });
+}
+
+// claim_property_token function
+#[test]
+fn claim_property_token_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .investor_funds
+                .get(&[1; 32].into())
+                .clone(),
+            None
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_084_000);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            416_000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            0
+        );
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 0);
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_funds
+                .get(&1984)
+                .copied(),
+            Some(400_000)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_fees
+                .get(&1984)
+                .copied(),
+            Some(4_000)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_tax
+                .get(&1984)
+                .copied(),
+            Some(12_000)
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_084_000);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            0
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            416_000
+        );
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
+        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 0);
+        assert_eq!(
+            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
+            60
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .investor_funds
+                .get(&[1; 32].into())
+                .clone()
+                .unwrap()
+                .paid_funds
+                .get(&1984)
+                .unwrap(),
+            &412_000_u128
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .investor_funds
+                .get(&[1; 32].into())
+                .clone()
+                .unwrap()
+                .paid_funds
+                .get(&1337),
+            None
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .investor_funds
+                .get(&[2; 32].into())
+                .clone(),
+            None
+        );
+        assert_eq!(PropertyLawyer::<Test>::get(0).is_some(), false);
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_funds
+                .get(&1984)
+                .copied(),
+            Some(1_000_000)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_fees
+                .get(&1984)
+                .copied(),
+            Some(10_000)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_tax
+                .get(&1984)
+                .copied(),
+            Some(30_000)
+        );
+        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 30);
+        assert_eq!(
+            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
+            0
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            1040_000
+        );
+        assert_eq!(PropertyLawyer::<Test>::get(0).is_some(), true);
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .investor_funds
+                .get(&[2; 32].into())
+                .clone()
+                .unwrap()
+                .paid_funds
+                .get(&1984)
+                .unwrap(),
+            &309_000_u128
+        );
+        System::assert_last_event(
+            Event::PropertyTokenClaimed {
+                listing_id: 0,
+                asset_id: 0,
+                owner: [30; 32].into(),
+                amount: 30,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn claim_property_token_works_2() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .investor_funds
+                .get(&[1; 32].into())
+                .clone(),
+            None
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_084_000);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            416_000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            0
+        );
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 0);
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_funds
+                .get(&1984)
+                .copied(),
+            Some(700_000)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_fees
+                .get(&1984)
+                .copied(),
+            Some(7_000)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_tax
+                .get(&1984)
+                .copied(),
+            Some(21_000)
+        );
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            40
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_084_000);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            0
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            728_000
+        );
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
+        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 0);
+        assert_eq!(LocalAssets::balance(0, &[30; 32].into()), 30);
+        assert_eq!(
+            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
+            30
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .investor_funds
+                .get(&[1; 32].into())
+                .clone()
+                .unwrap()
+                .paid_funds
+                .get(&1984)
+                .unwrap(),
+            &412_000_u128
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .investor_funds
+                .get(&[1; 32].into())
+                .clone()
+                .unwrap()
+                .paid_funds
+                .get(&1337),
+            None
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .investor_funds
+                .get(&[30; 32].into())
+                .clone()
+                .unwrap()
+                .paid_funds
+                .get(&1984)
+                .unwrap(),
+            &309_000_u128
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .investor_funds
+                .get(&[2; 32].into())
+                .clone(),
+            None
+        );
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([30; 32].into(), 0),
+            None
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            10,
+            1984,
+            None
+        ));
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([30; 32].into(), 0)
+                .unwrap()
+                .token_amount,
+            10
+        );
+        assert_ok!(Marketplace::withdraw_unclaimed(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            20,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_funds
+                .get(&1984)
+                .copied(),
+            Some(800_000)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_fees
+                .get(&1984)
+                .copied(),
+            Some(8_000)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_tax
+                .get(&1984)
+                .copied(),
+            Some(24_000)
+        );
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            40
+        );
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()),
+            20
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .investor_funds
+                .get(&[30; 32].into())
+                .clone()
+                .unwrap()
+                .paid_funds
+                .get(&1984)
+                .unwrap(),
+            &412_000_u128
+        );
+    })
+}
+
+#[test]
+fn claim_property_token_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [6; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_noop!(
+            Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::ListingNotFound
+        );
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            48,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            25,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            26,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::NoClaimWindow
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([6; 32].into()),
+            0,
+            1,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::claim_property_token(RuntimeOrigin::signed([3; 32].into()), 0,),
+            BadOrigin
+        );
+        assert_noop!(
+            Marketplace::claim_property_token(RuntimeOrigin::signed([2; 32].into()), 0,),
+            Error::<Test>::TokenOwnerNotFound
+        );
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+        ));
+        assert_noop!(
+            Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::TokenOwnerNotFound
+        );
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_noop!(
+            Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::NoClaimWindow
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            1,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::claim_property_token(RuntimeOrigin::signed([6; 32].into()), 0,),
+            Error::<Test>::NoValidTokenToClaim
+        );
+        assert_ok!(XcavateWhitelist::set_permission(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor,
+            pallet_xcavate_whitelist::AccessPermission::Revoked,
+        ));
+        assert_noop!(
+            Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0,),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn relist_unclaim_property_token_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [6; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .listed_token_amount,
+            0
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .unclaimed_token_amount,
+            30
+        );
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .listed_token_amount,
+            30
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0).unwrap().listing_expiry,
+            frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get()
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([6; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([6; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_funds
+                .get(&1984)
+                .copied(),
+            Some(1_000_000)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_fees
+                .get(&1984)
+                .copied(),
+            Some(10_000)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_tax
+                .get(&1984)
+                .copied(),
+            Some(30_000)
+        );
+        assert_eq!(LocalAssets::balance(0, &[6; 32].into()), 30);
+    })
+}
+
+// finalize_claim_window function
+#[test]
+fn finalize_claim_window_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .listed_token_amount,
+            0
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .unclaimed_token_amount,
+            30
+        );
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .listed_token_amount,
+            30
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0).unwrap().listing_expiry,
+            frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get()
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0).unwrap().relist_count,
+            1
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .unclaimed_token_amount,
+            0
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            15,
+            1984,
+            None
+        ));
+    })
+}
+
+#[test]
+fn finalize_claim_window_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_noop!(
+            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::ListingNotFound
+        );
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_noop!(
+            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::NoClaimWindow
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::ClaimWindowNotExpired
+        );
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0).unwrap().relist_count,
+            1
+        );
+        assert_ok!(Marketplace::withdraw_unclaimed(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0).unwrap().relist_count,
+            2
+        );
+        assert_noop!(
+            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::NoClaimWindow
+        );
+    })
+}
+
+#[test]
+fn finalize_claim_window_fails_2() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_noop!(
+            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::ListingNotFound
+        );
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_noop!(
+            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::NoClaimWindow
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_noop!(
+            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::NoClaimWindow
+        );
+    })
+}
+
+// create_spv function
+#[test]
+fn create_spv_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            45,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            25,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_eq!(
+            PropertyAssetInfo::<Test>::get(0).unwrap().spv_created,
+            false
+        );
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+    })
+}
+
+#[test]
+fn create_spv_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_noop!(
+            Marketplace::create_spv(RuntimeOrigin::signed([2; 32].into()), 0,),
+            Error::<Test>::NoObjectFound
+        );
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            48,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            25,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            26,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::create_spv(RuntimeOrigin::signed([2; 32].into()), 0,),
+            Error::<Test>::PropertyHasNotBeenSoldYet
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            1,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::create_spv(RuntimeOrigin::signed([1; 32].into()), 0,),
+            BadOrigin
+        );
+    })
+}
+
+// lawyer_claim_property function
+#[test]
+fn claim_property_works1() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            45,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            25,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_eq!(
+            ProposedLawyers::<Test>::get(0).unwrap().lawyer,
+            [10; 32].into()
+        );
+        assert_eq!(ProposedLawyers::<Test>::get(0).unwrap().costs, 4_000);
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            None
+        );
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_eq!(
+            SpvLawyerProposal::<Test>::get(0).unwrap().lawyer,
+            [11; 32].into()
+        );
+        assert_eq!(SpvLawyerProposal::<Test>::get(0).unwrap().expiry_block, 91);
+        assert_eq!(OngoingLawyerVoting::<Test>::get(0).is_some(), true);
+        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, None);
+        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 0);
+    })
+}
+
+#[test]
+fn claim_property_works2() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [9; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            200,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            1,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([9; 32].into()),
+            0,
+            98,
+            1337,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            50,
+            1337,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            51,
+            1337,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([9; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            15_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            Some([10; 32].into())
+        );
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            16_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            1
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([9; 32].into()),
+            0,
+            crate::Vote::Yes,
+            98
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            crate::Vote::Yes,
+            45
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
+            Some([11; 32].into())
+        );
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .spv_lawyer_costs
+                .get(&1984)
+                .unwrap(),
+            &0u128
+        );
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .spv_lawyer_costs
+                .get(&1337)
+                .unwrap(),
+            &16_000u128
+        );
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer_costs
+                .get(&1984)
+                .unwrap(),
+            &0u128
+        );
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer_costs
+                .get(&1337)
+                .unwrap(),
+            &15_000u128
+        );
+    })
+}
+
+#[test]
+fn claim_property_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [12; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            29,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([10; 32].into()),
+                0,
+                crate::LegalProperty::RealEstateDeveloperSide,
+                4_000,
+            ),
+            Error::<Test>::InvalidIndex
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            1,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([9; 32].into()),
+                0,
+                crate::LegalProperty::RealEstateDeveloperSide,
+                4_000,
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([10; 32].into()),
+                0,
+                crate::LegalProperty::RealEstateDeveloperSide,
+                11_000,
+            ),
+            Error::<Test>::CostsTooHigh
+        );
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_eq!(
+            ProposedLawyers::<Test>::get(0).unwrap().lawyer,
+            [10; 32].into()
+        );
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([11; 32].into()),
+                0,
+                crate::LegalProperty::RealEstateDeveloperSide,
+                4_000,
+            ),
+            Error::<Test>::LawyerProposalOngoing
+        );
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([10; 32].into()),
+                0,
+                crate::LegalProperty::SpvSide,
+                4_000,
+            ),
+            RealEstateAssetError::<Test>::SpvNotCreated
+        );
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([10; 32].into()),
+                0,
+                crate::LegalProperty::SpvSide,
+                4_000,
+            ),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true,
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            Some([10; 32].into())
+        );
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([11; 32].into()),
+                0,
+                crate::LegalProperty::RealEstateDeveloperSide,
+                4_000,
+            ),
+            Error::<Test>::LawyerJobTaken
+        );
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([10; 32].into()),
+                0,
+                crate::LegalProperty::SpvSide,
+                4_000,
+            ),
+            Error::<Test>::NoPermission
+        );
+        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, None);
+        assert_ok!(Regions::propose_new_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            RegionIdentifier::France
+        ));
+        assert_ok!(Regions::vote_on_region_proposal(
+            RuntimeOrigin::signed([8; 32].into()),
+            2,
+            pallet_regions::Vote::Yes,
+            10_000
+        ));
+        run_to_block(91);
+        assert_ok!(Regions::bid_on_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            2,
+            100_000
+        ));
+        run_to_block(121);
+        assert_ok!(Regions::create_new_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            2,
+            30,
+            Permill::from_percent(3)
+        ));
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            2,
+            bvec![20, 10]
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            2,
+            bvec![20, 10],
+            1_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             1,
-            20,
-            1984
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             1,
             30,
-            1984
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            1,
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            1,
+        ));
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([10; 32].into()),
+                1,
+                crate::LegalProperty::RealEstateDeveloperSide,
+                400,
+            ),
+            Error::<Test>::WrongRegion
+        );
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([12; 32].into()),
+            2,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([12; 32].into()),
+            1,
+            crate::LegalProperty::SpvSide,
+            400,
+        ));
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([12; 32].into()),
+                1,
+                crate::LegalProperty::RealEstateDeveloperSide,
+                400,
+            ),
+            Error::<Test>::NoPermission
+        );
+    })
+}
+
+#[test]
+fn claim_property_works_fails_2() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [9; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            200,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
             1,
-            30,
-            1984
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([9; 32].into()),
+            0,
+            98,
+            1337,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            50,
+            1337,
+            None
         ));
-        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            1,
-            20,
-            1984
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            51,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            1
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            1
+            RuntimeOrigin::signed([9; 32].into()),
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            1
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
         ));
-        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), true);
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            1,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
@@ -851,126 +3881,251 @@ fn listing_and_selling_multiple_objects() {
         ));
         assert_ok!(Marketplace::create_spv(
             RuntimeOrigin::signed([5; 32].into()),
-            1,
+            0,
         ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            1,
-            true,
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([11; 32].into()),
+                0,
+                crate::LegalProperty::SpvSide,
+                16_000,
+            ),
+            Error::<Test>::InvalidIndex
+        );
+        assert_noop!(
+            Marketplace::lawyer_claim_property(
+                RuntimeOrigin::signed([10; 32].into()),
+                0,
+                crate::LegalProperty::RealEstateDeveloperSide,
+                15_000,
+            ),
+            Error::<Test>::InvalidIndex
+        );
+        assert_eq!(RefundLegalExpired::<Test>::get(0), Some(200));
+    })
+}
+
+// approve_developer_lawyer function
+#[test]
+fn approve_developer_lawyer_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            1,
-            crate::LegalProperty::SpvSide,
-            4_000,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            1,
-            crate::Vote::Yes,
-            40,
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            1,
-            crate::Vote::Yes,
-            20,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            2,
-            10,
-            1984
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            2,
-            10,
-            1984
+            0,
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
-            2,
+            0,
             30,
-            1984
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            false
         ));
         assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([15; 32].into()),
+            RuntimeOrigin::signed([0; 32].into()),
             3,
             bvec![10, 10],
-            10_000,
+            1_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
-            0,
-            33,
-            1984
+            1,
+            30,
+            1984,
+            None
         ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            1,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             1,
-        ),);
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
             1,
-            true,
         ));
-        System::assert_last_event(
-            Event::DocumentsConfirmed {
-                signer: [10; 32].into(),
-                listing_id: 1,
-                legal_side: LegalProperty::RealEstateDeveloperSide,
-                approve: true,
-            }
-            .into(),
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            1,
+        ));
+        assert_eq!(
+            RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
+                .unwrap()
+                .active_cases,
+            0
         );
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
+        assert_eq!(ProposedLawyers::<Test>::get(0).is_none(), true);
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            None
+        );
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            3_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_eq!(
+            RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
+                .unwrap()
+                .active_cases,
+            1
+        );
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            1,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            300,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
             1,
-            true,
+            true
         ));
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .listed_token_amount,
-            67
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(2)
+            RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
                 .unwrap()
-                .listed_token_amount,
-            50
+                .active_cases,
+            2
         );
+        assert_eq!(ProposedLawyers::<Test>::get(0).is_none(), true);
         assert_eq!(
-            OngoingObjectListing::<Test>::get(3)
+            PropertyLawyer::<Test>::get(0)
                 .unwrap()
-                .listed_token_amount,
-            100
+                .real_estate_developer_lawyer,
+            Some([10; 32].into())
         );
         assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([2; 32].into(), 2)
+            PropertyLawyer::<Test>::get(0)
                 .unwrap()
-                .token_amount,
-            30
-        );
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 1),
-            None
-        );
-        assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(1, [1; 32].into()),
-            40
+                .real_estate_developer_lawyer_costs
+                .get(&1984)
+                .unwrap(),
+            &3_000u128
         );
-    });
+    })
 }
 
-// claim_property_token function
 #[test]
-fn claim_property_token_works() {
+fn approve_developer_lawyer_works_2() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -1008,6 +4163,15 @@ fn claim_property_token_works() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -1015,191 +4179,195 @@ fn claim_property_token_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
-        ));
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .investor_funds
-                .get(&[1; 32].into())
-                .clone(),
+            1984,
             None
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_084_000);
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
-            416_000
-        );
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            0
-        );
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 0);
+        ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_funds
-                .get(&1984)
-                .copied(),
-            Some(400_000)
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_fees
-                .get(&1984)
-                .copied(),
-            Some(4_000)
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_tax
-                .get(&1984)
-                .copied(),
-            Some(12_000)
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_084_000);
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
-            0
-        );
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            416_000
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_noop!(
+            Marketplace::approve_developer_lawyer(RuntimeOrigin::signed([0; 32].into()), 0, true),
+            Error::<Test>::InvalidIndex
         );
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
-        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 0);
-        assert_eq!(
-            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
-            60
+        assert!(ProposedLawyers::<Test>::get(0).is_none());
+        assert!(PropertyLawyer::<Test>::get(0).is_none());
+        assert_eq!(RefundLegalExpired::<Test>::get(0), Some(100));
+    })
+}
+
+#[test]
+fn approve_developer_lawyer_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_noop!(
+            Marketplace::approve_developer_lawyer(RuntimeOrigin::signed([0; 32].into()), 0, true),
+            Error::<Test>::ListingNotFound
         );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .investor_funds
-                .get(&[1; 32].into())
-                .clone()
-                .unwrap()
-                .paid_funds
-                .get(&1984)
-                .unwrap(),
-            &412_000_u128
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_noop!(
+            Marketplace::approve_developer_lawyer(RuntimeOrigin::signed([0; 32].into()), 0, true),
+            Error::<Test>::InvalidIndex
         );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .investor_funds
-                .get(&[1; 32].into())
-                .clone()
-                .unwrap()
-                .paid_funds
-                .get(&1337),
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
             None
-        );
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
             None
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .investor_funds
-                .get(&[2; 32].into())
-                .clone(),
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            30,
+            1984,
             None
-        );
-        assert_eq!(PropertyLawyer::<Test>::get(0).is_some(), false);
+        ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_funds
-                .get(&1984)
-                .copied(),
-            Some(1_000_000)
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_fees
-                .get(&1984)
-                .copied(),
-            Some(10_000)
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_tax
-                .get(&1984)
-                .copied(),
-            Some(30_000)
-        );
-        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 30);
-        assert_eq!(
-            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
-            0
-        );
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            1040_000
-        );
-        assert_eq!(PropertyLawyer::<Test>::get(0).is_some(), true);
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .investor_funds
-                .get(&[2; 32].into())
-                .clone()
-                .unwrap()
-                .paid_funds
-                .get(&1984)
-                .unwrap(),
-            &309_000_u128
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+        ));
+        assert_noop!(
+            Marketplace::approve_developer_lawyer(RuntimeOrigin::signed([0; 32].into()), 0, true),
+            Error::<Test>::NoLawyerProposed
         );
-        System::assert_last_event(
-            Event::PropertyTokenClaimed {
-                listing_id: 0,
-                asset_id: 0,
-                owner: [30; 32].into(),
-                amount: 30,
-            }
-            .into(),
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_noop!(
+            Marketplace::approve_developer_lawyer(RuntimeOrigin::signed([10; 32].into()), 0, false),
+            Error::<Test>::NoPermission
         );
     })
 }
 
+// vote_on_spv_lawyer function
 #[test]
-fn claim_property_token_works_2() {
+fn vote_on_spv_lawyer_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -1237,6 +4405,25 @@ fn claim_property_token_works_2() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -1244,232 +4431,173 @@ fn claim_property_token_works_2() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            30,
-            1984
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            30,
-            1984
-        ));
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .investor_funds
-                .get(&[1; 32].into())
-                .clone(),
+            20,
+            1984,
             None
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_084_000);
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
-            416_000
-        );
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            0
-        );
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 0);
+        ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_funds
-                .get(&1984)
-                .copied(),
-            Some(700_000)
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_fees
-                .get(&1984)
-                .copied(),
-            Some(7_000)
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_tax
-                .get(&1984)
-                .copied(),
-            Some(21_000)
-        );
-        assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
-            40
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_084_000);
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
-            0
-        );
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            728_000
-        );
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
-        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 0);
-        assert_eq!(LocalAssets::balance(0, &[30; 32].into()), 30);
-        assert_eq!(
-            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
-            30
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .investor_funds
-                .get(&[1; 32].into())
-                .clone()
-                .unwrap()
-                .paid_funds
-                .get(&1984)
-                .unwrap(),
-            &412_000_u128
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .investor_funds
-                .get(&[1; 32].into())
-                .clone()
-                .unwrap()
-                .paid_funds
-                .get(&1337),
-            None
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .investor_funds
-                .get(&[30; 32].into())
-                .clone()
-                .unwrap()
-                .paid_funds
-                .get(&1984)
-                .unwrap(),
-            &309_000_u128
-        );
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .investor_funds
-                .get(&[2; 32].into())
-                .clone(),
-            None
-        );
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
-        assert_ok!(Marketplace::finalize_claim_window(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
             0,
         ));
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([30; 32].into(), 0),
-            None
-        );
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
             0,
-            10,
-            1984
+            crate::LegalProperty::SpvSide,
+            4_000,
         ));
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([30; 32].into(), 0)
-                .unwrap()
-                .token_amount,
-            10
-        );
-        assert_ok!(Marketplace::withdraw_unclaimed(
-            RuntimeOrigin::signed([2; 32].into()),
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
+            crate::Vote::No,
+            40
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_eq!(
+            AssetsFreezer::balance_frozen(
+                0,
+                &MarketplaceFreezeReason::SpvLawyerVoting,
+                &[1; 32].into()
+            ),
+            40
+        );
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            20,
-            1984
+            crate::Vote::Yes,
+            40
         ));
-        assert_ok!(Marketplace::claim_property_token(
+        assert_eq!(
+            AssetsFreezer::balance_frozen(
+                0,
+                &MarketplaceFreezeReason::SpvLawyerVoting,
+                &[2; 32].into()
+            ),
+            40
+        );
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([30; 32].into()),
             0,
+            crate::Vote::No,
+            20
         ));
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_funds
-                .get(&1984)
-                .copied(),
-            Some(800_000)
+            AssetsFreezer::balance_frozen(
+                0,
+                &MarketplaceFreezeReason::SpvLawyerVoting,
+                &[30; 32].into()
+            ),
+            20
         );
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_fees
-                .get(&1984)
-                .copied(),
-            Some(8_000)
+            OngoingLawyerVoting::<Test>::get(0).unwrap(),
+            VoteStats {
+                yes_voting_power: 40,
+                no_voting_power: 60,
+            }
         );
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
+            UserLawyerVote::<Test>::get::<u64, AccountId>(0, [1; 32].into())
                 .unwrap()
-                .collected_tax
-                .get(&1984)
-                .copied(),
-            Some(24_000)
-        );
-        assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
-            40
+                .vote,
+            crate::Vote::No
         );
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
+            crate::Vote::Yes,
+            20
         ));
         assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()),
+            AssetsFreezer::balance_frozen(
+                0,
+                &MarketplaceFreezeReason::SpvLawyerVoting,
+                &[1; 32].into()
+            ),
             20
         );
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .investor_funds
-                .get(&[30; 32].into())
-                .clone()
+            OngoingLawyerVoting::<Test>::get(0).unwrap(),
+            VoteStats {
+                yes_voting_power: 60,
+                no_voting_power: 20,
+            }
+        );
+        assert_eq!(
+            UserLawyerVote::<Test>::get::<u64, AccountId>(0, [1; 32].into())
                 .unwrap()
-                .paid_funds
-                .get(&1984)
-                .unwrap(),
-            &412_000_u128
+                .vote,
+            crate::Vote::Yes
+        );
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Withdraw,
+            0
+        ));
+        assert_eq!(
+            AssetsFreezer::balance_frozen(
+                0,
+                &MarketplaceFreezeReason::SpvLawyerVoting,
+                &[1; 32].into()
+            ),
+            0
+        );
+        assert_eq!(
+            OngoingLawyerVoting::<Test>::get(0).unwrap(),
+            VoteStats {
+                yes_voting_power: 40,
+                no_voting_power: 20,
+            }
+        );
+        assert!(UserLawyerVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_none());
+        assert_noop!(
+            Marketplace::vote_on_spv_lawyer(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                crate::Vote::Withdraw,
+                0
+            ),
+            Error::<Test>::NoVoteToWithdraw
         );
     })
 }
 
 #[test]
-fn claim_property_token_fails() {
+fn vote_on_spv_lawyer_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -1492,6 +4620,11 @@ fn claim_property_token_fails() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
@@ -1509,17 +4642,26 @@ fn claim_property_token_fails() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [6; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
         ));
         assert_noop!(
-            Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::ListingNotFound
+            Marketplace::vote_on_spv_lawyer(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                crate::Vote::No,
+                20,
+            ),
+            Error::<Test>::NoLawyerProposed
         );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -1528,99 +4670,111 @@ fn claim_property_token_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            48,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            25,
-            1984
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            26,
-            1984
+            40,
+            1984,
+            None
         ));
-        assert_noop!(
-            Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::NoClaimWindow
-        );
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([6; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            1,
-            1984
+            20,
+            1984,
+            None
         ));
-        assert_noop!(
-            Marketplace::claim_property_token(RuntimeOrigin::signed([3; 32].into()), 0,),
-            BadOrigin
-        );
-        assert_noop!(
-            Marketplace::claim_property_token(RuntimeOrigin::signed([2; 32].into()), 0,),
-            Error::<Test>::TokenOwnerNotFound
-        );
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
         assert_noop!(
-            Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::TokenOwnerNotFound
-        );
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
-        assert_noop!(
-            Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::ClaimWindowExpired
+            Marketplace::vote_on_spv_lawyer(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                crate::Vote::No,
+                40
+            ),
+            Error::<Test>::NoLawyerProposed
         );
-        assert_ok!(Marketplace::finalize_claim_window(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
             0,
         ));
-        assert_noop!(
-            Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::NoClaimWindow
-        );
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
             0,
-            1,
-            1984
+            crate::LegalProperty::SpvSide,
+            4_000,
         ));
         assert_noop!(
-            Marketplace::claim_property_token(RuntimeOrigin::signed([6; 32].into()), 0,),
-            Error::<Test>::NoValidTokenToClaim
+            Marketplace::vote_on_spv_lawyer(
+                RuntimeOrigin::signed([0; 32].into()),
+                0,
+                crate::Vote::No,
+                100
+            ),
+            Error::<Test>::NotEnoughToken
         );
-        assert_ok!(XcavateWhitelist::set_permission(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor,
-            pallet_xcavate_whitelist::AccessPermission::Revoked,
-        ));
         assert_noop!(
-            Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0,),
-            BadOrigin
+            Marketplace::vote_on_spv_lawyer(
+                RuntimeOrigin::signed([0; 32].into()),
+                0,
+                crate::Vote::No,
+                0
+            ),
+            Error::<Test>::ZeroVoteAmount
+        );
+        assert_eq!(
+            AssetsFreezer::balance_frozen(
+                0,
+                &MarketplaceFreezeReason::SpvLawyerVoting,
+                &[0; 32].into()
+            ),
+            0
+        );
+        run_to_block(100);
+        assert_noop!(
+            Marketplace::vote_on_spv_lawyer(
+                RuntimeOrigin::signed([0; 32].into()),
+                0,
+                crate::Vote::No,
+                12
+            ),
+            Error::<Test>::VotingExpired
         );
     })
 }
 
+// vote_on_spv_lawyer function
 #[test]
-fn relist_unclaim_property_token_works() {
+fn finalize_spv_lawyer_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -1645,17 +4799,17 @@ fn relist_unclaim_property_token_works() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
+            [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
+            [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [6; 32].into(),
+            [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
@@ -1663,6 +4817,20 @@ fn relist_unclaim_property_token_works() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -1670,103 +4838,169 @@ fn relist_unclaim_property_token_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            30,
-            1984
+            40,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            30,
-            1984
+            20,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .listed_token_amount,
-            0
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .unclaimed_token_amount,
-            30
-        );
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
-        assert_ok!(Marketplace::finalize_claim_window(
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 0);
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
+            crate::Vote::No,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::No,
+            20
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 1);
+        run_to_block(121);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_eq!(OngoingLawyerVoting::<Test>::get(0).is_none(), true);
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
         ));
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .listed_token_amount,
-            30
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0).unwrap().listing_expiry,
-            frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get()
+            UserLawyerVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_none(),
+            true
         );
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([6; 32].into()),
+        assert_eq!(SpvLawyerProposal::<Test>::get(0).is_none(), true);
+        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, None);
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
             0,
-            30,
-            1984
+            crate::LegalProperty::SpvSide,
+            3_000,
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([6; 32].into()),
+        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 2);
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
             0,
+            crate::Vote::No,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            20
         ));
+        run_to_block(151);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_eq!(OngoingLawyerVoting::<Test>::get(0).is_none(), true);
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_funds
-                .get(&1984)
-                .copied(),
-            Some(1_000_000)
+            UserLawyerVote::<Test>::get::<u64, AccountId>(2, [1; 32].into()).is_none(),
+            false
         );
+        assert_eq!(SpvLawyerProposal::<Test>::get(0).is_none(), true);
+        assert_eq!(ListingSpvProposal::<Test>::get(0).is_none(), true);
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_fees
-                .get(&1984)
-                .copied(),
-            Some(10_000)
+            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
+            Some([10; 32].into())
         );
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
+            PropertyLawyer::<Test>::get(0)
                 .unwrap()
-                .collected_tax
+                .spv_lawyer_costs
                 .get(&1984)
-                .copied(),
-            Some(30_000)
+                .unwrap(),
+            &3_000u128
         );
-        assert_eq!(LocalAssets::balance(0, &[6; 32].into()), 30);
     })
 }
 
-// finalize_claim_window function
 #[test]
-fn finalize_claim_window_works() {
+fn finalize_spv_lawyer_works2() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -1789,6 +5023,11 @@ fn finalize_claim_window_works() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
@@ -1799,95 +5038,198 @@ fn finalize_claim_window_works() {
             [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            150,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            60,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            70,
+            1337,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            20,
+            1337,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
         ));
-        assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([0; 32].into()),
-            3,
-            bvec![10, 10],
-            10_000,
-            100,
-            bvec![22, 22],
-            false
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 0);
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
+            crate::Vote::No,
+            10
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            30,
-            1984
+            crate::Vote::Yes,
+            60
         ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
             0,
-            30,
-            1984
         ));
-        assert_ok!(Marketplace::claim_property_token(
+        assert_eq!(OngoingLawyerVoting::<Test>::get(0).is_none(), true);
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([2; 32].into()),
             0,
         ));
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .listed_token_amount,
-            0
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .unclaimed_token_amount,
-            30
+            UserLawyerVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_none(),
+            true
         );
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
-        assert_ok!(Marketplace::finalize_claim_window(
+        assert_eq!(SpvLawyerProposal::<Test>::get(0).is_none(), true);
+        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, None);
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            3_000,
+        ));
+        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 1);
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
+            crate::Vote::Yes,
+            60
         ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            crate::Vote::No,
+            15
+        ));
+        run_to_block(121);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+        ));
+        assert_eq!(OngoingLawyerVoting::<Test>::get(0).is_none(), true);
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .listed_token_amount,
-            30
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0).unwrap().listing_expiry,
-            frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get()
+            UserLawyerVote::<Test>::get::<u64, AccountId>(1, [1; 32].into()).is_none(),
+            true
         );
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            3_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            60
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            crate::Vote::No,
+            55
+        ));
+        run_to_block(151);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_eq!(SpvLawyerProposal::<Test>::get(0).is_none(), true);
+        assert_eq!(ListingSpvProposal::<Test>::get(0).is_none(), true);
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0).unwrap().relist_count,
-            1
+            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
+            Some([10; 32].into())
         );
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
+            PropertyLawyer::<Test>::get(0)
                 .unwrap()
-                .unclaimed_token_amount,
-            0
+                .spv_lawyer_costs
+                .get(&1984)
+                .unwrap(),
+            &3_000u128
         );
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            15,
-            1984
-        ));
     })
 }
 
 #[test]
-fn finalize_claim_window_fails() {
+fn finalize_spv_lawyer_works3() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -1910,6 +5252,11 @@ fn finalize_claim_window_fails() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
@@ -1925,10 +5272,15 @@ fn finalize_claim_window_fails() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_noop!(
-            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::ListingNotFound
-        );
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -1936,81 +5288,93 @@ fn finalize_claim_window_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_noop!(
-            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::NoClaimWindow
-        );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            30,
-            1984
+            40,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            30,
-            1984
+            20,
+            1984,
+            None
         ));
-        assert_noop!(
-            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::ClaimWindowNotExpired
-        );
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
-        assert_ok!(Marketplace::finalize_claim_window(
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 0);
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
+            crate::Vote::Yes,
+            40
         ));
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0).unwrap().relist_count,
-            1
-        );
-        assert_ok!(Marketplace::withdraw_unclaimed(
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([2; 32].into()),
             0,
+            crate::Vote::Yes,
+            40
         ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            30,
-            1984
+            crate::Vote::No,
+            20
         ));
         let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
         run_to_block(expiry);
-        assert_ok!(Marketplace::finalize_claim_window(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0).unwrap().relist_count,
-            2
-        );
         assert_noop!(
-            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::NoClaimWindow
+            Marketplace::finalize_spv_lawyer(RuntimeOrigin::signed([0; 32].into()), 0),
+            Error::<Test>::NoLawyerProposed
         );
+        assert!(PropertyLawyer::<Test>::get(0).is_none());
+        assert_eq!(SpvLawyerProposal::<Test>::get(0).is_none(), true);
+        assert_eq!(ListingSpvProposal::<Test>::get(0).is_none(), true);
     })
 }
 
 #[test]
-fn finalize_claim_window_fails_2() {
+fn finalize_spv_lawyer_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -2033,6 +5397,11 @@ fn finalize_claim_window_fails_2() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
@@ -2045,12 +5414,26 @@ fn finalize_claim_window_fails_2() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
         ));
         assert_noop!(
-            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::ListingNotFound
+            Marketplace::finalize_spv_lawyer(RuntimeOrigin::signed([0; 32].into()), 0,),
+            Error::<Test>::NoLawyerProposed
         );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -2059,29 +5442,32 @@ fn finalize_claim_window_fails_2() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_noop!(
-            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::NoClaimWindow
-        );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
+            45,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            30,
-            1984
+            40,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            30,
-            1984
+            15,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2096,15 +5482,51 @@ fn finalize_claim_window_fails_2() {
             0,
         ));
         assert_noop!(
-            Marketplace::finalize_claim_window(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::NoClaimWindow
+            Marketplace::finalize_spv_lawyer(RuntimeOrigin::signed([0; 32].into()), 0,),
+            Error::<Test>::NoLawyerProposed
+        );
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::No,
+            45
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_noop!(
+            Marketplace::finalize_spv_lawyer(RuntimeOrigin::signed([0; 32].into()), 0,),
+            Error::<Test>::VotingStillOngoing
+        );
+        run_to_block(91);
+        assert_noop!(
+            Marketplace::finalize_spv_lawyer(RuntimeOrigin::signed([10; 32].into()), 0,),
+            BadOrigin
         );
     })
 }
 
-// create_spv function
+// remove_lawyer_claim function
 #[test]
-fn create_spv_works() {
+fn remove_lawyer_claim_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -2142,6 +5564,33 @@ fn create_spv_works() {
             [31; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [12; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([12; 32].into()),
+            3,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -2149,25 +5598,32 @@ fn create_spv_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            45,
-            1984
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
-            25,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2181,25 +5637,95 @@ fn create_spv_works() {
             RuntimeOrigin::signed([31; 32].into()),
             0,
         ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            Some([10; 32].into())
+        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
+            [5; 32].into(),
             pallet_xcavate_whitelist::Role::SpvConfirmation
         ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
         assert_eq!(
-            PropertyAssetInfo::<Test>::get(0).unwrap().spv_created,
-            false
+            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
+            Some([11; 32].into())
         );
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([2; 32].into()),
+        assert_ok!(Marketplace::remove_lawyer_claim(
+            RuntimeOrigin::signed([10; 32].into()),
             0,
         ));
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            None
+        );
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
+            Some([11; 32].into())
+        );
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([12; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            Some([12; 32].into())
+        );
     })
 }
 
 #[test]
-fn create_spv_fails() {
+fn remove_lawyer_claim_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -2239,13 +5765,14 @@ fn create_spv_fails() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
         ));
-        assert_noop!(
-            Marketplace::create_spv(RuntimeOrigin::signed([2; 32].into()), 0,),
-            Error::<Test>::NoObjectFound
-        );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -2253,46 +5780,83 @@ fn create_spv_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            48,
-            1984
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            25,
-            1984
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+        ));
+        assert_noop!(
+            Marketplace::remove_lawyer_claim(RuntimeOrigin::signed([10; 32].into()), 0,),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_noop!(
+            Marketplace::remove_lawyer_claim(RuntimeOrigin::signed([10; 32].into()), 1,),
+            Error::<Test>::InvalidIndex
+        );
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
         ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
             0,
-            26,
-            1984
+            true
         ));
-        assert_noop!(
-            Marketplace::create_spv(RuntimeOrigin::signed([2; 32].into()), 0,),
-            Error::<Test>::PropertyHasNotBeenSoldYet
-        );
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
             0,
-            1,
-            1984
+            true,
         ));
         assert_noop!(
-            Marketplace::create_spv(RuntimeOrigin::signed([1; 32].into()), 0,),
-            BadOrigin
+            Marketplace::remove_lawyer_claim(RuntimeOrigin::signed([10; 32].into()), 0,),
+            Error::<Test>::AlreadyConfirmed
         );
     })
 }
 
-// lawyer_claim_property function
+// lawyer_confirm_documents function
 #[test]
-fn claim_property_works1() {
+fn finalize_property_deal() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -2322,12 +5886,12 @@ fn claim_property_works1() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
+            [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
@@ -2355,36 +5919,61 @@ fn claim_property_works1() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            200_000
+        );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            45,
-            1984
+            25,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            30,
-            1984
+            20,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            25,
-            1984
+            20,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            20,
+            1337,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            15,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
@@ -2393,16 +5982,16 @@ fn claim_property_works1() {
             crate::LegalProperty::RealEstateDeveloperSide,
             4_000,
         ));
-        assert_eq!(
-            ProposedLawyers::<Test>::get(0).unwrap().lawyer,
-            [10; 32].into()
-        );
-        assert_eq!(ProposedLawyers::<Test>::get(0).unwrap().costs, 4_000);
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
         assert_eq!(
             PropertyLawyer::<Test>::get(0)
                 .unwrap()
                 .real_estate_developer_lawyer,
-            None
+            Some([10; 32].into())
         );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
@@ -2419,19 +6008,94 @@ fn claim_property_works1() {
             crate::LegalProperty::SpvSide,
             4_000,
         ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            45
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            15
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
         assert_eq!(
-            SpvLawyerProposal::<Test>::get(0).unwrap().lawyer,
-            [11; 32].into()
+            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
+            Some([11; 32].into())
         );
-        assert_eq!(SpvLawyerProposal::<Test>::get(0).unwrap().expiry_block, 91);
-        assert_eq!(OngoingLawyerVoting::<Test>::get(0).is_some(), true);
-        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, None);
-        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 0);
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_status,
+            crate::DocumentStatus::Approved
+        );
+        assert_eq!(
+            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
+            0
+        );
+        assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().asset_id, 0);
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_eq!(
+            RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
+                .unwrap()
+                .active_cases,
+            0
+        );
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            0
+        );
+        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
+        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_594_000);
+        assert_eq!(ForeignAssets::balance(1337, &[0; 32].into()), 20_396_000);
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
+            4_000
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[8; 32].into()), 4_000);
+        assert_eq!(
+            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
+            4_000
+        );
+        assert_eq!(ForeignAssets::balance(1337, &[8; 32].into()), 4_000);
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_240_000);
+        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 942_000);
+        assert_eq!(ForeignAssets::balance(1984, &[30; 32].into()), 1_044_000);
+        assert_eq!(ForeignAssets::balance(1984, &[10; 32].into()), 0);
+        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 22_000);
+        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_292_000);
+        assert_eq!(ForeignAssets::balance(1337, &[2; 32].into()), 942_000);
+        assert_eq!(ForeignAssets::balance(1337, &[10; 32].into()), 0);
+        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 12_000);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
+        );
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 45);
+        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 40);
+        assert_eq!(LocalAssets::balance(0, &[30; 32].into()), 15);
     })
 }
 
 #[test]
-fn claim_property_works2() {
+fn finalize_property_deal_2() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -2469,11 +6133,6 @@ fn claim_property_works2() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [9; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -2497,42 +6156,39 @@ fn claim_property_works2() {
             3,
             bvec![10, 10],
             10_000,
-            200,
+            100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            1,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([9; 32].into()),
-            0,
-            98,
-            1337
+            40,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            50,
-            1337
+            30,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            51,
-            1337
+            30,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([9; 32].into()),
-            0,
-        ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
@@ -2545,7 +6201,7 @@ fn claim_property_works2() {
             RuntimeOrigin::signed([10; 32].into()),
             0,
             crate::LegalProperty::RealEstateDeveloperSide,
-            15_000,
+            4_000,
         ));
         assert_ok!(Marketplace::approve_developer_lawyer(
             RuntimeOrigin::signed([0; 32].into()),
@@ -2571,25 +6227,19 @@ fn claim_property_works2() {
             RuntimeOrigin::signed([11; 32].into()),
             0,
             crate::LegalProperty::SpvSide,
-            16_000,
+            4_000,
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             crate::Vote::Yes,
-            1
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([9; 32].into()),
-            0,
-            crate::Vote::Yes,
-            98
+            40
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             crate::Vote::Yes,
-            45
+            30
         ));
         run_to_block(91);
         assert_ok!(Marketplace::finalize_spv_lawyer(
@@ -2600,43 +6250,52 @@ fn claim_property_works2() {
             PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
             Some([11; 32].into())
         );
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
         assert_eq!(
             PropertyLawyer::<Test>::get(0)
                 .unwrap()
-                .spv_lawyer_costs
-                .get(&1984)
-                .unwrap(),
-            &0u128
-        );
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .spv_lawyer_costs
-                .get(&1337)
-                .unwrap(),
-            &16_000u128
-        );
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer_costs
-                .get(&1984)
-                .unwrap(),
-            &0u128
+                .real_estate_developer_status,
+            crate::DocumentStatus::Approved
         );
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
+        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_000_000);
+        assert_eq!(ForeignAssets::balance(1337, &[0; 32].into()), 20_990_000);
         assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer_costs
-                .get(&1337)
-                .unwrap(),
-            &15_000u128
+            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
+            0
+        );
+        assert_eq!(
+            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
+            8000
         );
+        assert_eq!(ForeignAssets::balance(1337, &[8; 32].into()), 8000);
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
+        assert_eq!(ForeignAssets::balance(1984, &[10; 32].into()), 0);
+        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 0);
+        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_084_000);
+        assert_eq!(ForeignAssets::balance(1337, &[2; 32].into()), 838_000);
+        assert_eq!(ForeignAssets::balance(1337, &[30; 32].into()), 888_000);
+        assert_eq!(ForeignAssets::balance(1337, &[10; 32].into()), 0);
+        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 34_000);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
+        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 30);
+        assert_eq!(LocalAssets::balance(0, &[30; 32].into()), 30);
     })
 }
 
 #[test]
-fn claim_property_fails() {
+fn finalize_property_deal_3() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -2648,7 +6307,34 @@ fn claim_property_fails() {
             [8; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        new_region_helper();
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RegionalOperator
+        ));
+        assert_ok!(Regions::propose_new_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            RegionIdentifier::Japan
+        ));
+        assert_ok!(Regions::vote_on_region_proposal(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            pallet_regions::Vote::Yes,
+            10_000
+        ));
+        run_to_block(31);
+        assert_ok!(Regions::bid_on_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            100_000
+        ));
+        run_to_block(61);
+        assert_ok!(Regions::create_new_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            30,
+            Permill::from_parts(32_500)
+        ));
         assert_ok!(Regions::create_new_location(
             RuntimeOrigin::signed([8; 32].into()),
             3,
@@ -2684,11 +6370,6 @@ fn claim_property_fails() {
             [11; 32].into(),
             pallet_xcavate_whitelist::Role::Lawyer
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [12; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
         assert_ok!(Regions::register_lawyer(
             RuntimeOrigin::signed([10; 32].into()),
             3,
@@ -2704,40 +6385,50 @@ fn claim_property_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: true,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            200_000
+        );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
+            20,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            20,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            30,
-            1984
+            20,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            29,
-            1984
+            20,
+            1984,
+            None
         ));
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([10; 32].into()),
-                0,
-                crate::LegalProperty::RealEstateDeveloperSide,
-                4_000,
-            ),
-            Error::<Test>::InvalidIndex
-        );
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            1,
-            1984
+            20,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2751,51 +6442,22 @@ fn claim_property_fails() {
             RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([9; 32].into()),
-                0,
-                crate::LegalProperty::RealEstateDeveloperSide,
-                4_000,
-            ),
-            BadOrigin
-        );
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([10; 32].into()),
-                0,
-                crate::LegalProperty::RealEstateDeveloperSide,
-                11_000,
-            ),
-            Error::<Test>::CostsTooHigh
-        );
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
             0,
             crate::LegalProperty::RealEstateDeveloperSide,
             4_000,
         ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
         assert_eq!(
-            ProposedLawyers::<Test>::get(0).unwrap().lawyer,
-            [10; 32].into()
-        );
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([11; 32].into()),
-                0,
-                crate::LegalProperty::RealEstateDeveloperSide,
-                4_000,
-            ),
-            Error::<Test>::LawyerProposalOngoing
-        );
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([10; 32].into()),
-                0,
-                crate::LegalProperty::SpvSide,
-                4_000,
-            ),
-            RealEstateAssetError::<Test>::SpvNotCreated
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            Some([10; 32].into())
         );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
@@ -2806,149 +6468,372 @@ fn claim_property_fails() {
             RuntimeOrigin::signed([5; 32].into()),
             0,
         ));
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([10; 32].into()),
-                0,
-                crate::LegalProperty::SpvSide,
-                4_000,
-            ),
-            Error::<Test>::NoPermission
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
+            Some([11; 32].into())
         );
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
             0,
             true,
         ));
         assert_eq!(
             PropertyLawyer::<Test>::get(0)
                 .unwrap()
-                .real_estate_developer_lawyer,
-            Some([10; 32].into())
+                .real_estate_developer_status,
+            crate::DocumentStatus::Approved
         );
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([11; 32].into()),
-                0,
-                crate::LegalProperty::RealEstateDeveloperSide,
-                4_000,
-            ),
-            Error::<Test>::LawyerJobTaken
+        assert_eq!(
+            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
+            0
         );
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([10; 32].into()),
-                0,
-                crate::LegalProperty::SpvSide,
-                4_000,
-            ),
-            Error::<Test>::NoPermission
+        assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().asset_id, 0);
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_tax
+                .get(&1984)
+                .copied(),
+            Some(19_500)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_tax
+                .get(&1337)
+                .copied(),
+            Some(13_000)
+        );
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            0
+        );
+        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
+        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_574_500);
+        assert_eq!(ForeignAssets::balance(1337, &[0; 32].into()), 20_383_000);
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
+            4_000
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[8; 32].into()), 4_000);
+        assert_eq!(
+            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
+            4_000
+        );
+        assert_eq!(ForeignAssets::balance(1337, &[8; 32].into()), 4_000);
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_298_000);
+        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 948_000);
+        assert_eq!(ForeignAssets::balance(1984, &[10; 32].into()), 19_500);
+        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 4_000);
+        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_298_000);
+        assert_eq!(ForeignAssets::balance(1337, &[2; 32].into()), 948_000);
+        assert_eq!(ForeignAssets::balance(1337, &[10; 32].into()), 13_000);
+        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 0);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
         );
-        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, None);
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
+    })
+}
+
+#[test]
+fn finalize_property_deal_4() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RegionalOperator
+        ));
         assert_ok!(Regions::propose_new_region(
             RuntimeOrigin::signed([8; 32].into()),
-            RegionIdentifier::France
+            RegionIdentifier::Japan
         ));
         assert_ok!(Regions::vote_on_region_proposal(
             RuntimeOrigin::signed([8; 32].into()),
-            2,
+            3,
             pallet_regions::Vote::Yes,
             10_000
         ));
-        run_to_block(91);
+        run_to_block(31);
         assert_ok!(Regions::bid_on_region(
             RuntimeOrigin::signed([8; 32].into()),
-            2,
+            3,
             100_000
         ));
-        run_to_block(121);
+        run_to_block(61);
         assert_ok!(Regions::create_new_region(
             RuntimeOrigin::signed([8; 32].into()),
-            2,
+            3,
             30,
-            Permill::from_percent(3)
+            Permill::from_parts(32_500)
         ));
         assert_ok!(Regions::create_new_location(
             RuntimeOrigin::signed([8; 32].into()),
-            2,
-            bvec![20, 10]
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
-            2,
-            bvec![20, 10],
-            1_000,
+            3,
+            bvec![10, 10],
+            10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: true,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            200_000
+        );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            1,
-            30,
-            1984
+            0,
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
-            1,
+            0,
             40,
-            1984
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
-            1,
-            30,
-            1984
+            0,
+            20,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            1,
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([2; 32].into()),
-            1,
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
-            1,
+            0,
         ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            1,
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
         ));
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([10; 32].into()),
-                1,
-                crate::LegalProperty::RealEstateDeveloperSide,
-                400,
-            ),
-            Error::<Test>::WrongRegion
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            Some([10; 32].into())
         );
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([12; 32].into()),
-            2,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([12; 32].into()),
-            1,
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
             crate::LegalProperty::SpvSide,
-            400,
+            4_000,
         ));
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([12; 32].into()),
-                1,
-                crate::LegalProperty::RealEstateDeveloperSide,
-                400,
-            ),
-            Error::<Test>::NoPermission
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            20
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
+            Some([11; 32].into())
+        );
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_status,
+            crate::DocumentStatus::Approved
+        );
+        assert_eq!(
+            LocalAssets::balance(40, &Marketplace::property_account_id(0)),
+            0
+        );
+        assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().asset_id, 0);
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_tax
+                .get(&1984)
+                .copied(),
+            Some(19_500)
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_tax
+                .get(&1337)
+                .copied(),
+            Some(13_000)
+        );
+        assert_ok!(ForeignAssets::transfer(
+            RuntimeOrigin::signed([1; 32].into()),
+            codec::Compact(1337),
+            sp_runtime::MultiAddress::Id(Marketplace::property_account_id(0)),
+            404_000
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            0
+        );
+        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
+        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_574_500);
+        assert_eq!(ForeignAssets::balance(1337, &[0; 32].into()), 20_383_000);
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
+            4_000
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[8; 32].into()), 4_000);
+        assert_eq!(
+            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
+            4_000
+        );
+        assert_eq!(ForeignAssets::balance(1337, &[8; 32].into()), 4_000);
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_096_000);
+        assert_eq!(ForeignAssets::balance(1984, &[30; 32].into()), 998_000);
+        assert_eq!(ForeignAssets::balance(1984, &[10; 32].into()), 19_500);
+        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 4_000);
+        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_096_000);
+        assert_eq!(ForeignAssets::balance(1337, &[10; 32].into()), 13_000);
+        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 0);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
         );
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
+        assert_eq!(LocalAssets::balance(0, &[30; 32].into()), 20);
     })
 }
 
 #[test]
-fn claim_property_works_fails_2() {
+fn reject_contract_and_refund() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -2986,11 +6871,6 @@ fn claim_property_works_fails_2() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [9; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -3014,50 +6894,71 @@ fn claim_property_works_fails_2() {
             3,
             bvec![10, 10],
             10_000,
-            200,
+            100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            1,
-            1984
+            25,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([9; 32].into()),
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            98,
-            1337
+            20,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            50,
-            1337
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            51,
-            1337
+            25,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([9; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([2; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
             0,
+            true
         ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            Some([10; 32].into())
+        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -3067,32 +6968,168 @@ fn claim_property_works_fails_2() {
             RuntimeOrigin::signed([5; 32].into()),
             0,
         ));
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([11; 32].into()),
-                0,
-                crate::LegalProperty::SpvSide,
-                16_000,
-            ),
-            Error::<Test>::LegalProcessFailed
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            45
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
+            Some([11; 32].into())
         );
-        assert_noop!(
-            Marketplace::lawyer_claim_property(
-                RuntimeOrigin::signed([10; 32].into()),
-                0,
-                crate::LegalProperty::RealEstateDeveloperSide,
-                15_000,
-            ),
-            Error::<Test>::LegalProcessFailed
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            false,
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_status,
+            crate::DocumentStatus::Rejected
+        );
+        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            0
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1337, &[1; 32].into()),
+            0
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_240_000);
+        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_292_000);
+        /*         assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).unwrap()
+                .paid_funds
+                .get(&1984)
+                .unwrap(),
+            &600000_u128
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).unwrap()
+                .paid_tax
+                .get(&1984)
+                .unwrap(),
+            &18000_u128
+        ); */
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            false,
+        ));
+        /*         System::assert_last_event(
+            Event::DocumentsConfirmed {
+                signer: [11; 32].into(),
+                listing_id: 0,
+                legal_side: LegalProperty::SpvSide,
+                approve: false,
+            }
+            .into(),
+        ); */
+        assert_eq!(RefundToken::<Test>::get(0).unwrap().refund_amount, 100);
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 45);
+        /*         assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).unwrap().token_amount,
+            100
+        ); */
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            200_000
+        );
+        assert_ok!(Marketplace::withdraw_rejected(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::withdraw_rejected(
+            RuntimeOrigin::signed([2; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::withdraw_rejected(
+            RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            0
+        );
+        assert_eq!(RefundToken::<Test>::get(0).is_none(), true);
+        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
+        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_000_000);
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
+            4000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
+            2000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            0
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_497_500);
+        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_498_000);
+        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_147_000);
+        assert_eq!(ForeignAssets::balance(1984, &[30; 32].into()), 1_197_500);
+        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 4000);
+        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 0);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).is_none(), true);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
+        );
+        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            0
+        );
+        assert_eq!(
+            Balances::free_balance(&(Marketplace::property_account_id(0))),
+            0
+        );
+        assert_eq!(Balances::balance(&(Marketplace::property_account_id(0))), 0);
+        assert_eq!(Nfts::owner(0, 0), None);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0), None);
+        assert_eq!(
+            RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
+                .unwrap()
+                .active_cases,
+            0
         );
     })
 }
 
-// approve_developer_lawyer function
 #[test]
-fn approve_developer_lawyer_works() {
+fn reject_contract_and_refund_2() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -3127,7 +7164,7 @@ fn approve_developer_lawyer_works() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [7; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
@@ -3135,10 +7172,19 @@ fn approve_developer_lawyer_works() {
             [10; 32].into(),
             pallet_xcavate_whitelist::Role::Lawyer
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
         assert_ok!(Regions::register_lawyer(
             RuntimeOrigin::signed([10; 32].into()),
             3,
         ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -3146,25 +7192,32 @@ fn approve_developer_lawyer_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            30,
-            1984
+            RuntimeOrigin::signed([7; 32].into()),
+            0,
+            40,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -3175,7 +7228,7 @@ fn approve_developer_lawyer_works() {
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([7; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
@@ -3187,114 +7240,137 @@ fn approve_developer_lawyer_works() {
         assert_ok!(Marketplace::approve_developer_lawyer(
             RuntimeOrigin::signed([0; 32].into()),
             0,
-            false
+            true
         ));
-        assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([0; 32].into()),
-            3,
-            bvec![10, 10],
-            1_000,
-            100,
-            bvec![22, 22],
-            false
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            Some([10; 32].into())
+        );
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
-            1,
-            40,
-            1984
+            0,
+            crate::Vote::Yes,
+            30
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([2; 32].into()),
-            1,
-            30,
-            1984
+            0,
+            crate::Vote::Yes,
+            30
         ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            1,
-            30,
-            1984
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
         ));
-        assert_ok!(Marketplace::claim_property_token(
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
             RuntimeOrigin::signed([1; 32].into()),
-            1,
+            0,
         ));
-        assert_ok!(Marketplace::claim_property_token(
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
             RuntimeOrigin::signed([2; 32].into()),
-            1,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            1,
+            0,
         ));
         assert_eq!(
-            RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
-                .unwrap()
-                .active_cases,
-            0
+            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
+            Some([11; 32].into())
         );
-        assert_eq!(ProposedLawyers::<Test>::get(0).is_none(), true);
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            false,
+        ));
         assert_eq!(
             PropertyLawyer::<Test>::get(0)
                 .unwrap()
-                .real_estate_developer_lawyer,
-            None
+                .real_estate_developer_status,
+            crate::DocumentStatus::Rejected
         );
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
+        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_188_000);
+        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 838_000);
+        assert_eq!(ForeignAssets::balance(1337, &[7; 32].into()), 84_000);
+        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_500_000);
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
             0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            3_000,
+            false,
         ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
+        assert_eq!(RefundToken::<Test>::get(0).unwrap().refund_amount, 100);
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 30);
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
+        );
+        assert_ok!(Marketplace::withdraw_rejected(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
         ));
+        assert_eq!(RefundToken::<Test>::get(0).unwrap().refund_amount, 70);
+        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_000_000);
         assert_eq!(
-            RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
-                .unwrap()
-                .active_cases,
-            1
+            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
+            0
         );
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            1,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            300,
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_497_000);
+        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 0);
+        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 0);
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
+        );
+        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            315000
+        );
+        assert_ok!(Marketplace::withdraw_rejected(
+            RuntimeOrigin::signed([2; 32].into()),
+            0
         ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            1,
-            true
+        assert_ok!(Marketplace::withdraw_rejected(
+            RuntimeOrigin::signed([7; 32].into()),
+            0
         ));
+        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
         assert_eq!(
-            RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
-                .unwrap()
-                .active_cases,
-            2
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            0
         );
-        assert_eq!(ProposedLawyers::<Test>::get(0).is_none(), true);
         assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            Some([10; 32].into())
+            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
+            2000
         );
         assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer_costs
-                .get(&1984)
-                .unwrap(),
-            &3_000u128
+            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
+            4000
         );
+        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 4000);
+        assert_eq!(RefundToken::<Test>::get(0).is_none(), true);
+        assert!(PropertyLawyer::<Test>::get(0).is_none());
     })
 }
 
+// withdraw_legal_process_expired function
 #[test]
-fn approve_developer_lawyer_works_2() {
+fn withdraw_legal_process_expired_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -3332,87 +7408,177 @@ fn approve_developer_lawyer_works_2() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
             pallet_xcavate_whitelist::Role::Lawyer
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
         assert_ok!(Regions::register_lawyer(
             RuntimeOrigin::signed([10; 32].into()),
             3,
         ));
-        assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([0; 32].into()),
-            3,
-            bvec![10, 10],
-            10_000,
-            100,
-            bvec![22, 22],
-            false
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(RuntimeOrigin::signed([0; 32].into()), 3, bvec![10, 10], 10_000, 100, bvec![22, 22], crate::SaleTerms { tax_paid_by_developer: false, sale_mode: crate::SaleMode::FixedPrice, soft_cap: None }));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1337,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            10,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            10,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            20
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
         ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            30,
-            1984
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            30,
-            1984
         ));
-        assert_ok!(Marketplace::claim_property_token(
+        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().legal_process_expiry, 161);
+        run_to_block(162);
+        assert_eq!(PropertyLawyer::<Test>::get(0), None);
+        assert_eq!(RefundLegalExpired::<Test>::get(0), Some(100));
+        assert_noop!(
+            Marketplace::lawyer_confirm_documents(
+                RuntimeOrigin::signed([10; 32].into()),
+                0,
+                false,
+            ),
+            Error::<Test>::InvalidIndex
+        );
+        assert_ok!(Marketplace::withdraw_legal_process_expired(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::claim_property_token(
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
+        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_500_000);
+        assert_ok!(Marketplace::withdraw_legal_process_expired(
             RuntimeOrigin::signed([2; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::claim_property_token(
+        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_150_000);
+        assert_ok!(Marketplace::withdraw_legal_process_expired(
             RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
-        ));
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
+        assert_ok!(Marketplace::withdraw_legal_process_expired(
+            RuntimeOrigin::signed([31; 32].into()),
             0,
-            true
         ));
-        assert_eq!(ProposedLawyers::<Test>::get(0).is_none(), true);
         assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            None
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            0
         );
         assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer_costs
-                .get(&1984)
-                .unwrap(),
-            &0u128
+            ForeignAssets::balance(1337, &Marketplace::property_account_id(0)),
+            0
         );
+        assert_eq!(Nfts::owner(0, 0), None);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0), None);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_eq!(PropertyLawyer::<Test>::get(0), None);
+        assert_eq!(RefundLegalExpired::<Test>::get(0), None);
+        assert_eq!(PropertyOwner::<Test>::get(0).len(), 0);
+        assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).unwrap().active_cases, 0);
+        assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([11; 32].into()).unwrap().active_cases, 0);
     })
 }
 
 #[test]
-fn approve_developer_lawyer_fails() {
+fn withdraw_legal_process_expired_works_2() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -3442,37 +7608,14 @@ fn approve_developer_lawyer_fails() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
+            [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateDeveloper
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_noop!(
-            Marketplace::approve_developer_lawyer(RuntimeOrigin::signed([0; 32].into()), 0, true),
-            Error::<Test>::ListingNotFound
-        );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -3480,62 +7623,93 @@ fn approve_developer_lawyer_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_noop!(
-            Marketplace::approve_developer_lawyer(RuntimeOrigin::signed([0; 32].into()), 0, true),
-            Error::<Test>::InvalidIndex
-        );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            30,
-            1984
+            20,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            30,
-            1984
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
+            RuntimeOrigin::signed([2; 32].into()),
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
             0,
         ));
-        assert_noop!(
-            Marketplace::approve_developer_lawyer(RuntimeOrigin::signed([0; 32].into()), 0, true),
-            Error::<Test>::NoLawyerProposed
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0).unwrap().legal_process_expiry,
+            161
         );
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
+        run_to_block(162);
+        assert_ok!(Marketplace::withdraw_legal_process_expired(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
         ));
-        assert_noop!(
-            Marketplace::approve_developer_lawyer(RuntimeOrigin::signed([10; 32].into()), 0, false),
-            Error::<Test>::NoPermission
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
+        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_500_000);
+        assert_ok!(Marketplace::withdraw_legal_process_expired(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_150_000);
+        assert_ok!(Marketplace::withdraw_legal_process_expired(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            0
+        );
+        assert_eq!(
+            ForeignAssets::balance(1337, &Marketplace::property_account_id(0)),
+            0
         );
+        assert_eq!(Nfts::owner(0, 0), None);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0), None);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_eq!(PropertyLawyer::<Test>::get(0), None);
+        assert_eq!(RefundLegalExpired::<Test>::get(0), None);
+        assert_eq!(PropertyOwner::<Test>::get(0).len(), 0);
     })
 }
 
-// vote_on_spv_lawyer function
 #[test]
-fn vote_on_spv_lawyer_works() {
+fn withdraw_legal_process_expired_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -3573,11 +7747,6 @@ fn vote_on_spv_lawyer_works() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -3592,6 +7761,14 @@ fn vote_on_spv_lawyer_works() {
             RuntimeOrigin::signed([10; 32].into()),
             3,
         ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_noop!(
+            Marketplace::withdraw_legal_process_expired(RuntimeOrigin::signed([1; 32].into()), 0),
+            Error::<Test>::ListingNotFound
+        );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -3599,44 +7776,85 @@ fn vote_on_spv_lawyer_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
+            20,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            25,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            40,
-            1984
+            20,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            25,
+            1337,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            20,
-            1984
+            10,
+            1984,
+            None
         ));
+        assert_noop!(
+            Marketplace::withdraw_legal_process_expired(RuntimeOrigin::signed([1; 32].into()), 0),
+            Error::<Test>::NoTokensOwned
+        );
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([2; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
             0,
+            true
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
         ));
         assert_ok!(Marketplace::create_spv(
             RuntimeOrigin::signed([5; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
+            RuntimeOrigin::signed([11; 32].into()),
             0,
             crate::LegalProperty::SpvSide,
             4_000,
@@ -3644,90 +7862,38 @@ fn vote_on_spv_lawyer_works() {
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::Vote::No,
+            crate::Vote::Yes,
             40
         ));
-        assert_eq!(
-            AssetsFreezer::balance_frozen(
-                0,
-                &MarketplaceFreezeReason::SpvLawyerVoting,
-                &[1; 32].into()
-            ),
-            40
-        );
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             crate::Vote::Yes,
             40
         ));
-        assert_eq!(
-            AssetsFreezer::balance_frozen(
-                0,
-                &MarketplaceFreezeReason::SpvLawyerVoting,
-                &[2; 32].into()
-            ),
-            40
-        );
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            crate::Vote::No,
-            20
-        ));
-        assert_eq!(
-            AssetsFreezer::balance_frozen(
-                0,
-                &MarketplaceFreezeReason::SpvLawyerVoting,
-                &[30; 32].into()
-            ),
-            20
-        );
-        assert_eq!(
-            OngoingLawyerVoting::<Test>::get(0).unwrap(),
-            VoteStats {
-                yes_voting_power: 40,
-                no_voting_power: 60,
-            }
-        );
-        assert_eq!(
-            UserLawyerVote::<Test>::get::<u64, AccountId>(0, [1; 32].into())
-                .unwrap()
-                .vote,
-            crate::Vote::No
-        );
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::Vote::Yes,
-            20
         ));
         assert_eq!(
-            AssetsFreezer::balance_frozen(
-                0,
-                &MarketplaceFreezeReason::SpvLawyerVoting,
-                &[1; 32].into()
-            ),
-            20
+            PropertyLawyer::<Test>::get(0).unwrap().legal_process_expiry,
+            161
         );
-        assert_eq!(
-            OngoingLawyerVoting::<Test>::get(0).unwrap(),
-            VoteStats {
-                yes_voting_power: 60,
-                no_voting_power: 20,
-            }
+        assert_noop!(
+            Marketplace::withdraw_legal_process_expired(RuntimeOrigin::signed([1; 32].into()), 0),
+            Error::<Test>::LegalProcessOngoing
         );
-        assert_eq!(
-            UserLawyerVote::<Test>::get::<u64, AccountId>(0, [1; 32].into())
-                .unwrap()
-                .vote,
-            crate::Vote::Yes
+        run_to_block(162);
+        assert_noop!(
+            Marketplace::withdraw_legal_process_expired(RuntimeOrigin::signed([3; 32].into()), 0,),
+            BadOrigin
         );
     })
 }
 
 #[test]
-fn vote_on_spv_lawyer_fails() {
+fn second_attempt_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -3750,11 +7916,6 @@ fn vote_on_spv_lawyer_fails() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
@@ -3784,15 +7945,10 @@ fn vote_on_spv_lawyer_fails() {
             RuntimeOrigin::signed([10; 32].into()),
             3,
         ));
-        assert_noop!(
-            Marketplace::vote_on_spv_lawyer(
-                RuntimeOrigin::signed([1; 32].into()),
-                0,
-                crate::Vote::No,
-                20,
-            ),
-            Error::<Test>::NoLawyerProposed
-        );
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -3800,46 +7956,61 @@ fn vote_on_spv_lawyer_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            40,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            20,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([2; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
             0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
         ));
-        assert_noop!(
-            Marketplace::vote_on_spv_lawyer(
-                RuntimeOrigin::signed([1; 32].into()),
-                0,
-                crate::Vote::No,
-                40
-            ),
-            Error::<Test>::NoLawyerProposed
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_lawyer,
+            Some([10; 32].into())
         );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
@@ -3851,81 +8022,127 @@ fn vote_on_spv_lawyer_fails() {
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
+            RuntimeOrigin::signed([11; 32].into()),
             0,
             crate::LegalProperty::SpvSide,
             4_000,
         ));
-        assert_noop!(
-            Marketplace::vote_on_spv_lawyer(
-                RuntimeOrigin::signed([0; 32].into()),
-                0,
-                crate::Vote::No,
-                100
-            ),
-            Error::<Test>::NotEnoughToken
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
+            Some([11; 32].into())
         );
-        assert_noop!(
-            Marketplace::vote_on_spv_lawyer(
-                RuntimeOrigin::signed([0; 32].into()),
-                0,
-                crate::Vote::No,
-                0
-            ),
-            Error::<Test>::ZeroVoteAmount
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_eq!(
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_status,
+            crate::DocumentStatus::Approved
         );
+        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            false,
+        ));
+        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().second_attempt, true);
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            false,
+        ));
         assert_eq!(
-            AssetsFreezer::balance_frozen(
-                0,
-                &MarketplaceFreezeReason::SpvLawyerVoting,
-                &[0; 32].into()
-            ),
+            PropertyLawyer::<Test>::get(0)
+                .unwrap()
+                .real_estate_developer_status,
+            crate::DocumentStatus::Rejected
+        );
+        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::withdraw_rejected(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::withdraw_rejected(
+            RuntimeOrigin::signed([2; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::withdraw_rejected(
+            RuntimeOrigin::signed([30; 32].into()),
             0
+        ));
+        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_000_000);
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
+            6000
         );
-        run_to_block(100);
-        assert_noop!(
-            Marketplace::vote_on_spv_lawyer(
-                RuntimeOrigin::signed([0; 32].into()),
-                0,
-                crate::Vote::No,
-                12
-            ),
-            Error::<Test>::VotingExpired
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_496_000);
+        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_147_000);
+        assert_eq!(ForeignAssets::balance(1984, &[30; 32].into()), 1_197_000);
+        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 4_000);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).is_none(), true);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
         );
+        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
     })
 }
 
-// vote_on_spv_lawyer function
 #[test]
-fn finalize_spv_lawyer_works() {
+fn lawyer_confirm_documents_fails() {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
+		System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
+		assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [8; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        new_region_helper();
-        assert_ok!(Regions::create_new_location(
-            RuntimeOrigin::signed([8; 32].into()),
-            3,
-            bvec![10, 10]
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
+		new_region_helper();
+		assert_ok!(Regions::create_new_location(RuntimeOrigin::signed([8; 32].into()), 3, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
+		assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
@@ -3950,49 +8167,33 @@ fn finalize_spv_lawyer_works() {
             [11; 32].into(),
             pallet_xcavate_whitelist::Role::Lawyer
         ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([0; 32].into()),
-            3,
-            bvec![10, 10],
-            10_000,
-            100,
-            bvec![22, 22],
-            false
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            40,
-            1337
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            20,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [12; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
         ));
+		assert_ok!(Regions::register_lawyer(RuntimeOrigin::signed([10; 32].into()), 3));
+		assert_ok!(Regions::register_lawyer(RuntimeOrigin::signed([11; 32].into()), 3));
+		assert_ok!(Regions::register_lawyer(RuntimeOrigin::signed([12; 32].into()), 3));
+		assert_ok!(Marketplace::list_property(RuntimeOrigin::signed([0; 32].into()), 3, bvec![10, 10], 10_000, 100, bvec![22, 22], crate::SaleTerms { tax_paid_by_developer: false, sale_mode: crate::SaleMode::FixedPrice, soft_cap: None }));
+		assert_ok!(Marketplace::buy_property_token(RuntimeOrigin::signed([1; 32].into()), 0, 40, 1984, None));
+        assert_ok!(Marketplace::buy_property_token(RuntimeOrigin::signed([2; 32].into()), 0, 30, 1984, None));
+        assert_ok!(Marketplace::buy_property_token(RuntimeOrigin::signed([30; 32].into()), 0, 30, 1984, None));
+        assert_ok!(Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0));
+        assert_ok!(Marketplace::claim_property_token(RuntimeOrigin::signed([2; 32].into()), 0));
+        assert_ok!(Marketplace::claim_property_token(RuntimeOrigin::signed([30; 32].into()), 0));
+		assert_ok!(Marketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+            true
+		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_lawyer, Some([10; 32].into()));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -4002,121 +8203,62 @@ fn finalize_spv_lawyer_works() {
             RuntimeOrigin::signed([5; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
-        ));
-        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 0);
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            crate::Vote::No,
-            40
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            crate::Vote::No,
-            20
-        ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
-        ));
-        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 1);
-        run_to_block(121);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_eq!(OngoingLawyerVoting::<Test>::get(0).is_none(), true);
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-        ));
-        assert_eq!(
-            UserLawyerVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_none(),
-            true
-        );
-        assert_eq!(SpvLawyerProposal::<Test>::get(0).is_none(), true);
-        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, None);
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            3_000,
-        ));
-        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 2);
+		assert_ok!(Marketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
             crate::Vote::Yes,
             40
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            crate::Vote::No,
-            40
-        ));
+		));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
+			RuntimeOrigin::signed([2; 32].into()),
+			0,
             crate::Vote::Yes,
-            20
-        ));
-        run_to_block(151);
+            30
+		));
+        run_to_block(91);
         assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_eq!(OngoingLawyerVoting::<Test>::get(0).is_none(), true);
-        assert_eq!(
-            UserLawyerVote::<Test>::get::<u64, AccountId>(2, [1; 32].into()).is_none(),
-            false
-        );
-        assert_eq!(SpvLawyerProposal::<Test>::get(0).is_none(), true);
-        assert_eq!(ListingSpvProposal::<Test>::get(0).is_none(), true);
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
-            Some([10; 32].into())
-        );
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .spv_lawyer_costs
-                .get(&1984)
-                .unwrap(),
-            &3_000u128
-        );
-    })
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, Some([11; 32].into()));
+		assert_noop!(Marketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			1,
+			false,
+		), Error::<Test>::InvalidIndex);
+		assert_noop!(Marketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([12; 32].into()),
+			0,
+			false,
+		), Error::<Test>::NoPermission);
+		assert_ok!(Marketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			false,
+		));
+		assert_noop!(Marketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+		), Error::<Test>::AlreadyConfirmed);
+        run_to_block(200);
+        assert_noop!(Marketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+		), Error::<Test>::InvalidIndex);
+	})
 }
 
+// list_token function
 #[test]
-fn finalize_spv_lawyer_works2() {
+fn relist_a_nft() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -4139,11 +8281,6 @@ fn finalize_spv_lawyer_works2() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
@@ -4173,32 +8310,43 @@ fn finalize_spv_lawyer_works2() {
             RuntimeOrigin::signed([10; 32].into()),
             3,
         ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
             bvec![10, 10],
             10_000,
-            150,
+            100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            60,
-            1984
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            70,
-            1337
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            20,
-            1337
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4212,6 +8360,17 @@ fn finalize_spv_lawyer_works2() {
             RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -4222,123 +8381,104 @@ fn finalize_spv_lawyer_works2() {
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
+            RuntimeOrigin::signed([11; 32].into()),
             0,
             crate::LegalProperty::SpvSide,
             4_000,
         ));
-        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 0);
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::Vote::No,
-            10
+            crate::Vote::Yes,
+            40
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             crate::Vote::Yes,
-            60
+            30
         ));
         run_to_block(91);
         assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-        ));
-        assert_eq!(OngoingLawyerVoting::<Test>::get(0).is_none(), true);
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_eq!(
-            UserLawyerVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_none(),
-            true
-        );
-        assert_eq!(SpvLawyerProposal::<Test>::get(0).is_none(), true);
-        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, None);
-        assert_ok!(Marketplace::lawyer_claim_property(
+        assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([10; 32].into()),
             0,
-            crate::LegalProperty::SpvSide,
-            3_000,
+            true,
         ));
-        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 1);
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
             0,
-            crate::Vote::Yes,
-            60
+            true,
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::Vote::No,
-            15
+            1000,
+            1
         ));
-        run_to_block(121);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
+        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
+        assert_eq!(TokenListings::<Test>::get(1).unwrap().item_id, 0);
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 39);
+        assert_eq!(
+            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
+            1
+        );
+    })
+}
+
+#[test]
+fn relist_property_token_not_created_with_marketplace_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            1,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            1,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_eq!(OngoingLawyerVoting::<Test>::get(0).is_none(), true);
-        assert_eq!(
-            UserLawyerVote::<Test>::get::<u64, AccountId>(1, [1; 32].into()).is_none(),
-            true
-        );
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            3_000,
+        assert_ok!(Nfts::create(
+            RuntimeOrigin::signed([0; 32].into()),
+            sp_runtime::MultiAddress::Id([0; 32].into()),
+            Default::default()
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(Nfts::mint(
+            RuntimeOrigin::signed([0; 32].into()),
             0,
-            crate::Vote::Yes,
-            60
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
             0,
-            crate::Vote::No,
-            55
+            sp_runtime::MultiAddress::Id([0; 32].into()),
+            None
         ));
-        run_to_block(151);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_eq!(SpvLawyerProposal::<Test>::get(0).is_none(), true);
-        assert_eq!(ListingSpvProposal::<Test>::get(0).is_none(), true);
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
-            Some([10; 32].into())
-        );
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .spv_lawyer_costs
-                .get(&1984)
-                .unwrap(),
-            &3_000u128
+        new_region_helper();
+        assert_noop!(
+            Marketplace::relist_token(RuntimeOrigin::signed([0; 32].into()), 0, 1000, 1),
+            RealEstateAssetError::<Test>::PropertyNotFound
         );
     })
 }
 
 #[test]
-fn finalize_spv_lawyer_works3() {
+fn relist_a_nft_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -4386,10 +8526,19 @@ fn finalize_spv_lawyer_works3() {
             [10; 32].into(),
             pallet_xcavate_whitelist::Role::Lawyer
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
         assert_ok!(Regions::register_lawyer(
             RuntimeOrigin::signed([10; 32].into()),
             3,
         ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -4397,25 +8546,32 @@ fn finalize_spv_lawyer_works3() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            40,
-            1337
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            20,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4429,6 +8585,21 @@ fn finalize_spv_lawyer_works3() {
             RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
+        assert_noop!(
+            Marketplace::relist_token(RuntimeOrigin::signed([1; 32].into()), 0, 1000, 10),
+            RealEstateAssetError::<Test>::PropertyNotFinalized
+        );
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -4439,12 +8610,11 @@ fn finalize_spv_lawyer_works3() {
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
+            RuntimeOrigin::signed([11; 32].into()),
             0,
             crate::LegalProperty::SpvSide,
             4_000,
         ));
-        assert_eq!(ListingSpvProposal::<Test>::get(0).unwrap(), 0);
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -4455,28 +8625,42 @@ fn finalize_spv_lawyer_works3() {
             RuntimeOrigin::signed([2; 32].into()),
             0,
             crate::Vote::Yes,
-            40
+            30
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::Vote::No,
-            20
         ));
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
             0,
+            true,
         ));
-        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, None);
-        assert_eq!(SpvLawyerProposal::<Test>::get(0).is_none(), true);
-        assert_eq!(ListingSpvProposal::<Test>::get(0).is_none(), true);
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+        assert_noop!(
+            Marketplace::relist_token(RuntimeOrigin::signed([0; 32].into()), 0, 1000, 1),
+            TokenError::FundsUnavailable
+        );
+        assert_noop!(
+            Marketplace::relist_token(RuntimeOrigin::signed([1; 32].into()), 0, 1000, 0),
+            Error::<Test>::AmountCannotBeZero
+        );
+        assert_noop!(
+            Marketplace::relist_token(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1),
+            Error::<Test>::InvalidTokenPrice
+        );
     })
 }
 
+// buy_relisted_token function
 #[test]
-fn finalize_spv_lawyer_fails() {
+fn buy_relisted_token_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -4501,17 +8685,17 @@ fn finalize_spv_lawyer_fails() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
+            [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
+            [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
+            [3; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
@@ -4519,6 +8703,11 @@ fn finalize_spv_lawyer_fails() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -4533,10 +8722,10 @@ fn finalize_spv_lawyer_fails() {
             RuntimeOrigin::signed([10; 32].into()),
             3,
         ));
-        assert_noop!(
-            Marketplace::finalize_spv_lawyer(RuntimeOrigin::signed([0; 32].into()), 0,),
-            Error::<Test>::NoLawyerProposed
-        );
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -4544,25 +8733,39 @@ fn finalize_spv_lawyer_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            45,
-            1984
+            3,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            40,
-            1337
+            47,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            15,
-            1984
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            20,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4576,10 +8779,21 @@ fn finalize_spv_lawyer_fails() {
             RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
-        assert_noop!(
-            Marketplace::finalize_spv_lawyer(RuntimeOrigin::signed([0; 32].into()), 0,),
-            Error::<Test>::NoLawyerProposed
-        );
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -4590,7 +8804,7 @@ fn finalize_spv_lawyer_fails() {
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
+            RuntimeOrigin::signed([11; 32].into()),
             0,
             crate::LegalProperty::SpvSide,
             4_000,
@@ -4598,30 +8812,105 @@ fn finalize_spv_lawyer_fails() {
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::Vote::No,
-            45
+            crate::Vote::Yes,
+            3
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             crate::Vote::Yes,
-            40
+            44
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            4
         ));
-        assert_noop!(
-            Marketplace::finalize_spv_lawyer(RuntimeOrigin::signed([0; 32].into()), 0,),
-            Error::<Test>::VotingStillOngoing
-        );
         run_to_block(91);
-        assert_noop!(
-            Marketplace::finalize_spv_lawyer(RuntimeOrigin::signed([10; 32].into()), 0,),
-            BadOrigin
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_eq!(ForeignAssets::balance(1984, &([0; 32].into())), 20990000);
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
+            8000
+        );
+        assert_eq!(ForeignAssets::balance(1984, &([8; 32].into())), 8000);
+        assert_eq!(ForeignAssets::balance(1984, &([1; 32].into())), 1_468_800);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            1000,
+            3
+        ));
+        assert_ok!(Marketplace::buy_relisted_token(
+            RuntimeOrigin::signed([3; 32].into()),
+            1,
+            2,
+            1984,
+            None
+        ));
+        assert_eq!(ForeignAssets::balance(1984, &([3; 32].into())), 3_000);
+        assert_eq!(LocalAssets::balance(0, &[3; 32].into()), 2);
+        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
+        assert_ok!(Marketplace::buy_relisted_token(
+            RuntimeOrigin::signed([3; 32].into()),
+            1,
+            1,
+            1984,
+            None
+        ));
+        assert_eq!(ForeignAssets::balance(1984, &([3; 32].into())), 2_000);
+        assert_eq!(TokenListings::<Test>::get(1).is_some(), false);
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            500,
+            1
+        ));
+        assert_ok!(Marketplace::buy_relisted_token(
+            RuntimeOrigin::signed([3; 32].into()),
+            2,
+            1,
+            1984,
+            None
+        ));
+        assert_eq!(TokenListings::<Test>::get(0).is_some(), false);
+        assert_eq!(PropertyOwner::<Test>::get(0).len(), 5);
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            2
         );
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [3; 32].into()),
+            4
+        );
+        assert_eq!(ForeignAssets::balance(1984, &([1; 32].into())), 1_469_295);
+        assert_eq!(ForeignAssets::balance(1984, &([3; 32].into())), 1_500);
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 2);
+        assert_eq!(LocalAssets::balance(0, &[3; 32].into()), 4);
     })
 }
 
-// remove_lawyer_claim function
 #[test]
-fn remove_lawyer_claim_works() {
+fn buy_relisted_token_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -4651,27 +8940,27 @@ fn remove_lawyer_claim_works() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
+            [3; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
+            [10; 32].into(),
             pallet_xcavate_whitelist::Role::Lawyer
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [12; 32].into(),
+            [11; 32].into(),
             pallet_xcavate_whitelist::Role::Lawyer
         ));
         assert_ok!(Regions::register_lawyer(
@@ -4682,10 +8971,6 @@ fn remove_lawyer_claim_works() {
             RuntimeOrigin::signed([11; 32].into()),
             3,
         ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([12; 32].into()),
-            3,
-        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -4693,36 +8978,43 @@ fn remove_lawyer_claim_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
@@ -4736,12 +9028,6 @@ fn remove_lawyer_claim_works() {
             0,
             true
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            Some([10; 32].into())
-        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -4764,7 +9050,7 @@ fn remove_lawyer_claim_works() {
             40
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
             crate::Vote::Yes,
             30
@@ -4774,48 +9060,112 @@ fn remove_lawyer_claim_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
-            Some([11; 32].into())
-        );
-        assert_ok!(Marketplace::remove_lawyer_claim(
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([10; 32].into()),
             0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
         ));
+        assert_eq!(ForeignAssets::balance(1984, &([0; 32].into())), 20990000);
         assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            None
+            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
+            8_000
         );
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
-            Some([11; 32].into())
+        assert_eq!(ForeignAssets::balance(1984, &([8; 32].into())), 8_000);
+        assert_eq!(ForeignAssets::balance(1984, &([1; 32].into())), 1_084_000);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+        assert_noop!(
+            Marketplace::buy_relisted_token(
+                RuntimeOrigin::signed([3; 32].into()),
+                1,
+                1,
+                1984,
+                None
+            ),
+            Error::<Test>::TokenNotForSale
         );
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([12; 32].into()),
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
+            500,
+            1
         ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
+        assert_noop!(
+            Marketplace::buy_relisted_token(
+                RuntimeOrigin::signed([3; 32].into()),
+                1,
+                1,
+                1983,
+                None
+            ),
+            Error::<Test>::PaymentAssetNotSupported
+        );
+        assert_ok!(XcavateWhitelist::set_permission(
+            RuntimeOrigin::signed([20; 32].into()),
+            [3; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor,
+            pallet_xcavate_whitelist::AccessPermission::Revoked,
+        ));
+        assert_noop!(
+            Marketplace::buy_relisted_token(
+                RuntimeOrigin::signed([3; 32].into()),
+                1,
+                1,
+                1984,
+                None
+            ),
+            BadOrigin
+        );
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            true
+            1_000,
+            20
         ));
+        assert_eq!(TokenListings::<Test>::get(2).unwrap().amount, 20);
         assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            Some([12; 32].into())
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            40
+        );
+        assert_noop!(
+            Marketplace::buy_relisted_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                2,
+                1,
+                1984,
+                Some(999)
+            ),
+            Error::<Test>::SlippageExceeded
+        );
+        assert_noop!(
+            Marketplace::buy_relisted_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                2,
+                11,
+                1984,
+                None
+            ),
+            Error::<Test>::ExceedsMaxOwnership
         );
     })
 }
 
+// make_offer function
 #[test]
-fn remove_lawyer_claim_fails() {
+fn make_offer_works() {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -4841,6 +9191,11 @@ fn remove_lawyer_claim_fails() {
             [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [30; 32].into(),
@@ -4861,6 +9216,14 @@ fn remove_lawyer_claim_fails() {
             [11; 32].into(),
             pallet_xcavate_whitelist::Role::Lawyer
         ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -4868,25 +9231,32 @@ fn remove_lawyer_claim_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4900,18 +9270,6 @@ fn remove_lawyer_claim_fails() {
             RuntimeOrigin::signed([31; 32].into()),
             0,
         ));
-        assert_noop!(
-            Marketplace::remove_lawyer_claim(RuntimeOrigin::signed([10; 32].into()), 0,),
-            Error::<Test>::NoPermission
-        );
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_noop!(
-            Marketplace::remove_lawyer_claim(RuntimeOrigin::signed([10; 32].into()), 1,),
-            Error::<Test>::InvalidIndex
-        );
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
             0,
@@ -4923,23 +9281,89 @@ fn remove_lawyer_claim_fails() {
             0,
             true
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([10; 32].into()),
             0,
             true,
         ));
-        assert_noop!(
-            Marketplace::remove_lawyer_claim(RuntimeOrigin::signed([10; 32].into()), 0,),
-            Error::<Test>::AlreadyConfirmed
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            500,
+            1
+        ));
+        assert_ok!(Marketplace::make_offer(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+            2000,
+            1,
+            1984
+        ));
+        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
+        assert_eq!(
+            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(),
+            true
+        );
+        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_148_000);
+        assert_eq!(
+            ForeignAssets::total_balance(1984, &[2; 32].into()),
+            1_150_000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            0
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
+            2000
         );
     })
 }
 
-// lawyer_confirm_documents function
 #[test]
-fn finalize_property_deal() {
+fn make_offer_fails() {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -4970,11 +9394,21 @@ fn finalize_property_deal() {
             [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [3; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -5000,52 +9434,43 @@ fn finalize_property_deal() {
             10_000,
             100,
             bvec![22, 22],
-            false
-        ));
-        assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            200_000
-        );
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            25,
-            1984
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            20,
-            1337
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            20,
-            1984
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            20,
-            1337
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([31; 32].into()),
             0,
-            15,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([31; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
@@ -5059,12 +9484,6 @@ fn finalize_property_deal() {
             0,
             true
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            Some([10; 32].into())
-        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -5084,92 +9503,112 @@ fn finalize_property_deal() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             crate::Vote::Yes,
-            45
+            40
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             crate::Vote::Yes,
-            15
+            30
         ));
         run_to_block(91);
         assert_ok!(Marketplace::finalize_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
-            Some([11; 32].into())
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_noop!(
+            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1, 1984),
+            Error::<Test>::TokenNotForSale
+        );
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            500,
+            1
+        ));
+        assert_noop!(
+            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 2, 1984),
+            Error::<Test>::NotEnoughTokenAvailable
+        );
+        assert_noop!(
+            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1, 100),
+            Error::<Test>::PaymentAssetNotSupported
+        );
+        assert_noop!(
+            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 0, 1984),
+            Error::<Test>::AmountCannotBeZero
+        );
+        assert_noop!(
+            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 0, 1, 1984),
+            Error::<Test>::InvalidTokenPrice
+        );
+        assert_ok!(XcavateWhitelist::set_permission(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor,
+            pallet_xcavate_whitelist::AccessPermission::Revoked,
+        ));
+        assert_noop!(
+            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1, 1984),
+            BadOrigin
+        );
+        assert_ok!(XcavateWhitelist::set_permission(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor,
+            pallet_xcavate_whitelist::AccessPermission::Compliant,
+        ));
+        assert_ok!(Marketplace::make_offer(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+            200,
+            1,
+            1984
+        ));
+        assert_ok!(Marketplace::make_offer(
+            RuntimeOrigin::signed([3; 32].into()),
+            1,
+            300,
+            1,
+            1984
+        ));
+        assert_noop!(
+            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 400, 1, 1984),
+            Error::<Test>::OnlyOneOfferPerUser
         );
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
-        ));
         assert_eq!(
-            PropertyLawyer::<Test>::get(0)
+            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into())
                 .unwrap()
-                .real_estate_developer_status,
-            crate::DocumentStatus::Approved
-        );
-        assert_eq!(
-            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
-            0
+                .token_price,
+            200
         );
-        assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().asset_id, 0);
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
-        ));
         assert_eq!(
-            RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
+            OngoingOffers::<Test>::get::<u32, AccountId>(1, [3; 32].into())
                 .unwrap()
-                .active_cases,
-            0
-        );
-        assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            0
-        );
-        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
-        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_594_000);
-        assert_eq!(ForeignAssets::balance(1337, &[0; 32].into()), 20_396_000);
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
-            4_000
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[8; 32].into()), 4_000);
-        assert_eq!(
-            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
-            4_000
-        );
-        assert_eq!(ForeignAssets::balance(1337, &[8; 32].into()), 4_000);
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_240_000);
-        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 942_000);
-        assert_eq!(ForeignAssets::balance(1984, &[30; 32].into()), 1_044_000);
-        assert_eq!(ForeignAssets::balance(1984, &[10; 32].into()), 0);
-        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 22_000);
-        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_292_000);
-        assert_eq!(ForeignAssets::balance(1337, &[2; 32].into()), 942_000);
-        assert_eq!(ForeignAssets::balance(1337, &[10; 32].into()), 0);
-        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 12_000);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
-        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
+                .token_price,
+            300
         );
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 45);
-        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 40);
-        assert_eq!(LocalAssets::balance(0, &[30; 32].into()), 15);
     })
 }
 
+// handle_offer function
 #[test]
-fn finalize_property_deal_2() {
+fn handle_offer_works() {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -5200,11 +9639,21 @@ fn finalize_property_deal_2() {
             [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [3; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -5230,36 +9679,43 @@ fn finalize_property_deal_2() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1337
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1337
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1337
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([31; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
@@ -5273,12 +9729,6 @@ fn finalize_property_deal_2() {
             0,
             true
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            Some([10; 32].into())
-        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -5301,7 +9751,7 @@ fn finalize_property_deal_2() {
             40
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
             crate::Vote::Yes,
             30
@@ -5311,95 +9761,123 @@ fn finalize_property_deal_2() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
-            Some([11; 32].into())
-        );
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([10; 32].into()),
             0,
             true,
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_status,
-            crate::DocumentStatus::Approved
-        );
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([11; 32].into()),
             0,
             true,
         ));
-        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
-        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_000_000);
-        assert_eq!(ForeignAssets::balance(1337, &[0; 32].into()), 20_990_000);
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            5000,
+            20
+        ));
+        assert_ok!(Marketplace::make_offer(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+            200,
+            1,
+            1984
+        ));
+        assert_ok!(Marketplace::make_offer(
+            RuntimeOrigin::signed([3; 32].into()),
+            1,
+            150,
+            1,
+            1337
+        ));
         assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
+            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
+            200
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1337, &[3; 32].into()),
+            150
+        );
+        assert_ok!(Marketplace::handle_offer(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+            [2; 32].into(),
+            crate::Offer::Reject
+        ));
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
             0
         );
+        assert_ok!(Marketplace::cancel_offer(
+            RuntimeOrigin::signed([3; 32].into()),
+            1
+        ));
+        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_150_000);
+        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
         assert_eq!(
-            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
-            8000
+            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(),
+            true
         );
-        assert_eq!(ForeignAssets::balance(1337, &[8; 32].into()), 8000);
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
-        assert_eq!(ForeignAssets::balance(1984, &[10; 32].into()), 0);
-        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 0);
-        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_084_000);
-        assert_eq!(ForeignAssets::balance(1337, &[2; 32].into()), 838_000);
-        assert_eq!(ForeignAssets::balance(1337, &[30; 32].into()), 888_000);
-        assert_eq!(ForeignAssets::balance(1337, &[10; 32].into()), 0);
-        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 34_000);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
-        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
-        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 30);
-        assert_eq!(LocalAssets::balance(0, &[30; 32].into()), 30);
+        assert_ok!(Marketplace::make_offer(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+            2000,
+            10,
+            1984
+        ));
+        assert_eq!(
+            ForeignAssets::total_balance(1984, &([2; 32].into())),
+            1_150_000
+        );
+        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_130_000);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
+            20000
+        );
+        assert_ok!(Marketplace::handle_offer(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+            [2; 32].into(),
+            crate::Offer::Accept
+        ));
+        assert_eq!(TokenListings::<Test>::get(1).unwrap().amount, 10);
+        assert_eq!(
+            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(),
+            true
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(1)),
+            0
+        );
+        assert_eq!(LocalAssets::balance(0, &([1; 32].into())), 20);
+        assert_eq!(LocalAssets::balance(0, &([2; 32].into())), 10);
+        assert_eq!(
+            ForeignAssets::balance(0, &Marketplace::property_account_id(0)),
+            0
+        );
+        assert_eq!(ForeignAssets::balance(1984, &([1; 32].into())), 1_103_800);
+        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_130_000);
     })
 }
 
 #[test]
-fn finalize_property_deal_3() {
+fn handle_offer_fails() {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
         ));
         assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [8; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [8; 32].into(),
-            pallet_xcavate_whitelist::Role::RegionalOperator
-        ));
-        assert_ok!(Regions::propose_new_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            RegionIdentifier::Japan
-        ));
-        assert_ok!(Regions::vote_on_region_proposal(
-            RuntimeOrigin::signed([8; 32].into()),
-            3,
-            pallet_regions::Vote::Yes,
-            10_000
-        ));
-        run_to_block(31);
-        assert_ok!(Regions::bid_on_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            3,
-            100_000
-        ));
-        run_to_block(61);
-        assert_ok!(Regions::create_new_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            3,
-            30,
-            Permill::from_parts(32_500)
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        new_region_helper();
         assert_ok!(Regions::create_new_location(
             RuntimeOrigin::signed([8; 32].into()),
             3,
@@ -5425,6 +9903,11 @@ fn finalize_property_deal_3() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -5450,52 +9933,43 @@ fn finalize_property_deal_3() {
             10_000,
             100,
             bvec![22, 22],
-            true
-        ));
-        assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            200_000
-        );
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            20,
-            1984
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            20,
-            1337
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            20,
-            1337
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            20,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([31; 32].into()),
             0,
-            20,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([31; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
@@ -5509,12 +9983,6 @@ fn finalize_property_deal_3() {
             0,
             true
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            Some([10; 32].into())
-        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -5537,96 +10005,111 @@ fn finalize_property_deal_3() {
             40
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
             crate::Vote::Yes,
-            40
+            30
         ));
         run_to_block(91);
         assert_ok!(Marketplace::finalize_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
-            Some([11; 32].into())
-        );
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([10; 32].into()),
             0,
             true,
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_status,
-            crate::DocumentStatus::Approved
-        );
-        assert_eq!(
-            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
-            0
-        );
-        assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().asset_id, 0);
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_tax
-                .get(&1984)
-                .copied(),
-            Some(19_500)
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_tax
-                .get(&1337)
-                .copied(),
-            Some(13_000)
-        );
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([11; 32].into()),
             0,
             true,
         ));
-        assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            0
+        assert_noop!(
+            Marketplace::handle_offer(
+                RuntimeOrigin::signed([1; 32].into()),
+                1,
+                [2; 32].into(),
+                crate::Offer::Reject
+            ),
+            Error::<Test>::TokenNotForSale
         );
-        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
-        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_574_500);
-        assert_eq!(ForeignAssets::balance(1337, &[0; 32].into()), 20_383_000);
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
-            4_000
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            5000,
+            2
+        ));
+        assert_noop!(
+            Marketplace::handle_offer(
+                RuntimeOrigin::signed([1; 32].into()),
+                1,
+                [2; 32].into(),
+                crate::Offer::Reject
+            ),
+            Error::<Test>::OfferNotFound
         );
-        assert_eq!(ForeignAssets::balance(1984, &[8; 32].into()), 4_000);
+        assert_ok!(Marketplace::make_offer(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+            200,
+            1,
+            1984
+        ));
+        assert_noop!(
+            Marketplace::handle_offer(
+                RuntimeOrigin::signed([2; 32].into()),
+                1,
+                [2; 32].into(),
+                crate::Offer::Accept
+            ),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            5000,
+            20
+        ));
+        assert_ok!(Marketplace::make_offer(
+            RuntimeOrigin::signed([1; 32].into()),
+            2,
+            200,
+            15,
+            1984
+        ));
         assert_eq!(
-            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
-            4_000
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            40
+        );
+        assert_noop!(
+            Marketplace::handle_offer(
+                RuntimeOrigin::signed([30; 32].into()),
+                2,
+                [1; 32].into(),
+                crate::Offer::Accept
+            ),
+            Error::<Test>::ExceedsMaxOwnership
         );
-        assert_eq!(ForeignAssets::balance(1337, &[8; 32].into()), 4_000);
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_298_000);
-        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 948_000);
-        assert_eq!(ForeignAssets::balance(1984, &[10; 32].into()), 19_500);
-        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 4_000);
-        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_298_000);
-        assert_eq!(ForeignAssets::balance(1337, &[2; 32].into()), 948_000);
-        assert_eq!(ForeignAssets::balance(1337, &[10; 32].into()), 13_000);
-        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 0);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
-        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
         assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            40
         );
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
     })
 }
 
+// cancel_offer function
 #[test]
-fn finalize_property_deal_4() {
+fn cancel_offer_works() {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -5636,34 +10119,7 @@ fn finalize_property_deal_4() {
             [8; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [8; 32].into(),
-            pallet_xcavate_whitelist::Role::RegionalOperator
-        ));
-        assert_ok!(Regions::propose_new_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            RegionIdentifier::Japan
-        ));
-        assert_ok!(Regions::vote_on_region_proposal(
-            RuntimeOrigin::signed([8; 32].into()),
-            3,
-            pallet_regions::Vote::Yes,
-            10_000
-        ));
-        run_to_block(31);
-        assert_ok!(Regions::bid_on_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            3,
-            100_000
-        ));
-        run_to_block(61);
-        assert_ok!(Regions::create_new_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            3,
-            30,
-            Permill::from_parts(32_500)
-        ));
+        new_region_helper();
         assert_ok!(Regions::create_new_location(
             RuntimeOrigin::signed([8; 32].into()),
             3,
@@ -5689,6 +10145,11 @@ fn finalize_property_deal_4() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -5714,40 +10175,43 @@ fn finalize_property_deal_4() {
             10_000,
             100,
             bvec![22, 22],
-            true
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            200_000
-        );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            40,
-            1337
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([31; 32].into()),
             0,
-            20,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([31; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
@@ -5761,12 +10225,6 @@ fn finalize_property_deal_4() {
             0,
             true
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            Some([10; 32].into())
-        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -5792,99 +10250,74 @@ fn finalize_property_deal_4() {
             RuntimeOrigin::signed([30; 32].into()),
             0,
             crate::Vote::Yes,
-            20
+            30
         ));
         run_to_block(91);
         assert_ok!(Marketplace::finalize_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
-            Some([11; 32].into())
-        );
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([10; 32].into()),
             0,
             true,
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_status,
-            crate::DocumentStatus::Approved
-        );
-        assert_eq!(
-            LocalAssets::balance(40, &Marketplace::property_account_id(0)),
-            0
-        );
-        assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().asset_id, 0);
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_tax
-                .get(&1984)
-                .copied(),
-            Some(19_500)
-        );
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_tax
-                .get(&1337)
-                .copied(),
-            Some(13_000)
-        );
-        assert_ok!(ForeignAssets::transfer(
-            RuntimeOrigin::signed([1; 32].into()),
-            codec::Compact(1337),
-            sp_runtime::MultiAddress::Id(Marketplace::property_account_id(0)),
-            404_000
-        ));
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([11; 32].into()),
             0,
             true,
         ));
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            500,
+            1
+        ));
+        assert_ok!(Marketplace::make_offer(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+            2000,
+            1,
+            1984
+        ));
+        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
         assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            0
+            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(),
+            true
         );
-        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
-        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_574_500);
-        assert_eq!(ForeignAssets::balance(1337, &[0; 32].into()), 20_383_000);
+        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_148_000);
         assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
-            4_000
+            ForeignAssets::total_balance(1984, &([2; 32].into())),
+            1_150_000
         );
-        assert_eq!(ForeignAssets::balance(1984, &[8; 32].into()), 4_000);
         assert_eq!(
-            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
-            4_000
+            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
+            2000
         );
-        assert_eq!(ForeignAssets::balance(1337, &[8; 32].into()), 4_000);
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_096_000);
-        assert_eq!(ForeignAssets::balance(1984, &[30; 32].into()), 998_000);
-        assert_eq!(ForeignAssets::balance(1984, &[10; 32].into()), 19_500);
-        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 4_000);
-        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_096_000);
-        assert_eq!(ForeignAssets::balance(1337, &[10; 32].into()), 13_000);
-        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 0);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
-        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_ok!(Marketplace::cancel_offer(
+            RuntimeOrigin::signed([2; 32].into()),
+            1
+        ));
+        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
         assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
+            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(),
+            false
+        );
+        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_150_000);
+        assert_eq!(
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(1)),
+            0
         );
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
-        assert_eq!(LocalAssets::balance(0, &[30; 32].into()), 20);
     })
 }
 
 #[test]
-fn reject_contract_and_refund() {
+fn cancel_offer_fails() {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -5920,6 +10353,11 @@ fn reject_contract_and_refund() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -5945,43 +10383,44 @@ fn reject_contract_and_refund() {
             10_000,
             100,
             bvec![22, 22],
-            false
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            25,
-            1984
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            20,
-            1337
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([31; 32].into()),
             0,
-            25,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
@@ -5994,12 +10433,6 @@ fn reject_contract_and_refund() {
             0,
             true
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            Some([10; 32].into())
-        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -6019,10 +10452,10 @@ fn reject_contract_and_refund() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             crate::Vote::Yes,
-            45
+            40
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
             crate::Vote::Yes,
             30
@@ -6036,141 +10469,106 @@ fn reject_contract_and_refund() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
-            Some([11; 32].into())
-        );
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([10; 32].into()),
             0,
-            false,
+            true,
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_status,
-            crate::DocumentStatus::Rejected
-        );
-        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
-
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
-            0
-        );
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1337, &[1; 32].into()),
-            0
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_240_000);
-        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_292_000);
-        /*         assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).unwrap()
-                .paid_funds
-                .get(&1984)
-                .unwrap(),
-            &600000_u128
-        );
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).unwrap()
-                .paid_tax
-                .get(&1984)
-                .unwrap(),
-            &18000_u128
-        ); */
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([11; 32].into()),
             0,
-            false,
+            true,
         ));
-        /*         System::assert_last_event(
-            Event::DocumentsConfirmed {
-                signer: [11; 32].into(),
-                listing_id: 0,
-                legal_side: LegalProperty::SpvSide,
-                approve: false,
-            }
-            .into(),
-        ); */
-        assert_eq!(RefundToken::<Test>::get(0).unwrap().refund_amount, 100);
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 45);
-        /*         assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).unwrap().token_amount,
-            100
-        ); */
-        assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            200_000
-        );
-        assert_ok!(Marketplace::withdraw_rejected(
+        assert_ok!(Marketplace::relist_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::withdraw_rejected(
-            RuntimeOrigin::signed([2; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::withdraw_rejected(
-            RuntimeOrigin::signed([30; 32].into()),
-            0
+            0,
+            500,
+            1
         ));
-        assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            0
-        );
-        assert_eq!(RefundToken::<Test>::get(0).is_none(), true);
-        assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
-        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_000_000);
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
-            4000
-        );
-        assert_eq!(
-            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
-            2000
-        );
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            0
+        assert_noop!(
+            Marketplace::cancel_offer(RuntimeOrigin::signed([2; 32].into()), 1),
+            Error::<Test>::OfferNotFound
         );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_497_500);
-        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_498_000);
-        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_147_000);
-        assert_eq!(ForeignAssets::balance(1984, &[30; 32].into()), 1_197_500);
-        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 4000);
-        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 0);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).is_none(), true);
-        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_ok!(Marketplace::make_offer(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+            2000,
+            1,
+            1984
+        ));
+        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
         assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
+            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(),
+            true
         );
-        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
         assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            0
+            ForeignAssets::total_balance(1984, &([2; 32].into())),
+            1_150_000
         );
+        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_148_000);
         assert_eq!(
-            Balances::free_balance(&(Marketplace::property_account_id(0))),
-            0
+            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
+            2000
         );
-        assert_eq!(Balances::balance(&(Marketplace::property_account_id(0))), 0);
-        assert_eq!(Nfts::owner(0, 0), None);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0), None);
+        assert_noop!(
+            Marketplace::cancel_offer(RuntimeOrigin::signed([1; 32].into()), 1),
+            Error::<Test>::OfferNotFound
+        );
+    })
+}
+
+// upgrade_object function
+#[test]
+fn upgrade_object_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::upgrade_object(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            30000
+        ));
         assert_eq!(
-            RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
-                .unwrap()
-                .active_cases,
-            0
+            OngoingObjectListing::<Test>::get(0).unwrap().token_price,
+            30000
         );
     })
 }
 
 #[test]
-fn reject_contract_and_refund_2() {
+fn upgrade_object_and_distribute_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -6205,7 +10603,7 @@ fn reject_contract_and_refund_2() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [7; 32].into(),
+            [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
@@ -6233,25 +10631,44 @@ fn reject_contract_and_refund_2() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            30,
-            1984
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            30,
-            1984
+            10,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::upgrade_object(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            20_000
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([7; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
             40,
-            1337
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            10,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -6262,7 +10679,7 @@ fn reject_contract_and_refund_2() {
             0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([7; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
@@ -6276,12 +10693,6 @@ fn reject_contract_and_refund_2() {
             0,
             true
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            Some([10; 32].into())
-        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -6301,110 +10712,46 @@ fn reject_contract_and_refund_2() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             crate::Vote::Yes,
-            30
+            40
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             crate::Vote::Yes,
-            30
+            40
         ));
         run_to_block(91);
         assert_ok!(Marketplace::finalize_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
-            Some([11; 32].into())
-        );
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([10; 32].into()),
             0,
-            false,
+            true,
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_status,
-            crate::DocumentStatus::Rejected
-        );
-        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_188_000);
-        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 838_000);
-        assert_eq!(ForeignAssets::balance(1337, &[7; 32].into()), 84_000);
-        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_500_000);
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([11; 32].into()),
             0,
-            false,
-        ));
-        assert_eq!(RefundToken::<Test>::get(0).unwrap().refund_amount, 100);
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 30);
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
-        );
-        assert_ok!(Marketplace::withdraw_rejected(
-            RuntimeOrigin::signed([1; 32].into()),
-            0
-        ));
-        assert_eq!(RefundToken::<Test>::get(0).unwrap().refund_amount, 70);
-        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_000_000);
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
-            0
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_497_000);
-        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 0);
-        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 0);
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
-        );
-        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            315000
-        );
-        assert_ok!(Marketplace::withdraw_rejected(
-            RuntimeOrigin::signed([2; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::withdraw_rejected(
-            RuntimeOrigin::signed([7; 32].into()),
-            0
+            true,
         ));
-        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            0
-        );
+        assert_eq!(ForeignAssets::balance(1984, &([0; 32].into())), 21485000);
         assert_eq!(
             ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
-            2000
-        );
-        assert_eq!(
-            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
-            4000
+            13000
         );
-        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 4000);
-        assert_eq!(RefundToken::<Test>::get(0).is_none(), true);
-        assert!(PropertyLawyer::<Test>::get(0).is_none());
+        assert_eq!(ForeignAssets::balance(1984, &([8; 32].into())), 13000);
+        assert_eq!(ForeignAssets::balance(1984, &([1; 32].into())), 1_084_000);
+        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 318_000);
+        assert_eq!(ForeignAssets::balance(1984, &([30; 32].into())), 888_000);
+
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
     })
 }
 
-// withdraw_legal_process_expired function
 #[test]
-fn withdraw_legal_process_expired_works() {
+fn upgrade_object_for_relisted_nft_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -6429,22 +10776,17 @@ fn withdraw_legal_process_expired_works() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
+            [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
+            [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
@@ -6472,47 +10814,44 @@ fn withdraw_legal_process_expired_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([0; 32].into()),
             0,
             40,
-            1337
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            10,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            40,
-            1984
+            30,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([0; 32].into()),
             0,
-            10,
-            1984
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([2; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0
+            0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
@@ -6541,80 +10880,51 @@ fn withdraw_legal_process_expired_works() {
             4_000,
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([0; 32].into()),
             0,
             crate::Vote::Yes,
             40
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([1; 32].into()),
             0,
             crate::Vote::Yes,
-            20
+            30
         ));
         run_to_block(91);
         assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([0; 32].into()),
             0,
-        ));
+        ),);
         assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-        ));
-        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().legal_process_expiry, 161);
-        run_to_block(162);
-        assert_noop!(
-            Marketplace::lawyer_confirm_documents(
-                RuntimeOrigin::signed([10; 32].into()),
-                0,
-                false,
-            ),
-            Error::<Test>::LegalProcessFailed
-        );
-        assert_ok!(Marketplace::withdraw_legal_process_expired(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([0; 32].into()),
             0,
         ));
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
-        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_500_000);
-        assert_ok!(Marketplace::withdraw_legal_process_expired(
-            RuntimeOrigin::signed([2; 32].into()),
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
             0,
+            true,
         ));
-        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_150_000);
-        assert_ok!(Marketplace::withdraw_legal_process_expired(
-            RuntimeOrigin::signed([30; 32].into()),
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
             0,
+            true,
         ));
-        assert_ok!(Marketplace::withdraw_legal_process_expired(
-            RuntimeOrigin::signed([31; 32].into()),
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([0; 32].into()),
             0,
+            1000,
+            1
         ));
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            0
-        );
-        assert_eq!(
-            ForeignAssets::balance(1337, &Marketplace::property_account_id(0)),
-            0
+        assert_noop!(
+            Marketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 1, 300),
+            Error::<Test>::TokenNotForSale
         );
-        assert_eq!(Nfts::owner(0, 0), None);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0), None);
-        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
-        assert_eq!(PropertyLawyer::<Test>::get(0), None);
-        assert_eq!(RefundLegalExpired::<Test>::get(0), None);
-        assert_eq!(PropertyOwner::<Test>::get(0).len(), 0);
-        assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).unwrap().active_cases, 0);
-        assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([11; 32].into()).unwrap().active_cases, 0);
     })
 }
 
 #[test]
-fn withdraw_legal_process_expired_works_2() {
+fn upgrade_object_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -6639,12 +10949,12 @@ fn withdraw_legal_process_expired_works_2() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
+            [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
+            [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
@@ -6652,6 +10962,15 @@ fn withdraw_legal_process_expired_works_2() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_noop!(
+            Marketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 0, 300),
+            Error::<Test>::TokenNotForSale
+        );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -6659,86 +10978,61 @@ fn withdraw_legal_process_expired_works_2() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([0; 32].into()),
             0,
             40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            20,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
+            30,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
             0,
+            30,
+            1984,
+            None
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().legal_process_expiry,
-            161
+        assert_noop!(
+            Marketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 0, 300),
+            Error::<Test>::PropertyAlreadySold
         );
-        run_to_block(162);
-        assert_ok!(Marketplace::withdraw_legal_process_expired(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
-        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 1_500_000);
-        assert_ok!(Marketplace::withdraw_legal_process_expired(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_150_000);
-        assert_ok!(Marketplace::withdraw_legal_process_expired(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            0
-        );
-        assert_eq!(
-            ForeignAssets::balance(1337, &Marketplace::property_account_id(0)),
-            0
+        run_to_block(100);
+        assert_noop!(
+            Marketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 1, 300),
+            Error::<Test>::ListingExpired
         );
-        assert_eq!(Nfts::owner(0, 0), None);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0), None);
-        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
-        assert_eq!(PropertyLawyer::<Test>::get(0), None);
-        assert_eq!(RefundLegalExpired::<Test>::get(0), None);
-        assert_eq!(PropertyOwner::<Test>::get(0).len(), 0);
     })
 }
 
+// delist_token function
 #[test]
-fn withdraw_legal_process_expired_fails() {
+fn delist_single_token_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -6776,6 +11070,11 @@ fn withdraw_legal_process_expired_fails() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -6794,10 +11093,6 @@ fn withdraw_legal_process_expired_fails() {
             RuntimeOrigin::signed([11; 32].into()),
             3,
         ));
-        assert_noop!(
-            Marketplace::withdraw_legal_process_expired(RuntimeOrigin::signed([1; 32].into()), 0),
-            Error::<Test>::ListingNotFound
-        );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -6805,53 +11100,44 @@ fn withdraw_legal_process_expired_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            20,
-            1984
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            25,
-            1337
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            20,
-            1984
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            25,
-            1337
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([31; 32].into()),
             0,
-            10,
-            1984
+            30,
+            1984,
+            None
         ));
-        assert_noop!(
-            Marketplace::withdraw_legal_process_expired(RuntimeOrigin::signed([1; 32].into()), 0),
-            Error::<Test>::NoTokensOwned
-        );
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
@@ -6886,34 +11172,79 @@ fn withdraw_legal_process_expired_fails() {
             40
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
             crate::Vote::Yes,
-            40
+            30
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            1000,
+            1
+        ));
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 39);
+        assert_eq!(
+            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
+            1
+        );
+        assert_ok!(Marketplace::delist_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            1
         ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
+        assert_eq!(TokenListings::<Test>::get(0), None);
+        assert_ok!(Marketplace::relist_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
+            1000,
+            3
         ));
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 37);
         assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().legal_process_expiry,
-            161
-        );
-        assert_noop!(
-            Marketplace::withdraw_legal_process_expired(RuntimeOrigin::signed([1; 32].into()), 0),
-            Error::<Test>::LegalProcessOngoing
+            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
+            3
         );
-        run_to_block(162);
-        assert_noop!(
-            Marketplace::withdraw_legal_process_expired(RuntimeOrigin::signed([3; 32].into()), 0,),
-            BadOrigin
+        assert_ok!(Marketplace::buy_relisted_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            2,
+            2,
+            1984,
+            None
+        ));
+        assert_eq!(
+            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
+            1
         );
+        assert_ok!(Marketplace::delist_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            2
+        ));
+        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 2);
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 38);
     })
 }
 
 #[test]
-fn second_attempt_works() {
+fn delist_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -6951,6 +11282,11 @@ fn second_attempt_works() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -6976,37 +11312,44 @@ fn second_attempt_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([2; 32].into()),
-            0
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
-            0
+            0,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
@@ -7019,12 +11362,6 @@ fn second_attempt_works() {
             0,
             true
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_lawyer,
-            Some([10; 32].into())
-        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -7061,225 +11398,35 @@ fn second_attempt_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer,
-            Some([11; 32].into())
-        );
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([10; 32].into()),
             0,
             true,
         ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_status,
-            crate::DocumentStatus::Approved
-        );
-        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            false,
-        ));
-        assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().second_attempt, true);
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            false,
-        ));
-        assert_eq!(
-            PropertyLawyer::<Test>::get(0)
-                .unwrap()
-                .real_estate_developer_status,
-            crate::DocumentStatus::Rejected
-        );
-        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([11; 32].into()),
             0,
             true,
-        ));
-        assert_ok!(Marketplace::withdraw_rejected(
-            RuntimeOrigin::signed([1; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::withdraw_rejected(
-            RuntimeOrigin::signed([2; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::withdraw_rejected(
-            RuntimeOrigin::signed([30; 32].into()),
-            0
-        ));
-        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_000_000);
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
-            6000
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_496_000);
-        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_147_000);
-        assert_eq!(ForeignAssets::balance(1984, &[30; 32].into()), 1_197_000);
-        assert_eq!(ForeignAssets::balance(1984, &[11; 32].into()), 4_000);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).is_none(), true);
-        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
-        );
-        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
-    })
-}
-
-#[test]
-fn lawyer_confirm_documents_fails() {
-    new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-        assert_ok!(XcavateWhitelist::add_admin(
-            RuntimeOrigin::root(),
-            [20; 32].into(),
-        ));
-		assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [8; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-		new_region_helper();
-		assert_ok!(Regions::create_new_location(RuntimeOrigin::signed([8; 32].into()), 3, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateDeveloper
-        ));
-		assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [12; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-		assert_ok!(Regions::register_lawyer(RuntimeOrigin::signed([10; 32].into()), 3));
-		assert_ok!(Regions::register_lawyer(RuntimeOrigin::signed([11; 32].into()), 3));
-		assert_ok!(Regions::register_lawyer(RuntimeOrigin::signed([12; 32].into()), 3));
-		assert_ok!(Marketplace::list_property(
-			RuntimeOrigin::signed([0; 32].into()),
-			3,
-			bvec![10, 10],
-			10_000,
-			100,
-			bvec![22, 22],
-			false
-		));
-		assert_ok!(Marketplace::buy_property_token(RuntimeOrigin::signed([1; 32].into()), 0, 40, 1984));
-        assert_ok!(Marketplace::buy_property_token(RuntimeOrigin::signed([2; 32].into()), 0, 30, 1984));
-        assert_ok!(Marketplace::buy_property_token(RuntimeOrigin::signed([30; 32].into()), 0, 30, 1984));
-        assert_ok!(Marketplace::claim_property_token(RuntimeOrigin::signed([1; 32].into()), 0));
-        assert_ok!(Marketplace::claim_property_token(RuntimeOrigin::signed([2; 32].into()), 0));
-        assert_ok!(Marketplace::claim_property_token(RuntimeOrigin::signed([30; 32].into()), 0));
-		assert_ok!(Marketplace::lawyer_claim_property(
-			RuntimeOrigin::signed([10; 32].into()),
-			0,
-			crate::LegalProperty::RealEstateDeveloperSide,
-			4_000,
-		));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-			RuntimeOrigin::signed([0; 32].into()),
-			0,
-            true
-		));
-		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_lawyer, Some([10; 32].into()));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
-        ));
-		assert_ok!(Marketplace::lawyer_claim_property(
-			RuntimeOrigin::signed([11; 32].into()),
-			0,
-			crate::LegalProperty::SpvSide,
-			4_000,
-		));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-			RuntimeOrigin::signed([1; 32].into()),
-			0,
-            crate::Vote::Yes,
-            40
-		));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-			RuntimeOrigin::signed([2; 32].into()),
-			0,
-            crate::Vote::Yes,
-            30
-		));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-			RuntimeOrigin::signed([1; 32].into()),
-			0,
-		));
-		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, Some([11; 32].into()));
-		assert_noop!(Marketplace::lawyer_confirm_documents(
-			RuntimeOrigin::signed([10; 32].into()),
-			1,
-			false,
-		), Error::<Test>::InvalidIndex);
-		assert_noop!(Marketplace::lawyer_confirm_documents(
-			RuntimeOrigin::signed([12; 32].into()),
-			0,
-			false,
-		), Error::<Test>::NoPermission);
-		assert_ok!(Marketplace::lawyer_confirm_documents(
-			RuntimeOrigin::signed([10; 32].into()),
-			0,
-			false,
-		));
-		assert_noop!(Marketplace::lawyer_confirm_documents(
-			RuntimeOrigin::signed([10; 32].into()),
-			0,
-			true,
-		), Error::<Test>::AlreadyConfirmed);
-        run_to_block(200);
-        assert_noop!(Marketplace::lawyer_confirm_documents(
-			RuntimeOrigin::signed([11; 32].into()),
-			0,
-			true,
-		), Error::<Test>::LegalProcessFailed);
-	})
+        ));
+        assert_ok!(Marketplace::relist_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            1000,
+            1
+        ));
+        assert_noop!(
+            Marketplace::delist_token(RuntimeOrigin::signed([4; 32].into()), 1),
+            Error::<Test>::NoPermission
+        );
+        assert_noop!(
+            Marketplace::delist_token(RuntimeOrigin::signed([1; 32].into()), 2),
+            Error::<Test>::TokenNotForSale
+        );
+    })
 }
 
-// list_token function
 #[test]
-fn relist_a_nft() {
+fn listing_objects_in_different_regions() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -7292,11 +11439,67 @@ fn relist_a_nft() {
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         new_region_helper();
+        assert_ok!(Regions::propose_new_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            RegionIdentifier::France
+        ));
+        assert_ok!(Regions::vote_on_region_proposal(
+            RuntimeOrigin::signed([8; 32].into()),
+            2,
+            pallet_regions::Vote::Yes,
+            10_000
+        ));
+        run_to_block(91);
+        assert_ok!(Regions::bid_on_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            2,
+            100_000
+        ));
+        run_to_block(121);
+        assert_ok!(Regions::create_new_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            2,
+            30,
+            Permill::from_percent(3)
+        ));
+        assert_ok!(Regions::propose_new_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            RegionIdentifier::India
+        ));
+        assert_ok!(Regions::vote_on_region_proposal(
+            RuntimeOrigin::signed([8; 32].into()),
+            4,
+            pallet_regions::Vote::Yes,
+            10_000
+        ));
+        run_to_block(151);
+        assert_ok!(Regions::bid_on_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            4,
+            100_000
+        ));
+        run_to_block(181);
+        assert_ok!(Regions::create_new_region(
+            RuntimeOrigin::signed([8; 32].into()),
+            4,
+            30,
+            Permill::from_percent(3)
+        ));
         assert_ok!(Regions::create_new_location(
             RuntimeOrigin::signed([8; 32].into()),
             3,
             bvec![10, 10]
         ));
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            2,
+            bvec![10, 10]
+        ));
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            4,
+            bvec![10, 10]
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [0; 32].into(),
@@ -7317,6 +11520,11 @@ fn relist_a_nft() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -7327,6 +11535,26 @@ fn relist_a_nft() {
             [11; 32].into(),
             pallet_xcavate_whitelist::Role::Lawyer
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [12; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [13; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [14; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [15; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
         assert_ok!(Regions::register_lawyer(
             RuntimeOrigin::signed([10; 32].into()),
             3,
@@ -7335,6 +11563,22 @@ fn relist_a_nft() {
             RuntimeOrigin::signed([11; 32].into()),
             3,
         ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([12; 32].into()),
+            2,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([13; 32].into()),
+            2,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([14; 32].into()),
+            4,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([15; 32].into()),
+            4,
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -7342,47 +11586,113 @@ fn relist_a_nft() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            2,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            4,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
+            1,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
+            RuntimeOrigin::signed([30; 32].into()),
+            1,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
+            RuntimeOrigin::signed([31; 32].into()),
+            1,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
+            1,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            1,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            1,
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            2,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            2,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            2,
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([2; 32].into()),
-            0,
+            2,
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
-            0,
+            2,
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            2,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
+            RuntimeOrigin::signed([12; 32].into()),
+            1,
             crate::LegalProperty::RealEstateDeveloperSide,
             4_000,
         ));
         assert_ok!(Marketplace::approve_developer_lawyer(
             RuntimeOrigin::signed([0; 32].into()),
-            0,
+            1,
             true
         ));
         assert_ok!(XcavateWhitelist::assign_role(
@@ -7392,107 +11702,115 @@ fn relist_a_nft() {
         ));
         assert_ok!(Marketplace::create_spv(
             RuntimeOrigin::signed([5; 32].into()),
-            0,
+            1,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
+            RuntimeOrigin::signed([13; 32].into()),
+            1,
             crate::LegalProperty::SpvSide,
             4_000,
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
+            1,
             crate::Vote::Yes,
             40
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
+            1,
+            crate::Vote::Yes,
+            30
+        ));
+        run_to_block(221);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+        ));
+        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([12; 32].into()),
+            1,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([13; 32].into()),
+            1,
+            true,
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            2,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([14; 32].into()),
+            2,
+            crate::LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([15; 32].into()),
+            2,
+            crate::LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            2,
+            true
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([2; 32].into()),
+            2,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            2,
             crate::Vote::Yes,
             30
         ));
-        run_to_block(91);
+        run_to_block(251);
         assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
+            RuntimeOrigin::signed([2; 32].into()),
+            2,
         ));
         assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
+            RuntimeOrigin::signed([14; 32].into()),
+            2,
             true,
         ));
         assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
+            RuntimeOrigin::signed([15; 32].into()),
+            2,
             true,
         ));
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
+        assert_eq!(PropertyAssetInfo::<Test>::get(1).unwrap().spv_created, true);
+        assert_eq!(PropertyAssetInfo::<Test>::get(2).unwrap().spv_created, true);
         assert_ok!(Marketplace::relist_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
+            1,
             1000,
-            1
-        ));
-        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-        assert_eq!(TokenListings::<Test>::get(1).unwrap().item_id, 0);
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 39);
-        assert_eq!(
-            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
-            1
-        );
-    })
-}
-
-#[test]
-fn relist_property_token_not_created_with_marketplace_fails() {
-    new_test_ext().execute_with(|| {
-        System::set_block_number(1);
-        assert_ok!(XcavateWhitelist::add_admin(
-            RuntimeOrigin::root(),
-            [20; 32].into(),
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateDeveloper
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(Nfts::create(
-            RuntimeOrigin::signed([0; 32].into()),
-            sp_runtime::MultiAddress::Id([0; 32].into()),
-            Default::default()
+            40
         ));
-        assert_ok!(Nfts::mint(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            0,
-            sp_runtime::MultiAddress::Id([0; 32].into()),
+        assert_ok!(Marketplace::buy_relisted_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            3,
+            40,
+            1984,
             None
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [8; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        new_region_helper();
-        assert_noop!(
-            Marketplace::relist_token(RuntimeOrigin::signed([0; 32].into()), 0, 1000, 1),
-            RealEstateAssetError::<Test>::PropertyNotFound
-        );
+        assert_eq!(LocalAssets::balance(1, &[2; 32].into()), 40);
+        assert_eq!(LocalAssets::balance(2, &[2; 32].into()), 40);
     })
 }
 
 #[test]
-fn relist_a_nft_fails() {
+fn cancel_property_purchase_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -7515,11 +11833,6 @@ fn relist_a_nft_fails() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
@@ -7530,29 +11843,6 @@ fn relist_a_nft_fails() {
             [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
-        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -7560,114 +11850,94 @@ fn relist_a_nft_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
+            30,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-        ));
-        assert_noop!(
-            Marketplace::relist_token(RuntimeOrigin::signed([1; 32].into()), 0, 1000, 10),
-            RealEstateAssetError::<Test>::PropertyNotFinalized
-        );
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            crate::Vote::Yes,
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .listed_token_amount,
             40
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            crate::Vote::Yes,
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0)
+                .unwrap()
+                .token_amount,
             30
-        ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([2; 32].into(), 0)
+                .unwrap()
+                .token_amount,
+            30
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_188_000);
+        assert_eq!(
+            ForeignAssets::total_balance(1984, &[1; 32].into()),
+            1_500_000
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            312_000
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
+            312_000
+        );
+        /*         assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_funds
+                .get(&1984)
+                .copied(),
+            Some(600_000)
+        ); */
+        assert_ok!(Marketplace::cancel_property_purchase(
             RuntimeOrigin::signed([1; 32].into()),
             0,
+            30
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
-        ));
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
-        assert_noop!(
-            Marketplace::relist_token(RuntimeOrigin::signed([0; 32].into()), 0, 1000, 1),
-            TokenError::FundsUnavailable
-        );
-        assert_noop!(
-            Marketplace::relist_token(RuntimeOrigin::signed([1; 32].into()), 0, 1000, 0),
-            Error::<Test>::AmountCannotBeZero
+        /*         assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_funds
+                .get(&1984)
+                .copied(),
+            Some(300_000)
+        ); */
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .listed_token_amount,
+            70
         );
-        assert_noop!(
-            Marketplace::relist_token(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1),
-            Error::<Test>::InvalidTokenPrice
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            0
         );
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
     })
 }
 
-// buy_relisted_token function
 #[test]
-fn buy_relisted_token_works() {
+fn cancel_property_purchase_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -7710,28 +11980,88 @@ fn buy_relisted_token_works() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        assert_noop!(
+            Marketplace::cancel_property_purchase(RuntimeOrigin::signed([1; 32].into()), 0, 10),
+            Error::<Test>::ListingNotFound
+        );
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::cancel_property_purchase(RuntimeOrigin::signed([3; 32].into()), 0, 10),
+            Error::<Test>::TokenOwnerNotFound
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::cancel_property_purchase(RuntimeOrigin::signed([1; 32].into()), 0, 10),
+            Error::<Test>::PropertyAlreadySold
+        );
+    })
+}
+
+#[test]
+fn cancel_property_purchase_fails_2() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
+            [8; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -7740,173 +12070,177 @@ fn buy_relisted_token_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            3,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            47,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0,
-            20,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
+            40,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0,
+        run_to_block(100);
+        assert_noop!(
+            Marketplace::cancel_property_purchase(RuntimeOrigin::signed([1; 32].into()), 0, 10),
+            Error::<Test>::ListingExpired
+        );
+    })
+}
+
+#[test]
+fn cancel_property_purchase_partially_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
+        assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::Vote::Yes,
-            3
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            crate::Vote::Yes,
-            44
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            crate::Vote::Yes,
-            4
+            30,
+            1984,
+            None
         ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
+        assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
+            10,
+            1337,
+            None
         ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            312_000
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1337, &[1; 32].into()),
+            104_000
+        );
+        assert_noop!(
+            Marketplace::cancel_property_purchase(RuntimeOrigin::signed([1; 32].into()), 0, 0),
+            Error::<Test>::AmountCannotBeZero
+        );
+        assert_noop!(
+            Marketplace::cancel_property_purchase(RuntimeOrigin::signed([1; 32].into()), 0, 41),
+            Error::<Test>::NotEnoughToken
+        );
+        assert_ok!(Marketplace::cancel_property_purchase(
             RuntimeOrigin::signed([1; 32].into()),
             0,
+            20
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
-        ));
-        assert_eq!(ForeignAssets::balance(1984, &([0; 32].into())), 20990000);
+        System::assert_last_event(
+            Event::InvestmentCancelled {
+                listing_id: 0,
+                investor: [1; 32].into(),
+                amount_returned: 20,
+                new_tokens_remaining: 80,
+                principal_refunded: BoundedBTreeMap::try_from(BTreeMap::from([
+                    (1337, 50_000),
+                    (1984, 150_000),
+                ]))
+                .unwrap(),
+                tax_refunded: BoundedBTreeMap::try_from(BTreeMap::from([
+                    (1337, 1_500),
+                    (1984, 4_500),
+                ]))
+                .unwrap(),
+            }
+            .into(),
+        );
+        let token_details = TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).unwrap();
+        assert_eq!(token_details.token_amount, 20);
+        assert_eq!(token_details.paid_funds.get(&1984).copied(), Some(150_000));
+        assert_eq!(token_details.paid_funds.get(&1337).copied(), Some(50_000));
+        assert_eq!(token_details.paid_tax.get(&1984).copied(), Some(4_500));
+        assert_eq!(token_details.paid_tax.get(&1337).copied(), Some(1_500));
+        let listing = OngoingObjectListing::<Test>::get(0).unwrap();
+        assert_eq!(listing.listed_token_amount, 80);
+        assert_eq!(listing.unclaimed_token_amount, 20);
         assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
-            8000
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            156_000
         );
-        assert_eq!(ForeignAssets::balance(1984, &([8; 32].into())), 8000);
-        assert_eq!(ForeignAssets::balance(1984, &([1; 32].into())), 1_468_800);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
-        assert_ok!(Marketplace::relist_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            1000,
-            3
-        ));
-        assert_ok!(Marketplace::buy_relisted_token(
-            RuntimeOrigin::signed([3; 32].into()),
-            1,
-            2,
-            1984
-        ));
-        assert_eq!(ForeignAssets::balance(1984, &([3; 32].into())), 3_000);
-        assert_eq!(LocalAssets::balance(0, &[3; 32].into()), 2);
-        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-        assert_ok!(Marketplace::buy_relisted_token(
-            RuntimeOrigin::signed([3; 32].into()),
-            1,
-            1,
-            1984
-        ));
-        assert_eq!(ForeignAssets::balance(1984, &([3; 32].into())), 2_000);
-        assert_eq!(TokenListings::<Test>::get(1).is_some(), false);
-        assert_ok!(Marketplace::relist_token(
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1337, &[1; 32].into()),
+            52_000
+        );
+        assert_ok!(Marketplace::cancel_property_purchase(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            500,
-            1
-        ));
-        assert_ok!(Marketplace::buy_relisted_token(
-            RuntimeOrigin::signed([3; 32].into()),
-            2,
-            1,
-            1984
+            20
         ));
-        assert_eq!(TokenListings::<Test>::get(0).is_some(), false);
-        assert_eq!(PropertyOwner::<Test>::get(0).len(), 5);
+        assert!(TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).is_none());
+        let listing = OngoingObjectListing::<Test>::get(0).unwrap();
+        assert_eq!(listing.listed_token_amount, 100);
+        assert_eq!(listing.unclaimed_token_amount, 0);
         assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
-            2
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            0
         );
         assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [3; 32].into()),
-            4
+            AssetsHolder::total_balance_on_hold(1337, &[1; 32].into()),
+            0
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_500_000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1337, AccountId::from([1; 32])),
+            1_500_000
         );
-        assert_eq!(ForeignAssets::balance(1984, &([1; 32].into())), 1_469_295);
-        assert_eq!(ForeignAssets::balance(1984, &([3; 32].into())), 1_500);
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 2);
-        assert_eq!(LocalAssets::balance(0, &[3; 32].into()), 4);
     })
 }
 
 #[test]
-fn buy_relisted_token_fails() {
+fn cancel_property_purchase_in_parts_releases_whole_fee() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -7934,38 +12268,77 @@ fn buy_relisted_token_fails() {
             [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_050,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [3; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            3,
+            1984,
+            None
+        ));
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            31_355
+        );
+        for _ in 0..3 {
+            assert_ok!(Marketplace::cancel_property_purchase(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                1
+            ));
+        }
+        assert!(TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).is_none());
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            0
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_500_000
+        );
+    })
+}
+
+#[test]
+fn withdraw_expired_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [8; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -7974,153 +12347,70 @@ fn buy_relisted_token_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
             30,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            crate::Vote::Yes,
-            30
-        ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
+            1984,
+            None
         ));
-        assert_eq!(ForeignAssets::balance(1984, &([0; 32].into())), 20990000);
         assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
-            8_000
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .listed_token_amount,
+            70
         );
-        assert_eq!(ForeignAssets::balance(1984, &([8; 32].into())), 8_000);
-        assert_eq!(ForeignAssets::balance(1984, &([1; 32].into())), 1_084_000);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
-        assert_noop!(
-            Marketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 1, 1984),
-            Error::<Test>::TokenNotForSale
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0)
+                .unwrap()
+                .token_amount,
+            30
         );
-        assert_ok!(Marketplace::relist_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            500,
-            1
-        ));
-        assert_noop!(
-            Marketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 1, 1983),
-            Error::<Test>::PaymentAssetNotSupported
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_188_000);
+        assert_eq!(
+            ForeignAssets::total_balance(1984, &[1; 32].into()),
+            1_500_000
         );
-        assert_ok!(XcavateWhitelist::set_permission(
-            RuntimeOrigin::signed([20; 32].into()),
-            [3; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor,
-            pallet_xcavate_whitelist::AccessPermission::Revoked,
-        ));
-        assert_noop!(
-            Marketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 1, 1984),
-            BadOrigin
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            312_000
         );
-        assert_ok!(Marketplace::relist_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            1_000,
-            20
+        /*         assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_funds
+                .get(&1984)
+                .copied(),
+            Some(300_000)
+        ); */
+        run_to_block(100);
+        assert_ok!(Marketplace::withdraw_expired(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
         ));
-        assert_eq!(TokenListings::<Test>::get(2).unwrap().amount, 20);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
         assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
-            40
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
         );
-        assert_noop!(
-            Marketplace::buy_relisted_token(RuntimeOrigin::signed([1; 32].into()), 2, 10, 1984),
-            Error::<Test>::ExceedsMaxOwnership
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            0
         );
     })
 }
 
-// make_offer function
 #[test]
-fn make_offer_works() {
+fn withdraw_expired_works_2() {
     new_test_ext().execute_with(|| {
+        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -8153,165 +12443,150 @@ fn make_offer_works() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
+            [3; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
-        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
             bvec![10, 10],
-            10_000,
+            1_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            crate::LegalProperty::SpvSide,
-            4_000,
+            20,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([3; 32].into()),
             0,
-            crate::Vote::Yes,
-            40
+            4,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            crate::Vote::Yes,
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .listed_token_amount,
+            46
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0)
+                .unwrap()
+                .token_amount,
             30
-        ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
-        ));
-        assert_ok!(Marketplace::relist_token(
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_468_800);
+        assert_eq!(
+            ForeignAssets::total_balance(1984, &[1; 32].into()),
+            1_500_000
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_129_200);
+        assert_eq!(
+            ForeignAssets::total_balance(1984, &[2; 32].into()),
+            1_150_000
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[3; 32].into()), 840);
+        assert_eq!(ForeignAssets::total_balance(1984, &[3; 32].into()), 5_000);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            31_200
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
+            20_800
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[3; 32].into()),
+            4_160
+        );
+        run_to_block(100);
+        assert_ok!(Marketplace::withdraw_expired(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
-            500,
-            1
+            0
         ));
-        assert_ok!(Marketplace::make_offer(
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .listed_token_amount,
+            76
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([3; 32].into(), 0)
+                .unwrap()
+                .token_amount,
+            4
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            0
+        );
+        assert_ok!(Marketplace::withdraw_expired(
             RuntimeOrigin::signed([2; 32].into()),
-            1,
-            2000,
-            1,
-            1984
+            0
         ));
-        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
         assert_eq!(
-            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(),
-            true
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            200_000
         );
-        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_148_000);
         assert_eq!(
-            ForeignAssets::total_balance(1984, &[2; 32].into()),
-            1_150_000
+            Balances::free_balance(&(Marketplace::property_account_id(0))),
+            99
         );
         assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            Balances::balance(&(Marketplace::property_account_id(0))),
+            99
+        );
+        assert_ok!(Marketplace::withdraw_expired(
+            RuntimeOrigin::signed([3; 32].into()),
+            0
+        ));
+        assert_eq!(
+            Balances::free_balance(&(Marketplace::property_account_id(0))),
+            0
+        );
+        assert_eq!(Balances::balance(&(Marketplace::property_account_id(0))), 0);
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[3; 32].into()), 5_000);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[3; 32].into()),
             0
         );
         assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
-            2000
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            0
         );
     })
 }
 
 #[test]
-fn make_offer_fails() {
+fn withdraw_expired_fails() {
     new_test_ext().execute_with(|| {
+        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -8342,39 +12617,15 @@ fn make_offer_fails() {
             [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [3; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
-        ));
+        assert_noop!(
+            Marketplace::withdraw_expired(RuntimeOrigin::signed([1; 32].into()), 0),
+            Error::<Test>::ListingNotFound
+        );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -8382,174 +12633,116 @@ fn make_offer_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
+        assert_noop!(
+            Marketplace::withdraw_expired(RuntimeOrigin::signed([1; 32].into()), 0),
+            Error::<Test>::ListingNotExpired
+        );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
+            30,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
+        run_to_block(100);
+        assert_noop!(
+            Marketplace::withdraw_expired(RuntimeOrigin::signed([1; 32].into()), 0),
+            Error::<Test>::PropertyAlreadySold
+        );
+    })
+}
+
+#[test]
+fn withdraw_expired_fails_2() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            crate::Vote::Yes,
-            30
-        ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_noop!(
-            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1, 1984),
-            Error::<Test>::TokenNotForSale
-        );
-        assert_ok!(Marketplace::relist_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            500,
-            1
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_noop!(
-            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 2, 1984),
-            Error::<Test>::NotEnoughTokenAvailable
-        );
-        assert_noop!(
-            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1, 100),
-            Error::<Test>::PaymentAssetNotSupported
-        );
-        assert_noop!(
-            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 0, 1984),
-            Error::<Test>::AmountCannotBeZero
-        );
-        assert_noop!(
-            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 0, 1, 1984),
-            Error::<Test>::InvalidTokenPrice
-        );
-        assert_ok!(XcavateWhitelist::set_permission(
+        assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor,
-            pallet_xcavate_whitelist::AccessPermission::Revoked,
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_noop!(
-            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1, 1984),
-            BadOrigin
-        );
-        assert_ok!(XcavateWhitelist::set_permission(
+        assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor,
-            pallet_xcavate_whitelist::AccessPermission::Compliant,
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::make_offer(
-            RuntimeOrigin::signed([2; 32].into()),
-            1,
-            200,
-            1,
-            1984
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_ok!(Marketplace::make_offer(
-            RuntimeOrigin::signed([3; 32].into()),
-            1,
-            300,
-            1,
-            1984
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            39,
+            1984,
+            None
         ));
+        run_to_block(100);
         assert_noop!(
-            Marketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 400, 1, 1984),
-            Error::<Test>::OnlyOneOfferPerUser
-        );
-        assert_eq!(
-            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into())
-                .unwrap()
-                .token_price,
-            200
-        );
-        assert_eq!(
-            OngoingOffers::<Test>::get::<u32, AccountId>(1, [3; 32].into())
-                .unwrap()
-                .token_price,
-            300
+            Marketplace::withdraw_expired(RuntimeOrigin::signed([2; 32].into()), 0),
+            Error::<Test>::TokenOwnerNotFound
         );
     })
 }
 
-// handle_offer function
 #[test]
-fn handle_offer_works() {
+fn send_property_token_works() {
     new_test_ext().execute_with(|| {
+        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -8620,37 +12813,44 @@ fn handle_offer_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([31; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
@@ -8704,104 +12904,65 @@ fn handle_offer_works() {
             0,
             true,
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
-        ));
-        assert_ok!(Marketplace::relist_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            5000,
-            20
-        ));
-        assert_ok!(Marketplace::make_offer(
-            RuntimeOrigin::signed([2; 32].into()),
-            1,
-            200,
-            1,
-            1984
-        ));
-        assert_ok!(Marketplace::make_offer(
-            RuntimeOrigin::signed([3; 32].into()),
-            1,
-            150,
-            1,
-            1337
-        ));
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
-            200
-        );
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1337, &[3; 32].into()),
-            150
-        );
-        assert_ok!(Marketplace::handle_offer(
-            RuntimeOrigin::signed([1; 32].into()),
-            1,
-            [2; 32].into(),
-            crate::Offer::Reject
-        ));
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
-            0
-        );
-        assert_ok!(Marketplace::cancel_offer(
-            RuntimeOrigin::signed([3; 32].into()),
-            1
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
         ));
-        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_150_000);
-        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
         assert_eq!(
-            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(),
-            true
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            40
         );
-        assert_ok!(Marketplace::make_offer(
-            RuntimeOrigin::signed([2; 32].into()),
-            1,
-            2000,
-            10,
-            1984
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
+        assert_eq!(PropertyOwner::<Test>::get(0).len(), 3);
+        assert_ok!(Marketplace::send_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            [2; 32].into(),
+            20
         ));
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 20);
+        assert_eq!(PropertyOwner::<Test>::get(0).len(), 4);
         assert_eq!(
-            ForeignAssets::total_balance(1984, &([2; 32].into())),
-            1_150_000
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            20
         );
-        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_130_000);
+        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 20);
         assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
-            20000
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()),
+            20
         );
-        assert_ok!(Marketplace::handle_offer(
+        assert_ok!(Marketplace::send_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            1,
-            [2; 32].into(),
-            crate::Offer::Accept
+            0,
+            [3; 32].into(),
+            20
         ));
-        assert_eq!(TokenListings::<Test>::get(1).unwrap().amount, 10);
-        assert_eq!(
-            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(),
-            true
-        );
+        assert_ok!(Marketplace::send_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            [3; 32].into(),
+            20
+        ));
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 0);
+        assert_eq!(PropertyOwner::<Test>::get(0).len(), 3);
+        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 0);
         assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(1)),
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()),
             0
         );
-        assert_eq!(LocalAssets::balance(0, &([1; 32].into())), 20);
-        assert_eq!(LocalAssets::balance(0, &([2; 32].into())), 10);
+        assert_eq!(LocalAssets::balance(0, &[3; 32].into()), 40);
         assert_eq!(
-            ForeignAssets::balance(0, &Marketplace::property_account_id(0)),
-            0
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [3; 32].into()),
+            40
         );
-        assert_eq!(ForeignAssets::balance(1984, &([1; 32].into())), 1_103_800);
-        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_130_000);
     })
 }
 
 #[test]
-fn handle_offer_fails() {
+fn send_property_token_works_2() {
     new_test_ext().execute_with(|| {
+        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -8827,11 +12988,6 @@ fn handle_offer_fails() {
             [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [30; 32].into(),
@@ -8864,40 +13020,47 @@ fn handle_offer_fails() {
             RuntimeOrigin::signed([0; 32].into()),
             3,
             bvec![10, 10],
-            10_000,
-            100,
+            5_000,
+            200,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
+            80,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            30,
-            1984
+            60,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
-            30,
-            1984
+            60,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([31; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
@@ -8929,13 +13092,13 @@ fn handle_offer_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             crate::Vote::Yes,
-            40
+            80
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             crate::Vote::Yes,
-            30
+            45
         ));
         run_to_block(91);
         assert_ok!(Marketplace::finalize_spv_lawyer(
@@ -8960,83 +13123,51 @@ fn handle_offer_fails() {
             0,
             true,
         ));
-        assert_noop!(
-            Marketplace::handle_offer(
-                RuntimeOrigin::signed([1; 32].into()),
-                1,
-                [2; 32].into(),
-                crate::Offer::Reject
-            ),
-            Error::<Test>::TokenNotForSale
-        );
-        assert_ok!(Marketplace::relist_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            5000,
-            2
-        ));
-        assert_noop!(
-            Marketplace::handle_offer(
-                RuntimeOrigin::signed([1; 32].into()),
-                1,
-                [2; 32].into(),
-                crate::Offer::Reject
-            ),
-            Error::<Test>::OfferNotFound
-        );
-        assert_ok!(Marketplace::make_offer(
-            RuntimeOrigin::signed([2; 32].into()),
-            1,
-            200,
-            1,
-            1984
-        ));
-        assert_noop!(
-            Marketplace::handle_offer(
-                RuntimeOrigin::signed([2; 32].into()),
-                1,
-                [2; 32].into(),
-                crate::Offer::Accept
-            ),
-            Error::<Test>::NoPermission
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            80
         );
-        assert_ok!(Marketplace::relist_token(
+        assert_ok!(Marketplace::send_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            5000,
-            20
-        ));
-        assert_ok!(Marketplace::make_offer(
-            RuntimeOrigin::signed([1; 32].into()),
-            2,
-            200,
-            15,
-            1984
+            [1; 32].into(),
+            19
         ));
         assert_eq!(
             PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
-            40
+            99
         );
         assert_noop!(
-            Marketplace::handle_offer(
+            Marketplace::send_property_token(
                 RuntimeOrigin::signed([30; 32].into()),
-                2,
+                0,
                 [1; 32].into(),
-                crate::Offer::Accept
+                19
             ),
             Error::<Test>::ExceedsMaxOwnership
         );
-        assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
-            40
+        assert_noop!(
+            Marketplace::send_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                [30; 32].into(),
+                80
+            ),
+            Error::<Test>::ExceedsMaxOwnership
         );
+        assert_ok!(Marketplace::send_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            [30; 32].into(),
+            35
+        ));
     })
 }
 
-// cancel_offer function
 #[test]
-fn cancel_offer_works() {
+fn send_property_token_fails() {
     new_test_ext().execute_with(|| {
+        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -9057,16 +13188,20 @@ fn cancel_offer_works() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
+        assert_noop!(
+            Marketplace::send_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                [2; 32].into(),
+                20
+            ),
+            BadOrigin
+        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [30; 32].into(),
@@ -9077,24 +13212,6 @@ fn cancel_offer_works() {
             [31; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
-        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -9102,37 +13219,85 @@ fn cancel_offer_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0
+        ));
+        assert_noop!(
+            Marketplace::send_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                [2; 32].into(),
+                20
+            ),
+            Error::<Test>::UserNotWhitelisted
+        );
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_noop!(
+            Marketplace::send_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                1,
+                [1; 32].into(),
+                20
+            ),
+            Error::<Test>::NoObjectFound
+        );
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0,
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
@@ -9177,10 +13342,6 @@ fn cancel_offer_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([10; 32].into()),
             0,
@@ -9191,53 +13352,55 @@ fn cancel_offer_works() {
             0,
             true,
         ));
-        assert_ok!(Marketplace::relist_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            500,
-            1
-        ));
-        assert_ok!(Marketplace::make_offer(
-            RuntimeOrigin::signed([2; 32].into()),
-            1,
-            2000,
-            1,
-            1984
-        ));
-        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-        assert_eq!(
-            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(),
-            true
-        );
-        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_148_000);
-        assert_eq!(
-            ForeignAssets::total_balance(1984, &([2; 32].into())),
-            1_150_000
+        assert_noop!(
+            Marketplace::send_property_token(
+                RuntimeOrigin::signed([2; 32].into()),
+                0,
+                [1; 32].into(),
+                5
+            ),
+            RealEstateAssetError::<Test>::NotEnoughToken
         );
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
-            2000
+        assert_noop!(
+            Marketplace::send_property_token(
+                RuntimeOrigin::signed([2; 32].into()),
+                0,
+                [1; 32].into(),
+                30
+            ),
+            Error::<Test>::ExceedsMaxOwnership
         );
-        assert_ok!(Marketplace::cancel_offer(
-            RuntimeOrigin::signed([2; 32].into()),
-            1
+        assert_ok!(XcavateWhitelist::set_permission(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor,
+            pallet_xcavate_whitelist::AccessPermission::Revoked,
         ));
-        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-        assert_eq!(
-            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(),
-            false
+        assert_noop!(
+            Marketplace::send_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                [2; 32].into(),
+                5
+            ),
+            Error::<Test>::UserNotWhitelisted
         );
-        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_150_000);
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(1)),
-            0
+        assert_noop!(
+            Marketplace::send_property_token(
+                RuntimeOrigin::signed([2; 32].into()),
+                0,
+                [1; 32].into(),
+                5
+            ),
+            BadOrigin
         );
     })
 }
 
 #[test]
-fn cancel_offer_fails() {
+fn send_property_token_fails_if_relist() {
     new_test_ext().execute_with(|| {
+        System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -9270,12 +13433,12 @@ fn cancel_offer_fails() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [3; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
+            [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
@@ -9303,37 +13466,44 @@ fn cancel_offer_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
+            20,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            30,
-            1984
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            30,
-            1984
+            40,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
+            RuntimeOrigin::signed([2; 32].into()),
+            0
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0,
+            RuntimeOrigin::signed([30; 32].into()),
+            0
         ));
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
@@ -9346,6 +13516,7 @@ fn cancel_offer_fails() {
             0,
             true
         ));
+
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [5; 32].into(),
@@ -9362,7 +13533,7 @@ fn cancel_offer_fails() {
             4_000,
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
             crate::Vote::Yes,
             40
@@ -9371,15 +13542,11 @@ fn cancel_offer_fails() {
             RuntimeOrigin::signed([30; 32].into()),
             0,
             crate::Vote::Yes,
-            30
+            40
         ));
         run_to_block(91);
         assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::lawyer_confirm_documents(
@@ -9392,47 +13559,92 @@ fn cancel_offer_fails() {
             0,
             true,
         ));
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            20
+        );
+        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 20);
+        assert_eq!(PropertyOwner::<Test>::get(0).len(), 3);
         assert_ok!(Marketplace::relist_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            500,
-            1
+            1000,
+            15
         ));
         assert_noop!(
-            Marketplace::cancel_offer(RuntimeOrigin::signed([2; 32].into()), 1),
-            Error::<Test>::OfferNotFound
+            Marketplace::send_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                [2; 32].into(),
+                9
+            ),
+            RealEstateAssetError::<Test>::NotEnoughToken
         );
-        assert_ok!(Marketplace::make_offer(
-            RuntimeOrigin::signed([2; 32].into()),
-            1,
-            2000,
-            1,
-            1984
+    })
+}
+
+#[test]
+fn withdraw_deposit_unsold_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-        assert_eq!(
-            OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(),
-            true
-        );
         assert_eq!(
-            ForeignAssets::total_balance(1984, &([2; 32].into())),
-            1_150_000
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            200_000
         );
-        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 1_148_000);
+        run_to_block(100);
+        assert_ok!(Marketplace::withdraw_deposit_unsold(
+            RuntimeOrigin::signed([0; 32].into()),
+            0
+        ));
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
         assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
-            2000
-        );
-        assert_noop!(
-            Marketplace::cancel_offer(RuntimeOrigin::signed([1; 32].into()), 1),
-            Error::<Test>::OfferNotFound
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            0
         );
     })
 }
 
-// upgrade_object function
 #[test]
-fn upgrade_object_works() {
+fn withdraw_deposit_unsold_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -9455,6 +13667,16 @@ fn upgrade_object_works() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -9462,22 +13684,42 @@ fn upgrade_object_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_ok!(Marketplace::upgrade_object(
-            RuntimeOrigin::signed([0; 32].into()),
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            200_000
+        );
+        run_to_block(20);
+        assert_noop!(
+            Marketplace::withdraw_deposit_unsold(RuntimeOrigin::signed([0; 32].into()), 0),
+            Error::<Test>::ListingNotExpired
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            30000
+            10,
+            1984,
+            None
         ));
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0).unwrap().token_price,
-            30000
+        assert_noop!(
+            Marketplace::withdraw_deposit_unsold(RuntimeOrigin::signed([0; 32].into()), 1),
+            Error::<Test>::ListingNotFound
+        );
+        run_to_block(100);
+        assert_noop!(
+            Marketplace::withdraw_deposit_unsold(RuntimeOrigin::signed([0; 32].into()), 0),
+            Error::<Test>::TokenNotReturned
         );
     })
 }
 
 #[test]
-fn upgrade_object_and_distribute_works() {
+fn withdraw_deposit_unsold_fails_2() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -9502,36 +13744,23 @@ fn upgrade_object_and_distribute_works() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
+            [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
+            [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -9540,119 +13769,49 @@ fn upgrade_object_and_distribute_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
+            200_000
+        );
+        run_to_block(20);
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            10,
-            1984
-        ));
-        assert_ok!(Marketplace::upgrade_object(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            20_000
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            40,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            10,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
-        ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
+            30,
+            1984,
+            None
         ));
-        assert_eq!(ForeignAssets::balance(1984, &([0; 32].into())), 21485000);
-        assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::treasury_account_id()),
-            13000
+        run_to_block(100);
+        assert_noop!(
+            Marketplace::withdraw_deposit_unsold(RuntimeOrigin::signed([0; 32].into()), 0),
+            Error::<Test>::PropertyAlreadySold
         );
-        assert_eq!(ForeignAssets::balance(1984, &([8; 32].into())), 13000);
-        assert_eq!(ForeignAssets::balance(1984, &([1; 32].into())), 1_084_000);
-        assert_eq!(ForeignAssets::balance(1984, &([2; 32].into())), 318_000);
-        assert_eq!(ForeignAssets::balance(1984, &([30; 32].into())), 888_000);
-
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().spv_created, true);
-        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
     })
 }
 
+// withdraw_unclaimed function
 #[test]
-fn upgrade_object_for_relisted_nft_fails() {
+fn withdraw_unclaimed_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -9675,11 +13834,6 @@ fn upgrade_object_for_relisted_nft_fails() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
@@ -9692,133 +13846,91 @@ fn upgrade_object_for_relisted_nft_fails() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
-            3,
-            bvec![10, 10],
-            10_000,
-            100,
-            bvec![22, 22],
-            false
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
+        assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::Vote::Yes,
-            30
+            40,
+            1984,
+            None
         ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-        ),);
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([0; 32].into()),
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
             0,
+            30,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            true,
+            30,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            true,
         ));
-        assert_ok!(Marketplace::relist_token(
-            RuntimeOrigin::signed([0; 32].into()),
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            1000,
-            1
         ));
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .listed_token_amount,
+            0
+        );
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .unclaimed_token_amount,
+            30
+        );
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 838_000);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
+            312_000
+        );
+        assert!(TokenOwner::<Test>::get::<AccountId, u32>([2; 32].into(), 0).is_some());
+        // The purchase of an earlier listing round can not be cancelled in the relisting.
         assert_noop!(
-            Marketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 1, 300),
-            Error::<Test>::TokenNotForSale
+            Marketplace::cancel_property_purchase(RuntimeOrigin::signed([2; 32].into()), 0, 10),
+            Error::<Test>::NoPermission
         );
+        assert_ok!(Marketplace::withdraw_unclaimed(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_150_000);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
+            0
+        );
+        assert!(TokenOwner::<Test>::get::<AccountId, u32>([2; 32].into(), 0).is_none());
     })
 }
 
 #[test]
-fn upgrade_object_fails() {
+fn withdraw_unclaimed_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -9841,11 +13953,6 @@ fn upgrade_object_fails() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
@@ -9853,18 +13960,14 @@ fn upgrade_object_fails() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
+            [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_noop!(
-            Marketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 0, 300),
-            Error::<Test>::TokenNotForSale
-        );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -9872,50 +13975,65 @@ fn upgrade_object_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([0; 32].into()),
+            RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
+        assert_noop!(
+            Marketplace::withdraw_unclaimed(RuntimeOrigin::signed([2; 32].into()), 0,),
+            Error::<Test>::TokenOwnerNotFound
+        );
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_noop!(
-            Marketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 0, 300),
-            Error::<Test>::PropertyAlreadySold
+            Marketplace::withdraw_unclaimed(RuntimeOrigin::signed([2; 32].into()), 0,),
+            Error::<Test>::NoPermission
         );
-        assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([0; 32].into()),
-            3,
-            bvec![10, 10],
-            10_000,
-            100,
-            bvec![22, 22],
-            false
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
         ));
-        run_to_block(100);
         assert_noop!(
-            Marketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 1, 300),
-            Error::<Test>::ListingExpired
+            Marketplace::withdraw_unclaimed(RuntimeOrigin::signed([2; 32].into()), 0,),
+            Error::<Test>::NoPermission
+        );
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_ok!(Marketplace::withdraw_unclaimed(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+        ));
+        assert_noop!(
+            Marketplace::withdraw_unclaimed(RuntimeOrigin::signed([2; 32].into()), 0,),
+            Error::<Test>::TokenOwnerNotFound
         );
     })
 }
 
-// delist_token function
+// withdraw_claiming_expired function
 #[test]
-fn delist_single_token_works() {
+fn withdraw_claiming_expired_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -9953,29 +14071,6 @@ fn delist_single_token_works() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
-        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -9983,25 +14078,32 @@ fn delist_single_token_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -10011,115 +14113,55 @@ fn delist_single_token_works() {
             RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            crate::Vote::Yes,
-            30
-        ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_ok!(Marketplace::withdraw_unclaimed(
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            true,
         ));
-        assert_ok!(Marketplace::relist_token(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            1000,
-            1
+            30,
+            1984,
+            None
         ));
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 39);
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_eq!(RefundClaimedToken::<Test>::get(0).unwrap(), 70);
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_084_000);
         assert_eq!(
-            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
-            1
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            728_000
         );
-        assert_ok!(Marketplace::delist_token(
+        assert_ok!(Marketplace::withdraw_claiming_expired(
             RuntimeOrigin::signed([1; 32].into()),
-            1
+            0,
         ));
-        assert_eq!(TokenListings::<Test>::get(0), None);
-        assert_ok!(Marketplace::relist_token(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(Marketplace::withdraw_claiming_expired(
+            RuntimeOrigin::signed([30; 32].into()),
             0,
-            1000,
-            3
         ));
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 37);
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
         assert_eq!(
-            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
-            3
+            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            0
         );
-        assert_ok!(Marketplace::buy_relisted_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            2,
-            2,
-            1984
-        ));
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).is_none(), true);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
         assert_eq!(
-            LocalAssets::balance(0, &Marketplace::property_account_id(0)),
-            1
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
+            None
         );
-        assert_ok!(Marketplace::delist_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            2
-        ));
-        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 2);
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 38);
+        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
+        assert_eq!(Balances::balance(&(Marketplace::property_account_id(0))), 0);
+        assert_eq!(Nfts::owner(0, 0), None);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0), None);
     })
 }
 
 #[test]
-fn delist_fails() {
+fn withdraw_claiming_expired_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -10157,29 +14199,10 @@ fn delist_fails() {
             [30; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [4; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
-        ));
+        assert_noop!(
+            Marketplace::withdraw_claiming_expired(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::TokenNotRefunded
+        );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -10187,265 +14210,104 @@ fn delist_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
+        assert_ok!(Marketplace::withdraw_unclaimed(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            crate::Vote::Yes,
-            30
-        ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
         ));
-        assert_ok!(Marketplace::relist_token(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            1000,
-            1
+            30,
+            1984,
+            None
         ));
         assert_noop!(
-            Marketplace::delist_token(RuntimeOrigin::signed([4; 32].into()), 1),
-            Error::<Test>::NoPermission
+            Marketplace::withdraw_claiming_expired(RuntimeOrigin::signed([1; 32].into()), 0,),
+            Error::<Test>::TokenNotRefunded
         );
+        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
+        run_to_block(expiry);
         assert_noop!(
-            Marketplace::delist_token(RuntimeOrigin::signed([1; 32].into()), 2),
-            Error::<Test>::TokenNotForSale
+            Marketplace::withdraw_claiming_expired(RuntimeOrigin::signed([0; 32].into()), 0,),
+            BadOrigin
         );
+        assert_ok!(Marketplace::withdraw_claiming_expired(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
     })
 }
 
 #[test]
-fn listing_objects_in_different_regions() {
+fn marketplace_api_queries_work() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
-            [20; 32].into(),
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [8; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        new_region_helper();
-        assert_ok!(Regions::propose_new_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            RegionIdentifier::France
-        ));
-        assert_ok!(Regions::vote_on_region_proposal(
-            RuntimeOrigin::signed([8; 32].into()),
-            2,
-            pallet_regions::Vote::Yes,
-            10_000
-        ));
-        run_to_block(91);
-        assert_ok!(Regions::bid_on_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            2,
-            100_000
-        ));
-        run_to_block(121);
-        assert_ok!(Regions::create_new_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            2,
-            30,
-            Permill::from_percent(3)
-        ));
-        assert_ok!(Regions::propose_new_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            RegionIdentifier::India
-        ));
-        assert_ok!(Regions::vote_on_region_proposal(
-            RuntimeOrigin::signed([8; 32].into()),
-            4,
-            pallet_regions::Vote::Yes,
-            10_000
-        ));
-        run_to_block(151);
-        assert_ok!(Regions::bid_on_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            4,
-            100_000
-        ));
-        run_to_block(181);
-        assert_ok!(Regions::create_new_region(
-            RuntimeOrigin::signed([8; 32].into()),
-            4,
-            30,
-            Permill::from_percent(3)
-        ));
-        assert_ok!(Regions::create_new_location(
-            RuntimeOrigin::signed([8; 32].into()),
-            3,
-            bvec![10, 10]
-        ));
-        assert_ok!(Regions::create_new_location(
-            RuntimeOrigin::signed([8; 32].into()),
-            2,
-            bvec![10, 10]
-        ));
-        assert_ok!(Regions::create_new_location(
-            RuntimeOrigin::signed([8; 32].into()),
-            4,
-            bvec![10, 10]
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateDeveloper
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+            [20; 32].into(),
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [12; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [13; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [14; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [15; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([12; 32].into()),
-            2,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([13; 32].into()),
-            2,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([14; 32].into()),
-            4,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([15; 32].into()),
-            4,
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -10454,332 +14316,678 @@ fn listing_objects_in_different_regions() {
             10_000,
             100,
             bvec![22, 22],
-            false
-        ));
-        assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([0; 32].into()),
-            2,
-            bvec![10, 10],
-            10_000,
-            100,
-            bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
-            4,
+            3,
             bvec![10, 10],
-            10_000,
+            5_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            1,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            1,
+            0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            1,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             1,
+            20,
+            1337,
+            None
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            1,
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            10,
+            1337,
+            None
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            1,
+
+        let mut listings = Marketplace::ongoing_listings(None, 10);
+        listings.sort_by_key(|listing| listing.listing_id);
+        assert_eq!(listings.len(), 2);
+        assert_eq!(listings[0].listing_id, 0);
+        assert_eq!(listings[0].token_price, 10_000);
+        assert_eq!(listings[0].listed_token_amount, 60);
+        assert_eq!(listings[1].listing_id, 1);
+        assert_eq!(listings[1].listed_token_amount, 80);
+        let first_page = Marketplace::ongoing_listings(None, 1);
+        assert_eq!(first_page.len(), 1);
+        let second_page = Marketplace::ongoing_listings(Some(first_page[0].listing_id), 10);
+        assert_eq!(second_page.len(), 1);
+        assert_ne!(second_page[0].listing_id, first_page[0].listing_id);
+        assert!(Marketplace::ongoing_listings(Some(second_page[0].listing_id), 10).is_empty());
+
+        let funding = Marketplace::listing_funding(0).unwrap();
+        assert_eq!(funding.token_amount, 100);
+        assert_eq!(funding.sold_token_amount, 40);
+        assert_eq!(funding.unclaimed_token_amount, 40);
+        assert_eq!(
+            funding.funding,
+            vec![
+                PaymentAssetFunding {
+                    payment_asset: 1337,
+                    funds: 100_000,
+                    fees: 1_000,
+                    tax: 3_000,
+                },
+                PaymentAssetFunding {
+                    payment_asset: 1984,
+                    funds: 300_000,
+                    fees: 3_000,
+                    tax: 9_000,
+                },
+            ]
+        );
+        assert_eq!(Marketplace::listing_funding(2), None);
+
+        let mut purchases = Marketplace::account_purchases([1; 32].into(), None, 10);
+        purchases.sort_by_key(|purchase| purchase.listing_id);
+        assert_eq!(purchases.len(), 2);
+        assert_eq!(purchases[0].listing_id, 0);
+        assert_eq!(purchases[0].token_amount, 30);
+        assert_eq!(purchases[0].paid_funds, vec![(1984, 300_000)]);
+        assert_eq!(purchases[0].paid_tax, vec![(1984, 9_000)]);
+        assert_eq!(purchases[1].listing_id, 1);
+        assert_eq!(purchases[1].paid_funds, vec![(1337, 100_000)]);
+        let first_page = Marketplace::account_purchases([1; 32].into(), None, 1);
+        assert_eq!(first_page.len(), 1);
+        let second_page =
+            Marketplace::account_purchases([1; 32].into(), Some(first_page[0].listing_id), 10);
+        assert_eq!(second_page.len(), 1);
+        assert_ne!(second_page[0].listing_id, first_page[0].listing_id);
+        assert!(Marketplace::account_purchases([3; 32].into(), None, 10).is_empty());
+
+        OngoingOffers::<Test>::insert(
+            5,
+            AccountId::from([2; 32]),
+            OfferDetails {
+                token_price: 2_000,
+                amount: 10,
+                payment_assets: 1984,
+            },
+        );
+        assert_eq!(
+            Marketplace::listing_offers(5, None, 10),
+            vec![OfferInfo {
+                offeror: [2; 32].into(),
+                token_price: 2_000,
+                amount: 10,
+                payment_asset: 1984,
+            }]
+        );
+        assert!(Marketplace::listing_offers(5, Some([2; 32].into()), 10).is_empty());
+        OngoingOffers::<Test>::insert(
+            5,
+            AccountId::from([3; 32]),
+            OfferDetails {
+                token_price: 1_500,
+                amount: 5,
+                payment_assets: 1337,
+            },
+        );
+        let first_page = Marketplace::listing_offers(5, None, 1);
+        assert_eq!(first_page.len(), 1);
+        let second_page = Marketplace::listing_offers(5, Some(first_page[0].offeror.clone()), 10);
+        assert_eq!(second_page.len(), 1);
+        assert_ne!(second_page[0].offeror, first_page[0].offeror);
+        assert!(Marketplace::token_listings(None, 10).is_empty());
+    })
+}
+
+#[test]
+fn order_book_matching_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        registered_property_helper();
+        let treasury_balance = ForeignAssets::balance(1984, Marketplace::treasury_account_id());
+        let seller_balance = ForeignAssets::balance(1984, AccountId::from([2; 32]));
+        let second_seller_balance = ForeignAssets::balance(1984, AccountId::from([30; 32]));
+        assert_ok!(Marketplace::place_ask(
             RuntimeOrigin::signed([2; 32].into()),
-            2,
-            40,
-            1984
+            0,
+            1000,
+            5
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::place_ask(
             RuntimeOrigin::signed([30; 32].into()),
-            2,
-            30,
-            1984
+            0,
+            900,
+            5
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::place_ask(
             RuntimeOrigin::signed([31; 32].into()),
-            2,
-            30,
-            1984
+            0,
+            1000,
+            5
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            2,
+        assert_eq!(AssetAsks::<Test>::get(0).into_inner(), vec![1, 0, 2]);
+        assert_eq!(
+            LocalAssets::balance(0, Marketplace::property_account_id(0)),
+            15
+        );
+        assert_ok!(Marketplace::place_bid(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            1000,
+            8,
+            1984
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            2,
+        System::assert_last_event(
+            Event::OrderFilled {
+                maker_order_id: 0,
+                taker_order_id: 3,
+                asset_id: 0,
+                buyer: [1; 32].into(),
+                seller: [2; 32].into(),
+                token_price: 1000,
+                amount: 3,
+                payment_asset: 1984,
+                maker_amount_remaining: 2,
+            }
+            .into(),
+        );
+        assert_eq!(AssetAsks::<Test>::get(0).into_inner(), vec![0, 2]);
+        assert_eq!(AssetBids::<Test>::get(0).len(), 0);
+        assert_eq!(Orders::<Test>::get(0).unwrap().amount, 2);
+        assert!(Orders::<Test>::get(1).is_none());
+        assert!(Orders::<Test>::get(3).is_none());
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_461_300
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([2; 32])),
+            seller_balance + 2_970
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([30; 32])),
+            second_seller_balance + 4_455
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, Marketplace::treasury_account_id()),
+            treasury_balance + 75
+        );
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            11
+        );
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()),
+            44
+        );
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [30; 32].into()),
+            25
+        );
+        assert_eq!(LocalAssets::balance(0, AccountId::from([1; 32])), 11);
+        assert_eq!(
+            LocalAssets::balance(0, Marketplace::property_account_id(0)),
+            7
+        );
+        assert_ok!(Marketplace::place_bid(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            950,
+            4,
+            1337
         ));
-        assert_ok!(Marketplace::claim_property_token(
+        assert_eq!(AssetBids::<Test>::get(0).into_inner(), vec![4]);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1337, &[1; 32].into()),
+            3_800
+        );
+        assert_ok!(Marketplace::place_ask(
             RuntimeOrigin::signed([31; 32].into()),
-            2,
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([12; 32].into()),
-            1,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
+            0,
+            900,
+            2
         ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            1,
-            true
+        System::assert_last_event(
+            Event::OrderFilled {
+                maker_order_id: 4,
+                taker_order_id: 5,
+                asset_id: 0,
+                buyer: [1; 32].into(),
+                seller: [31; 32].into(),
+                token_price: 950,
+                amount: 2,
+                payment_asset: 1337,
+                maker_amount_remaining: 2,
+            }
+            .into(),
+        );
+        assert_eq!(Orders::<Test>::get(4).unwrap().amount, 2);
+        assert!(Orders::<Test>::get(5).is_none());
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1337, &[1; 32].into()),
+            1_900
+        );
+        assert_eq!(
+            ForeignAssets::balance(1337, AccountId::from([1; 32])),
+            1_496_200
+        );
+        assert_eq!(LocalAssets::balance(0, AccountId::from([1; 32])), 13);
+        assert_eq!(LocalAssets::balance(0, AccountId::from([31; 32])), 13);
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [31; 32].into()),
+            18
+        );
+        assert_eq!(NextOrderId::<Test>::get(), 6);
+    })
+}
+
+#[test]
+fn order_book_matches_prices_in_reference_unit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
+        registered_property_helper();
+        assert_ok!(ForeignAssets::force_create(
+            RuntimeOrigin::root(),
+            codec::Compact(100),
+            sp_runtime::MultiAddress::Id([0; 32].into()),
+            true,
+            1
         ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            1,
+        assert_ok!(ForeignAssets::force_set_metadata(
+            RuntimeOrigin::root(),
+            codec::Compact(100),
+            "DOT".into(),
+            "DOT".into(),
+            10,
+            false
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([13; 32].into()),
-            1,
-            crate::LegalProperty::SpvSide,
-            4_000,
+        assert_ok!(ForeignAssets::mint(
+            RuntimeOrigin::signed([0; 32].into()),
+            codec::Compact(100),
+            sp_runtime::MultiAddress::Id([1; 32].into()),
+            1_000_000_000
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            100,
             1,
-            crate::Vote::Yes,
-            40
+            true
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            1,
-            crate::Vote::Yes,
-            30
+        assert_ok!(PriceOracle::set_exchange_rate(
+            RuntimeOrigin::root(),
+            100,
+            FixedU128::from_u32(5)
         ));
-        run_to_block(221);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            1,
+        assert_ok!(Marketplace::place_ask(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            1000,
+            2
         ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
+        // An ask of 1000 in the reference unit costs 2_000_000 units of the payment asset.
+        assert_ok!(Marketplace::place_bid(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([12; 32].into()),
+            1_999_999,
             1,
-            true,
+            100
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([13; 32].into()),
+        assert_eq!(AssetBids::<Test>::get(0).into_inner(), vec![1]);
+        assert_eq!(Orders::<Test>::get(0).unwrap().amount, 2);
+        assert_ok!(Marketplace::place_bid(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            2_000_000,
             1,
-            true,
+            100
         ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            2,
+        System::assert_last_event(
+            Event::OrderFilled {
+                maker_order_id: 0,
+                taker_order_id: 2,
+                asset_id: 0,
+                buyer: [1; 32].into(),
+                seller: [2; 32].into(),
+                token_price: 1000,
+                amount: 1,
+                payment_asset: 100,
+                maker_amount_remaining: 1,
+            }
+            .into(),
+        );
+        assert_eq!(
+            ForeignAssets::balance(100, AccountId::from([1; 32])),
+            1_000_000_000 - 1_999_999 - 2_000_000
+        );
+        // The open bid is worth less than 1000 in the reference unit.
+        assert_ok!(Marketplace::place_ask(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            1000,
+            1
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([14; 32].into()),
-            2,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
+        assert_eq!(AssetBids::<Test>::get(0).into_inner(), vec![1]);
+        assert_eq!(AssetAsks::<Test>::get(0).into_inner(), vec![0, 3]);
+        assert_ok!(Marketplace::place_ask(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            995,
+            1
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([15; 32].into()),
-            2,
-            crate::LegalProperty::SpvSide,
-            4_000,
+        System::assert_last_event(
+            Event::OrderFilled {
+                maker_order_id: 1,
+                taker_order_id: 4,
+                asset_id: 0,
+                buyer: [1; 32].into(),
+                seller: [30; 32].into(),
+                token_price: 1_999_999,
+                amount: 1,
+                payment_asset: 100,
+                maker_amount_remaining: 0,
+            }
+            .into(),
+        );
+        assert_eq!(AssetBids::<Test>::get(0).len(), 0);
+    })
+}
+
+#[test]
+fn cancel_order_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            2,
-            true
+        registered_property_helper();
+        assert_ok!(Marketplace::place_bid(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            800,
+            5,
+            1984
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
+        assert_ok!(Marketplace::place_ask(
             RuntimeOrigin::signed([2; 32].into()),
-            2,
-            crate::Vote::Yes,
-            40
+            0,
+            1000,
+            4
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            2,
-            crate::Vote::Yes,
-            30
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            4_000
+        );
+        assert_eq!(LocalAssets::balance(0, AccountId::from([2; 32])), 43);
+        assert_ok!(Marketplace::cancel_order(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
         ));
-        run_to_block(251);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
+        System::assert_last_event(
+            Event::OrderCancelled {
+                order_id: 0,
+                asset_id: 0,
+                owner: [1; 32].into(),
+                amount_returned: 5,
+            }
+            .into(),
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            0
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_468_800
+        );
+        assert_eq!(AssetBids::<Test>::get(0).len(), 0);
+        assert_ok!(Marketplace::cancel_order(
             RuntimeOrigin::signed([2; 32].into()),
-            2,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([14; 32].into()),
-            2,
-            true,
+            1
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([15; 32].into()),
-            2,
-            true,
+        assert_eq!(LocalAssets::balance(0, AccountId::from([2; 32])), 47);
+        assert_eq!(
+            LocalAssets::balance(0, Marketplace::property_account_id(0)),
+            0
+        );
+        assert_eq!(AssetAsks::<Test>::get(0).len(), 0);
+        assert!(Orders::<Test>::get(0).is_none());
+        assert!(Orders::<Test>::get(1).is_none());
+    })
+}
+
+#[test]
+fn order_book_limits_open_orders_per_account() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
-        assert_eq!(PropertyAssetInfo::<Test>::get(1).unwrap().spv_created, true);
-        assert_eq!(PropertyAssetInfo::<Test>::get(2).unwrap().spv_created, true);
-        assert_ok!(Marketplace::relist_token(
+        registered_property_helper();
+        for _ in 0..5 {
+            assert_ok!(Marketplace::place_ask(
+                RuntimeOrigin::signed([2; 32].into()),
+                0,
+                2000,
+                1
+            ));
+        }
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::OrderDepositReserve.into(), &[2; 32].into()),
+            500
+        );
+        assert_eq!(
+            AccountOpenOrders::<Test>::get::<AccountId, u32>([2; 32].into(), 0),
+            5
+        );
+        assert_eq!(OrderDeposits::<Test>::get(0), Some(([2; 32].into(), 100)));
+        assert_noop!(
+            Marketplace::place_ask(RuntimeOrigin::signed([2; 32].into()), 0, 2000, 1),
+            Error::<Test>::TooManyOpenOrders
+        );
+        assert_ok!(Marketplace::place_bid(
             RuntimeOrigin::signed([1; 32].into()),
+            0,
+            2000,
             1,
-            1000,
-            40
+            1984
         ));
-        assert_ok!(Marketplace::buy_relisted_token(
+        assert!(Orders::<Test>::get(0).is_none());
+        assert!(OrderDeposits::<Test>::get(0).is_none());
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::OrderDepositReserve.into(), &[2; 32].into()),
+            400
+        );
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::OrderDepositReserve.into(), &[1; 32].into()),
+            0
+        );
+        assert_ok!(Marketplace::place_ask(
             RuntimeOrigin::signed([2; 32].into()),
-            3,
-            40,
-            1984
+            0,
+            2000,
+            1
         ));
-        assert_eq!(LocalAssets::balance(1, &[2; 32].into()), 40);
-        assert_eq!(LocalAssets::balance(2, &[2; 32].into()), 40);
+        assert_ok!(Marketplace::cancel_order(
+            RuntimeOrigin::signed([2; 32].into()),
+            1
+        ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::OrderDepositReserve.into(), &[2; 32].into()),
+            400
+        );
+        assert_eq!(
+            AccountOpenOrders::<Test>::get::<AccountId, u32>([2; 32].into(), 0),
+            4
+        );
+        for order_id in [2, 3, 4, 6] {
+            assert_ok!(Marketplace::cancel_order(
+                RuntimeOrigin::signed([2; 32].into()),
+                order_id
+            ));
+        }
+        assert!(!AccountOpenOrders::<Test>::contains_key::<AccountId, u32>(
+            [2; 32].into(),
+            0
+        ));
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::OrderDepositReserve.into(), &[2; 32].into()),
+            0
+        );
     })
 }
 
 #[test]
-fn cancel_property_purchase_works() {
+fn insert_order_skips_orders_without_exchange_rate() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [8; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        new_region_helper();
-        assert_ok!(Regions::create_new_location(
-            RuntimeOrigin::signed([8; 32].into()),
-            3,
-            bvec![10, 10]
+        registered_property_helper();
+        assert_ok!(ForeignAssets::force_create(
+            RuntimeOrigin::root(),
+            codec::Compact(100),
+            sp_runtime::MultiAddress::Id([0; 32].into()),
+            true,
+            1
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        assert_ok!(ForeignAssets::force_set_metadata(
+            RuntimeOrigin::root(),
+            codec::Compact(100),
+            "DOT".into(),
+            "DOT".into(),
+            10,
+            false
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        assert_ok!(ForeignAssets::mint(
+            RuntimeOrigin::signed([0; 32].into()),
+            codec::Compact(100),
+            sp_runtime::MultiAddress::Id([1; 32].into()),
+            1_000_000_000
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            100,
+            1,
+            true
         ));
-        assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([0; 32].into()),
-            3,
-            bvec![10, 10],
-            10_000,
+        assert_ok!(PriceOracle::set_exchange_rate(
+            RuntimeOrigin::root(),
             100,
-            bvec![22, 22],
-            false
+            FixedU128::from_u32(5)
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        // A bid of 4_000_000 is worth 2000 in the reference unit.
+        assert_ok!(Marketplace::place_bid(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            30,
+            4_000_000,
+            1,
+            100
+        ));
+        assert_ok!(Marketplace::place_bid(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            1000,
+            1,
             1984
         ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+        assert_eq!(AssetBids::<Test>::get(0).into_inner(), vec![0, 1]);
+        assert_ok!(PriceOracle::remove_exchange_rate(
+            RuntimeOrigin::root(),
+            100
+        ));
+        assert_ok!(Marketplace::place_bid(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            30,
+            900,
+            1,
             1984
         ));
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .listed_token_amount,
-            40
+        assert_eq!(AssetBids::<Test>::get(0).into_inner(), vec![0, 1, 2]);
+    })
+}
+
+#[test]
+fn order_book_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        registered_property_helper();
+        assert_noop!(
+            Marketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1000, 0, 1984),
+            Error::<Test>::AmountCannotBeZero
         );
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0)
-                .unwrap()
-                .token_amount,
-            30
+        assert_noop!(
+            Marketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, 1984),
+            Error::<Test>::InvalidTokenPrice
         );
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([2; 32].into(), 0)
-                .unwrap()
-                .token_amount,
-            30
+        assert_noop!(
+            Marketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1000, 1, 10),
+            Error::<Test>::PaymentAssetNotSupported
+        );
+        assert_noop!(
+            Marketplace::place_bid(RuntimeOrigin::signed([2; 32].into()), 0, 1000, 4, 1984),
+            Error::<Test>::ExceedsMaxOwnership
+        );
+        assert_noop!(
+            Marketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 1, 1000, 1, 1984),
+            RealEstateAssetError::<Test>::PropertyNotFound
+        );
+        assert_noop!(
+            Marketplace::place_ask(RuntimeOrigin::signed([1; 32].into()), 0, 1000, 0),
+            Error::<Test>::AmountCannotBeZero
         );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_188_000);
-        assert_eq!(
-            ForeignAssets::total_balance(1984, &[1; 32].into()),
-            1_500_000
+        assert_noop!(
+            Marketplace::place_ask(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1),
+            Error::<Test>::InvalidTokenPrice
         );
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
-            312_000
+        assert_noop!(
+            Marketplace::place_ask(RuntimeOrigin::signed([1; 32].into()), 0, 1000, 4),
+            TokenError::FundsUnavailable
         );
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
-            312_000
+        assert_noop!(
+            Marketplace::cancel_order(RuntimeOrigin::signed([1; 32].into()), 0),
+            Error::<Test>::OrderNotFound
         );
-        /*         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_funds
-                .get(&1984)
-                .copied(),
-            Some(600_000)
-        ); */
-        assert_ok!(Marketplace::cancel_property_purchase(
+        assert_ok!(Marketplace::place_ask(
             RuntimeOrigin::signed([1; 32].into()),
-            0
+            0,
+            1000,
+            1
         ));
-        /*         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_funds
-                .get(&1984)
-                .copied(),
-            Some(300_000)
-        ); */
-        assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .listed_token_amount,
-            70
-        );
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
-            0
+        assert_noop!(
+            Marketplace::cancel_order(RuntimeOrigin::signed([2; 32].into()), 0),
+            Error::<Test>::NoPermission
         );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
     })
 }
 
 #[test]
-fn cancel_property_purchase_fails() {
+fn dutch_auction_price_declines() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -10812,20 +15020,6 @@ fn cancel_property_purchase_fails() {
             [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [3; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_noop!(
-            Marketplace::cancel_property_purchase(RuntimeOrigin::signed([1; 32].into()), 0),
-            Error::<Test>::ListingNotFound
-        );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -10833,39 +15027,77 @@ fn cancel_property_purchase_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::DutchAuction {
+                    floor_price: 5_000,
+                    price_decrement: 1_000,
+                },
+                soft_cap: None
+            }
         ));
+        run_to_block(64);
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            30,
-            1984
+            10,
+            1984,
+            None
         ));
+        System::assert_last_event(
+            Event::PropertyTokenBought {
+                listing_index: 0,
+                asset_id: 0,
+                buyer: [1; 32].into(),
+                amount_purchased: 10,
+                price_paid: 70_000,
+                tax_paid: 2_100,
+                payment_asset: 1984,
+                new_tokens_remaining: 90,
+            }
+            .into(),
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            72_800
+        );
+        run_to_block(80);
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            30,
-            1984
+            10,
+            1984,
+            None
         ));
-        assert_noop!(
-            Marketplace::cancel_property_purchase(RuntimeOrigin::signed([3; 32].into()), 0),
-            Error::<Test>::TokenOwnerNotFound
+        System::assert_last_event(
+            Event::PropertyTokenBought {
+                listing_index: 0,
+                asset_id: 0,
+                buyer: [2; 32].into(),
+                amount_purchased: 10,
+                price_paid: 50_000,
+                tax_paid: 1_500,
+                payment_asset: 1984,
+                new_tokens_remaining: 80,
+            }
+            .into(),
         );
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            40,
-            1984
-        ));
-        assert_noop!(
-            Marketplace::cancel_property_purchase(RuntimeOrigin::signed([1; 32].into()), 0),
-            Error::<Test>::PropertyAlreadySold
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
+            52_000
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([2; 32].into(), 0)
+                .unwrap()
+                .paid_funds
+                .get(&1984),
+            Some(&50_000)
         );
     })
 }
 
 #[test]
-fn cancel_property_purchase_fails_2() {
+fn list_property_with_invalid_dutch_auction_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -10888,16 +15120,97 @@ fn cancel_property_purchase_fails_2() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
+        assert_noop!(
+            Marketplace::list_property(
+                RuntimeOrigin::signed([0; 32].into()),
+                3,
+                bvec![10, 10],
+                10_000,
+                100,
+                bvec![22, 22],
+                crate::SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: crate::SaleMode::DutchAuction {
+                        floor_price: 0,
+                        price_decrement: 1_000,
+                    },
+                    soft_cap: None
+                }
+            ),
+            Error::<Test>::InvalidTokenPrice
+        );
+        assert_noop!(
+            Marketplace::list_property(
+                RuntimeOrigin::signed([0; 32].into()),
+                3,
+                bvec![10, 10],
+                10_000,
+                100,
+                bvec![22, 22],
+                crate::SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: crate::SaleMode::DutchAuction {
+                        floor_price: 20_000,
+                        price_decrement: 1_000,
+                    },
+                    soft_cap: None
+                }
+            ),
+            Error::<Test>::InvalidTokenPrice
+        );
+        assert_noop!(
+            Marketplace::list_property(
+                RuntimeOrigin::signed([0; 32].into()),
+                3,
+                bvec![10, 10],
+                10_000,
+                100,
+                bvec![22, 22],
+                crate::SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: crate::SaleMode::DutchAuction {
+                        floor_price: 5_000,
+                        price_decrement: 0,
+                    },
+                    soft_cap: None
+                }
+            ),
+            Error::<Test>::InvalidTokenPrice
+        );
+    })
+}
+
+#[test]
+fn english_auction_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
+            [8; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
+        for investor in [[1; 32], [2; 32], [30; 32], [31; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                investor.into(),
+                pallet_xcavate_whitelist::Role::RealEstateInvestor
+            ));
+        }
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -10905,30 +15218,127 @@ fn cancel_property_purchase_fails_2() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::EnglishAuction,
+                soft_cap: None
+            }
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::place_auction_bid(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            30,
-            1984
+            12_000,
+            40,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::place_auction_bid(
             RuntimeOrigin::signed([2; 32].into()),
             0,
+            11_000,
             40,
-            1984
+            1984,
+            None
         ));
-        run_to_block(100);
-        assert_noop!(
-            Marketplace::cancel_property_purchase(RuntimeOrigin::signed([1; 32].into()), 0),
-            Error::<Test>::ListingExpired
+        assert_ok!(Marketplace::place_auction_bid(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            10_000,
+            30,
+            1984,
+            None
+        ));
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[30; 32].into()),
+            312_000
+        );
+        assert_ok!(Marketplace::place_auction_bid(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            10_500,
+            20,
+            1984,
+            None
+        ));
+        System::assert_has_event(
+            Event::AuctionBidOutbid {
+                listing_id: 0,
+                bidder: [30; 32].into(),
+                amount: 30,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::AuctionBidPlaced {
+                listing_id: 0,
+                bidder: [31; 32].into(),
+                token_price: 10_500,
+                amount: 20,
+                payment_asset: 1984,
+            }
+            .into(),
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[30; 32].into()),
+            0
+        );
+        let bidders: Vec<AccountId> = AuctionBids::<Test>::get(0)
+            .iter()
+            .map(|bid| bid.bidder.clone())
+            .collect();
+        let expected_bidders: Vec<AccountId> =
+            vec![[1; 32].into(), [2; 32].into(), [31; 32].into()];
+        assert_eq!(bidders, expected_bidders);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[31; 32].into()),
+            218_400
+        );
+
+        run_to_block(92);
+        System::assert_last_event(
+            Event::AuctionSettled {
+                listing_id: 0,
+                asset_id: 0,
+                sold_token_amount: 100,
+                successful: true,
+            }
+            .into(),
+        );
+        assert!(AuctionBids::<Test>::get(0).is_empty());
+        let listing = OngoingObjectListing::<Test>::get(0).unwrap();
+        assert_eq!(listing.listed_token_amount, 0);
+        assert_eq!(listing.unclaimed_token_amount, 100);
+        assert_eq!(listing.claim_expiry, Some(192));
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0)
+                .unwrap()
+                .token_amount,
+            40
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([31; 32].into(), 0)
+                .unwrap()
+                .paid_funds
+                .get(&1984),
+            Some(&210_000)
+        );
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            499_200
+        );
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_eq!(
+            pallet_real_estate_asset::PropertyOwnerToken::<Test>::get(0, AccountId::from([1; 32])),
+            40
         );
     })
 }
 
 #[test]
-fn withdraw_expired_works() {
+fn english_auction_settles_at_the_exchange_rate_of_the_bids() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -10951,10 +15361,44 @@ fn withdraw_expired_works() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        assert_ok!(ForeignAssets::force_create(
+            RuntimeOrigin::root(),
+            codec::Compact(100),
+            sp_runtime::MultiAddress::Id([0; 32].into()),
+            true,
+            1
+        ));
+        assert_ok!(ForeignAssets::force_set_metadata(
+            RuntimeOrigin::root(),
+            codec::Compact(100),
+            "DOT".into(),
+            "DOT".into(),
+            10,
+            false
+        ));
+        for investor in [[1; 32], [2; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                investor.into(),
+                pallet_xcavate_whitelist::Role::RealEstateInvestor
+            ));
+            assert_ok!(ForeignAssets::mint(
+                RuntimeOrigin::signed([0; 32].into()),
+                codec::Compact(100),
+                sp_runtime::MultiAddress::Id(investor.into()),
+                5_000_000_000
+            ));
+        }
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            100,
+            1,
+            true
+        ));
+        assert_ok!(PriceOracle::set_exchange_rate(
+            RuntimeOrigin::root(),
+            100,
+            FixedU128::from_u32(5)
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -10963,63 +15407,78 @@ fn withdraw_expired_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::EnglishAuction,
+                soft_cap: None
+            }
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::place_auction_bid(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            30,
-            1984
+            12_000,
+            50,
+            100,
+            None
+        ));
+        assert_ok!(Marketplace::place_auction_bid(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            10_000,
+            50,
+            100,
+            None
         ));
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .listed_token_amount,
-            70
+            AssetsHolder::total_balance_on_hold(100, &[1; 32].into()),
+            1_248_000_000
         );
         assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0)
-                .unwrap()
-                .token_amount,
-            30
+            AssetsHolder::total_balance_on_hold(100, &[2; 32].into()),
+            1_040_000_000
         );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_188_000);
+
+        // The payment asset loses value and the rate is outdated at settlement.
+        System::set_block_number(50);
+        assert_ok!(PriceOracle::set_exchange_rate(
+            RuntimeOrigin::root(),
+            100,
+            FixedU128::from_u32(2)
+        ));
+        run_to_block(92);
+        System::assert_last_event(
+            Event::AuctionSettled {
+                listing_id: 0,
+                asset_id: 0,
+                sold_token_amount: 100,
+                successful: true,
+            }
+            .into(),
+        );
+        let token_details = TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).unwrap();
+        assert_eq!(token_details.token_amount, 50);
         assert_eq!(
-            ForeignAssets::total_balance(1984, &[1; 32].into()),
-            1_500_000
+            token_details.paid_funds.get(&100).copied(),
+            Some(1_200_000_000)
         );
+        assert_eq!(token_details.paid_tax.get(&100).copied(), Some(36_000_000));
         assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
-            312_000
+            AssetsHolder::total_balance_on_hold(100, &[1; 32].into()),
+            1_248_000_000
         );
-        /*         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .collected_funds
-                .get(&1984)
-                .copied(),
-            Some(300_000)
-        ); */
-        run_to_block(100);
-        assert_ok!(Marketplace::withdraw_expired(
-            RuntimeOrigin::signed([1; 32].into()),
-            0
-        ));
-        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
         assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
+            AssetsHolder::total_balance_on_hold(100, &[2; 32].into()),
+            1_040_000_000
         );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
         assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
-            0
+            ListingPaymentValues::<Test>::get(0, 100),
+            (2_200_000_000, 1_100_000)
         );
     })
 }
 
 #[test]
-fn withdraw_expired_works_2() {
+fn english_auction_without_enough_bids_refunds() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -11044,151 +15503,73 @@ fn withdraw_expired_works_2() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
+            [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [3; 32].into(),
+            [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
             bvec![10, 10],
-            1_000,
+            10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::EnglishAuction,
+                soft_cap: None
+            }
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::place_auction_bid(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            20,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([3; 32].into()),
-            0,
-            4,
-            1984
+            10_000,
+            40,
+            1984,
+            None
         ));
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .listed_token_amount,
-            46
-        );
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0)
-                .unwrap()
-                .token_amount,
-            30
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_468_800);
-        assert_eq!(
-            ForeignAssets::total_balance(1984, &[1; 32].into()),
-            1_500_000
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            416_000
         );
-        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_129_200);
-        assert_eq!(
-            ForeignAssets::total_balance(1984, &[2; 32].into()),
-            1_150_000
+        run_to_block(92);
+        System::assert_last_event(
+            Event::AuctionSettled {
+                listing_id: 0,
+                asset_id: 0,
+                sold_token_amount: 0,
+                successful: false,
+            }
+            .into(),
         );
-        assert_eq!(ForeignAssets::balance(1984, &[3; 32].into()), 840);
-        assert_eq!(ForeignAssets::total_balance(1984, &[3; 32].into()), 5_000);
         assert_eq!(
             AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
-            31_200
-        );
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
-            20_800
+            0
         );
         assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[3; 32].into()),
-            4_160
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_500_000
         );
-        run_to_block(100);
-        assert_ok!(Marketplace::withdraw_expired(
-            RuntimeOrigin::signed([1; 32].into()),
-            0
-        ));
+        assert!(TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).is_none());
         assert_eq!(
             OngoingObjectListing::<Test>::get(0)
                 .unwrap()
                 .listed_token_amount,
-            76
-        );
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
-        );
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([3; 32].into(), 0)
-                .unwrap()
-                .token_amount,
-            4
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
-            0
-        );
-        assert_ok!(Marketplace::withdraw_expired(
-            RuntimeOrigin::signed([2; 32].into()),
-            0
-        ));
-        assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            200_000
-        );
-        assert_eq!(
-            Balances::free_balance(&(Marketplace::property_account_id(0))),
-            99
-        );
-        assert_eq!(
-            Balances::balance(&(Marketplace::property_account_id(0))),
-            99
+            100
         );
-        assert_ok!(Marketplace::withdraw_expired(
-            RuntimeOrigin::signed([3; 32].into()),
+        assert_ok!(Marketplace::withdraw_deposit_unsold(
+            RuntimeOrigin::signed([0; 32].into()),
             0
         ));
-        assert_eq!(
-            Balances::free_balance(&(Marketplace::property_account_id(0))),
-            0
-        );
-        assert_eq!(Balances::balance(&(Marketplace::property_account_id(0))), 0);
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[3; 32].into()), 5_000);
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[3; 32].into()),
-            0
-        );
-        assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            0
-        );
     })
 }
 
 #[test]
-fn withdraw_expired_fails() {
+fn english_auction_rejects_bid_exceeding_ownership_cap() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -11211,25 +15592,13 @@ fn withdraw_expired_fails() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_noop!(
-            Marketplace::withdraw_expired(RuntimeOrigin::signed([1; 32].into()), 0),
-            Error::<Test>::ListingNotFound
-        );
+        for investor in [[1; 32], [2; 32], [30; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                investor.into(),
+                pallet_xcavate_whitelist::Role::RealEstateInvestor
+            ));
+        }
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -11237,40 +15606,80 @@ fn withdraw_expired_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::EnglishAuction,
+                soft_cap: None
+            }
         ));
-        assert_noop!(
-            Marketplace::withdraw_expired(RuntimeOrigin::signed([1; 32].into()), 0),
-            Error::<Test>::ListingNotExpired
-        );
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::place_auction_bid(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
+            10_000,
+            50,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::place_auction_bid(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            30,
-            1984
+            10_000,
+            40,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_ok!(Marketplace::place_auction_bid(
             RuntimeOrigin::signed([30; 32].into()),
             0,
-            30,
-            1984
+            10_000,
+            10,
+            1984,
+            None
         ));
-        run_to_block(100);
-        assert_noop!(
-            Marketplace::withdraw_expired(RuntimeOrigin::signed([1; 32].into()), 0),
-            Error::<Test>::PropertyAlreadySold
+        assert_ok!(Marketplace::set_ownership_cap(
+            RuntimeOrigin::root(),
+            0,
+            Some(Permill::from_percent(40))
+        ));
+        run_to_block(92);
+        System::assert_has_event(
+            Event::AuctionBidRejected {
+                listing_id: 0,
+                bidder: [1; 32].into(),
+                amount: 50,
+                error: Err(Error::<Test>::ExceedsMaxOwnership.into()),
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            Event::AuctionSettled {
+                listing_id: 0,
+                asset_id: 0,
+                sold_token_amount: 50,
+                successful: false,
+            }
+            .into(),
+        );
+        assert!(TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).is_none());
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            0
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_500_000
+        );
+        assert_eq!(
+            TokenOwner::<Test>::get::<AccountId, u32>([2; 32].into(), 0)
+                .unwrap()
+                .token_amount,
+            40
         );
     })
 }
 
 #[test]
-fn withdraw_expired_fails_2() {
+fn english_auction_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -11293,20 +15702,25 @@ fn withdraw_expired_fails_2() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        for investor in [[1; 32], [2; 32], [30; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                investor.into(),
+                pallet_xcavate_whitelist::Role::RealEstateInvestor
+            ));
+        }
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -11315,24 +15729,146 @@ fn withdraw_expired_fails_2() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::EnglishAuction,
+                soft_cap: None
+            }
         ));
-        assert_ok!(Marketplace::buy_property_token(
+        assert_noop!(
+            Marketplace::place_auction_bid(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                10_000,
+                10,
+                1984,
+                None
+            ),
+            Error::<Test>::InvalidSaleMode
+        );
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                1,
+                10,
+                1984,
+                None
+            ),
+            Error::<Test>::InvalidSaleMode
+        );
+        assert_noop!(
+            Marketplace::place_auction_bid(
+                RuntimeOrigin::signed([1; 32].into()),
+                1,
+                9_000,
+                10,
+                1984,
+                None
+            ),
+            Error::<Test>::BidTooLow
+        );
+        assert_noop!(
+            Marketplace::place_auction_bid(
+                RuntimeOrigin::signed([1; 32].into()),
+                1,
+                10_000,
+                51,
+                1984,
+                None
+            ),
+            Error::<Test>::ExceedsMaxOwnership
+        );
+        assert_noop!(
+            Marketplace::place_auction_bid(
+                RuntimeOrigin::signed([1; 32].into()),
+                1,
+                11_000,
+                49,
+                1984,
+                Some(560_559)
+            ),
+            Error::<Test>::SlippageExceeded
+        );
+        assert_ok!(Marketplace::place_auction_bid(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
-            39,
-            1984
+            1,
+            11_000,
+            49,
+            1984,
+            Some(560_560)
         ));
-        run_to_block(100);
         assert_noop!(
-            Marketplace::withdraw_expired(RuntimeOrigin::signed([2; 32].into()), 0),
-            Error::<Test>::TokenOwnerNotFound
+            Marketplace::place_auction_bid(
+                RuntimeOrigin::signed([1; 32].into()),
+                1,
+                12_000,
+                1,
+                1984,
+                None
+            ),
+            Error::<Test>::OnlyOneBidPerUser
+        );
+        assert_ok!(Marketplace::place_auction_bid(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+            11_000,
+            49,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::place_auction_bid(
+            RuntimeOrigin::signed([30; 32].into()),
+            1,
+            10_000,
+            2,
+            1984,
+            None
+        ));
+        assert_noop!(
+            Marketplace::place_auction_bid(
+                RuntimeOrigin::signed([0; 32].into()),
+                1,
+                10_000,
+                1,
+                1984,
+                None
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            Marketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 1, 20_000),
+            Error::<Test>::InvalidSaleMode
+        );
+        assert_noop!(
+            Marketplace::settle_auction(RuntimeOrigin::signed([1; 32].into()), 1),
+            Error::<Test>::ListingNotExpired
+        );
+        assert_noop!(
+            Marketplace::settle_auction(RuntimeOrigin::signed([1; 32].into()), 0),
+            Error::<Test>::InvalidSaleMode
+        );
+        run_to_block(92);
+        assert_noop!(
+            Marketplace::place_auction_bid(
+                RuntimeOrigin::signed([30; 32].into()),
+                1,
+                20_000,
+                1,
+                1984,
+                None
+            ),
+            Error::<Test>::NotEnoughTokenAvailable
+        );
+        assert!(AuctionBids::<Test>::get(1).is_empty());
+        assert_noop!(
+            Marketplace::settle_auction(RuntimeOrigin::signed([1; 32].into()), 1),
+            Error::<Test>::NoAuctionBids
         );
     })
 }
 
 #[test]
-fn send_property_token_works() {
+fn soft_cap_sale_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -11355,48 +15891,28 @@ fn send_property_token_works() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
+        for investor in [[2; 32], [30; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                investor.into(),
+                pallet_xcavate_whitelist::Role::RealEstateInvestor
+            ));
+        }
+        for lawyer in [[10; 32], [11; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                lawyer.into(),
+                pallet_xcavate_whitelist::Role::Lawyer
+            ));
+            assert_ok!(Regions::register_lawyer(
+                RuntimeOrigin::signed(lawyer.into()),
+                3,
+            ));
+        }
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [3; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -11405,38 +15921,61 @@ fn send_property_token_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: Some(600_000)
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            40,
-            1984
+            47,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0,
-            30,
-            1984
+            1984,
+            None
         ));
+        run_to_block(92);
+        System::assert_last_event(
+            Event::SoftCapReached {
+                listing_id: 0,
+                asset_id: 0,
+                sold_token_amount: 77,
+                unsold_token_amount: 23,
+                claim_deadline: 192,
+            }
+            .into(),
+        );
+        let listing = OngoingObjectListing::<Test>::get(0).unwrap();
+        assert_eq!(listing.token_amount, 77);
+        assert_eq!(listing.listed_token_amount, 0);
+        assert_eq!(listing.unclaimed_token_amount, 77);
+        assert_noop!(
+            Marketplace::withdraw_expired(RuntimeOrigin::signed([2; 32].into()), 0),
+            Error::<Test>::PropertyAlreadySold
+        );
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([30; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0
+            0,
         ));
+        assert!(PropertyLawyer::<Test>::get(0).is_some());
+        assert_eq!(
+            OngoingObjectListing::<Test>::get(0)
+                .unwrap()
+                .collected_funds
+                .get(&1984),
+            Some(&770_000)
+        );
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
             0,
@@ -11448,11 +15987,6 @@ fn send_property_token_works() {
             0,
             true
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
         assert_ok!(Marketplace::create_spv(
             RuntimeOrigin::signed([5; 32].into()),
             0,
@@ -11464,88 +15998,41 @@ fn send_property_token_works() {
             4_000,
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
-        ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
             crate::Vote::Yes,
-            30
+            47
         ));
-        run_to_block(91);
+        run_to_block(123);
         assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
         ));
         assert_ok!(Marketplace::lawyer_confirm_documents(
             RuntimeOrigin::signed([10; 32].into()),
             0,
-            true,
-        ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
-        ));
-        assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
-            40
-        );
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 40);
-        assert_eq!(PropertyOwner::<Test>::get(0).len(), 3);
-        assert_ok!(Marketplace::send_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            [2; 32].into(),
-            20
-        ));
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 20);
-        assert_eq!(PropertyOwner::<Test>::get(0).len(), 4);
-        assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
-            20
-        );
-        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 20);
-        assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()),
-            20
-        );
-        assert_ok!(Marketplace::send_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            [3; 32].into(),
-            20
+            true,
         ));
-        assert_ok!(Marketplace::send_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
             0,
-            [3; 32].into(),
-            20
+            true,
         ));
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 0);
-        assert_eq!(PropertyOwner::<Test>::get(0).len(), 3);
-        assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 0);
+        assert!(OngoingObjectListing::<Test>::get(0).is_none());
         assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()),
-            0
+            PropertyOwnerToken::<Test>::get(0, AccountId::from([0; 32])),
+            23
         );
-        assert_eq!(LocalAssets::balance(0, &[3; 32].into()), 40);
+        assert_eq!(LocalAssets::balance(0, AccountId::from([0; 32])), 23);
         assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [3; 32].into()),
-            40
+            LocalAssets::balance(0, Marketplace::property_account_id(0)),
+            0
         );
     })
 }
 
 #[test]
-fn send_property_token_works_2() {
+fn soft_cap_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -11573,177 +16060,243 @@ fn send_property_token_works_2() {
             [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
-        ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
-        ));
+        assert_noop!(
+            Marketplace::list_property(
+                RuntimeOrigin::signed([0; 32].into()),
+                3,
+                bvec![10, 10],
+                10_000,
+                100,
+                bvec![22, 22],
+                crate::SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: crate::SaleMode::FixedPrice,
+                    soft_cap: Some(0)
+                }
+            ),
+            Error::<Test>::InvalidSoftCap
+        );
+        assert_noop!(
+            Marketplace::list_property(
+                RuntimeOrigin::signed([0; 32].into()),
+                3,
+                bvec![10, 10],
+                10_000,
+                100,
+                bvec![22, 22],
+                crate::SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: crate::SaleMode::FixedPrice,
+                    soft_cap: Some(1_000_001)
+                }
+            ),
+            Error::<Test>::InvalidSoftCap
+        );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
             bvec![10, 10],
-            5_000,
-            200,
+            10_000,
+            100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: Some(500_000)
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            80,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            60,
-            1984
+            50,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+        assert_eq!(
+            ListingPaymentValues::<Test>::get(0, 1984),
+            (500_000, 500_000)
+        );
+        assert_ok!(Marketplace::cancel_property_purchase(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            60,
-            1984
+            10
         ));
-        assert_ok!(Marketplace::claim_property_token(
+        assert_eq!(
+            ListingPaymentValues::<Test>::get(0, 1984),
+            (400_000, 400_000)
+        );
+        assert_noop!(
+            Marketplace::finalize_soft_cap(RuntimeOrigin::signed([1; 32].into()), 0),
+            Error::<Test>::ListingNotExpired
+        );
+        run_to_block(92);
+        assert_noop!(
+            Marketplace::finalize_soft_cap(RuntimeOrigin::signed([1; 32].into()), 0),
+            Error::<Test>::SoftCapNotReached
+        );
+        assert_ok!(Marketplace::withdraw_expired(
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0
+        assert!(OngoingObjectListing::<Test>::get(0).is_none());
+        assert_noop!(
+            Marketplace::finalize_soft_cap(RuntimeOrigin::signed([1; 32].into()), 0),
+            Error::<Test>::ListingNotFound
+        );
+    })
+}
+
+#[test]
+fn soft_cap_ignores_developer_ownership_cap() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: Some(200_000)
+            }
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
+        assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::Vote::Yes,
-            80
+            20,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            crate::Vote::Yes,
-            45
+        // The soft cap is met although the 80 unsold token of the developer exceed the
+        // ownership cap.
+        run_to_block(92);
+        System::assert_last_event(
+            Event::SoftCapReached {
+                listing_id: 0,
+                asset_id: 0,
+                sold_token_amount: 20,
+                unsold_token_amount: 80,
+                claim_deadline: 92 + ClaimWindowTime::get(),
+            }
+            .into(),
+        );
+        let listing = OngoingObjectListing::<Test>::get(0).unwrap();
+        assert_eq!(listing.token_amount, 20);
+        assert!(listing.claim_expiry.is_some());
+    })
+}
+
+#[test]
+fn english_auction_with_soft_cap_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
         ));
-        assert_ok!(Marketplace::unfreeze_spv_lawyer_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::EnglishAuction,
+                soft_cap: Some(300_000)
+            }
         ));
-        assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
-            80
-        );
-        assert_ok!(Marketplace::send_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+        assert_ok!(Marketplace::place_auction_bid(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            [1; 32].into(),
-            19
-        ));
-        assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
-            99
-        );
-        assert_noop!(
-            Marketplace::send_property_token(
-                RuntimeOrigin::signed([30; 32].into()),
-                0,
-                [1; 32].into(),
-                19
-            ),
-            Error::<Test>::ExceedsMaxOwnership
+            11_000,
+            50,
+            1984,
+            None
+        ));
+        run_to_block(92);
+        System::assert_has_event(
+            Event::SoftCapReached {
+                listing_id: 0,
+                asset_id: 0,
+                sold_token_amount: 50,
+                unsold_token_amount: 50,
+                claim_deadline: 192,
+            }
+            .into(),
         );
-        assert_noop!(
-            Marketplace::send_property_token(
-                RuntimeOrigin::signed([1; 32].into()),
-                0,
-                [30; 32].into(),
-                80
-            ),
-            Error::<Test>::ExceedsMaxOwnership
+        System::assert_last_event(
+            Event::AuctionSettled {
+                listing_id: 0,
+                asset_id: 0,
+                sold_token_amount: 50,
+                successful: true,
+            }
+            .into(),
         );
-        assert_ok!(Marketplace::send_property_token(
+        let listing = OngoingObjectListing::<Test>::get(0).unwrap();
+        assert_eq!(listing.token_amount, 50);
+        assert_eq!(listing.unclaimed_token_amount, 50);
+        assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0,
-            [30; 32].into(),
-            35
+            0
         ));
+        assert!(PropertyLawyer::<Test>::get(0).is_some());
     })
 }
 
 #[test]
-fn send_property_token_fails() {
+fn set_accepted_asset_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -11766,29 +16319,31 @@ fn send_property_token_fails() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_noop!(
-            Marketplace::send_property_token(
-                RuntimeOrigin::signed([1; 32].into()),
-                0,
-                [2; 32].into(),
-                20
-            ),
-            BadOrigin
-        );
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        assert_ok!(ForeignAssets::force_create(
+            RuntimeOrigin::root(),
+            codec::Compact(1000),
+            sp_runtime::MultiAddress::Id([0; 32].into()),
+            true,
+            1
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        assert_ok!(ForeignAssets::force_set_metadata(
+            RuntimeOrigin::root(),
+            codec::Compact(1000),
+            "USDX".into(),
+            "USDX".into(),
+            6,
+            false
+        ));
+        assert_ok!(ForeignAssets::mint(
+            RuntimeOrigin::signed([0; 32].into()),
+            codec::Compact(1000),
+            sp_runtime::MultiAddress::Id([1; 32].into()),
+            1_000_000
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -11797,155 +16352,354 @@ fn send_property_token_fails() {
             10_000,
             100,
             bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                10,
+                1000,
+                None
+            ),
+            Error::<Test>::PaymentAssetNotSupported
+        );
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            1000,
+            1_000,
             false
         ));
+        System::assert_last_event(
+            Event::AcceptedAssetSet {
+                asset_id: 1000,
+                min_amount: 1_000,
+                uses_exchange_rate: false,
+            }
+            .into(),
+        );
+        assert_eq!(AcceptedPaymentAssets::<Test>::get().len(), 3);
+        assert_eq!(
+            Marketplace::asset_details(1000),
+            Some(AcceptedAssetDetails {
+                min_amount: 1_000,
+                uses_exchange_rate: false
+            })
+        );
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0
+            10,
+            1000,
+            None
         ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1000, &[1; 32].into()),
+            104_000
+        );
+        assert_ok!(Marketplace::remove_accepted_asset(
+            RuntimeOrigin::root(),
+            1000
         ));
+        System::assert_last_event(Event::AcceptedAssetRemoved { asset_id: 1000 }.into());
+        assert_eq!(Marketplace::accepted_assets(), vec![1337, 1984]);
         assert_noop!(
-            Marketplace::send_property_token(
+            Marketplace::buy_property_token(
                 RuntimeOrigin::signed([1; 32].into()),
                 0,
-                [2; 32].into(),
-                20
+                10,
+                1000,
+                None
             ),
-            Error::<Test>::UserNotWhitelisted
+            Error::<Test>::PaymentAssetNotSupported
+        );
+        // Purchases in a removed payment asset can still be refunded.
+        assert_ok!(Marketplace::cancel_property_purchase(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            10
+        ));
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1000, &[1; 32].into()),
+            0
         );
+        assert_eq!(
+            ForeignAssets::balance(1000, AccountId::from([1; 32])),
+            1_000_000
+        );
+    })
+}
+
+#[test]
+fn payment_assets_can_be_replaced_during_listing() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
+            [8; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_noop!(
-            Marketplace::send_property_token(
-                RuntimeOrigin::signed([1; 32].into()),
-                1,
-                [1; 32].into(),
-                20
-            ),
-            Error::<Test>::NoObjectFound
-        );
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
+        for asset_id in [1000, 1001] {
+            assert_ok!(ForeignAssets::force_create(
+                RuntimeOrigin::root(),
+                codec::Compact(asset_id),
+                sp_runtime::MultiAddress::Id([0; 32].into()),
+                true,
+                1
+            ));
+            assert_ok!(ForeignAssets::force_set_metadata(
+                RuntimeOrigin::root(),
+                codec::Compact(asset_id),
+                "USDX".into(),
+                "USDX".into(),
+                6,
+                false
+            ));
+            assert_ok!(ForeignAssets::mint(
+                RuntimeOrigin::signed([0; 32].into()),
+                codec::Compact(asset_id),
+                sp_runtime::MultiAddress::Id([1; 32].into()),
+                1_000_000
+            ));
+        }
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
             3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            1000,
+            1,
+            false
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
+            10,
+            1000,
+            None
         ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
-            0,
-            true
+        assert_ok!(Marketplace::remove_accepted_asset(
+            RuntimeOrigin::root(),
+            1337
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            1001,
+            1,
+            false
         ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
+        // The empty entry of the removed asset makes room for the new payment asset.
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
             0,
+            10,
+            1001,
+            None
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
+        assert_eq!(
+            ListingPaymentValues::<Test>::get(0, 1001),
+            (100_000, 100_000)
+        );
+        let token_details = TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).unwrap();
+        assert_eq!(token_details.token_amount, 20);
+        assert_eq!(token_details.paid_funds.get(&1001).copied(), Some(100_000));
+    })
+}
+
+#[test]
+fn migration_to_v1_moves_accepted_assets_into_storage() {
+    new_test_ext().execute_with(|| {
+        frame_support::parameter_types! {
+            pub InitialAssets: Vec<(u32, u128, bool)> =
+                vec![(1337, 1, false), (1984, 100, false), (1000, 1, false), (1001, 1, false)];
+        }
+        AcceptedPaymentAssets::<Test>::kill();
+        StorageVersion::new(0).put::<Marketplace>();
+        crate::migrations::v1::MigrateV0ToV1::<Test, InitialAssets>::on_runtime_upgrade();
+        assert_eq!(Marketplace::on_chain_storage_version(), 1);
+        assert_eq!(Marketplace::accepted_assets(), vec![1000, 1337, 1984]);
+        assert_eq!(
+            Marketplace::asset_details(1984),
+            Some(AcceptedAssetDetails {
+                min_amount: 100,
+                uses_exchange_rate: false
+            })
+        );
+    })
+}
+
+#[test]
+fn migration_to_v2_translates_ongoing_listings() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            crate::Vote::Yes,
-            30
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
         ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
+        let listing_block = System::block_number();
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
+        let listing = OngoingObjectListing::<Test>::get(0).unwrap();
+        let old_listing = crate::migrations::v2::OldPropertyListingDetails::<_, _, Test> {
+            real_estate_developer: listing.real_estate_developer.clone(),
+            token_price: listing.token_price,
+            collected_funds: listing.collected_funds.clone(),
+            collected_tax: listing.collected_tax.clone(),
+            collected_fees: listing.collected_fees.clone(),
+            asset_id: listing.asset_id,
+            item_id: listing.item_id,
+            collection_id: listing.collection_id,
+            token_amount: listing.token_amount,
+            listed_token_amount: listing.listed_token_amount,
+            tax_paid_by_developer: listing.tax_paid_by_developer,
+            tax: listing.tax,
+            listing_expiry: listing.listing_expiry,
+            investor_funds: listing.investor_funds.clone(),
+            claim_expiry: listing.claim_expiry,
+            relist_count: listing.relist_count,
+            unclaimed_token_amount: listing.unclaimed_token_amount,
+        };
+        frame_support::storage::unhashed::put(
+            &OngoingObjectListing::<Test>::hashed_key_for(0),
+            &old_listing,
+        );
+        StorageVersion::new(1).put::<Marketplace>();
+        crate::migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+        assert_eq!(Marketplace::on_chain_storage_version(), 2);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), Some(listing));
+        let listing = OngoingObjectListing::<Test>::get(0).unwrap();
+        assert_eq!(listing.sale_mode, crate::SaleMode::FixedPrice);
+        assert_eq!(listing.listed_at, listing_block);
+        assert_eq!(listing.soft_cap, None);
+    })
+}
+
+#[test]
+fn set_accepted_asset_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            Marketplace::set_accepted_asset(RuntimeOrigin::signed([0; 32].into()), 1984, 1, false),
+            BadOrigin
+        );
+        assert_noop!(
+            Marketplace::set_accepted_asset(RuntimeOrigin::root(), 1000, 1, false),
+            Error::<Test>::AssetNotSupported
+        );
+        for asset_id in [1000, 1001] {
+            assert_ok!(ForeignAssets::force_create(
+                RuntimeOrigin::root(),
+                codec::Compact(asset_id),
+                sp_runtime::MultiAddress::Id([0; 32].into()),
+                true,
+                1
+            ));
+            assert_ok!(ForeignAssets::force_set_metadata(
+                RuntimeOrigin::root(),
+                codec::Compact(asset_id),
+                "USDX".into(),
+                "USDX".into(),
+                6,
+                false
+            ));
+        }
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            1000,
+            1,
+            false
         ));
         assert_noop!(
-            Marketplace::send_property_token(
-                RuntimeOrigin::signed([2; 32].into()),
-                0,
-                [1; 32].into(),
-                5
-            ),
-            RealEstateAssetError::<Test>::NotEnoughToken
+            Marketplace::set_accepted_asset(RuntimeOrigin::root(), 1001, 1, false),
+            Error::<Test>::TooManyAcceptedAssets
+        );
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            1984,
+            100,
+            false
+        ));
+        assert_eq!(
+            Marketplace::asset_details(1984),
+            Some(AcceptedAssetDetails {
+                min_amount: 100,
+                uses_exchange_rate: false
+            })
         );
         assert_noop!(
-            Marketplace::send_property_token(
-                RuntimeOrigin::signed([2; 32].into()),
-                0,
-                [1; 32].into(),
-                30
-            ),
-            Error::<Test>::ExceedsMaxOwnership
+            Marketplace::remove_accepted_asset(RuntimeOrigin::signed([0; 32].into()), 1000),
+            BadOrigin
+        );
+        assert_noop!(
+            Marketplace::remove_accepted_asset(RuntimeOrigin::root(), 1001),
+            Error::<Test>::PaymentAssetNotSupported
         );
     })
 }
 
 #[test]
-fn send_property_token_fails_if_relist() {
+fn payment_below_minimum_amount_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -11973,38 +16727,123 @@ fn send_property_token_fails_if_relist() {
             [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [3; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            1984,
+            200_000,
+            false
+        ));
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                10,
+                1984,
+                None
+            ),
+            Error::<Test>::PaymentBelowMinimum
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            10,
+            1337,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            20,
+            1984,
+            None
+        ));
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            208_000
+        );
+    })
+}
+
+#[test]
+fn purchase_price_converts_to_asset_decimals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [8; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [10; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [11; 32].into(),
-            pallet_xcavate_whitelist::Role::Lawyer
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([10; 32].into()),
-            3,
+        for (asset_id, decimals) in [(1000, 8), (1001, 1)] {
+            assert_ok!(ForeignAssets::force_create(
+                RuntimeOrigin::root(),
+                codec::Compact(asset_id),
+                sp_runtime::MultiAddress::Id([0; 32].into()),
+                true,
+                1
+            ));
+            assert_ok!(ForeignAssets::force_set_metadata(
+                RuntimeOrigin::root(),
+                codec::Compact(asset_id),
+                "USDX".into(),
+                "USDX".into(),
+                decimals,
+                false
+            ));
+            assert_ok!(ForeignAssets::mint(
+                RuntimeOrigin::signed([0; 32].into()),
+                codec::Compact(asset_id),
+                sp_runtime::MultiAddress::Id([1; 32].into()),
+                100_000_000
+            ));
+        }
+        assert_ok!(Marketplace::remove_accepted_asset(
+            RuntimeOrigin::root(),
+            1984
         ));
-        assert_ok!(Regions::register_lawyer(
-            RuntimeOrigin::signed([11; 32].into()),
-            3,
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            1000,
+            1,
+            false
+        ));
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            1001,
+            1,
+            false
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -12013,118 +16852,201 @@ fn send_property_token_fails_if_relist() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            20,
-            1984
+            10,
+            1337,
+            None
         ));
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1337, &[1; 32].into()),
+            104_000
+        );
+        // The price of 100_000 in the reference unit equals 10_000_000 with 8 decimals.
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
+            RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
+            10,
+            1000,
+            None
         ));
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1000, &[1; 32].into()),
+            10_400_000
+        );
+        // The price of 30_000 in the reference unit is rounded up to 1 with 1 decimal.
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0
-        ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            crate::LegalProperty::RealEstateDeveloperSide,
-            4_000,
-        ));
-        assert_ok!(Marketplace::approve_developer_lawyer(
-            RuntimeOrigin::signed([0; 32].into()),
             0,
-            true
+            3,
+            1001,
+            None
         ));
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1001, &[1; 32].into()),
+            1
+        );
+        let token_details = TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).unwrap();
+        assert_eq!(token_details.token_amount, 23);
+        assert_eq!(token_details.paid_funds.get(&1337).copied(), Some(100_000));
+        assert_eq!(
+            token_details.paid_funds.get(&1000).copied(),
+            Some(10_000_000)
+        );
+        assert_eq!(token_details.paid_funds.get(&1001).copied(), Some(1));
+        assert_eq!(token_details.paid_tax.get(&1000).copied(), Some(300_000));
+    })
+}
 
+#[test]
+fn buy_property_token_with_exchange_rate_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([8; 32].into()),
+            3,
+            bvec![10, 10]
         ));
-        assert_ok!(Marketplace::lawyer_claim_property(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            crate::LegalProperty::SpvSide,
-            4_000,
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            crate::Vote::Yes,
-            40
+        assert_ok!(ForeignAssets::force_create(
+            RuntimeOrigin::root(),
+            codec::Compact(100),
+            sp_runtime::MultiAddress::Id([0; 32].into()),
+            true,
+            1
         ));
-        run_to_block(91);
-        assert_ok!(Marketplace::finalize_spv_lawyer(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
+        assert_ok!(ForeignAssets::force_set_metadata(
+            RuntimeOrigin::root(),
+            codec::Compact(100),
+            "DOT".into(),
+            "DOT".into(),
+            10,
+            false
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([10; 32].into()),
-            0,
-            true,
+        assert_ok!(ForeignAssets::mint(
+            RuntimeOrigin::signed([0; 32].into()),
+            codec::Compact(100),
+            sp_runtime::MultiAddress::Id([1; 32].into()),
+            1_000_000_000
         ));
-        assert_ok!(Marketplace::lawyer_confirm_documents(
-            RuntimeOrigin::signed([11; 32].into()),
-            0,
-            true,
+        assert_ok!(Marketplace::set_accepted_asset(
+            RuntimeOrigin::root(),
+            100,
+            1,
+            true
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        assert_eq!(
-            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
-            20
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                10,
+                100,
+                None
+            ),
+            Error::<Test>::ExchangeRateUnavailable
         );
-        assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 20);
-        assert_eq!(PropertyOwner::<Test>::get(0).len(), 3);
-        assert_ok!(Marketplace::relist_token(
+        assert_ok!(PriceOracle::set_exchange_rate(
+            RuntimeOrigin::root(),
+            100,
+            FixedU128::from_u32(5)
+        ));
+        // The price of 100_000 in the reference unit equals 200_000_000 with a rate of 5.
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                10,
+                100,
+                Some(207_999_999)
+            ),
+            Error::<Test>::SlippageExceeded
+        );
+        assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            1000,
-            15
+            10,
+            100,
+            Some(208_000_000)
         ));
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(100, &[1; 32].into()),
+            208_000_000
+        );
+        let token_details = TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0).unwrap();
+        assert_eq!(
+            token_details.paid_funds.get(&100).copied(),
+            Some(200_000_000)
+        );
+        assert_eq!(token_details.paid_tax.get(&100).copied(), Some(6_000_000));
+        assert_eq!(
+            ListingPaymentValues::<Test>::get(0, 100),
+            (200_000_000, 100_000)
+        );
         assert_noop!(
-            Marketplace::send_property_token(
+            Marketplace::buy_property_token(
                 RuntimeOrigin::signed([1; 32].into()),
                 0,
-                [2; 32].into(),
-                9
+                10,
+                1984,
+                Some(103_999)
             ),
-            RealEstateAssetError::<Test>::NotEnoughToken
+            Error::<Test>::SlippageExceeded
+        );
+        System::set_block_number(72);
+        assert_noop!(
+            Marketplace::buy_property_token(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                10,
+                100,
+                None
+            ),
+            Error::<Test>::ExchangeRateUnavailable
         );
     })
 }
 
 #[test]
-fn withdraw_deposit_unsold_works() {
+fn expired_rounds_are_processed_on_initialize() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -12147,40 +17069,101 @@ fn withdraw_deposit_unsold_works() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        for investor in [[1; 32], [2; 32], [30; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                investor.into(),
+                pallet_xcavate_whitelist::Role::RealEstateInvestor
+            ));
+        }
+        for _ in 0..2 {
+            assert_ok!(Marketplace::list_property(
+                RuntimeOrigin::signed([0; 32].into()),
+                3,
+                bvec![10, 10],
+                10_000,
+                100,
+                bvec![22, 22],
+                crate::SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: crate::SaleMode::FixedPrice,
+                    soft_cap: None
+                }
+            ));
+        }
+        assert_eq!(
+            ListingRoundsExpiring::<Test>::get(92).into_inner(),
+            vec![0, 1]
+        );
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            10,
+            1984,
+            None
         ));
-        assert_ok!(Marketplace::list_property(
-            RuntimeOrigin::signed([0; 32].into()),
-            3,
-            bvec![10, 10],
-            10_000,
-            100,
-            bvec![22, 22],
-            false
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
+            40,
+            1984,
+            None
         ));
-        assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            200_000
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            1,
+            20,
+            1984,
+            None
+        ));
+        assert_eq!(ClaimRoundsExpiring::<Test>::get(162).into_inner(), vec![1]);
+        run_to_block(92);
+        System::assert_last_event(
+            Event::ListingExpired {
+                listing_id: 0,
+                unsold_token_amount: 90,
+            }
+            .into(),
         );
-        run_to_block(100);
-        assert_ok!(Marketplace::withdraw_deposit_unsold(
-            RuntimeOrigin::signed([0; 32].into()),
-            0
+        assert!(ListingRoundsExpiring::<Test>::get(92).is_empty());
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            1,
         ));
-        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
-        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            1,
+        ));
+        run_to_block(162);
+        System::assert_last_event(
+            Event::UnclaimedRelisted {
+                listing_id: 1,
+                amount: 20,
+                relist_count: 1,
+            }
+            .into(),
+        );
+        let listing = OngoingObjectListing::<Test>::get(1).unwrap();
+        assert_eq!(listing.listed_token_amount, 20);
+        assert_eq!(listing.claim_expiry, None);
+        assert_eq!(listing.listing_expiry, 262);
         assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            0
+            ListingRoundsExpiring::<Test>::get(263).into_inner(),
+            vec![1]
         );
+        assert!(ClaimRoundsExpiring::<Test>::get(162).is_empty());
     })
 }
 
 #[test]
-fn withdraw_deposit_unsold_fails() {
+fn expiry_moves_to_next_block_when_round_is_full() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -12203,16 +17186,9 @@ fn withdraw_deposit_unsold_fails() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
+        let full_round: frame_support::BoundedVec<ListingId, MaxListingForBlock> =
+            (100..200).collect::<Vec<_>>().try_into().unwrap();
+        ListingRoundsExpiring::<Test>::insert(92, full_round);
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -12220,37 +17196,30 @@ fn withdraw_deposit_unsold_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            200_000
-        );
-        run_to_block(20);
-        assert_noop!(
-            Marketplace::withdraw_deposit_unsold(RuntimeOrigin::signed([0; 32].into()), 0),
-            Error::<Test>::ListingNotExpired
-        );
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            10,
-            1984
-        ));
-        assert_noop!(
-            Marketplace::withdraw_deposit_unsold(RuntimeOrigin::signed([0; 32].into()), 1),
-            Error::<Test>::ListingNotFound
+            OngoingObjectListing::<Test>::get(0).unwrap().listing_expiry,
+            91
         );
-        run_to_block(100);
-        assert_noop!(
-            Marketplace::withdraw_deposit_unsold(RuntimeOrigin::signed([0; 32].into()), 0),
-            Error::<Test>::TokenNotReturned
+        assert_eq!(ListingRoundsExpiring::<Test>::get(93).into_inner(), vec![0]);
+        run_to_block(93);
+        System::assert_last_event(
+            Event::ListingExpired {
+                listing_id: 0,
+                unsold_token_amount: 100,
+            }
+            .into(),
         );
     })
 }
 
 #[test]
-fn withdraw_deposit_unsold_fails_2() {
+fn expiry_processing_carries_listings_over_the_weight_limit() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -12273,26 +17242,12 @@ fn withdraw_deposit_unsold_fails_2() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [0; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
+        ExpiryProcessingWeight::set(
+            <Test as crate::Config>::WeightInfo::finalize_soft_cap().saturating_mul(2),
+        );
+        let round: frame_support::BoundedVec<ListingId, MaxListingForBlock> =
+            vec![100, 101].try_into().unwrap();
+        ListingRoundsExpiring::<Test>::insert(92, round);
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -12300,42 +17255,37 @@ fn withdraw_deposit_unsold_fails_2() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_eq!(
-            Balances::balance_on_hold(&HoldReason::ListingDepositReserve.into(), &([0; 32].into())),
-            200_000
+            ListingRoundsExpiring::<Test>::get(92).into_inner(),
+            vec![100, 101, 0]
         );
-        run_to_block(20);
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        run_to_block(100);
-        assert_noop!(
-            Marketplace::withdraw_deposit_unsold(RuntimeOrigin::signed([0; 32].into()), 0),
-            Error::<Test>::PropertyAlreadySold
+        run_to_block(92);
+        assert!(ListingRoundsExpiring::<Test>::get(92).is_empty());
+        assert_eq!(ListingRoundsExpiring::<Test>::get(93).into_inner(), vec![0]);
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Marketplace(Event::ListingExpired { .. })
+        )));
+        run_to_block(93);
+        System::assert_last_event(
+            Event::ListingExpired {
+                listing_id: 0,
+                unsold_token_amount: 100,
+            }
+            .into(),
         );
+        assert!(ListingRoundsExpiring::<Test>::get(93).is_empty());
     })
 }
 
-// withdraw_unclaimed function
 #[test]
-fn withdraw_unclaimed_works() {
+fn process_refunds_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -12358,21 +17308,13 @@ fn withdraw_unclaimed_works() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
+        for investor in [[1; 32], [2; 32], [30; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                investor.into(),
+                pallet_xcavate_whitelist::Role::RealEstateInvestor
+            ));
+        }
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -12380,75 +17322,86 @@ fn withdraw_unclaimed_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
+        for investor in [[1; 32], [2; 32], [30; 32]] {
+            assert_ok!(Marketplace::buy_property_token(
+                RuntimeOrigin::signed(investor.into()),
+                0,
+                10,
+                1984,
+                None
+            ));
+        }
+        assert_eq!(ListingTokenOwners::<Test>::iter_prefix(0).count(), 3);
         assert_eq!(
-            OngoingObjectListing::<Test>::get(0)
-                .unwrap()
-                .listed_token_amount,
-            0
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_396_000
+        );
+        run_to_block(100);
+        assert_ok!(Marketplace::process_refunds(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+            2
+        ));
+        System::assert_last_event(
+            Event::RefundsProcessed {
+                listing_id: 0,
+                refunded_investors: 2,
+                completed: false,
+            }
+            .into(),
         );
+        assert_eq!(ListingTokenOwners::<Test>::iter_prefix(0).count(), 1);
         assert_eq!(
             OngoingObjectListing::<Test>::get(0)
                 .unwrap()
-                .unclaimed_token_amount,
-            30
+                .listed_token_amount,
+            90
         );
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
-        assert_ok!(Marketplace::finalize_claim_window(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(Marketplace::process_refunds(
+            RuntimeOrigin::signed([5; 32].into()),
             0,
+            10
         ));
-        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 838_000);
-        assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
-            312_000
+        System::assert_last_event(
+            Event::RefundsProcessed {
+                listing_id: 0,
+                refunded_investors: 1,
+                completed: true,
+            }
+            .into(),
         );
-        assert!(TokenOwner::<Test>::get::<AccountId, u32>([2; 32].into(), 0).is_some());
-        assert_ok!(Marketplace::withdraw_unclaimed(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_150_000);
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_eq!(ListingDeposits::<Test>::get(0), None);
+        assert_eq!(PropertyAssetInfo::<Test>::get(0), None);
+        for investor in [[1; 32], [2; 32], [30; 32]] {
+            assert_eq!(
+                TokenOwner::<Test>::get::<AccountId, u32>(investor.into(), 0),
+                None
+            );
+            assert_eq!(
+                AssetsHolder::total_balance_on_hold(1984, &investor.into()),
+                0
+            );
+        }
         assert_eq!(
-            AssetsHolder::total_balance_on_hold(1984, &[2; 32].into()),
-            0
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_500_000
         );
-        assert!(TokenOwner::<Test>::get::<AccountId, u32>([2; 32].into(), 0).is_none());
+        assert_eq!(ListingTokenOwners::<Test>::iter_prefix(0).count(), 0);
     })
 }
 
 #[test]
-fn withdraw_unclaimed_fails() {
+fn process_refunds_releases_the_configured_fee() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
+        MarketplaceFeePercentage::set(5);
         assert_ok!(XcavateWhitelist::add_admin(
             RuntimeOrigin::root(),
             [20; 32].into(),
@@ -12474,16 +17427,6 @@ fn withdraw_unclaimed_fails() {
             [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -12491,62 +17434,47 @@ fn withdraw_unclaimed_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            30,
-            1984
+            10,
+            1984,
+            None
         ));
-        assert_noop!(
-            Marketplace::withdraw_unclaimed(RuntimeOrigin::signed([2; 32].into()), 0,),
-            Error::<Test>::TokenOwnerNotFound
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_392_000
         );
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_noop!(
-            Marketplace::withdraw_unclaimed(RuntimeOrigin::signed([2; 32].into()), 0,),
-            Error::<Test>::NoPermission
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            108_000
         );
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
+        run_to_block(100);
+        assert_ok!(Marketplace::process_refunds(
+            RuntimeOrigin::signed([5; 32].into()),
             0,
+            10
         ));
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
-        assert_noop!(
-            Marketplace::withdraw_unclaimed(RuntimeOrigin::signed([2; 32].into()), 0,),
-            Error::<Test>::NoPermission
+        assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+        assert_eq!(
+            AssetsHolder::total_balance_on_hold(1984, &[1; 32].into()),
+            0
         );
-        assert_ok!(Marketplace::finalize_claim_window(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::withdraw_unclaimed(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_noop!(
-            Marketplace::withdraw_unclaimed(RuntimeOrigin::signed([2; 32].into()), 0,),
-            Error::<Test>::TokenOwnerNotFound
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_500_000
         );
     })
 }
 
-// withdraw_claiming_expired function
 #[test]
-fn withdraw_claiming_expired_works() {
+fn process_refunds_claimed_token_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -12569,21 +17497,13 @@ fn withdraw_claiming_expired_works() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
+        for investor in [[1; 32], [2; 32], [30; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                investor.into(),
+                pallet_xcavate_whitelist::Role::RealEstateInvestor
+            ));
+        }
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
@@ -12591,25 +17511,32 @@ fn withdraw_claiming_expired_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -12621,10 +17548,6 @@ fn withdraw_claiming_expired_works() {
         ));
         let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
         run_to_block(expiry);
-        assert_ok!(Marketplace::finalize_claim_window(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
         assert_ok!(Marketplace::withdraw_unclaimed(
             RuntimeOrigin::signed([2; 32].into()),
             0,
@@ -12633,48 +17556,56 @@ fn withdraw_claiming_expired_works() {
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
         run_to_block(expiry);
-        assert_ok!(Marketplace::finalize_claim_window(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_eq!(RefundClaimedToken::<Test>::get(0).unwrap(), 70);
+        assert_ok!(Marketplace::process_refunds(
+            RuntimeOrigin::signed([5; 32].into()),
             0,
+            1
         ));
-        assert_eq!(RefundClaimedToken::<Test>::get(0).unwrap(), 70);
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_084_000);
+        System::assert_last_event(
+            Event::RefundsProcessed {
+                listing_id: 0,
+                refunded_investors: 1,
+                completed: false,
+            }
+            .into(),
+        );
+        assert_eq!(RefundClaimedToken::<Test>::get(0).unwrap(), 30);
         assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
-            728_000
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_500_000
         );
-        assert_ok!(Marketplace::withdraw_claiming_expired(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::withdraw_claiming_expired(
-            RuntimeOrigin::signed([30; 32].into()),
+        assert_ok!(Marketplace::process_refunds(
+            RuntimeOrigin::signed([5; 32].into()),
             0,
+            10
         ));
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_500_000);
+        System::assert_last_event(
+            Event::RefundsProcessed {
+                listing_id: 0,
+                refunded_investors: 1,
+                completed: true,
+            }
+            .into(),
+        );
+        assert_eq!(RefundClaimedToken::<Test>::get(0), None);
         assert_eq!(
-            ForeignAssets::balance(1984, &Marketplace::property_account_id(0)),
+            ForeignAssets::balance(1984, Marketplace::property_account_id(0)),
             0
         );
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).is_none(), true);
         assert_eq!(OngoingObjectListing::<Test>::get(0), None);
-        assert_eq!(
-            TokenOwner::<Test>::get::<AccountId, u32>([1; 32].into(), 0),
-            None
-        );
-        assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
-        assert_eq!(Balances::balance(&(Marketplace::property_account_id(0))), 0);
-        assert_eq!(Nfts::owner(0, 0), None);
         assert_eq!(PropertyAssetInfo::<Test>::get(0), None);
+        assert_eq!(Balances::balance(&(Marketplace::property_account_id(0))), 0);
     })
 }
 
 #[test]
-fn withdraw_claiming_expired_fails() {
+fn process_refunds_fails() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -12697,24 +17628,9 @@ fn withdraw_claiming_expired_fails() {
             [0; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateDeveloper
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [1; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [2; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_noop!(
-            Marketplace::withdraw_claiming_expired(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::TokenNotRefunded
+            Marketplace::process_refunds(RuntimeOrigin::signed([5; 32].into()), 0, 10),
+            Error::<Test>::ListingNotFound
         );
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -12723,67 +17639,20 @@ fn withdraw_claiming_expired_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-            40,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-            30,
-            1984
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
-            0,
-        ));
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
-        assert_ok!(Marketplace::finalize_claim_window(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::withdraw_unclaimed(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-        ));
-        assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([2; 32].into()),
-            0,
-            30,
-            1984
+            crate::SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: crate::SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
-        let expiry = frame_system::Pallet::<Test>::block_number() + ClaimWindowTime::get() + 1;
-        run_to_block(expiry);
         assert_noop!(
-            Marketplace::withdraw_claiming_expired(RuntimeOrigin::signed([1; 32].into()), 0,),
-            Error::<Test>::TokenNotRefunded
+            Marketplace::process_refunds(RuntimeOrigin::signed([5; 32].into()), 0, 10),
+            Error::<Test>::ListingNotExpired
         );
-        assert_ok!(Marketplace::finalize_claim_window(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
+        run_to_block(100);
         assert_noop!(
-            Marketplace::withdraw_claiming_expired(RuntimeOrigin::signed([0; 32].into()), 0,),
-            BadOrigin
+            Marketplace::process_refunds(RuntimeOrigin::signed([5; 32].into()), 0, 10),
+            Error::<Test>::NoRefundsPending
         );
-        assert_ok!(Marketplace::withdraw_claiming_expired(
-            RuntimeOrigin::signed([1; 32].into()),
-            0,
-        ));
     })
 }
@@ -6,6 +6,7 @@ use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::Permill;
 use frame_support::{sp_runtime::RuntimeDebug, DefaultNoBound};
 use scale_info::TypeInfo;
+use sp_std::vec::Vec;
 
 /// Infos regarding the listing of a real estate object.
 #[derive(Encode, Decode, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
@@ -65,6 +66,22 @@ pub struct PropertyListingDetails<NftId, NftCollectionId, T: Config> {
     pub claim_expiry: Option<BlockNumberFor<T>>,
     pub relist_count: u8,
     pub unclaimed_token_amount: u32,
+    pub sale_mode: SaleMode<<T as pallet::Config>::Balance>,
+    pub listed_at: BlockNumberFor<T>,
+    pub soft_cap: Option<<T as pallet::Config>::Balance>,
+}
+
+/// Infos regarding a bid in an english auction of a property.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct AuctionBid<T: Config> {
+    pub bidder: AccountIdOf<T>,
+    pub token_price: <T as pallet::Config>::Balance,
+    pub amount: u32,
+    pub payment_asset: u32,
+    pub held_amount: <T as pallet::Config>::Balance,
+    pub transfer_price: <T as pallet::Config>::Balance,
+    pub tax: <T as pallet::Config>::Balance,
 }
 
 /// Infos regarding the listing of a token.
@@ -177,6 +194,32 @@ where
     }
 }
 
+/// Infos regarding an order in the order book of a property.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct OrderDetails<T: Config> {
+    pub owner: AccountIdOf<T>,
+    pub asset_id: u32,
+    pub side: OrderSide,
+    pub token_price: <T as pallet::Config>::Balance,
+    pub amount: u32,
+    pub payment_asset: Option<u32>,
+    pub created_at: BlockNumberFor<T>,
+}
+
+impl<T: Config> OrderDetails<T>
+where
+    <T as pallet::Config>::Balance: CheckedMul + TryFrom<u128>,
+{
+    pub fn get_total_amount(&self) -> Result<<T as pallet::Config>::Balance, Error<T>> {
+        let amount_in_balance: <T as pallet::Config>::Balance = (self.amount as u128).into();
+
+        self.token_price
+            .checked_mul(&amount_in_balance)
+            .ok_or(Error::<T>::MultiplyError)
+    }
+}
+
 #[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct ProposedDeveloperLawyer<T: Config> {
@@ -210,6 +253,24 @@ pub struct VoteRecord {
     pub power: u32,
 }
 
+/// Details of a payment asset accepted by the marketplace.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    RuntimeDebug,
+    TypeInfo,
+)]
+pub struct AcceptedAssetDetails<Balance> {
+    pub min_amount: Balance,
+    pub uses_exchange_rate: bool,
+}
+
 /// Represents a payout to a single entity, broken down by currency.
 #[derive(
     Encode,
@@ -224,15 +285,17 @@ pub struct VoteRecord {
 )]
 #[scale_info(skip_type_params(T))]
 pub struct Payout<T: Config> {
-    pub amount_in_usdc: <T as pallet::Config>::Balance,
-    pub amount_in_usdt: <T as pallet::Config>::Balance,
+    pub amounts: BoundedBTreeMap<
+        u32,
+        <T as pallet::Config>::Balance,
+        <T as pallet::Config>::MaxAcceptedAssets,
+    >,
 }
 
 impl<T: Config> Default for Payout<T> {
     fn default() -> Self {
         Self {
-            amount_in_usdc: Zero::zero(),
-            amount_in_usdt: Zero::zero(),
+            amounts: Default::default(),
         }
     }
 }
@@ -297,6 +360,73 @@ pub enum Offer {
     Reject,
 }
 
+/// Sale mode of the primary sale of a property.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    RuntimeDebug,
+    TypeInfo,
+)]
+pub enum SaleMode<Balance> {
+    /// Token are sold at the fixed token price.
+    FixedPrice,
+    /// The token price declines every block by `price_decrement` until `floor_price` is reached.
+    DutchAuction {
+        floor_price: Balance,
+        price_decrement: Balance,
+    },
+    /// Investors bid on token with ascending prices until the listing expires.
+    EnglishAuction,
+}
+
+/// Terms of the primary sale of a property.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    RuntimeDebug,
+    TypeInfo,
+)]
+pub struct SaleTerms<Balance> {
+    /// Bool if the tax is paid by the real estate developer or not.
+    pub tax_paid_by_developer: bool,
+    /// The sale mode of the primary sale.
+    pub sale_mode: SaleMode<Balance>,
+    /// The minimum amount in the reference unit that has to be raised for the sale to proceed.
+    /// If `None` all token have to be sold.
+    pub soft_cap: Option<Balance>,
+}
+
+/// Side of an order in the order book.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    RuntimeDebug,
+    TypeInfo,
+)]
+pub enum OrderSide {
+    Bid,
+    Ask,
+}
+
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[derive(
     Encode,
@@ -348,6 +478,15 @@ pub enum DocumentStatus {
 pub enum Vote {
     Yes,
     No,
+    Withdraw,
+}
+
+/// Round of a listing that gets processed once it expired.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum ExpiryRound {
+    Listing,
+    ClaimWindow,
+    LegalProcess,
 }
 
 /// AccountId storage.
@@ -356,3 +495,73 @@ pub enum Vote {
 pub struct PalletIdStorage<T: Config> {
     pallet_id: AccountIdOf<T>,
 }
+
+/// Overview of an ongoing property listing returned by the runtime api.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ListingInfo<AccountId, Balance, BlockNumber> {
+    pub listing_id: ListingId,
+    pub real_estate_developer: AccountId,
+    pub asset_id: u32,
+    pub token_price: Balance,
+    pub token_amount: u32,
+    pub listed_token_amount: u32,
+    pub tax_paid_by_developer: bool,
+    pub tax: Permill,
+    pub listing_expiry: BlockNumber,
+    pub claim_expiry: Option<BlockNumber>,
+    pub sale_mode: SaleMode<Balance>,
+    pub soft_cap: Option<Balance>,
+}
+
+/// Overview of a relisted token listing returned by the runtime api.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TokenListingInfo<AccountId, Balance> {
+    pub listing_id: ListingId,
+    pub seller: AccountId,
+    pub asset_id: u32,
+    pub token_price: Balance,
+    pub amount: u32,
+}
+
+/// Funds raised by a listing in a single payment asset.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PaymentAssetFunding<Balance> {
+    pub payment_asset: u32,
+    pub funds: Balance,
+    pub fees: Balance,
+    pub tax: Balance,
+}
+
+/// Funding progress of an ongoing property listing.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ListingFunding<Balance> {
+    pub listing_id: ListingId,
+    pub token_amount: u32,
+    pub sold_token_amount: u32,
+    pub unclaimed_token_amount: u32,
+    pub funding: Vec<PaymentAssetFunding<Balance>>,
+}
+
+/// An open offer on a token listing.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OfferInfo<AccountId, Balance> {
+    pub offeror: AccountId,
+    pub token_price: Balance,
+    pub amount: u32,
+    pub payment_asset: u32,
+}
+
+/// Unclaimed purchase of an account in a property listing.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PurchaseInfo<Balance> {
+    pub listing_id: ListingId,
+    pub token_amount: u32,
+    pub paid_funds: Vec<(u32, Balance)>,
+    pub paid_tax: Vec<(u32, Balance)>,
+}
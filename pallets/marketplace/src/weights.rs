@@ -59,6 +59,16 @@ pub trait WeightInfo {
 	fn remove_lawyer_claim() -> Weight;
 	fn lawyer_confirm_documents(a: u32, ) -> Weight;
 	fn send_property_token() -> Weight;
+	fn place_bid(n: u32, ) -> Weight;
+	fn place_ask(n: u32, ) -> Weight;
+	fn cancel_order() -> Weight;
+	fn place_auction_bid(n: u32, ) -> Weight;
+	fn settle_auction(n: u32, ) -> Weight;
+	fn finalize_soft_cap() -> Weight;
+	fn set_accepted_asset() -> Weight;
+	fn remove_accepted_asset() -> Weight;
+	fn process_refunds(n: u32, ) -> Weight;
+	fn set_ownership_cap() -> Weight;
 }
 
 /// Weight functions for `pallet_marketplace`.
@@ -1032,4 +1042,286 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(13))
 			.saturating_add(T::DbWeight::get().writes(10))
 	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyAssetInfo` (r:1 w:0)
+	/// Proof: `RealEstateAsset::PropertyAssetInfo` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyOwnerToken` (r:2 w:2)
+	/// Proof: `RealEstateAsset::PropertyOwnerToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NextOrderId` (r:1 w:1)
+	/// Proof: `Marketplace::NextOrderId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AssetAsks` (r:1 w:1)
+	/// Proof: `Marketplace::AssetAsks` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AssetBids` (r:1 w:1)
+	/// Proof: `Marketplace::AssetBids` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Orders` (r:100 w:101)
+	/// Proof: `Marketplace::Orders` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:3 w:3)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::Holds` (r:1 w:1)
+	/// Proof: `AssetsHolder::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::BalancesOnHold` (r:1 w:1)
+	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAssets::Asset` (r:1 w:1)
+	/// Proof: `RealEstateAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAssets::Account` (r:2 w:2)
+	/// Proof: `RealEstateAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyOwner` (r:1 w:1)
+	/// Proof: `RealEstateAsset::PropertyOwner` (`max_values`: None, `max_size`: Some(8022), added: 10497, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn place_bid(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `16871 + n * (126 ±0)`
+		//  Estimated: `11487 + n * (2574 ±0)`
+		// Minimum execution time: 471_203_000 picoseconds.
+		Weight::from_parts(392_518_407, 0)
+			.saturating_add(Weight::from_parts(0, 11487))
+			// Standard Error: 61_904
+			.saturating_add(Weight::from_parts(143_771_310, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(13))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2574).saturating_mul(n.into()))
+	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyAssetInfo` (r:1 w:0)
+	/// Proof: `RealEstateAsset::PropertyAssetInfo` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAssets::Asset` (r:1 w:1)
+	/// Proof: `RealEstateAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAssets::Account` (r:2 w:2)
+	/// Proof: `RealEstateAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NextOrderId` (r:1 w:1)
+	/// Proof: `Marketplace::NextOrderId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AssetBids` (r:1 w:1)
+	/// Proof: `Marketplace::AssetBids` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AssetAsks` (r:1 w:1)
+	/// Proof: `Marketplace::AssetAsks` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Orders` (r:100 w:101)
+	/// Proof: `Marketplace::Orders` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyOwnerToken` (r:2 w:2)
+	/// Proof: `RealEstateAsset::PropertyOwnerToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:3 w:3)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::Holds` (r:1 w:1)
+	/// Proof: `AssetsHolder::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::BalancesOnHold` (r:1 w:1)
+	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyOwner` (r:1 w:1)
+	/// Proof: `RealEstateAsset::PropertyOwner` (`max_values`: None, `max_size`: Some(8022), added: 10497, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn place_ask(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `16512 + n * (126 ±0)`
+		//  Estimated: `11487 + n * (2574 ±0)`
+		// Minimum execution time: 489_627_000 picoseconds.
+		Weight::from_parts(405_120_774, 0)
+			.saturating_add(Weight::from_parts(0, 11487))
+			// Standard Error: 64_218
+			.saturating_add(Weight::from_parts(158_904_612, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(14))
+			.saturating_add(T::DbWeight::get().writes((10_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2574).saturating_mul(n.into()))
+	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Orders` (r:1 w:1)
+	/// Proof: `Marketplace::Orders` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AssetBids` (r:1 w:1)
+	/// Proof: `Marketplace::AssetBids` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::Holds` (r:1 w:1)
+	/// Proof: `AssetsHolder::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::BalancesOnHold` (r:1 w:1)
+	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `15214`
+		//  Estimated: `4287`
+		// Minimum execution time: 196_350_000 picoseconds.
+		Weight::from_parts(211_487_000, 0)
+			.saturating_add(Weight::from_parts(0, 4287))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OngoingObjectListing` (r:1 w:0)
+	/// Proof: `Marketplace::OngoingObjectListing` (`max_values`: None, `max_size`: Some(28769), added: 31244, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyAssetInfo` (r:1 w:0)
+	/// Proof: `RealEstateAsset::PropertyAssetInfo` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Regions::RegionDetails` (r:1 w:0)
+	/// Proof: `Regions::RegionDetails` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAssets::Account` (r:1 w:0)
+	/// Proof: `RealEstateAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::TokenOwner` (r:1 w:0)
+	/// Proof: `Marketplace::TokenOwner` (`max_values`: None, `max_size`: Some(155), added: 2630, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AuctionBids` (r:1 w:1)
+	/// Proof: `Marketplace::AuctionBids` (`max_values`: None, `max_size`: Some(18002), added: 20477, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:250 w:250)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::Holds` (r:250 w:250)
+	/// Proof: `AssetsHolder::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::BalancesOnHold` (r:250 w:250)
+	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 250]`.
+	fn place_auction_bid(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2913 + n * (247 ±0)`
+		//  Estimated: `32234 + n * (2609 ±0)`
+		// Minimum execution time: 118_402_000 picoseconds.
+		Weight::from_parts(109_236_518, 0)
+			.saturating_add(Weight::from_parts(0, 32234))
+			// Standard Error: 21_517
+			.saturating_add(Weight::from_parts(38_120_946, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(n.into()))
+	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OngoingObjectListing` (r:1 w:1)
+	/// Proof: `Marketplace::OngoingObjectListing` (`max_values`: None, `max_size`: Some(28769), added: 31244, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AuctionBids` (r:1 w:1)
+	/// Proof: `Marketplace::AuctionBids` (`max_values`: None, `max_size`: Some(18002), added: 20477, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyAssetInfo` (r:1 w:0)
+	/// Proof: `RealEstateAsset::PropertyAssetInfo` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Regions::RegionDetails` (r:1 w:0)
+	/// Proof: `Regions::RegionDetails` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAssets::Account` (r:250 w:0)
+	/// Proof: `RealEstateAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::TokenOwner` (r:250 w:250)
+	/// Proof: `Marketplace::TokenOwner` (`max_values`: None, `max_size`: Some(155), added: 2630, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:250 w:250)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::Holds` (r:250 w:250)
+	/// Proof: `AssetsHolder::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::BalancesOnHold` (r:250 w:250)
+	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[3, 250]`.
+	fn settle_auction(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3127 + n * (362 ±0)`
+		//  Estimated: `32234 + n * (2630 ±0)`
+		// Minimum execution time: 131_825_000 picoseconds.
+		Weight::from_parts(97_604_331, 0)
+			.saturating_add(Weight::from_parts(0, 32234))
+			// Standard Error: 34_908
+			.saturating_add(Weight::from_parts(61_372_415, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2630).saturating_mul(n.into()))
+	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OngoingObjectListing` (r:1 w:1)
+	/// Proof: `Marketplace::OngoingObjectListing` (`max_values`: None, `max_size`: Some(28773), added: 31248, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AuctionBids` (r:1 w:0)
+	/// Proof: `Marketplace::AuctionBids` (`max_values`: None, `max_size`: Some(18002), added: 20477, mode: `MaxEncodedLen`)
+	fn finalize_soft_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `19946`
+		//  Estimated: `32238`
+		// Minimum execution time: 398_214_000 picoseconds.
+		Weight::from_parts(437_905_000, 0)
+			.saturating_add(Weight::from_parts(0, 32238))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AcceptedPaymentAssets` (r:1 w:1)
+	/// Proof: `Marketplace::AcceptedPaymentAssets` (`max_values`: Some(1), `max_size`: Some(106), added: 601, mode: `MaxEncodedLen`)
+	fn set_accepted_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3675`
+		// Minimum execution time: 14_802_000 picoseconds.
+		Weight::from_parts(15_391_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Marketplace::AcceptedPaymentAssets` (r:1 w:1)
+	/// Proof: `Marketplace::AcceptedPaymentAssets` (`max_values`: Some(1), `max_size`: Some(106), added: 601, mode: `MaxEncodedLen`)
+	fn remove_accepted_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `181`
+		//  Estimated: `1591`
+		// Minimum execution time: 8_937_000 picoseconds.
+		Weight::from_parts(9_412_000, 0)
+			.saturating_add(Weight::from_parts(0, 1591))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Marketplace::OngoingObjectListing` (r:1 w:1)
+	/// Proof: `Marketplace::OngoingObjectListing` (`max_values`: None, `max_size`: Some(28769), added: 31244, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::RefundToken` (r:1 w:0)
+	/// Proof: `Marketplace::RefundToken` (`max_values`: None, `max_size`: Some(1163), added: 3638, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::RefundLegalExpired` (r:1 w:0)
+	/// Proof: `Marketplace::RefundLegalExpired` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::RefundClaimedToken` (r:1 w:0)
+	/// Proof: `Marketplace::RefundClaimedToken` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AcceptedPaymentAssets` (r:1 w:0)
+	/// Proof: `Marketplace::AcceptedPaymentAssets` (`max_values`: Some(1), `max_size`: Some(106), added: 601, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::ListingTokenOwners` (r:251 w:250)
+	/// Proof: `Marketplace::ListingTokenOwners` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::TokenOwner` (r:250 w:250)
+	/// Proof: `Marketplace::TokenOwner` (`max_values`: None, `max_size`: Some(155), added: 2630, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::Holds` (r:500 w:500)
+	/// Proof: `AssetsHolder::Holds` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::BalancesOnHold` (r:500 w:500)
+	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:500 w:500)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 250]`.
+	fn process_refunds(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1402 + n * (302 ±0)`
+		//  Estimated: `4628 + n * (5218 ±0)`
+		// Minimum execution time: 61_304_000 picoseconds.
+		Weight::from_parts(48_912_377, 0)
+			.saturating_add(Weight::from_parts(0, 4628))
+			// Standard Error: 41_275
+			.saturating_add(Weight::from_parts(64_380_511, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+	}
+	/// Storage: `RealEstateAsset::PropertyAssetInfo` (r:1 w:0)
+	/// Proof: `RealEstateAsset::PropertyAssetInfo` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OwnershipCap` (r:1 w:1)
+	/// Proof: `Marketplace::OwnershipCap` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_ownership_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3528`
+		// Minimum execution time: 14_118_000 picoseconds.
+		Weight::from_parts(14_703_000, 0)
+			.saturating_add(Weight::from_parts(0, 3528))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
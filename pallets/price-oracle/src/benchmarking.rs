@@ -0,0 +1,43 @@
+//! Benchmarking setup for pallet-price-oracle
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as PriceOracle;
+use frame_benchmarking::v2::*;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_exchange_rate() {
+        let rate = FixedU128::from_rational(52, 10);
+
+        #[extrinsic_call]
+        set_exchange_rate(RawOrigin::Root, 100, rate);
+
+        assert_eq!(
+            ExchangeRates::<T>::get(100).map(|details| details.rate),
+            Some(rate)
+        );
+    }
+
+    #[benchmark]
+    fn remove_exchange_rate() {
+        assert_ok!(PriceOracle::<T>::set_exchange_rate(
+            RawOrigin::Root.into(),
+            100,
+            FixedU128::from_rational(52, 10)
+        ));
+
+        #[extrinsic_call]
+        remove_exchange_rate(RawOrigin::Root, 100);
+
+        assert!(!ExchangeRates::<T>::contains_key(100));
+    }
+
+    impl_benchmark_test_suite!(PriceOracle, crate::mock::new_test_ext(), crate::mock::Test);
+}
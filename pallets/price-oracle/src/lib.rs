@@ -0,0 +1,148 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use frame_support::{
+    sp_runtime::{
+        traits::{Saturating, Zero},
+        FixedU128,
+    },
+    traits::Get,
+};
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Infos regarding the exchange rate of an asset.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+    pub struct ExchangeRateDetails<BlockNumber> {
+        pub rate: FixedU128,
+        pub updated_at: BlockNumber,
+    }
+
+    /// Configure the pallet by specifying the parameters and types on which it depends.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Because this pallet emits events, it depends on the runtime's definition of an event.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Type representing the weight of this pallet.
+        type WeightInfo: WeightInfo;
+        /// Origin who can feed the exchange rates.
+        type FeederOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The amount of blocks after which an exchange rate is considered outdated.
+        #[pallet::constant]
+        type MaxRateAge: Get<BlockNumberFor<Self>>;
+    }
+
+    /// Mapping of the asset id to the exchange rate of the asset.
+    #[pallet::storage]
+    pub type ExchangeRates<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, ExchangeRateDetails<BlockNumberFor<T>>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The exchange rate of an asset has been updated.
+        ExchangeRateUpdated { asset_id: u32, rate: FixedU128 },
+        /// The exchange rate of an asset has been removed.
+        ExchangeRateRemoved { asset_id: u32 },
+    }
+
+    // Errors inform users that something went wrong.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The exchange rate must be greater than zero.
+        InvalidExchangeRate,
+        /// There is no exchange rate for this asset.
+        ExchangeRateNotFound,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the exchange rate of an asset.
+        ///
+        /// The origin must be the FeederOrigin.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset that gets the exchange rate updated.
+        /// - `rate`: The value of one whole unit of the asset in the reference unit.
+        ///
+        /// Emits `ExchangeRateUpdated` event when successful.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_exchange_rate())]
+        pub fn set_exchange_rate(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            rate: FixedU128,
+        ) -> DispatchResult {
+            T::FeederOrigin::ensure_origin(origin)?;
+            ensure!(!rate.is_zero(), Error::<T>::InvalidExchangeRate);
+            ExchangeRates::<T>::insert(
+                asset_id,
+                ExchangeRateDetails {
+                    rate,
+                    updated_at: <frame_system::Pallet<T>>::block_number(),
+                },
+            );
+            Self::deposit_event(Event::<T>::ExchangeRateUpdated { asset_id, rate });
+            Ok(())
+        }
+
+        /// Remove the exchange rate of an asset.
+        ///
+        /// The origin must be the FeederOrigin.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset that gets the exchange rate removed.
+        ///
+        /// Emits `ExchangeRateRemoved` event when successful.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_exchange_rate())]
+        pub fn remove_exchange_rate(origin: OriginFor<T>, asset_id: u32) -> DispatchResult {
+            T::FeederOrigin::ensure_origin(origin)?;
+            ensure!(
+                ExchangeRates::<T>::contains_key(asset_id),
+                Error::<T>::ExchangeRateNotFound
+            );
+            ExchangeRates::<T>::remove(asset_id);
+            Self::deposit_event(Event::<T>::ExchangeRateRemoved { asset_id });
+            Ok(())
+        }
+    }
+}
+
+pub trait ExchangeRateProvider {
+    /// Returns the value of one whole unit of the asset in the reference unit.
+    fn exchange_rate(asset_id: u32) -> Option<FixedU128>;
+}
+
+impl ExchangeRateProvider for () {
+    fn exchange_rate(_asset_id: u32) -> Option<FixedU128> {
+        None
+    }
+}
+
+impl<T: Config> ExchangeRateProvider for Pallet<T> {
+    fn exchange_rate(asset_id: u32) -> Option<FixedU128> {
+        let details = ExchangeRates::<T>::get(asset_id)?;
+        let rate_age = <frame_system::Pallet<T>>::block_number().saturating_sub(details.updated_at);
+        (rate_age <= T::MaxRateAge::get()).then_some(details.rate)
+    }
+}
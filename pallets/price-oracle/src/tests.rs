@@ -0,0 +1,118 @@
+use crate::{mock::*, Error, Event, ExchangeRateDetails, ExchangeRateProvider, ExchangeRates};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::BadOrigin, FixedU128};
+
+#[test]
+fn set_exchange_rate_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let rate = FixedU128::from_rational(52, 10);
+        assert_ok!(PriceOracle::set_exchange_rate(
+            RuntimeOrigin::root(),
+            100,
+            rate
+        ));
+        System::assert_last_event(
+            Event::ExchangeRateUpdated {
+                asset_id: 100,
+                rate,
+            }
+            .into(),
+        );
+        assert_eq!(
+            ExchangeRates::<Test>::get(100),
+            Some(ExchangeRateDetails {
+                rate,
+                updated_at: 1
+            })
+        );
+        assert_eq!(PriceOracle::exchange_rate(100), Some(rate));
+        System::set_block_number(5);
+        assert_ok!(PriceOracle::set_exchange_rate(
+            RuntimeOrigin::root(),
+            100,
+            FixedU128::from_u32(6)
+        ));
+        assert_eq!(
+            ExchangeRates::<Test>::get(100),
+            Some(ExchangeRateDetails {
+                rate: FixedU128::from_u32(6),
+                updated_at: 5
+            })
+        );
+    });
+}
+
+#[test]
+fn set_exchange_rate_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            PriceOracle::set_exchange_rate(RuntimeOrigin::signed(1), 100, FixedU128::from_u32(5)),
+            BadOrigin
+        );
+        assert_noop!(
+            PriceOracle::set_exchange_rate(RuntimeOrigin::root(), 100, FixedU128::from_u32(0)),
+            Error::<Test>::InvalidExchangeRate
+        );
+    });
+}
+
+#[test]
+fn remove_exchange_rate_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(PriceOracle::set_exchange_rate(
+            RuntimeOrigin::root(),
+            100,
+            FixedU128::from_u32(5)
+        ));
+        assert_ok!(PriceOracle::remove_exchange_rate(
+            RuntimeOrigin::root(),
+            100
+        ));
+        System::assert_last_event(Event::ExchangeRateRemoved { asset_id: 100 }.into());
+        assert_eq!(ExchangeRates::<Test>::get(100), None);
+        assert_eq!(PriceOracle::exchange_rate(100), None);
+    });
+}
+
+#[test]
+fn remove_exchange_rate_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(PriceOracle::set_exchange_rate(
+            RuntimeOrigin::root(),
+            100,
+            FixedU128::from_u32(5)
+        ));
+        assert_noop!(
+            PriceOracle::remove_exchange_rate(RuntimeOrigin::signed(1), 100),
+            BadOrigin
+        );
+        assert_noop!(
+            PriceOracle::remove_exchange_rate(RuntimeOrigin::root(), 101),
+            Error::<Test>::ExchangeRateNotFound
+        );
+    });
+}
+
+#[test]
+fn outdated_exchange_rate_is_not_provided() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(PriceOracle::set_exchange_rate(
+            RuntimeOrigin::root(),
+            100,
+            FixedU128::from_u32(5)
+        ));
+        System::set_block_number(11);
+        assert_eq!(
+            PriceOracle::exchange_rate(100),
+            Some(FixedU128::from_u32(5))
+        );
+        System::set_block_number(12);
+        assert_eq!(PriceOracle::exchange_rate(100), None);
+        assert_eq!(<() as ExchangeRateProvider>::exchange_rate(100), None);
+    });
+}
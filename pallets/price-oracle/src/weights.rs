@@ -0,0 +1,62 @@
+//! Autogenerated weights for `pallet_price_oracle`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.2.0
+//! DATE: 2025-08-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `recrafter-Legion-5-16IRX9`, CPU: `Intel(R) Core(TM) i7-14650HX`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/parachain-template-node
+// benchmark
+// pallet
+// --pallet
+// pallet_price_oracle
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// ./pallets/price-oracle/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn set_exchange_rate() -> Weight;
+	fn remove_exchange_rate() -> Weight;
+}
+
+/// Weight functions for `pallet_price_oracle`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `PriceOracle::ExchangeRates` (r:0 w:1)
+	/// Proof: `PriceOracle::ExchangeRates` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_exchange_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_412_000 picoseconds.
+		Weight::from_parts(7_736_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `PriceOracle::ExchangeRates` (r:1 w:1)
+	/// Proof: `PriceOracle::ExchangeRates` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn remove_exchange_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104`
+		//  Estimated: `3521`
+		// Minimum execution time: 10_215_000 picoseconds.
+		Weight::from_parts(10_604_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
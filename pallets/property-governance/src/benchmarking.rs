@@ -7,11 +7,11 @@ use crate::Pallet as PropertyGovernance;
 use frame_benchmarking::v2::*;
 use frame_support::sp_runtime::{Permill, Saturating};
 use frame_support::traits::fungibles::InspectFreeze;
-use frame_support::traits::{fungible::Mutate, Hooks};
+use frame_support::traits::{fungible::Mutate, EnsureOrigin, Hooks};
 use frame_support::BoundedVec;
 use frame_support::{assert_ok, traits::Get};
 use frame_system::{Pallet as System, RawOrigin};
-use pallet_marketplace::types::LegalProperty;
+use pallet_marketplace::types::{LegalProperty, SaleMode, SaleTerms};
 use pallet_marketplace::Pallet as Marketplace;
 use pallet_property_management::Pallet as PropertyManagement;
 use pallet_regions::Pallet as Regions;
@@ -144,10 +144,14 @@ fn list_and_sell_property<T: Config>(
         token_price,
         token_amount,
         metadata,
-        tax_paid_by_developer,
+        SaleTerms {
+            tax_paid_by_developer,
+            sale_mode: SaleMode::FixedPrice,
+            soft_cap: None
+        }
     ));
     let listing_id = 0;
-    let payment_asset = <T as pallet_marketplace::Config>::AcceptedAssets::get()[0];
+    let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
     let buyer: T::AccountId = account("buyer", 0, 0);
     assert_ok!(
         <T as pallet_marketplace::Config>::NativeCurrency::mint_into(
@@ -179,6 +183,7 @@ fn list_and_sell_property<T: Config>(
         listing_id,
         1,
         payment_asset,
+        None,
     ));
 
     claim_buyers_property_token::<T>(token_amount - 1, listing_id);
@@ -304,7 +309,7 @@ fn add_buyers_to_listing<T: Config + pallet_marketplace::Config>(
 
     for i in 1..=buyers {
         let buyer: T::AccountId = account("buyer", i, i);
-        let payment_asset_buyers = <T as pallet_marketplace::Config>::AcceptedAssets::get()[0];
+        let payment_asset_buyers = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
         assert_ok!(
             <T as pallet_marketplace::Config>::NativeCurrency::mint_into(
                 &buyer,
@@ -327,7 +332,8 @@ fn add_buyers_to_listing<T: Config + pallet_marketplace::Config>(
             RawOrigin::Signed(buyer).into(),
             0,
             1,
-            payment_asset_buyers
+            payment_asset_buyers,
+            None
         ));
     }
 }
@@ -410,6 +416,15 @@ fn run_to_block<T: Config>(new_block: frame_system::pallet_prelude::BlockNumberF
     }
 }
 
+fn fill_delegators<T: Config>(delegate: &T::AccountId, scope: DelegationScope) {
+    let mut delegators = BoundedVec::default();
+    for i in 1..<T as pallet::Config>::MaxDelegators::get() {
+        let delegator: T::AccountId = account("delegator", i, i);
+        delegators.try_push(delegator).unwrap();
+    }
+    Delegators::<T>::insert(delegate, scope, delegators);
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -436,13 +451,18 @@ mod benchmarks {
 
         let data = BoundedVec::try_from("Proposal".as_bytes().to_vec()).unwrap();
 
-        let proposal_amount = T::LowProposal::get().saturating_mul(2_u32.into());
+        let proposal_amount = T::HighProposal::get();
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
 
         #[extrinsic_call]
         propose(
             RawOrigin::Signed(letting_agent.clone()),
             asset_id,
-            proposal_amount,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: letting_agent.clone(),
+                amount: proposal_amount,
+                payment_asset,
+            },
             data.clone(),
         );
 
@@ -495,12 +515,17 @@ mod benchmarks {
             set_letting_agent::<T>(region_id, location.clone(), 0, token_owner.clone(), admin);
 
         let data = BoundedVec::try_from("Proposal".as_bytes().to_vec()).unwrap();
-        let proposal_amount = T::LowProposal::get().saturating_mul(2_u32.into());
+        let proposal_amount = T::HighProposal::get();
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
 
         assert_ok!(PropertyGovernance::<T>::propose(
             RawOrigin::Signed(letting_agent.clone()).into(),
             asset_id,
-            proposal_amount,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: letting_agent.clone(),
+                amount: proposal_amount,
+                payment_asset,
+            },
             data.clone(),
         ));
         let token_amount = pallet_real_estate_asset::PropertyOwnerToken::<T>::get(0, &token_owner);
@@ -546,12 +571,17 @@ mod benchmarks {
             set_letting_agent::<T>(region_id, location.clone(), 0, token_owner.clone(), admin);
 
         let data = BoundedVec::try_from("Proposal".as_bytes().to_vec()).unwrap();
-        let proposal_amount = T::LowProposal::get().saturating_mul(2_u32.into());
+        let proposal_amount = T::HighProposal::get();
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
 
         assert_ok!(PropertyGovernance::<T>::propose(
             RawOrigin::Signed(letting_agent.clone()).into(),
             asset_id,
-            proposal_amount,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: letting_agent.clone(),
+                amount: proposal_amount,
+                payment_asset,
+            },
             data.clone(),
         ));
         let token_amount = pallet_real_estate_asset::PropertyOwnerToken::<T>::get(0, &token_owner);
@@ -907,7 +937,7 @@ mod benchmarks {
             &bidder_2,
             total_funds
         ));
-        let payment_asset = <T as pallet::Config>::AcceptedAssets::get()[0];
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
         let auction_amount: <T as pallet::Config>::Balance = 100_000_000_000u128.into();
         assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
             payment_asset,
@@ -990,7 +1020,7 @@ mod benchmarks {
             &bidder,
             total_funds
         ));
-        let payment_asset = <T as pallet::Config>::AcceptedAssets::get()[0];
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
         let auction_amount: <T as pallet::Config>::Balance = 100_000_000_000u128.into();
         assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
             payment_asset,
@@ -1091,7 +1121,7 @@ mod benchmarks {
             &bidder,
             total_funds
         ));
-        let payment_asset = <T as pallet::Config>::AcceptedAssets::get()[0];
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
         let auction_amount: <T as pallet::Config>::Balance = 100_000_000_000u128.into();
         assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
             payment_asset,
@@ -1188,7 +1218,7 @@ mod benchmarks {
             &bidder,
             total_funds
         ));
-        let payment_asset = <T as pallet::Config>::AcceptedAssets::get()[0];
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
         let auction_amount: <T as pallet::Config>::Balance = 100_000_000_000u128.into();
         assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
             payment_asset,
@@ -1304,7 +1334,7 @@ mod benchmarks {
             &bidder,
             total_funds
         ));
-        let payment_asset = <T as pallet::Config>::AcceptedAssets::get()[0];
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
         let auction_amount: <T as pallet::Config>::Balance = 100_000_000_000u128.into();
         assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
             payment_asset,
@@ -1425,6 +1455,204 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn delegate_voting_power() {
+        let (region_owner, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(region_owner.clone(), admin.clone());
+        let (token_owner, asset_id) =
+            create_registered_property::<T>(region_owner, region_id, location, admin);
+        let delegator: T::AccountId = account("buyer", 1, 1);
+        let previous_delegate: T::AccountId = account("buyer", 2, 2);
+        let scope = DelegationScope::Property(asset_id);
+        assert_ok!(PropertyGovernance::<T>::delegate_voting_power(
+            RawOrigin::Signed(delegator.clone()).into(),
+            scope.clone(),
+            previous_delegate.clone(),
+        ));
+        fill_delegators::<T>(&token_owner, scope.clone());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(delegator.clone()),
+            scope.clone(),
+            token_owner.clone(),
+        );
+
+        assert_eq!(
+            Delegations::<T>::get(&delegator, &scope),
+            Some(token_owner.clone())
+        );
+        assert!(Delegators::<T>::get(&token_owner, &scope).contains(&delegator));
+        assert!(Delegators::<T>::get(&previous_delegate, &scope).is_empty());
+    }
+
+    #[benchmark]
+    fn revoke_delegation() {
+        let (region_owner, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(region_owner.clone(), admin.clone());
+        let (token_owner, asset_id) =
+            create_registered_property::<T>(region_owner, region_id, location, admin);
+        let delegator: T::AccountId = account("buyer", 1, 1);
+        let scope = DelegationScope::Property(asset_id);
+        fill_delegators::<T>(&token_owner, scope.clone());
+        assert_ok!(PropertyGovernance::<T>::delegate_voting_power(
+            RawOrigin::Signed(delegator.clone()).into(),
+            scope.clone(),
+            token_owner.clone(),
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(delegator.clone()), scope.clone());
+
+        assert!(Delegations::<T>::get(&delegator, &scope).is_none());
+        assert!(!Delegators::<T>::get(&token_owner, &scope).contains(&delegator));
+    }
+
+    #[benchmark]
+    fn cast_delegated_vote() {
+        let (region_owner, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(region_owner.clone(), admin.clone());
+        let (token_owner, asset_id) =
+            create_registered_property::<T>(region_owner, region_id, location, admin);
+        let delegator: T::AccountId = account("buyer", 1, 1);
+        assert_ok!(PropertyGovernance::<T>::delegate_voting_power(
+            RawOrigin::Signed(delegator.clone()).into(),
+            DelegationScope::Property(asset_id),
+            token_owner.clone(),
+        ));
+        let mut vote_stats = VoteStats {
+            yes_voting_power: 0,
+            no_voting_power: 0,
+        };
+
+        #[block]
+        {
+            assert_ok!(PropertyGovernance::<T>::cast_delegated_votes::<
+                UserProposalVote<T>,
+            >(
+                &token_owner,
+                asset_id,
+                0,
+                &crate::Vote::Yes,
+                &mut vote_stats,
+                &MarketplaceFreezeReason::ProposalVoting,
+            ));
+        }
+
+        let voting_power = pallet_real_estate_asset::PropertyOwnerToken::<T>::get(0, &delegator);
+        assert_eq!(vote_stats.yes_voting_power, voting_power);
+        assert!(UserProposalVote::<T>::get(0, &delegator).unwrap().delegated);
+    }
+
+    #[benchmark]
+    fn set_proposal_requirements() -> Result<(), BenchmarkError> {
+        let requirements = VotingRequirements {
+            quorum: Percent::from_percent(60),
+            threshold: Percent::from_percent(75),
+        };
+        let origin = <T as pallet::Config>::ProposalRequirementsOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            ProposalCategory::TokenBuyback,
+            requirements.clone(),
+        );
+
+        assert_eq!(
+            ProposalRequirements::<T>::get(ProposalCategory::TokenBuyback),
+            Some(requirements)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn sell_token_to_buyback() {
+        let (region_owner, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(region_owner.clone(), admin.clone());
+        let (_, asset_id) =
+            create_registered_property::<T>(region_owner, region_id, location, admin);
+        let seller: T::AccountId = account("buyer", 1, 1);
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
+        let price_per_token: <T as pallet::Config>::Balance = 1_000u128.into();
+        TokenBuybacks::<T>::insert(
+            asset_id,
+            TokenBuybackInfo::<T> {
+                price_per_token,
+                payment_asset,
+                remaining_token: 2,
+            },
+        );
+        let reserve_amount: <T as pallet_property_management::Config>::Balance = 2_000u128.into();
+        pallet_property_management::PropertyReserve::<T>::insert(
+            asset_id,
+            payment_asset,
+            reserve_amount,
+        );
+        assert_ok!(
+            <T as pallet_property_management::Config>::ForeignCurrency::mint_into(
+                payment_asset,
+                &PropertyManagement::<T>::property_account_id(asset_id),
+                reserve_amount
+            )
+        );
+        assert_ok!(Marketplace::<T>::unfreeze_spv_lawyer_token(
+            RawOrigin::Signed(seller.clone()).into(),
+            0
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(seller.clone()), asset_id, 1);
+
+        assert_eq!(
+            pallet_real_estate_asset::PropertyOwnerToken::<T>::get(asset_id, &seller),
+            0
+        );
+        assert_eq!(
+            TokenBuybacks::<T>::get(asset_id).unwrap().remaining_token,
+            1
+        );
+    }
+
+    #[benchmark]
+    fn propose_call() {
+        let (region_owner, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(region_owner.clone(), admin.clone());
+        let (token_owner, asset_id) = create_registered_property::<T>(
+            region_owner.clone(),
+            region_id,
+            location.clone(),
+            admin.clone(),
+        );
+        let letting_agent =
+            set_letting_agent::<T>(region_id, location.clone(), 0, token_owner, admin);
+
+        let expiry_block = <System<T>>::block_number().saturating_add(T::VotingTime::get());
+        let mut proposals = BoundedVec::default();
+        for i in 1..T::MaxVotesForBlock::get() {
+            proposals.try_push(i).unwrap();
+        }
+        ProposalRoundsExpiring::<T>::insert(expiry_block, proposals);
+
+        let data = BoundedVec::try_from("Proposal".as_bytes().to_vec()).unwrap();
+        let call: <T as pallet::Config>::RuntimeCall =
+            pallet_property_management::Call::<T>::dismiss_letting_agent { asset_id }.into();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(letting_agent.clone()),
+            asset_id,
+            Box::new(call),
+            data.clone(),
+        );
+
+        let proposal_id = 0;
+        assert!(Proposals::<T>::contains_key(proposal_id));
+        assert!(ProposalCalls::<T>::contains_key(proposal_id));
+        assert!(ProposalRoundsExpiring::<T>::get(expiry_block).contains(&asset_id));
+    }
+
     impl_benchmark_test_suite!(
         PropertyGovernance,
         crate::mock::new_test_ext(),
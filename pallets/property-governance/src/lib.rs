@@ -10,17 +10,22 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
 use frame_support::{
-    sp_runtime::{traits::AccountIdConversion, Percent, Saturating},
+    dispatch::{extract_actual_weight, DispatchClass, GetDispatchInfo, PostDispatchInfo},
+    sp_runtime::{
+        traits::{AccountIdConversion, BlakeTwo256, Dispatchable, Hash},
+        Percent, Permill, Saturating,
+    },
     traits::{
         fungible::{BalancedHold, Credit},
         fungibles::{Mutate as FungiblesMutate, MutateFreeze, MutateHold as FungiblesMutateHold},
         tokens::{fungible, fungibles},
         tokens::{imbalance::OnUnbalanced, Balance, Precision, Preservation},
-        EnsureOriginWithArg,
+        Contains, EnsureOriginWithArg,
     },
     PalletId,
 };
@@ -35,10 +40,15 @@ use pallet_real_estate_asset::traits::{
 
 use pallet_regions::{LawyerManagement, Pallet as PalletRegions};
 
+use pallet_marketplace::AcceptedAssetRegistry;
+
 use pallet_xcavate_whitelist::Role;
 
+use scale_info::prelude::{boxed::Box, vec::Vec};
+
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type RuntimeHoldReasonOf<T> = <T as pallet_property_management::Config>::RuntimeHoldReason;
+pub type ProposalKindOf<T> = ProposalKind<AccountIdOf<T>, <T as Config>::Balance>;
 
 pub type NegativeImbalanceOf<T> =
     Credit<<T as frame_system::Config>::AccountId, <T as Config>::NativeCurrency>;
@@ -49,7 +59,11 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Proposal with the proposal Details.
@@ -58,11 +72,140 @@ pub mod pallet {
     #[scale_info(skip_type_params(T))]
     pub struct Proposal<T: Config> {
         pub proposer: AccountIdOf<T>,
-        pub amount: <T as pallet::Config>::Balance,
+        pub kind: ProposalKindOf<T>,
         pub created_at: BlockNumberFor<T>,
         pub metadata: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>,
     }
 
+    /// Kind of a proposal and the effect that it has once it passes.
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        RuntimeDebug,
+        TypeInfo,
+    )]
+    pub enum ProposalKind<AccountId, Balance> {
+        /// Pays the amount out of the property reserve to the beneficiary, e.g. a contractor.
+        CapitalExpenditure {
+            beneficiary: AccountId,
+            amount: Balance,
+            payment_asset: u32,
+        },
+        /// Sets the rent of the property.
+        RentChange { rent: Balance },
+        /// Sets the financing terms of the property.
+        Refinancing {
+            principal: Balance,
+            interest_rate: Permill,
+        },
+        /// Sets the share of the income that the letting agent can charge as a fee.
+        LettingAgentFeeChange { fee: Percent },
+        /// Opens a buyback of property token that is paid out of the property reserve.
+        TokenBuyback {
+            token_amount: u32,
+            price_per_token: Balance,
+            payment_asset: u32,
+        },
+        /// Sets the share of the distributed income that is added to the property reserve.
+        ReservePolicyChange { reserve_share: Percent },
+        /// Dispatches a call with the property collective origin of the property.
+        Dispatch { call_hash: [u8; 32] },
+    }
+
+    impl<AccountId, Balance> ProposalKind<AccountId, Balance> {
+        /// Returns the category of the proposal kind.
+        pub fn category(&self) -> ProposalCategory {
+            match self {
+                ProposalKind::CapitalExpenditure { .. } => ProposalCategory::CapitalExpenditure,
+                ProposalKind::RentChange { .. } => ProposalCategory::RentChange,
+                ProposalKind::Refinancing { .. } => ProposalCategory::Refinancing,
+                ProposalKind::LettingAgentFeeChange { .. } => {
+                    ProposalCategory::LettingAgentFeeChange
+                }
+                ProposalKind::TokenBuyback { .. } => ProposalCategory::TokenBuyback,
+                ProposalKind::ReservePolicyChange { .. } => ProposalCategory::ReservePolicyChange,
+                ProposalKind::Dispatch { .. } => ProposalCategory::Dispatch,
+            }
+        }
+    }
+
+    /// Category of a proposal kind.
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        RuntimeDebug,
+        TypeInfo,
+    )]
+    pub enum ProposalCategory {
+        CapitalExpenditure,
+        RentChange,
+        Refinancing,
+        LettingAgentFeeChange,
+        TokenBuyback,
+        ReservePolicyChange,
+        Dispatch,
+    }
+
+    /// Votes that a proposal needs to pass.
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        RuntimeDebug,
+        TypeInfo,
+    )]
+    pub struct VotingRequirements {
+        /// The share of the token supply that has to vote.
+        pub quorum: Percent,
+        /// The share of the token supply that has to vote yes.
+        pub threshold: Percent,
+    }
+
+    /// Buyback of property token that is paid out of the property reserve.
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct TokenBuybackInfo<T: Config> {
+        pub price_per_token: <T as pallet::Config>::Balance,
+        pub payment_asset: u32,
+        pub remaining_token: u32,
+    }
+
+    /// Origin of the token holders of a property.
+    #[pallet::origin]
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        RuntimeDebug,
+        TypeInfo,
+    )]
+    pub enum Origin {
+        /// The token holders of a property, acting through a passed proposal.
+        PropertyCollective(u32),
+    }
+
     /// Sale proposal with the proposal Details.
     #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
     #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
@@ -97,6 +240,7 @@ pub mod pallet {
     pub enum Vote {
         Yes,
         No,
+        Withdraw,
     }
 
     /// Current status of the sale process.
@@ -134,6 +278,46 @@ pub mod pallet {
         pub no_voting_power: u32,
     }
 
+    /// Type of a finished voting.
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+    pub enum ProposalType {
+        /// A proposal of the letting agent.
+        Proposal(ProposalCategory),
+        /// A challenge against the letting agent.
+        Challenge,
+        /// A proposal to sell the property.
+        Sale,
+    }
+
+    /// Outcome of a finished voting.
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+    pub enum ProposalOutcome {
+        /// The voting passed and its effect has been executed.
+        Executed,
+        /// The voting passed but executing its effect failed.
+        ExecutionFailed,
+        /// The voting did not reach the quorum or had more no than yes votes.
+        Rejected,
+        /// The voting did not reach the yes vote threshold.
+        ThresholdNotReached,
+    }
+
+    /// Record of a finished voting that is kept in the proposal history of a property.
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+    pub struct ProposalRecord<BlockNumber> {
+        pub proposal_id: ProposalId,
+        pub proposal_type: ProposalType,
+        pub outcome: ProposalOutcome,
+        pub yes_voting_power: u32,
+        pub no_voting_power: u32,
+        /// The share of the token supply that voted.
+        pub turnout: Permill,
+        pub finished_at: BlockNumber,
+    }
+
     /// Voting stats.
     #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
     #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
@@ -180,6 +364,28 @@ pub mod pallet {
         pub vote: Vote,
         pub asset_id: u32,
         pub power: u32,
+        /// Whether the vote has been cast by a delegate.
+        pub delegated: bool,
+    }
+
+    /// Scope for which an account delegates its voting power.
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        RuntimeDebug,
+        TypeInfo,
+    )]
+    pub enum DelegationScope {
+        /// Delegation for a single property.
+        Property(u32),
+        /// Delegation for all properties without a property delegation.
+        Global,
     }
 
     #[pallet::config]
@@ -200,6 +406,7 @@ pub mod pallet {
             + TypeInfo
             + From<u128>
             + Into<<Self as pallet_real_estate_asset::Config>::Balance>
+            + Into<<Self as pallet_property_management::Config>::Balance>
             + Default;
 
         /// The reservable currency type.
@@ -264,10 +471,6 @@ pub mod pallet {
         #[pallet::constant]
         type HighThreshold: Get<Percent>;
 
-        /// Proposal amount to be considered a low proposal.
-        #[pallet::constant]
-        type LowProposal: Get<<Self as pallet::Config>::Balance>;
-
         /// Proposal amount to be considered a high proposal.
         #[pallet::constant]
         type HighProposal: Get<<Self as pallet::Config>::Balance>;
@@ -287,8 +490,8 @@ pub mod pallet {
         /// Handler for the unbalanced reduction when slashing a letting agent.
         type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
-        #[pallet::constant]
-        type AcceptedAssets: Get<[u32; 2]>;
+        /// The registry of the accepted payment assets.
+        type AcceptedAssets: AcceptedAssetRegistry<<Self as pallet::Config>::Balance>;
 
         /// The Trasury's pallet id, used for deriving its sovereign account ID.
         #[pallet::constant]
@@ -300,7 +503,7 @@ pub mod pallet {
             + PropertyTokenInspect<Self>;
 
         type PermissionOrigin: EnsureOriginWithArg<
-            Self::RuntimeOrigin,
+            <Self as frame_system::Config>::RuntimeOrigin,
             Role,
             Success = Self::AccountId,
         >;
@@ -309,6 +512,35 @@ pub mod pallet {
         type MinVotingQuorum: Get<Percent>;
         #[pallet::constant]
         type MinPropertySaleQuorum: Get<Percent>;
+
+        /// The maximum amount of accounts that can delegate to a delegate per scope.
+        #[pallet::constant]
+        type MaxDelegators: Get<u32>;
+
+        /// The origin that can set the voting requirements of proposal categories.
+        type ProposalRequirementsOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// The overarching origin type, which includes the property collective origin.
+        type RuntimeOrigin: From<Origin>;
+
+        /// The overarching call type that passed proposals can dispatch.
+        type RuntimeCall: Parameter
+            + Dispatchable<
+                RuntimeOrigin = <Self as pallet::Config>::RuntimeOrigin,
+                PostInfo = PostDispatchInfo,
+            > + GetDispatchInfo
+            + From<pallet_property_management::Call<Self>>;
+
+        /// The calls that passed proposals can dispatch.
+        type CallFilter: Contains<<Self as pallet::Config>::RuntimeCall>;
+
+        /// The maximum length of an encoded call that a proposal can dispatch.
+        #[pallet::constant]
+        type MaxCallLength: Get<u32>;
+
+        /// The maximum amount of finished votings that are kept in the history of a property.
+        #[pallet::constant]
+        type MaxArchivedProposals: Get<u32>;
     }
 
     pub type ProposalId = u64;
@@ -458,6 +690,60 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Mapping of an account and delegation scope to the delegate of the account.
+    #[pallet::storage]
+    pub type Delegations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        DelegationScope,
+        AccountIdOf<T>,
+        OptionQuery,
+    >;
+
+    /// Mapping of a delegate and delegation scope to the accounts that delegated to it.
+    #[pallet::storage]
+    pub type Delegators<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        DelegationScope,
+        BoundedVec<AccountIdOf<T>, <T as Config>::MaxDelegators>,
+        ValueQuery,
+    >;
+
+    /// Mapping of a proposal category to the votes that a proposal of the category needs.
+    #[pallet::storage]
+    pub type ProposalRequirements<T: Config> =
+        StorageMap<_, Blake2_128Concat, ProposalCategory, VotingRequirements, OptionQuery>;
+
+    /// Mapping of asset id to the ongoing token buyback of the property.
+    #[pallet::storage]
+    pub type TokenBuybacks<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, TokenBuybackInfo<T>, OptionQuery>;
+
+    /// Mapping of proposal id to the encoded call that the proposal dispatches.
+    #[pallet::storage]
+    pub type ProposalCalls<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ProposalId,
+        BoundedVec<u8, <T as pallet::Config>::MaxCallLength>,
+        OptionQuery,
+    >;
+
+    /// Mapping of asset id to the most recent finished votings of the property.
+    #[pallet::storage]
+    pub type ProposalHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<ProposalRecord<BlockNumberFor<T>>, <T as pallet::Config>::MaxArchivedProposals>,
+        ValueQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -494,7 +780,7 @@ pub mod pallet {
         /// The proposal has been executed.
         ProposalExecuted {
             asset_id: u32,
-            amount: <T as pallet::Config>::Balance,
+            kind: ProposalKindOf<T>,
         },
         /// The agent got slashed.
         AgentSlashed {
@@ -505,6 +791,8 @@ pub mod pallet {
         AgentChanged { asset_id: u32 },
         /// A proposal got rejected.
         ProposalRejected { proposal_id: ProposalId },
+        /// A passed proposal could not be executed.
+        ProposalExecutionFailed { proposal_id: ProposalId },
         /// A challenge has been rejected/
         ChallengeRejected { asset_id: u32 },
         /// The threshold could not be reached for a proposal.
@@ -593,6 +881,37 @@ pub mod pallet {
             voter: AccountIdOf<T>,
             amount: u32,
         },
+        /// An account delegated its voting power.
+        VotingPowerDelegated {
+            delegator: AccountIdOf<T>,
+            delegate: AccountIdOf<T>,
+            scope: DelegationScope,
+        },
+        /// An account revoked the delegation of its voting power.
+        DelegationRevoked {
+            delegator: AccountIdOf<T>,
+            delegate: AccountIdOf<T>,
+            scope: DelegationScope,
+        },
+        /// The voting requirements of a proposal category have been set.
+        ProposalRequirementsSet {
+            category: ProposalCategory,
+            requirements: VotingRequirements,
+        },
+        /// A token buyback has been opened for a property.
+        TokenBuybackOpened {
+            asset_id: u32,
+            token_amount: u32,
+            price_per_token: <T as pallet::Config>::Balance,
+            payment_asset: u32,
+        },
+        /// Property token has been sold to a token buyback.
+        TokenSoldToBuyback {
+            asset_id: u32,
+            seller: AccountIdOf<T>,
+            token_amount: u32,
+            price: <T as pallet::Config>::Balance,
+        },
     }
 
     #[pallet::error]
@@ -675,8 +994,30 @@ pub mod pallet {
         ProposalOngoing,
         /// The amount for voting has to be higher than 0.
         ZeroVoteAmount,
+        /// There is no vote to withdraw.
+        NoVoteToWithdraw,
         /// Proposal amount can not be 0.
         ZeroAmount,
+        /// An account can not delegate to itself.
+        CannotDelegateToSelf,
+        /// The delegate has already too many delegators.
+        TooManyDelegators,
+        /// The account has not delegated its voting power.
+        NoDelegation,
+        /// The voting power of the account has been delegated.
+        VotingPowerDelegated,
+        /// The property has no ongoing token buyback.
+        NoTokenBuyback,
+        /// The token buyback does not buy this amount of token.
+        NotEnoughBuybackToken,
+        /// The call can not be dispatched by a proposal.
+        CallFiltered,
+        /// The encoded call is too long.
+        CallTooLong,
+        /// The call of the proposal could not be decoded.
+        UndecodableCall,
+        /// Proposals of this kind have to be created with `propose_call`.
+        InvalidProposalKind,
     }
 
     #[pallet::hooks]
@@ -687,7 +1028,7 @@ pub mod pallet {
             let ended_votings = ProposalRoundsExpiring::<T>::take(n);
             // checks if there is a voting for a proposal ending in this block.
             ended_votings.iter().for_each(|item| {
-                weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 3));
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(6, 5));
                 //UserProposalVote::<T>::remove(item);
                 if let Err(e) = Self::finish_proposal(*item) {
                     Self::deposit_event(Event::ProposalProcessingFailed {
@@ -700,7 +1041,7 @@ pub mod pallet {
             let ended_votings = SaleProposalRoundsExpiring::<T>::take(n);
             // Checks if there is a voting for a sale porposal in this block;
             ended_votings.iter().for_each(|item| {
-                weight = weight.saturating_add(T::DbWeight::get().reads_writes(6, 4));
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(7, 5));
                 //UserSaleProposalVote::<T>::remove(item);
                 if let Err(e) = Self::finish_sale_proposal(*item) {
                     Self::deposit_event(Event::SaleProposalProcessingFailed {
@@ -726,7 +1067,7 @@ pub mod pallet {
             let ended_challenge_votings = ChallengeRoundsExpiring::<T>::take(n);
             // checks if there is a voting for an challenge ending in this block.
             ended_challenge_votings.iter().for_each(|item| {
-                weight = weight.saturating_add(T::DbWeight::get().reads_writes(7, 9));
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(8, 10));
                 //UserChallengeVote::<T>::remove(item);
                 if let Err(e) = Self::finish_challenge(*item) {
                     Self::deposit_event(Event::ChallengeProcessingFailed {
@@ -748,7 +1089,7 @@ pub mod pallet {
         ///
         /// Parameters:
         /// - `asset_id`: The asset id of the property.
-        /// - `amount`: The amount the letting agent is asking for.
+        /// - `kind`: The kind of the proposal that is executed once the proposal passes.
         /// - `data`: The data regarding this proposal.
         ///
         /// Emits `Proposed` event when successful.
@@ -757,65 +1098,20 @@ pub mod pallet {
         pub fn propose(
             origin: OriginFor<T>,
             asset_id: u32,
-            amount: <T as pallet::Config>::Balance,
+            kind: ProposalKindOf<T>,
             data: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>,
         ) -> DispatchResult {
-            let signer = <T as pallet::Config>::PermissionOrigin::ensure_origin(
-                origin,
-                &Role::LettingAgent,
-            )?;
-            ensure!(
-                pallet_property_management::LettingStorage::<T>::get(asset_id)
-                    .ok_or(Error::<T>::NoLettingAgentFound)?
-                    == signer,
-                Error::<T>::NoPermission
-            );
-            ensure!(
-                !AssetProposal::<T>::contains_key(asset_id),
-                Error::<T>::ProposalOngoing
-            );
-            ensure!(amount > Zero::zero(), Error::<T>::ZeroAmount);
+            let signer = Self::ensure_proposer(origin, asset_id)?;
+            Self::ensure_valid_proposal_kind(&kind)?;
 
-            let current_block_number = <frame_system::Pallet<T>>::block_number();
             let proposal = Proposal {
-                proposer: signer.clone(),
-                amount,
-                created_at: current_block_number,
+                proposer: signer,
+                kind,
+                created_at: <frame_system::Pallet<T>>::block_number(),
                 metadata: data,
             };
 
-            // Check if the amount is less than LowProposal
-            if amount <= <T as Config>::LowProposal::get() {
-                // Execute the proposal immediately
-                Self::execute_proposal(asset_id, proposal)?;
-                return Ok(());
-            }
-
-            let proposal_id = ProposalCount::<T>::get();
-            let expiry_block =
-                current_block_number.saturating_add(<T as Config>::VotingTime::get());
-            ProposalRoundsExpiring::<T>::try_mutate(expiry_block, |keys| {
-                keys.try_push(asset_id)
-                    .map_err(|_| Error::<T>::TooManyProposals)?;
-                Ok::<(), DispatchError>(())
-            })?;
-
-            let vote_stats = VoteStats {
-                yes_voting_power: 0,
-                no_voting_power: 0,
-            };
-            AssetProposal::<T>::insert(asset_id, proposal_id);
-            Proposals::<T>::insert(proposal_id, proposal);
-            OngoingProposalVotes::<T>::insert(proposal_id, vote_stats);
-            let next_proposal_id = proposal_id
-                .checked_add(1)
-                .ok_or(Error::<T>::ArithmeticOverflow)?;
-            ProposalCount::<T>::put(next_proposal_id);
-            Self::deposit_event(Event::Proposed {
-                proposal_id,
-                asset_id,
-                proposer: signer,
-            });
+            Self::do_propose(asset_id, proposal)?;
             Ok(())
         }
 
@@ -890,12 +1186,13 @@ pub mod pallet {
         ///
         /// Parameters:
         /// - `proposal_id`: The index of the proposal.
-        /// - `vote`: Must be either a Yes vote or a No vote.
+        /// - `vote`: A Yes or No vote, or Withdraw to take back a previous vote.
         /// - `amount`: The amount of property token that the caller is using for voting.
         ///
         /// Emits `VotedOnProposal` event when successful.
         #[pallet::call_index(2)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::vote_on_proposal())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::vote_on_proposal()
+            .saturating_add(Pallet::<T>::delegated_votes_weight()))]
         pub fn vote_on_proposal(
             origin: OriginFor<T>,
             asset_id: u32,
@@ -913,10 +1210,26 @@ pub mod pallet {
             );
             let owner_list = <T as pallet::Config>::PropertyToken::get_property_owner(asset_id);
             ensure!(owner_list.contains(&signer), Error::<T>::NoPermission);
-            ensure!(amount > 0, Error::<T>::ZeroVoteAmount);
+            ensure!(
+                Self::delegate_of(&signer, asset_id).is_none(),
+                Error::<T>::VotingPowerDelegated
+            );
+            ensure!(
+                vote == Vote::Withdraw || amount > 0,
+                Error::<T>::ZeroVoteAmount
+            );
             let voting_power =
                 <T as pallet::Config>::PropertyToken::get_token_balance(asset_id, &signer);
-            ensure!(voting_power >= amount, Error::<T>::NoPermission);
+            ensure!(
+                vote == Vote::Withdraw || voting_power >= amount,
+                Error::<T>::NoPermission
+            );
+            ensure!(
+                vote != Vote::Withdraw
+                    || UserProposalVote::<T>::contains_key(proposal_id, &signer)
+                    || !Self::delegators_of(&signer, asset_id).is_empty(),
+                Error::<T>::NoVoteToWithdraw
+            );
             OngoingProposalVotes::<T>::try_mutate(proposal_id, |maybe_current_vote| {
                 let current_vote = maybe_current_vote.as_mut().ok_or(Error::<T>::NotOngoing)?;
                 UserProposalVote::<T>::try_mutate(proposal_id, &signer, |maybe_vote_record| {
@@ -939,16 +1252,10 @@ pub mod pallet {
                                     .no_voting_power
                                     .saturating_sub(previous_vote.power)
                             }
+                            Vote::Withdraw => {}
                         }
                     }
 
-                    <T as pallet::Config>::AssetsFreezer::increase_frozen(
-                        asset_id,
-                        &MarketplaceFreezeReason::ProposalVoting,
-                        &signer,
-                        amount.into(),
-                    )?;
-
                     match vote {
                         Vote::Yes => {
                             current_vote.yes_voting_power =
@@ -958,15 +1265,32 @@ pub mod pallet {
                             current_vote.no_voting_power =
                                 current_vote.no_voting_power.saturating_add(amount)
                         }
+                        Vote::Withdraw => return Ok(()),
                     }
 
+                    <T as pallet::Config>::AssetsFreezer::increase_frozen(
+                        asset_id,
+                        &MarketplaceFreezeReason::ProposalVoting,
+                        &signer,
+                        amount.into(),
+                    )?;
+
                     *maybe_vote_record = Some(VoteRecord {
                         vote: vote.clone(),
                         asset_id,
                         power: amount,
+                        delegated: false,
                     });
                     Ok::<(), DispatchError>(())
                 })?;
+                Self::cast_delegated_votes::<UserProposalVote<T>>(
+                    &signer,
+                    asset_id,
+                    proposal_id,
+                    &vote,
+                    current_vote,
+                    &MarketplaceFreezeReason::ProposalVoting,
+                )?;
                 Ok::<(), DispatchError>(())
             })?;
             Self::deposit_event(Event::VotedOnProposal {
@@ -1024,12 +1348,13 @@ pub mod pallet {
         ///
         /// Parameters:
         /// - `asset_id: u32`: The index of the challenge.
-        /// - `vote`: Must be either a Yes vote or a No vote.
+        /// - `vote`: A Yes or No vote, or Withdraw to take back a previous vote.
         /// - `amount`: The amount of property token that the caller is using for voting.
         ///
         /// Emits `VotedOnChallenge` event when successful.
         #[pallet::call_index(4)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::vote_on_letting_agent_challenge())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::vote_on_letting_agent_challenge()
+            .saturating_add(Pallet::<T>::delegated_votes_weight()))]
         pub fn vote_on_letting_agent_challenge(
             origin: OriginFor<T>,
             asset_id: u32,
@@ -1048,10 +1373,26 @@ pub mod pallet {
             );
             let owner_list = <T as pallet::Config>::PropertyToken::get_property_owner(asset_id);
             ensure!(owner_list.contains(&signer), Error::<T>::NoPermission);
-            ensure!(amount > 0, Error::<T>::ZeroVoteAmount);
+            ensure!(
+                Self::delegate_of(&signer, asset_id).is_none(),
+                Error::<T>::VotingPowerDelegated
+            );
+            ensure!(
+                vote == Vote::Withdraw || amount > 0,
+                Error::<T>::ZeroVoteAmount
+            );
             let voting_power =
                 <T as pallet::Config>::PropertyToken::get_token_balance(asset_id, &signer);
-            ensure!(voting_power >= amount, Error::<T>::NoPermission);
+            ensure!(
+                vote == Vote::Withdraw || voting_power >= amount,
+                Error::<T>::NoPermission
+            );
+            ensure!(
+                vote != Vote::Withdraw
+                    || UserChallengeVote::<T>::contains_key(proposal_id, &signer)
+                    || !Self::delegators_of(&signer, asset_id).is_empty(),
+                Error::<T>::NoVoteToWithdraw
+            );
             OngoingChallengeVotes::<T>::try_mutate(proposal_id, |maybe_current_vote| {
                 let current_vote = maybe_current_vote.as_mut().ok_or(Error::<T>::NotOngoing)?;
                 UserChallengeVote::<T>::try_mutate(proposal_id, &signer, |maybe_vote_record| {
@@ -1073,16 +1414,10 @@ pub mod pallet {
                                     .no_voting_power
                                     .saturating_sub(previous_vote.power)
                             }
+                            Vote::Withdraw => {}
                         }
                     }
 
-                    <T as pallet::Config>::AssetsFreezer::increase_frozen(
-                        asset_id,
-                        &MarketplaceFreezeReason::ChallengeVoting,
-                        &signer,
-                        amount.into(),
-                    )?;
-
                     match vote {
                         Vote::Yes => {
                             current_vote.yes_voting_power =
@@ -1092,15 +1427,32 @@ pub mod pallet {
                             current_vote.no_voting_power =
                                 current_vote.no_voting_power.saturating_add(amount)
                         }
+                        Vote::Withdraw => return Ok(()),
                     }
 
-                    *maybe_vote_record = Some(VoteRecord {
-                        vote: vote.clone(),
+                    <T as pallet::Config>::AssetsFreezer::increase_frozen(
                         asset_id,
-                        power: amount,
+                        &MarketplaceFreezeReason::ChallengeVoting,
+                        &signer,
+                        amount.into(),
+                    )?;
+
+                    *maybe_vote_record = Some(VoteRecord {
+                        vote: vote.clone(),
+                        asset_id,
+                        power: amount,
+                        delegated: false,
                     });
                     Ok::<(), DispatchError>(())
                 })?;
+                Self::cast_delegated_votes::<UserChallengeVote<T>>(
+                    &signer,
+                    asset_id,
+                    proposal_id,
+                    &vote,
+                    current_vote,
+                    &MarketplaceFreezeReason::ChallengeVoting,
+                )?;
                 Ok::<(), DispatchError>(())
             })?;
             Self::deposit_event(Event::VotedOnChallenge {
@@ -1218,12 +1570,13 @@ pub mod pallet {
         ///
         /// Parameters:
         /// - `asset_id`: The asset id of the property.
-        /// - `vote`: Must be either a Yes vote or a No vote.
+        /// - `vote`: A Yes or No vote, or Withdraw to take back a previous vote.
         /// - `amount`: The amount of property token that the caller is using for voting.
         ///
         /// Emits `VotedOnPropertySaleProposal` event when successful.
         #[pallet::call_index(7)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::vote_on_property_sale())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::vote_on_property_sale()
+            .saturating_add(Pallet::<T>::delegated_votes_weight()))]
         pub fn vote_on_property_sale(
             origin: OriginFor<T>,
             asset_id: u32,
@@ -1242,10 +1595,26 @@ pub mod pallet {
             );
             let owner_list = <T as pallet::Config>::PropertyToken::get_property_owner(asset_id);
             ensure!(owner_list.contains(&signer), Error::<T>::NoPermission);
-            ensure!(amount > 0, Error::<T>::ZeroVoteAmount);
+            ensure!(
+                Self::delegate_of(&signer, asset_id).is_none(),
+                Error::<T>::VotingPowerDelegated
+            );
+            ensure!(
+                vote == Vote::Withdraw || amount > 0,
+                Error::<T>::ZeroVoteAmount
+            );
             let voting_power =
                 <T as pallet::Config>::PropertyToken::get_token_balance(asset_id, &signer);
-            ensure!(voting_power >= amount, Error::<T>::NoPermission);
+            ensure!(
+                vote == Vote::Withdraw || voting_power >= amount,
+                Error::<T>::NoPermission
+            );
+            ensure!(
+                vote != Vote::Withdraw
+                    || UserSaleProposalVote::<T>::contains_key(proposal_id, &signer)
+                    || !Self::delegators_of(&signer, asset_id).is_empty(),
+                Error::<T>::NoVoteToWithdraw
+            );
             OngoingSaleProposalVotes::<T>::try_mutate(proposal_id, |maybe_current_vote| {
                 let current_vote = maybe_current_vote.as_mut().ok_or(Error::<T>::NotOngoing)?;
                 UserSaleProposalVote::<T>::try_mutate(proposal_id, &signer, |maybe_vote_record| {
@@ -1268,16 +1637,10 @@ pub mod pallet {
                                     .no_voting_power
                                     .saturating_sub(previous_vote.power)
                             }
+                            Vote::Withdraw => {}
                         }
                     }
 
-                    <T as pallet::Config>::AssetsFreezer::increase_frozen(
-                        asset_id,
-                        &MarketplaceFreezeReason::SaleVoting,
-                        &signer,
-                        amount.into(),
-                    )?;
-
                     match vote {
                         Vote::Yes => {
                             current_vote.yes_voting_power =
@@ -1287,15 +1650,32 @@ pub mod pallet {
                             current_vote.no_voting_power =
                                 current_vote.no_voting_power.saturating_add(amount)
                         }
+                        Vote::Withdraw => return Ok(()),
                     }
 
+                    <T as pallet::Config>::AssetsFreezer::increase_frozen(
+                        asset_id,
+                        &MarketplaceFreezeReason::SaleVoting,
+                        &signer,
+                        amount.into(),
+                    )?;
+
                     *maybe_vote_record = Some(VoteRecord {
                         vote: vote.clone(),
                         asset_id,
                         power: amount,
+                        delegated: false,
                     });
                     Ok::<(), DispatchError>(())
                 })?;
+                Self::cast_delegated_votes::<UserSaleProposalVote<T>>(
+                    &signer,
+                    asset_id,
+                    proposal_id,
+                    &vote,
+                    current_vote,
+                    &MarketplaceFreezeReason::SaleVoting,
+                )?;
                 Ok::<(), DispatchError>(())
             })?;
             Self::deposit_event(Event::VotedOnPropertySaleProposal {
@@ -1370,7 +1750,7 @@ pub mod pallet {
                 &Role::RealEstateInvestor,
             )?;
             ensure!(
-                <T as pallet::Config>::AcceptedAssets::get().contains(&payment_asset),
+                <T as pallet::Config>::AcceptedAssets::is_accepted(payment_asset),
                 Error::<T>::PaymentAssetNotSupported
             );
             let reserve_amount = price
@@ -1661,7 +2041,7 @@ pub mod pallet {
                 );
                 ensure!(!sale_info.finalized, Error::<T>::AlreadyFinalized);
                 ensure!(
-                    <T as pallet::Config>::AcceptedAssets::get().contains(&payment_asset),
+                    <T as pallet::Config>::AcceptedAssets::is_accepted(payment_asset),
                     Error::<T>::PaymentAssetNotSupported
                 );
 
@@ -1786,7 +2166,7 @@ pub mod pallet {
 
             let total_token = property_info.token_amount;
             let property_token_amount =
-                <T as pallet::Config>::PropertyToken::take_property_token(asset_id, &signer);
+                <T as pallet::Config>::PropertyToken::take_property_token(asset_id, &signer)?;
             ensure!(!property_token_amount.is_zero(), Error::<T>::NoFundsToClaim);
 
             let mut owner_share = net_amount
@@ -1874,6 +2254,202 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Lets a token holder delegate its voting power to another account.
+        /// The voting power is counted for the delegate when the delegate votes. A delegation
+        /// for a property takes precedence over a global delegation.
+        ///
+        /// The origin must be Signed and the sender must have the role of a real estate investor.
+        ///
+        /// Parameters:
+        /// - `scope`: The property or all properties for which the voting power is delegated.
+        /// - `delegate`: The account that votes on behalf of the sender.
+        ///
+        /// Emits `VotingPowerDelegated` event when successful.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::delegate_voting_power())]
+        pub fn delegate_voting_power(
+            origin: OriginFor<T>,
+            scope: DelegationScope,
+            delegate: AccountIdOf<T>,
+        ) -> DispatchResult {
+            let signer = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                origin,
+                &Role::RealEstateInvestor,
+            )?;
+            ensure!(signer != delegate, Error::<T>::CannotDelegateToSelf);
+            if let Some(previous_delegate) = Delegations::<T>::take(&signer, &scope) {
+                Delegators::<T>::mutate(&previous_delegate, &scope, |delegators| {
+                    delegators.retain(|delegator| delegator != &signer)
+                });
+            }
+            Delegators::<T>::try_mutate(&delegate, &scope, |delegators| {
+                delegators
+                    .try_push(signer.clone())
+                    .map_err(|_| Error::<T>::TooManyDelegators)
+            })?;
+            Delegations::<T>::insert(&signer, &scope, &delegate);
+            Self::deposit_event(Event::VotingPowerDelegated {
+                delegator: signer,
+                delegate,
+                scope,
+            });
+            Ok(())
+        }
+
+        /// Lets a token holder revoke the delegation of its voting power.
+        /// Votes that the delegate already cast stay frozen until the voting ended.
+        ///
+        /// The origin must be Signed.
+        ///
+        /// Parameters:
+        /// - `scope`: The scope of the delegation.
+        ///
+        /// Emits `DelegationRevoked` event when successful.
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_delegation())]
+        pub fn revoke_delegation(origin: OriginFor<T>, scope: DelegationScope) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            let delegate =
+                Delegations::<T>::take(&signer, &scope).ok_or(Error::<T>::NoDelegation)?;
+            Delegators::<T>::mutate(&delegate, &scope, |delegators| {
+                delegators.retain(|delegator| delegator != &signer)
+            });
+            Self::deposit_event(Event::DelegationRevoked {
+                delegator: signer,
+                delegate,
+                scope,
+            });
+            Ok(())
+        }
+
+        /// Sets the votes that proposals of a category need to pass.
+        ///
+        /// The origin must be the ProposalRequirementsOrigin.
+        ///
+        /// Parameters:
+        /// - `category`: The category of the proposals.
+        /// - `requirements`: The quorum and yes threshold of the proposals.
+        ///
+        /// Emits `ProposalRequirementsSet` event when successful.
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_proposal_requirements())]
+        pub fn set_proposal_requirements(
+            origin: OriginFor<T>,
+            category: ProposalCategory,
+            requirements: VotingRequirements,
+        ) -> DispatchResult {
+            <T as pallet::Config>::ProposalRequirementsOrigin::ensure_origin(origin)?;
+            ProposalRequirements::<T>::insert(&category, &requirements);
+            Self::deposit_event(Event::ProposalRequirementsSet {
+                category,
+                requirements,
+            });
+            Ok(())
+        }
+
+        /// Lets a token holder sell property token to the token buyback of the property.
+        /// The token holder gets paid out of the property reserve.
+        ///
+        /// The origin must be Signed and the sender must have the role of a real estate investor.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset id of the property.
+        /// - `token_amount`: The amount of property token that the sender sells.
+        ///
+        /// Emits `TokenSoldToBuyback` event when successful.
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::sell_token_to_buyback())]
+        pub fn sell_token_to_buyback(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            token_amount: u32,
+        ) -> DispatchResult {
+            let signer = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                origin,
+                &Role::RealEstateInvestor,
+            )?;
+            ensure!(token_amount > 0, Error::<T>::ZeroAmount);
+            let mut buyback =
+                TokenBuybacks::<T>::get(asset_id).ok_or(Error::<T>::NoTokenBuyback)?;
+            buyback.remaining_token = buyback
+                .remaining_token
+                .checked_sub(token_amount)
+                .ok_or(Error::<T>::NotEnoughBuybackToken)?;
+            let price = buyback
+                .price_per_token
+                .checked_mul(&((token_amount as u128).into()))
+                .ok_or(Error::<T>::MultiplyError)?;
+
+            <T as pallet::Config>::PropertyToken::redeem_property_token(
+                asset_id,
+                &signer,
+                token_amount,
+            )?;
+            pallet_property_management::Pallet::<T>::withdraw_from_reserve(
+                asset_id,
+                buyback.payment_asset,
+                &signer,
+                price.into(),
+            )?;
+
+            if buyback.remaining_token == 0 {
+                TokenBuybacks::<T>::remove(asset_id);
+            } else {
+                TokenBuybacks::<T>::insert(asset_id, buyback);
+            }
+            Self::deposit_event(Event::TokenSoldToBuyback {
+                asset_id,
+                seller: signer,
+                token_amount,
+                price,
+            });
+            Ok(())
+        }
+
+        /// Creates a proposal that dispatches a call with the property collective origin
+        /// of the property once it passes.
+        /// The letting agent and the token holders of the property can propose.
+        ///
+        /// The origin must be Signed and the sender must either be the letting agent of the
+        /// property or a real estate investor that holds token of the property.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset id of the property.
+        /// - `call`: The call that is dispatched once the proposal passes.
+        /// - `data`: The data regarding this proposal.
+        ///
+        /// Emits `Proposed` event when successful.
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::propose_call())]
+        pub fn propose_call(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            call: Box<<T as pallet::Config>::RuntimeCall>,
+            data: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>,
+        ) -> DispatchResult {
+            let signer = Self::ensure_call_proposer(origin, asset_id)?;
+            ensure!(
+                <T as pallet::Config>::CallFilter::contains(&call),
+                Error::<T>::CallFiltered
+            );
+            let encoded_call: BoundedVec<u8, <T as pallet::Config>::MaxCallLength> = call
+                .encode()
+                .try_into()
+                .map_err(|_| Error::<T>::CallTooLong)?;
+
+            let proposal = Proposal {
+                proposer: signer,
+                kind: ProposalKind::Dispatch {
+                    call_hash: BlakeTwo256::hash(&encoded_call).into(),
+                },
+                created_at: <frame_system::Pallet<T>>::block_number(),
+                metadata: data,
+            };
+            let proposal_id = Self::do_propose(asset_id, proposal)?;
+            ProposalCalls::<T>::insert(proposal_id, encoded_call);
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -1887,6 +2463,91 @@ pub mod pallet {
             <T as pallet::Config>::TreasuryId::get().into_account_truncating()
         }
 
+        /// Ensures that the origin is the letting agent of the property and that the property
+        /// has no ongoing proposal.
+        fn ensure_proposer(
+            origin: OriginFor<T>,
+            asset_id: u32,
+        ) -> Result<AccountIdOf<T>, DispatchError> {
+            let signer = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                origin,
+                &Role::LettingAgent,
+            )?;
+            ensure!(
+                pallet_property_management::LettingStorage::<T>::get(asset_id)
+                    .ok_or(Error::<T>::NoLettingAgentFound)?
+                    == signer,
+                Error::<T>::NoPermission
+            );
+            ensure!(
+                !AssetProposal::<T>::contains_key(asset_id),
+                Error::<T>::ProposalOngoing
+            );
+            Ok(signer)
+        }
+
+        /// Ensures that the origin is the letting agent or a token holder of the property and
+        /// that the property has no ongoing proposal.
+        fn ensure_call_proposer(
+            origin: OriginFor<T>,
+            asset_id: u32,
+        ) -> Result<AccountIdOf<T>, DispatchError> {
+            let letting_agent = pallet_property_management::LettingStorage::<T>::get(asset_id);
+            let signer = match <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                origin.clone(),
+                &Role::LettingAgent,
+            ) {
+                Ok(signer) if letting_agent.as_ref() == Some(&signer) => signer,
+                _ => {
+                    let signer = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                        origin,
+                        &Role::RealEstateInvestor,
+                    )?;
+                    let owner_list =
+                        <T as pallet::Config>::PropertyToken::get_property_owner(asset_id);
+                    ensure!(owner_list.contains(&signer), Error::<T>::NoPermission);
+                    signer
+                }
+            };
+            ensure!(
+                !AssetProposal::<T>::contains_key(asset_id),
+                Error::<T>::ProposalOngoing
+            );
+            Ok(signer)
+        }
+
+        /// Opens the voting on a proposal.
+        fn do_propose(asset_id: u32, proposal: Proposal<T>) -> Result<ProposalId, DispatchError> {
+            let proposal_id = ProposalCount::<T>::get();
+            let expiry_block = proposal
+                .created_at
+                .saturating_add(<T as Config>::VotingTime::get());
+            ProposalRoundsExpiring::<T>::try_mutate(expiry_block, |keys| {
+                keys.try_push(asset_id)
+                    .map_err(|_| Error::<T>::TooManyProposals)?;
+                Ok::<(), DispatchError>(())
+            })?;
+
+            let vote_stats = VoteStats {
+                yes_voting_power: 0,
+                no_voting_power: 0,
+            };
+            let proposer = proposal.proposer.clone();
+            AssetProposal::<T>::insert(asset_id, proposal_id);
+            Proposals::<T>::insert(proposal_id, proposal);
+            OngoingProposalVotes::<T>::insert(proposal_id, vote_stats);
+            let next_proposal_id = proposal_id
+                .checked_add(1)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            ProposalCount::<T>::put(next_proposal_id);
+            Self::deposit_event(Event::Proposed {
+                proposal_id,
+                asset_id,
+                proposer,
+            });
+            Ok(proposal_id)
+        }
+
         // Slashes the letting agent.
         fn slash_letting_agent(asset_id: u32, letting_agent: AccountIdOf<T>) -> DispatchResult {
             let amount = <T as Config>::MinSlashingAmount::get();
@@ -1916,6 +2577,7 @@ pub mod pallet {
             let proposal_id = AssetProposal::<T>::take(asset_id).ok_or(Error::<T>::NotOngoing)?;
             let voting_results = OngoingProposalVotes::<T>::take(proposal_id);
             let proposals = Proposals::<T>::take(proposal_id);
+            let call = ProposalCalls::<T>::take(proposal_id);
             if let Some(proposal) = proposals {
                 if let Some(voting_result) = voting_results {
                     let asset_details =
@@ -1930,36 +2592,48 @@ pub mod pallet {
 
                         ensure!(total_supply > Zero::zero(), Error::<T>::NoObjectFound);
 
-                        let quorum_percent: u32 = <T as pallet::Config>::MinVotingQuorum::get()
-                            .deconstruct()
-                            .into();
+                        let requirements = Self::voting_requirements(&proposal.kind);
+                        let quorum_percent: u32 = requirements.quorum.deconstruct().into();
 
                         let meets_quorum = total_votes.saturating_mul(100u32)
                             > total_supply.saturating_mul(quorum_percent);
 
-                        let mut meets_threshold = true;
-                        if proposal.amount >= <T as Config>::HighProposal::get() {
-                            let high_threshold_percent: u32 =
-                                <T as Config>::HighThreshold::get().deconstruct().into();
-                            meets_threshold = voting_result.yes_voting_power.saturating_mul(100u32)
-                                >= total_supply.saturating_mul(high_threshold_percent);
-                        }
+                        let threshold_percent: u32 = requirements.threshold.deconstruct().into();
+                        let meets_threshold = voting_result.yes_voting_power.saturating_mul(100u32)
+                            >= total_supply.saturating_mul(threshold_percent);
 
-                        if voting_result.yes_voting_power > voting_result.no_voting_power
+                        let proposal_type = ProposalType::Proposal(proposal.kind.category());
+                        let outcome = if voting_result.yes_voting_power
+                            > voting_result.no_voting_power
                             && meets_threshold
                             && meets_quorum
                         {
-                            let _ = Self::execute_proposal(asset_id, proposal);
+                            if Self::execute_proposal(asset_id, proposal, call).is_err() {
+                                Self::deposit_event(Event::ProposalExecutionFailed { proposal_id });
+                                ProposalOutcome::ExecutionFailed
+                            } else {
+                                ProposalOutcome::Executed
+                            }
                         } else if voting_result.yes_voting_power <= voting_result.no_voting_power
                             || !meets_quorum
                         {
                             Self::deposit_event(Event::ProposalRejected { proposal_id });
+                            ProposalOutcome::Rejected
                         } else {
                             Self::deposit_event(Event::ProposalThresHoldNotReached {
                                 proposal_id,
-                                required_threshold: <T as Config>::HighThreshold::get(),
+                                required_threshold: requirements.threshold,
                             });
-                        }
+                            ProposalOutcome::ThresholdNotReached
+                        };
+                        Self::archive_proposal(
+                            asset_id,
+                            proposal_id,
+                            proposal_type,
+                            outcome,
+                            &voting_result,
+                            total_supply,
+                        );
                     }
                 }
             }
@@ -1994,11 +2668,24 @@ pub mod pallet {
                     let meets_threshold = yes_votes.saturating_mul(100u32)
                         >= total_supply.saturating_mul(threshold_percent);
 
-                    if meets_threshold && meets_quorum {
-                        let _ = Self::execute_sale_proposal(asset_id, total_supply);
+                    let outcome = if meets_threshold && meets_quorum {
+                        if Self::execute_sale_proposal(asset_id, total_supply).is_err() {
+                            ProposalOutcome::ExecutionFailed
+                        } else {
+                            ProposalOutcome::Executed
+                        }
                     } else {
                         Self::deposit_event(Event::PropertySaleProposalRejected { asset_id });
-                    }
+                        ProposalOutcome::Rejected
+                    };
+                    Self::archive_proposal(
+                        asset_id,
+                        proposal_id,
+                        ProposalType::Sale,
+                        outcome,
+                        &voting_result,
+                        total_supply,
+                    );
                 }
             }
             Ok(())
@@ -2049,52 +2736,378 @@ pub mod pallet {
             let meets_quorum =
                 total_votes.saturating_mul(100u32) > total_supply.saturating_mul(quorum_percent);
 
-            if voting_result.yes_voting_power > voting_result.no_voting_power && meets_quorum {
-                let letting_agent = pallet_property_management::LettingStorage::<T>::get(asset_id)
+            let (outcome, result) =
+                if voting_result.yes_voting_power > voting_result.no_voting_power && meets_quorum {
+                    match Self::execute_challenge(asset_id) {
+                        Ok(()) => (ProposalOutcome::Executed, Ok(())),
+                        Err(e) => (ProposalOutcome::ExecutionFailed, Err(e)),
+                    }
+                } else {
+                    Self::deposit_event(Event::ChallengeRejected { asset_id });
+                    (ProposalOutcome::Rejected, Ok(()))
+                };
+            Self::archive_proposal(
+                asset_id,
+                proposal_id,
+                ProposalType::Challenge,
+                outcome,
+                &voting_result,
+                total_supply,
+            );
+            result
+        }
+
+        /// Slashes the letting agent of a property after a passed challenge.
+        fn execute_challenge(asset_id: u32) -> DispatchResult {
+            let letting_agent = pallet_property_management::LettingStorage::<T>::get(asset_id)
+                .ok_or(Error::<T>::NoLettingAgentFound)?;
+            let mut letting_info =
+                pallet_property_management::LettingInfo::<T>::get(letting_agent.clone())
                     .ok_or(Error::<T>::NoLettingAgentFound)?;
-                let mut letting_info =
-                    pallet_property_management::LettingInfo::<T>::get(letting_agent.clone())
-                        .ok_or(Error::<T>::NoLettingAgentFound)?;
-                Self::slash_letting_agent(asset_id, letting_agent.clone())?;
-                let active_strikes = letting_info
+            Self::slash_letting_agent(asset_id, letting_agent.clone())?;
+            let active_strikes = letting_info
+                .active_strikes
+                .get(&asset_id)
+                .copied()
+                .unwrap_or(0)
+                .saturating_add(1);
+
+            if let Some(entry) = letting_info.active_strikes.get_mut(&asset_id) {
+                *entry = active_strikes;
+            } else {
+                letting_info
                     .active_strikes
-                    .get(&asset_id)
-                    .copied()
-                    .unwrap_or(0)
-                    .saturating_add(1);
+                    .try_insert(asset_id, active_strikes)
+                    .map_err(|_| Error::<T>::TooManyAssignedProperties)?;
+            }
 
-                if let Some(entry) = letting_info.active_strikes.get_mut(&asset_id) {
-                    *entry = active_strikes;
-                } else {
-                    letting_info
-                        .active_strikes
-                        .try_insert(asset_id, active_strikes)
-                        .map_err(|_| Error::<T>::TooManyAssignedProperties)?;
-                }
+            if active_strikes >= 3 {
+                let _ = Self::change_letting_agent(asset_id);
+                letting_info.active_strikes.remove(&asset_id);
+            }
+            pallet_property_management::LettingInfo::<T>::insert(letting_agent, letting_info);
+            Ok(())
+        }
 
-                if active_strikes >= 3 {
-                    let _ = Self::change_letting_agent(asset_id);
-                    letting_info.active_strikes.remove(&asset_id);
-                }
-                pallet_property_management::LettingInfo::<T>::insert(letting_agent, letting_info);
-            } else {
-                Self::deposit_event(Event::ChallengeRejected { asset_id });
+        /// Adds a finished voting to the proposal history of a property.
+        /// The oldest record is dropped once the history is full.
+        fn archive_proposal(
+            asset_id: u32,
+            proposal_id: ProposalId,
+            proposal_type: ProposalType,
+            outcome: ProposalOutcome,
+            voting_result: &VoteStats,
+            total_supply: u32,
+        ) {
+            let total_votes = voting_result
+                .yes_voting_power
+                .saturating_add(voting_result.no_voting_power);
+            let record = ProposalRecord {
+                proposal_id,
+                proposal_type,
+                outcome,
+                yes_voting_power: voting_result.yes_voting_power,
+                no_voting_power: voting_result.no_voting_power,
+                turnout: Permill::from_rational(total_votes, total_supply),
+                finished_at: <frame_system::Pallet<T>>::block_number(),
+            };
+            ProposalHistory::<T>::mutate(asset_id, |history| {
+                let _ = history.force_insert_keep_right(history.len(), record);
+            });
+        }
+
+        /// Returns up to `limit` finished votings of a property, starting with the most recent
+        /// one or after the voting of the proposal `start`.
+        pub fn proposal_history(
+            asset_id: u32,
+            start: Option<ProposalId>,
+            limit: u32,
+        ) -> Vec<ProposalRecord<BlockNumberFor<T>>> {
+            let history = ProposalHistory::<T>::get(asset_id);
+            let skipped = match start {
+                Some(proposal_id) => history
+                    .iter()
+                    .rev()
+                    .position(|record| record.proposal_id == proposal_id)
+                    .map_or(history.len(), |position| position.saturating_add(1)),
+                None => 0,
+            };
+            history
+                .into_iter()
+                .rev()
+                .skip(skipped)
+                .take(limit as usize)
+                .collect()
+        }
+
+        /// Returns the delegate that votes on behalf of the account for a property.
+        pub fn delegate_of(account: &AccountIdOf<T>, asset_id: u32) -> Option<AccountIdOf<T>> {
+            Delegations::<T>::get(account, DelegationScope::Property(asset_id))
+                .or_else(|| Delegations::<T>::get(account, DelegationScope::Global))
+        }
+
+        /// Returns the accounts whose voting power for a property is counted for the delegate.
+        pub fn delegators_of(delegate: &AccountIdOf<T>, asset_id: u32) -> Vec<AccountIdOf<T>> {
+            let mut delegators =
+                Delegators::<T>::get(delegate, DelegationScope::Property(asset_id)).into_inner();
+            delegators.extend(
+                Delegators::<T>::get(delegate, DelegationScope::Global)
+                    .into_iter()
+                    .filter(|delegator| {
+                        !Delegations::<T>::contains_key(
+                            delegator,
+                            DelegationScope::Property(asset_id),
+                        )
+                    }),
+            );
+            delegators
+        }
+
+        /// Returns the weight of casting the votes of the maximum amount of delegators.
+        pub fn delegated_votes_weight() -> Weight {
+            <T as pallet::Config>::WeightInfo::cast_delegated_vote()
+                .saturating_mul(<T as Config>::MaxDelegators::get().saturating_mul(2).into())
+        }
+
+        /// Casts the vote of the delegate with the voting power of its delegators.
+        /// Votes that a delegator cast personally are not changed.
+        pub(crate) fn cast_delegated_votes<VoteStorage>(
+            delegate: &AccountIdOf<T>,
+            asset_id: u32,
+            proposal_id: ProposalId,
+            vote: &Vote,
+            current_vote: &mut VoteStats,
+            freeze_reason: &MarketplaceFreezeReason,
+        ) -> DispatchResult
+        where
+            VoteStorage: frame_support::storage::StorageDoubleMap<
+                ProposalId,
+                AccountIdOf<T>,
+                VoteRecord,
+                Query = Option<VoteRecord>,
+            >,
+        {
+            for delegator in Self::delegators_of(delegate, asset_id) {
+                let voting_power =
+                    <T as pallet::Config>::PropertyToken::get_token_balance(asset_id, &delegator);
+                VoteStorage::try_mutate(proposal_id, &delegator, |maybe_vote_record| {
+                    if let Some(previous_vote) = maybe_vote_record.take() {
+                        if !previous_vote.delegated {
+                            *maybe_vote_record = Some(previous_vote);
+                            return Ok(());
+                        }
+                        <T as pallet::Config>::AssetsFreezer::decrease_frozen(
+                            asset_id,
+                            freeze_reason,
+                            &delegator,
+                            previous_vote.power.into(),
+                        )?;
+                        match previous_vote.vote {
+                            Vote::Yes => {
+                                current_vote.yes_voting_power = current_vote
+                                    .yes_voting_power
+                                    .saturating_sub(previous_vote.power)
+                            }
+                            Vote::No => {
+                                current_vote.no_voting_power = current_vote
+                                    .no_voting_power
+                                    .saturating_sub(previous_vote.power)
+                            }
+                            Vote::Withdraw => {}
+                        }
+                    }
+
+                    if voting_power.is_zero() {
+                        return Ok(());
+                    }
+                    match vote {
+                        Vote::Yes => {
+                            current_vote.yes_voting_power =
+                                current_vote.yes_voting_power.saturating_add(voting_power)
+                        }
+                        Vote::No => {
+                            current_vote.no_voting_power =
+                                current_vote.no_voting_power.saturating_add(voting_power)
+                        }
+                        Vote::Withdraw => return Ok(()),
+                    }
+                    <T as pallet::Config>::AssetsFreezer::increase_frozen(
+                        asset_id,
+                        freeze_reason,
+                        &delegator,
+                        voting_power.into(),
+                    )?;
+                    *maybe_vote_record = Some(VoteRecord {
+                        vote: vote.clone(),
+                        asset_id,
+                        power: voting_power,
+                        delegated: true,
+                    });
+                    Ok::<(), DispatchError>(())
+                })?;
             }
             Ok(())
         }
 
         /// Executes a proposal once it passes.
-        fn execute_proposal(asset_id: u32, proposal: Proposal<T>) -> DispatchResult {
-            let proposal_amount = proposal.amount;
+        fn execute_proposal(
+            asset_id: u32,
+            proposal: Proposal<T>,
+            call: Option<BoundedVec<u8, <T as pallet::Config>::MaxCallLength>>,
+        ) -> DispatchResult {
+            match proposal.kind.clone() {
+                ProposalKind::CapitalExpenditure {
+                    beneficiary,
+                    amount,
+                    payment_asset,
+                } => {
+                    pallet_property_management::Pallet::<T>::withdraw_from_reserve(
+                        asset_id,
+                        payment_asset,
+                        &beneficiary,
+                        amount.into(),
+                    )?;
+                }
+                ProposalKind::RentChange { rent } => {
+                    pallet_property_management::Pallet::<T>::update_property_terms(
+                        asset_id,
+                        |terms| terms.rent = Some(rent.into()),
+                    );
+                }
+                ProposalKind::Refinancing {
+                    principal,
+                    interest_rate,
+                } => {
+                    pallet_property_management::Pallet::<T>::update_property_terms(
+                        asset_id,
+                        |terms| {
+                            terms.financing = Some(pallet_property_management::Financing {
+                                principal: principal.into(),
+                                interest_rate,
+                            })
+                        },
+                    );
+                }
+                ProposalKind::LettingAgentFeeChange { fee } => {
+                    pallet_property_management::Pallet::<T>::update_property_terms(
+                        asset_id,
+                        |terms| terms.letting_agent_fee = Some(fee),
+                    );
+                }
+                ProposalKind::TokenBuyback {
+                    token_amount,
+                    price_per_token,
+                    payment_asset,
+                } => {
+                    TokenBuybacks::<T>::insert(
+                        asset_id,
+                        TokenBuybackInfo {
+                            price_per_token,
+                            payment_asset,
+                            remaining_token: token_amount,
+                        },
+                    );
+                    Self::deposit_event(Event::TokenBuybackOpened {
+                        asset_id,
+                        token_amount,
+                        price_per_token,
+                        payment_asset,
+                    });
+                }
+                ProposalKind::ReservePolicyChange { reserve_share } => {
+                    pallet_property_management::Pallet::<T>::update_property_terms(
+                        asset_id,
+                        |terms| terms.reserve_share = Some(reserve_share),
+                    );
+                }
+                ProposalKind::Dispatch { .. } => {
+                    let encoded_call = call.ok_or(Error::<T>::UndecodableCall)?;
+                    let call = <T as pallet::Config>::RuntimeCall::decode(&mut &encoded_call[..])
+                        .map_err(|_| Error::<T>::UndecodableCall)?;
+                    ensure!(
+                        <T as pallet::Config>::CallFilter::contains(&call),
+                        Error::<T>::CallFiltered
+                    );
+                    let info = call.get_dispatch_info();
+                    let result = call.dispatch(Origin::PropertyCollective(asset_id).into());
+                    // The call is dispatched in on_initialize, so its weight is registered here
+                    frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                        extract_actual_weight(&result, &info),
+                        DispatchClass::Mandatory,
+                    );
+                    result.map_err(|e| e.error)?;
+                }
+            }
 
             Self::deposit_event(Event::ProposalExecuted {
                 asset_id,
-                amount: proposal_amount,
+                kind: proposal.kind,
             });
 
             Ok(())
         }
 
+        /// Ensures that the parameters of a proposal kind are valid.
+        fn ensure_valid_proposal_kind(kind: &ProposalKindOf<T>) -> DispatchResult {
+            match kind {
+                ProposalKind::CapitalExpenditure {
+                    amount,
+                    payment_asset,
+                    ..
+                } => {
+                    ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+                    ensure!(
+                        <T as pallet::Config>::AcceptedAssets::is_accepted(*payment_asset),
+                        Error::<T>::PaymentAssetNotSupported
+                    );
+                }
+                ProposalKind::RentChange { rent } => {
+                    ensure!(!rent.is_zero(), Error::<T>::ZeroAmount);
+                }
+                ProposalKind::Refinancing { principal, .. } => {
+                    ensure!(!principal.is_zero(), Error::<T>::ZeroAmount);
+                }
+                ProposalKind::TokenBuyback {
+                    token_amount,
+                    price_per_token,
+                    payment_asset,
+                } => {
+                    ensure!(
+                        *token_amount > 0 && !price_per_token.is_zero(),
+                        Error::<T>::ZeroAmount
+                    );
+                    ensure!(
+                        <T as pallet::Config>::AcceptedAssets::is_accepted(*payment_asset),
+                        Error::<T>::PaymentAssetNotSupported
+                    );
+                }
+                ProposalKind::LettingAgentFeeChange { .. }
+                | ProposalKind::ReservePolicyChange { .. } => {}
+                ProposalKind::Dispatch { .. } => {
+                    return Err(Error::<T>::InvalidProposalKind.into());
+                }
+            }
+            Ok(())
+        }
+
+        /// Returns the votes that a proposal of a kind needs to pass.
+        /// Capital expenditures from HighProposal on need at least the HighThreshold.
+        pub fn voting_requirements(kind: &ProposalKindOf<T>) -> VotingRequirements {
+            let mut requirements =
+                ProposalRequirements::<T>::get(kind.category()).unwrap_or(VotingRequirements {
+                    quorum: <T as pallet::Config>::MinVotingQuorum::get(),
+                    threshold: Percent::zero(),
+                });
+            if let ProposalKind::CapitalExpenditure { amount, .. } = kind {
+                if *amount >= <T as Config>::HighProposal::get() {
+                    requirements.threshold = requirements
+                        .threshold
+                        .max(<T as Config>::HighThreshold::get());
+                }
+            }
+            requirements
+        }
+
         /// Executes a sale proposal once it passes.
         fn execute_sale_proposal(asset_id: u32, property_token_amount: u32) -> DispatchResult {
             let property_sale_info = PropertySaleInfo {
@@ -2169,6 +3182,27 @@ pub mod pallet {
     }
 }
 
+/// Ensures that the origin is the property collective of the property.
+pub struct EnsurePropertyCollective;
+
+impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOriginWithArg<O, u32>
+    for EnsurePropertyCollective
+{
+    type Success = ();
+
+    fn try_origin(origin: O, asset_id: &u32) -> Result<Self::Success, O> {
+        origin.into().and_then(|origin| match origin {
+            Origin::PropertyCollective(id) if id == *asset_id => Ok(()),
+            origin => Err(O::from(origin)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(asset_id: &u32) -> Result<O, ()> {
+        Ok(O::from(Origin::PropertyCollective(*asset_id)))
+    }
+}
+
 sp_api::decl_runtime_apis! {
     pub trait PropertyGovernanceApi<AccountId>
     where
@@ -2176,4 +3210,17 @@ sp_api::decl_runtime_apis! {
     {
         fn get_governance_account_id() -> AccountId;
     }
+
+    pub trait PropertyGovernanceHistoryApi<BlockNumber>
+    where
+        BlockNumber: Codec,
+    {
+        /// Returns the finished votings of a property, starting with the most recent one or
+        /// after the voting of the proposal `start`.
+        fn proposal_history(
+            asset_id: u32,
+            start: Option<ProposalId>,
+            limit: u32,
+        ) -> Vec<ProposalRecord<BlockNumber>>;
+    }
 }
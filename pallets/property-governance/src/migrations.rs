@@ -0,0 +1,89 @@
+//! Storage migrations for the property governance pallet.
+
+use super::*;
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+pub mod v1 {
+    use super::*;
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    /// The vote record of a user before votes could be cast by a delegate.
+    #[derive(Encode, Decode)]
+    pub struct OldVoteRecord {
+        pub vote: Vote,
+        pub asset_id: u32,
+        pub power: u32,
+    }
+
+    impl From<OldVoteRecord> for VoteRecord {
+        fn from(old: OldVoteRecord) -> Self {
+            VoteRecord {
+                vote: old.vote,
+                asset_id: old.asset_id,
+                power: old.power,
+                delegated: false,
+            }
+        }
+    }
+
+    /// The proposal before proposals were typed.
+    #[derive(Encode, Decode)]
+    pub struct OldProposal<T: Config> {
+        pub proposer: AccountIdOf<T>,
+        pub amount: <T as pallet::Config>::Balance,
+        pub created_at: BlockNumberFor<T>,
+        pub metadata: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>,
+    }
+
+    /// Translates the vote records of ongoing votings into votes that have been cast by the
+    /// voters themselves and the ongoing proposals into capital expenditures that are paid to
+    /// their proposer in the first accepted payment asset.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()
+                .first()
+                .copied()
+                .unwrap_or_default();
+            Proposals::<T>::translate::<OldProposal<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(Proposal {
+                    proposer: old.proposer.clone(),
+                    kind: ProposalKind::CapitalExpenditure {
+                        beneficiary: old.proposer,
+                        amount: old.amount,
+                        payment_asset,
+                    },
+                    created_at: old.created_at,
+                    metadata: old.metadata,
+                })
+            });
+            UserProposalVote::<T>::translate::<OldVoteRecord, _>(|_, _, old| {
+                translated.saturating_inc();
+                Some(old.into())
+            });
+            UserChallengeVote::<T>::translate::<OldVoteRecord, _>(|_, _, old| {
+                translated.saturating_inc();
+                Some(old.into())
+            });
+            UserSaleProposalVote::<T>::translate::<OldVoteRecord, _>(|_, _, old| {
+                translated.saturating_inc();
+                Some(old.into())
+            });
+            T::DbWeight::get().reads_writes(translated.saturating_add(1), translated)
+        }
+    }
+
+    /// Migrates the property governance from storage version 0 to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
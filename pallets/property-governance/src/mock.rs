@@ -3,13 +3,14 @@ use super::*;
 use crate as pallet_property_governance;
 use frame_support::{
     derive_impl, parameter_types,
-    traits::{AsEnsureOriginWithArg, EnsureOriginWithArg, OriginTrait},
+    traits::{AsEnsureOriginWithArg, Contains, EnsureOriginWithArg, OriginTrait},
+    weights::Weight,
     BoundedVec, PalletId,
 };
-use sp_core::{ConstU128, ConstU32};
+use sp_core::{ConstU128, ConstU32, ConstU64};
 use sp_runtime::{
     traits::{AccountIdLookup, BlakeTwo256, IdentifyAccount, Verify},
-    MultiSignature,
+    MultiSignature, Permill,
 };
 
 use frame_system::EnsureRoot;
@@ -236,6 +237,14 @@ impl pallet_xcavate_whitelist::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_xcavate_whitelist::weights::SubstrateWeight<Test>;
     type WhitelistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type RoleGrantPeriod = ConstU64<100_000>;
+    type ExpiryNoticePeriod = ConstU64<10>;
+    type MaxExpiriesPerBlock = ConstU32<100>;
+    type MaxJurisdictions = ConstU32<10>;
+    type PendingActionLifetime = ConstU64<20>;
+    type MaxApprovals = ConstU32<5>;
+    type MaxRoleAssignments = ConstU32<100>;
+    type MaxLinkedAccounts = ConstU32<5>;
 }
 
 use pallet_xcavate_whitelist::{self as whitelist, RolePermission};
@@ -296,6 +305,34 @@ impl<T: whitelist::Config> EnsureOriginWithArg<T::RuntimeOrigin, whitelist::Role
     }
 }
 
+impl<T: whitelist::Config> EnsureOriginWithArg<T::RuntimeOrigin, whitelist::InvestmentEligibility>
+    for EnsureCompliant<T>
+{
+    type Success = T::AccountId;
+
+    fn try_origin(
+        origin: T::RuntimeOrigin,
+        eligibility: &whitelist::InvestmentEligibility,
+    ) -> Result<Self::Success, T::RuntimeOrigin> {
+        let Some(who) = origin.clone().into_signer() else {
+            return Err(origin);
+        };
+        if whitelist::Pallet::<T>::is_eligible(&who, eligibility) {
+            Ok(who)
+        } else {
+            Err(origin)
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(
+        _eligibility: &whitelist::InvestmentEligibility,
+    ) -> Result<T::RuntimeOrigin, ()> {
+        let account = frame_benchmarking::whitelisted_caller();
+        Ok(frame_system::RawOrigin::Signed(account).into())
+    }
+}
+
 parameter_types! {
     pub const Postcode: u32 = 10;
     pub const MaximumListingDuration: u64 = 10_000;
@@ -354,6 +391,7 @@ impl pallet_real_estate_asset::Config for Test {
     type AssetId = <Self as pallet_assets::Config<Instance1>>::AssetId;
     type PropertyAccountFundingAmount = ConstU128<100>;
     type MaxPropertyToken = MaxPropertyTokens;
+    type OnBalanceChange = PropertyManagement;
 }
 
 parameter_types! {
@@ -362,13 +400,19 @@ parameter_types! {
     pub const MaxPropertyTokens: u32 = 1000;
     pub const MaxNftsInCollection: u32 = 100;
     pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
-    pub const AcceptedPaymentAssets: [u32; 2] = [1337, 1984];
     pub const MaximumAcceptedAssets: u32 = 2;
+    pub const PriceReferenceDecimals: u8 = 6;
     pub const LawyerVotingDuration: BlockNumber = 30;
     pub const LegalProcessDuration: BlockNumber = 100;
     pub const MinimumVotingQuorum: Percent = Percent::from_percent(50);
+    pub const PropertyIncomeReserveShare: Percent = Percent::from_percent(10);
     pub const ClaimWindowTime: BlockNumber = 100;
     pub const MaximumRelistAttempts: u8 = 1;
+    pub const MaxOrdersPerProperty: u32 = 100;
+    pub const MaxOrdersPerInvestor: u32 = 5;
+    pub const MaxListingForBlock: u32 = 100;
+    pub const ExpiryProcessingWeight: Weight = Weight::MAX;
+    pub const DefaultPropertyOwnershipCap: Permill = Permill::from_percent(50);
 }
 
 /// Configure the pallet-xcavate-staking in pallets/xcavate-staking.
@@ -389,8 +433,10 @@ impl pallet_marketplace::Config for Test {
     type AssetId = <Self as pallet_assets::Config<Instance1>>::AssetId;
     type ListingDeposit = ConstU128<10>;
     type MarketplaceFeePercentage = ConstU128<1>;
-    type AcceptedAssets = AcceptedPaymentAssets;
+    type AssetRegistryOrigin = EnsureRoot<Self::AccountId>;
     type MaxAcceptedAssets = MaximumAcceptedAssets;
+    type ReferenceDecimals = PriceReferenceDecimals;
+    type ExchangeRates = ();
     type PropertyToken = RealEstateAsset;
     type LawyerVotingTime = LawyerVotingDuration;
     type LegalProcessTime = LegalProcessDuration;
@@ -400,6 +446,13 @@ impl pallet_marketplace::Config for Test {
     type MinVotingQuorum = MinimumVotingQuorum;
     type ClaimWindow = ClaimWindowTime;
     type MaxRelistAttempts = MaximumRelistAttempts;
+    type MaxOrdersPerAsset = MaxOrdersPerProperty;
+    type MaxOrdersPerAccount = MaxOrdersPerInvestor;
+    type OrderDeposit = ConstU128<100>;
+    type MaxListingsForBlock = MaxListingForBlock;
+    type ExpiryProcessingWeight = ExpiryProcessingWeight;
+    type DefaultOwnershipCap = DefaultPropertyOwnershipCap;
+    type OwnershipCapOrigin = EnsurePropertyCollective;
 }
 
 parameter_types! {
@@ -424,11 +477,13 @@ impl pallet_property_management::Config for Test {
     type MaxProperties = MaxProperty;
     type MaxLettingAgents = MaxLettingAgent;
     type MaxLocations = MaxLocation;
-    type AcceptedAssets = AcceptedPaymentAssets;
+    type AcceptedAssets = Marketplace;
     type PropertyToken = RealEstateAsset;
     type LettingAgentVotingTime = LettingAgentVotingDuration;
     type PermissionOrigin = EnsureHasRole<Self>;
     type MinVotingQuorum = MinimumVotingQuorum;
+    type PropertyGovernanceOrigin = EnsurePropertyCollective;
+    type IncomeReserveShare = PropertyIncomeReserveShare;
 }
 
 parameter_types! {
@@ -441,6 +496,20 @@ parameter_types! {
     pub const SalesProposalThreshold: Percent = Percent::from_percent(90);
     pub const AuctionDuration: BlockNumber = 30;
     pub const MinimumPropertySaleQuorum: Percent = Percent::from_percent(75);
+    pub const MaxPropertyDelegators: u32 = 10;
+}
+
+pub struct PropertyCollectiveCalls;
+impl Contains<RuntimeCall> for PropertyCollectiveCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::PropertyManagement(
+                pallet_property_management::Call::sweep_undistributed_income { .. }
+                    | pallet_property_management::Call::dismiss_letting_agent { .. }
+            ) | RuntimeCall::Marketplace(pallet_marketplace::Call::set_ownership_cap { .. })
+        )
+    }
 }
 
 /// Configure the pallet-property-governance in pallets/property-governance.
@@ -458,18 +527,24 @@ impl pallet_property_governance::Config for Test {
     type MaxVotesForBlock = MaxVoteForBlock;
     type MinSlashingAmount = ConstU128<100>;
     type HighThreshold = HighVotingThreshold;
-    type LowProposal = ConstU128<500>;
     type HighProposal = ConstU128<2000>;
     type MarketplacePalletId = MarketplacePalletId;
     type SaleApprovalYesThreshold = SalesProposalThreshold;
     type AuctionTime = AuctionDuration;
     type Slash = ();
-    type AcceptedAssets = AcceptedPaymentAssets;
+    type AcceptedAssets = Marketplace;
     type TreasuryId = TreasuryPalletId;
     type PropertyToken = RealEstateAsset;
     type PermissionOrigin = EnsureHasRole<Self>;
     type MinVotingQuorum = MinimumVotingQuorum;
     type MinPropertySaleQuorum = MinimumPropertySaleQuorum;
+    type MaxDelegators = MaxPropertyDelegators;
+    type ProposalRequirementsOrigin = EnsureRoot<Self::AccountId>;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type CallFilter = PropertyCollectiveCalls;
+    type MaxCallLength = ConstU32<512>;
+    type MaxArchivedProposals = ConstU32<3>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -507,7 +582,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
             true,
             1,
         )], // Genesis assets: id, owner, is_sufficient, min_balance
-        metadata: vec![(1984, "USDT".into(), "USDT".into(), 0)], // Genesis metadata: id, name, symbol, decimals
+        metadata: vec![(1984, "USDT".into(), "USDT".into(), 6)], // Genesis metadata: id, name, symbol, decimals
         accounts: vec![
             (1984, [0; 32].into(), 20_000_000),
             (1984, [1; 32].into(), 1_500_000),
@@ -531,7 +606,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
             true,
             1,
         )], // Genesis assets: id, owner, is_sufficient, min_balance
-        metadata: vec![(1337, "USDC".into(), "USDC".into(), 0)], // Genesis metadata: id, name, symbol, decimals
+        metadata: vec![(1337, "USDC".into(), "USDC".into(), 6)], // Genesis metadata: id, name, symbol, decimals
         accounts: vec![
             (1337, [4; 32].into(), 5000),
             (1337, [10; 32].into(), 1_000_000),
@@ -541,5 +616,11 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     .assimilate_storage(&mut test)
     .unwrap();
 
+    pallet_marketplace::GenesisConfig::<Test> {
+        accepted_assets: vec![(1337, 1, false), (1984, 1, false)], // Accepted payment assets: id, min_amount, uses_exchange_rate
+    }
+    .assimilate_storage(&mut test)
+    .unwrap();
+
     test.into()
 }
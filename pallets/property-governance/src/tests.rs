@@ -1,24 +1,32 @@
 use crate::{mock::*, Error, Event};
+use codec::Encode;
 use frame_support::{
     assert_noop, assert_ok,
-    sp_runtime::{traits::BadOrigin, Percent, Permill},
+    sp_runtime::{
+        traits::{BadOrigin, BlakeTwo256, Hash},
+        Percent, Permill,
+    },
     traits::{
         fungible::InspectHold,
         fungibles::{Inspect, InspectFreeze, InspectHold as FungiblesInspectHold},
-        OnFinalize, OnInitialize,
+        GetStorageVersion, OnFinalize, OnInitialize, OnRuntimeUpgrade, StorageVersion,
     },
 };
 
 use crate::{
     AssetLettingChallenge, AssetProposal, AssetSaleProposal, ChallengeRoundsExpiring, Challenges,
-    OngoingChallengeVotes, OngoingProposalVotes, OngoingSaleProposalVotes, PropertySale,
-    PropertySaleFunds, Proposals, Reserve, SaleAuctions, SaleProposals, UserChallengeVote,
-    UserProposalVote, UserSaleProposalVote, VoteRecord,
+    DelegationScope, Delegations, Delegators, OngoingChallengeVotes, OngoingProposalVotes,
+    OngoingSaleProposalVotes, PropertySale, PropertySaleFunds, ProposalCalls, ProposalCategory,
+    ProposalHistory, ProposalKind, ProposalOutcome, ProposalRecord, ProposalRequirements,
+    ProposalType, Proposals, Reserve, SaleAuctions, SaleProposals, TokenBuybacks,
+    UserChallengeVote, UserProposalVote, UserSaleProposalVote, VoteRecord, VotingRequirements,
 };
 
-use pallet_property_management::{InvestorFunds, LettingInfo, LettingStorage};
+use pallet_property_management::{
+    InvestorFunds, LettingInfo, LettingStorage, PropertyReserve, PropertyTermsStorage,
+};
 
-use pallet_marketplace::types::LegalProperty;
+use pallet_marketplace::types::{LegalProperty, SaleMode, SaleTerms};
 
 use pallet_real_estate_asset::{Error as RealEstateAssetError, PropertyAssetInfo, PropertyOwner};
 
@@ -105,7 +113,11 @@ fn listing_process() {
         10_000,
         100,
         bvec![22, 22],
-        false
+        SaleTerms {
+            tax_paid_by_developer: false,
+            sale_mode: SaleMode::FixedPrice,
+            soft_cap: None
+        }
     ));
 }
 
@@ -198,6 +210,94 @@ fn lawyer_process(accounts: Vec<(AccountId, u32)>) {
     ));
 }
 
+fn property_with_reserve_setup() {
+    assert_ok!(XcavateWhitelist::add_admin(
+        RuntimeOrigin::root(),
+        [20; 32].into(),
+    ));
+    listing_process();
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [1; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [30; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [31; 32].into(),
+        pallet_xcavate_whitelist::Role::RealEstateInvestor
+    ));
+    assert_ok!(Marketplace::buy_property_token(
+        RuntimeOrigin::signed([1; 32].into()),
+        0,
+        40,
+        1984,
+        None
+    ));
+    assert_ok!(Marketplace::buy_property_token(
+        RuntimeOrigin::signed([30; 32].into()),
+        0,
+        30,
+        1984,
+        None
+    ));
+    assert_ok!(Marketplace::buy_property_token(
+        RuntimeOrigin::signed([31; 32].into()),
+        0,
+        30,
+        1984,
+        None
+    ));
+    assert_ok!(Marketplace::claim_property_token(
+        RuntimeOrigin::signed([1; 32].into()),
+        0
+    ));
+    assert_ok!(Marketplace::claim_property_token(
+        RuntimeOrigin::signed([30; 32].into()),
+        0
+    ));
+    assert_ok!(Marketplace::claim_property_token(
+        RuntimeOrigin::signed([31; 32].into()),
+        0
+    ));
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [5; 32].into(),
+        pallet_xcavate_whitelist::Role::SpvConfirmation
+    ));
+    assert_ok!(Marketplace::create_spv(
+        RuntimeOrigin::signed([5; 32].into()),
+        0,
+    ));
+    lawyer_process(vec![([1; 32].into(), 40), ([30; 32].into(), 30)]);
+    assert_ok!(XcavateWhitelist::assign_role(
+        RuntimeOrigin::signed([20; 32].into()),
+        [4; 32].into(),
+        pallet_xcavate_whitelist::Role::LettingAgent
+    ));
+    setting_letting_agent(
+        [4; 32].into(),
+        vec![([1; 32].into(), 40), ([30; 32].into(), 30)],
+    );
+    assert_eq!(LettingStorage::<Test>::get(0).unwrap(), [4; 32].into());
+    assert_ok!(ForeignAssets::transfer(
+        RuntimeOrigin::signed([0; 32].into()),
+        codec::Compact(1984),
+        sp_runtime::MultiAddress::Id([4; 32].into()),
+        100_000
+    ));
+    assert_ok!(PropertyManagement::distribute_income(
+        RuntimeOrigin::signed([4; 32].into()),
+        0,
+        100_000,
+        1984,
+    ));
+}
+
 #[test]
 fn propose_works() {
     new_test_ext().execute_with(|| {
@@ -226,19 +326,22 @@ fn propose_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -275,11 +378,15 @@ fn propose_works() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([2; 32].into()),
             0,
-            1000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
-        assert_eq!(Proposals::<Test>::get(0).is_some(), true);
-        assert_eq!(OngoingProposalVotes::<Test>::get(0).is_some(), true);
+        assert!(Proposals::<Test>::get(0).is_some());
+        assert!(OngoingProposalVotes::<Test>::get(0).is_some());
         assert_eq!(AssetProposal::<Test>::get(0).unwrap(), 0);
     });
 }
@@ -312,19 +419,22 @@ fn proposal_with_low_amount_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -361,25 +471,68 @@ fn proposal_with_low_amount_works() {
         assert_ok!(PropertyManagement::distribute_income(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            1000,
+            5000,
             1984,
         ));
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 500);
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            500,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 500,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
+        assert!(Proposals::<Test>::get(0).is_some());
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 500);
+        assert_noop!(
+            PropertyGovernance::propose(
+                RuntimeOrigin::signed([4; 32].into()),
+                0,
+                ProposalKind::CapitalExpenditure {
+                    beneficiary: [4; 32].into(),
+                    amount: 500,
+                    payment_asset: 1984,
+                },
+                bvec![10, 10]
+            ),
+            Error::<Test>::ProposalOngoing
+        );
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
         System::assert_last_event(
             Event::ProposalExecuted {
                 asset_id: 0,
-                amount: 500,
+                kind: ProposalKind::CapitalExpenditure {
+                    beneficiary: [4; 32].into(),
+                    amount: 500,
+                    payment_asset: 1984,
+                },
             }
             .into(),
         );
-        assert_eq!(Balances::free_balance(&([4; 32].into())), 4000);
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 4000);
-        assert_eq!(OngoingProposalVotes::<Test>::get(0).is_some(), false);
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 0);
+        assert_eq!(Balances::free_balance(AccountId::from([4; 32])), 4000);
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 500);
+        assert_eq!(
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
+            4500
+        );
+        assert!(OngoingProposalVotes::<Test>::get(0).is_none());
     });
 }
 
@@ -416,25 +569,32 @@ fn propose_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_noop!(
             PropertyGovernance::propose(
                 RuntimeOrigin::signed([2; 32].into()),
                 0,
-                1000,
+                ProposalKind::CapitalExpenditure {
+                    beneficiary: [4; 32].into(),
+                    amount: 1000,
+                    payment_asset: 1984,
+                },
                 bvec![10, 10]
             ),
             Error::<Test>::NoLettingAgentFound
@@ -475,7 +635,11 @@ fn propose_fails() {
             PropertyGovernance::propose(
                 RuntimeOrigin::signed([2; 32].into()),
                 0,
-                1000,
+                ProposalKind::CapitalExpenditure {
+                    beneficiary: [4; 32].into(),
+                    amount: 1000,
+                    payment_asset: 1984,
+                },
                 bvec![10, 10]
             ),
             Error::<Test>::NoPermission
@@ -483,14 +647,22 @@ fn propose_fails() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([0; 32].into()),
             0,
-            1000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
         assert_noop!(
             PropertyGovernance::propose(
                 RuntimeOrigin::signed([0; 32].into()),
                 0,
-                1000,
+                ProposalKind::CapitalExpenditure {
+                    beneficiary: [4; 32].into(),
+                    amount: 1000,
+                    payment_asset: 1984,
+                },
                 bvec![10, 10]
             ),
             Error::<Test>::ProposalOngoing
@@ -526,19 +698,22 @@ fn challenge_against_letting_agent_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -575,9 +750,9 @@ fn challenge_against_letting_agent_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
-        assert_eq!(AssetLettingChallenge::<Test>::get(0).is_some(), true);
-        assert_eq!(OngoingChallengeVotes::<Test>::get(0).is_some(), true);
-        assert_eq!(Challenges::<Test>::get(0).is_some(), true);
+        assert!(AssetLettingChallenge::<Test>::get(0).is_some());
+        assert!(OngoingChallengeVotes::<Test>::get(0).is_some());
+        assert!(Challenges::<Test>::get(0).is_some());
     });
 }
 
@@ -614,19 +789,22 @@ fn challenge_against_letting_agent_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -673,7 +851,7 @@ fn challenge_against_letting_agent_fails() {
             ),
             Error::<Test>::NoPermission
         );
-        assert_eq!(Challenges::<Test>::get(0).is_some(), false);
+        assert!(Challenges::<Test>::get(0).is_none());
         assert_ok!(PropertyGovernance::challenge_against_letting_agent(
             RuntimeOrigin::signed([1; 32].into()),
             0
@@ -716,25 +894,29 @@ fn vote_on_proposal_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             15,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             15,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -777,7 +959,11 @@ fn vote_on_proposal_works() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([0; 32].into()),
             0,
-            1000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
         assert_ok!(PropertyGovernance::vote_on_proposal(
@@ -800,6 +986,7 @@ fn vote_on_proposal_works() {
                 vote: crate::Vote::Yes,
                 asset_id: 0,
                 power: 45,
+                delegated: false,
             }
         );
         assert_ok!(PropertyGovernance::vote_on_proposal(
@@ -846,8 +1033,76 @@ fn vote_on_proposal_works() {
                 vote: crate::Vote::No,
                 asset_id: 0,
                 power: 30,
+                delegated: false,
             }
         );
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Withdraw,
+            0
+        ));
+        let current_vote = OngoingProposalVotes::<Test>::get(0).unwrap();
+        assert_eq!(current_vote.yes_voting_power, 10);
+        assert_eq!(current_vote.no_voting_power, 40);
+        assert_eq!(
+            AssetsFreezer::balance_frozen(
+                0,
+                &MarketplaceFreezeReason::ProposalVoting,
+                &[1; 32].into()
+            ),
+            0
+        );
+        assert!(UserProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_none());
+        assert_noop!(
+            PropertyGovernance::vote_on_proposal(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                crate::Vote::Withdraw,
+                0
+            ),
+            Error::<Test>::NoVoteToWithdraw
+        );
+    });
+}
+
+#[test]
+fn withdrawn_vote_does_not_count_towards_quorum() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        property_with_reserve_setup();
+        assert_ok!(PropertyGovernance::propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
+            bvec![10, 10]
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Withdraw,
+            0
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        System::assert_last_event(Event::ProposalRejected { proposal_id: 0 }.into());
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 10_000);
     });
 }
 
@@ -907,25 +1162,32 @@ fn proposal_pass() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1011,17 +1273,25 @@ fn proposal_pass() {
             vec![([1; 32].into(), 40), ([30; 32].into(), 30)],
         );
         assert_eq!(LettingStorage::<Test>::get(0).unwrap(), [0; 32].into());
-        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 20_000_000);
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([0; 32])),
+            20_000_000
+        );
         assert_ok!(PropertyManagement::distribute_income(
             RuntimeOrigin::signed([0; 32].into()),
             0,
-            1000,
+            10_000,
             1984,
         ));
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 1_000);
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([0; 32].into()),
             0,
-            1000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [0; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
         assert_ok!(PropertyGovernance::vote_on_proposal(
@@ -1036,24 +1306,40 @@ fn proposal_pass() {
             crate::Vote::Yes,
             30
         ));
-        assert_eq!(Proposals::<Test>::get(0).is_some(), true);
-        assert_eq!(Balances::free_balance(&([0; 32].into())), 19_999_000);
-        assert_eq!(ForeignAssets::balance(1984, &[0; 32].into()), 19_999_000);
+        assert!(Proposals::<Test>::get(0).is_some());
+        assert_eq!(Balances::free_balance(AccountId::from([0; 32])), 19_999_000);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
-            1_000
+            ForeignAssets::balance(1984, AccountId::from([0; 32])),
+            19_990_000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
+            10_000
         );
         let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
         run_to_block(expiry);
         System::assert_last_event(
             Event::ProposalExecuted {
                 asset_id: 0,
-                amount: 1000,
+                kind: ProposalKind::CapitalExpenditure {
+                    beneficiary: [0; 32].into(),
+                    amount: 1000,
+                    payment_asset: 1984,
+                },
             }
             .into(),
         );
-        assert_eq!(Proposals::<Test>::get(0).is_none(), true);
-        assert_eq!(OngoingProposalVotes::<Test>::get(0).is_none(), true);
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 0);
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([0; 32])),
+            19_991_000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
+            9_000
+        );
+        assert!(Proposals::<Test>::get(0).is_none());
+        assert!(OngoingProposalVotes::<Test>::get(0).is_none());
     });
 }
 
@@ -1085,19 +1371,22 @@ fn proposal_pass_2() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1131,10 +1420,26 @@ fn proposal_pass_2() {
             vec![([1; 32].into(), 40), ([30; 32].into(), 30)],
         );
         assert_eq!(LettingStorage::<Test>::get(0).unwrap(), [4; 32].into());
+        assert_ok!(ForeignAssets::transfer(
+            RuntimeOrigin::signed([0; 32].into()),
+            codec::Compact(1984),
+            sp_runtime::MultiAddress::Id([4; 32].into()),
+            100_000
+        ));
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            100_000,
+            1984,
+        ));
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            10000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [7; 32].into(),
+                amount: 10000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
         assert_ok!(PropertyGovernance::vote_on_proposal(
@@ -1155,17 +1460,31 @@ fn proposal_pass_2() {
             crate::Vote::Yes,
             30
         ));
-        assert_eq!(Proposals::<Test>::get(0).is_some(), true);
+        assert!(Proposals::<Test>::get(0).is_some());
+        let beneficiary_balance = ForeignAssets::balance(1984, AccountId::from([7; 32]));
         let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
         run_to_block(expiry);
         System::assert_last_event(
             Event::ProposalExecuted {
                 asset_id: 0,
-                amount: 10000,
+                kind: ProposalKind::CapitalExpenditure {
+                    beneficiary: [7; 32].into(),
+                    amount: 10000,
+                    payment_asset: 1984,
+                },
             }
             .into(),
         );
-        assert_eq!(Proposals::<Test>::get(0).is_none(), true);
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 0);
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([4; 32])),
+            5_000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([7; 32])),
+            beneficiary_balance + 10_000
+        );
+        assert!(Proposals::<Test>::get(0).is_none());
         assert_eq!(
             InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([1; 32].into(), 0, 1984)),
             0
@@ -1201,19 +1520,22 @@ fn proposal_not_pass() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1256,7 +1578,11 @@ fn proposal_not_pass() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            1000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
         assert_ok!(PropertyGovernance::vote_on_proposal(
@@ -1271,21 +1597,21 @@ fn proposal_not_pass() {
             crate::Vote::No,
             30
         ));
-        assert_eq!(Proposals::<Test>::get(0).is_some(), true);
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 4000);
-        assert_eq!(Balances::free_balance(&([0; 32].into())), 19_999_900);
+        assert!(Proposals::<Test>::get(0).is_some());
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 4000);
+        assert_eq!(Balances::free_balance(AccountId::from([0; 32])), 19_999_900);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             1000
         );
         let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
         run_to_block(expiry);
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 4000);
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 4000);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             1000
         );
-        assert_eq!(Proposals::<Test>::get(0).is_none(), true);
+        assert!(Proposals::<Test>::get(0).is_none());
         System::assert_last_event(Event::ProposalRejected { proposal_id: 0 }.into());
     });
 }
@@ -1318,19 +1644,22 @@ fn proposal_not_pass_2() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             15,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1373,7 +1702,11 @@ fn proposal_not_pass_2() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            10000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 10000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
         assert_ok!(PropertyGovernance::vote_on_proposal(
@@ -1388,11 +1721,18 @@ fn proposal_not_pass_2() {
             crate::Vote::Yes,
             15
         ));
-        assert_eq!(Proposals::<Test>::get(0).is_some(), true);
-        assert_eq!(Proposals::<Test>::get(0).unwrap().amount, 10000);
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 4000);
+        assert!(Proposals::<Test>::get(0).is_some());
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().kind,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 10000,
+                payment_asset: 1984,
+            }
+        );
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 4000);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             1000
         );
         let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
@@ -1404,10 +1744,10 @@ fn proposal_not_pass_2() {
             }
             .into(),
         );
-        assert_eq!(Proposals::<Test>::get(0).is_none(), true);
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 4000);
+        assert!(Proposals::<Test>::get(0).is_none());
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 4000);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             1000
         );
     });
@@ -1441,19 +1781,22 @@ fn proposal_not_pass_3() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             15,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1496,7 +1839,11 @@ fn proposal_not_pass_3() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            1000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
         assert_ok!(PropertyGovernance::vote_on_proposal(
@@ -1505,25 +1852,574 @@ fn proposal_not_pass_3() {
             crate::Vote::Yes,
             40
         ));
-        assert_eq!(Proposals::<Test>::get(0).is_some(), true);
-        assert_eq!(Proposals::<Test>::get(0).unwrap().amount, 1000);
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 4000);
+        assert!(Proposals::<Test>::get(0).is_some());
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
-            1000
+            Proposals::<Test>::get(0).unwrap().kind,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            }
+        );
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 4000);
+        assert_eq!(
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
+            1000
         );
         let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
         run_to_block(expiry);
         System::assert_last_event(Event::ProposalRejected { proposal_id: 0 }.into());
-        assert_eq!(Proposals::<Test>::get(0).is_none(), true);
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 4000);
+        assert!(Proposals::<Test>::get(0).is_none());
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 4000);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             1000
         );
     });
 }
 
+#[test]
+fn proposals_update_property_terms() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        property_with_reserve_setup();
+        assert_ok!(PropertyGovernance::propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            ProposalKind::RentChange { rent: 2_000 },
+            bvec![10, 10]
+        ));
+        assert!(Proposals::<Test>::get(0).is_some());
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        System::assert_last_event(
+            Event::ProposalExecuted {
+                asset_id: 0,
+                kind: ProposalKind::RentChange { rent: 2_000 },
+            }
+            .into(),
+        );
+        assert_eq!(PropertyTermsStorage::<Test>::get(0).rent, Some(2_000));
+        assert_ok!(PropertyGovernance::propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            ProposalKind::ReservePolicyChange {
+                reserve_share: Percent::from_percent(30)
+            },
+            bvec![10, 10]
+        ));
+        assert_ok!(PropertyGovernance::unfreeze_proposal_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        assert_eq!(
+            PropertyManagement::reserve_share(0),
+            Percent::from_percent(30)
+        );
+        assert_eq!(PropertyTermsStorage::<Test>::get(0).rent, Some(2_000));
+        assert_noop!(
+            PropertyGovernance::propose(
+                RuntimeOrigin::signed([4; 32].into()),
+                0,
+                ProposalKind::RentChange { rent: 0 },
+                bvec![10, 10]
+            ),
+            Error::<Test>::ZeroAmount
+        );
+    });
+}
+
+#[test]
+fn set_proposal_requirements_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        property_with_reserve_setup();
+        let requirements = VotingRequirements {
+            quorum: Percent::from_percent(50),
+            threshold: Percent::from_percent(80),
+        };
+        assert_noop!(
+            PropertyGovernance::set_proposal_requirements(
+                RuntimeOrigin::signed([4; 32].into()),
+                ProposalCategory::LettingAgentFeeChange,
+                requirements.clone(),
+            ),
+            BadOrigin
+        );
+        assert_ok!(PropertyGovernance::set_proposal_requirements(
+            RuntimeOrigin::root(),
+            ProposalCategory::LettingAgentFeeChange,
+            requirements.clone(),
+        ));
+        assert_eq!(
+            ProposalRequirements::<Test>::get(ProposalCategory::LettingAgentFeeChange),
+            Some(requirements)
+        );
+        assert_ok!(PropertyGovernance::propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            ProposalKind::LettingAgentFeeChange {
+                fee: Percent::from_percent(5)
+            },
+            bvec![10, 10]
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        System::assert_last_event(
+            Event::ProposalThresHoldNotReached {
+                proposal_id: 0,
+                required_threshold: Percent::from_percent(80),
+            }
+            .into(),
+        );
+        assert_eq!(PropertyTermsStorage::<Test>::get(0).letting_agent_fee, None);
+    });
+}
+
+#[test]
+fn token_buyback_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        property_with_reserve_setup();
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 10_000);
+        assert_noop!(
+            PropertyGovernance::sell_token_to_buyback(
+                RuntimeOrigin::signed([31; 32].into()),
+                0,
+                10
+            ),
+            Error::<Test>::NoTokenBuyback
+        );
+        assert_ok!(PropertyGovernance::propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            ProposalKind::TokenBuyback {
+                token_amount: 30,
+                price_per_token: 100,
+                payment_asset: 1984,
+            },
+            bvec![10, 10]
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        assert_eq!(TokenBuybacks::<Test>::get(0).unwrap().remaining_token, 30);
+        let balance = ForeignAssets::balance(1984, AccountId::from([31; 32]));
+        assert_ok!(PropertyGovernance::sell_token_to_buyback(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            20
+        ));
+        System::assert_last_event(
+            Event::TokenSoldToBuyback {
+                asset_id: 0,
+                seller: [31; 32].into(),
+                token_amount: 20,
+                price: 2_000,
+            }
+            .into(),
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([31; 32])),
+            balance + 2_000
+        );
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 8_000);
+        assert_eq!(
+            pallet_real_estate_asset::PropertyOwnerToken::<Test>::get(0, AccountId::from([31; 32])),
+            10
+        );
+        assert_eq!(TokenBuybacks::<Test>::get(0).unwrap().remaining_token, 10);
+        assert_noop!(
+            PropertyGovernance::sell_token_to_buyback(
+                RuntimeOrigin::signed([31; 32].into()),
+                0,
+                20
+            ),
+            Error::<Test>::NotEnoughBuybackToken
+        );
+        assert_noop!(
+            PropertyGovernance::sell_token_to_buyback(RuntimeOrigin::signed([31; 32].into()), 0, 0),
+            Error::<Test>::ZeroAmount
+        );
+        assert_ok!(PropertyGovernance::sell_token_to_buyback(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            10
+        ));
+        assert!(TokenBuybacks::<Test>::get(0).is_none());
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 7_000);
+        assert!(!PropertyOwner::<Test>::get(0).contains(&[31; 32].into()));
+    });
+}
+
+#[test]
+fn income_is_distributed_to_remaining_token_after_buyback() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        property_with_reserve_setup();
+        assert_ok!(PropertyGovernance::propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            ProposalKind::TokenBuyback {
+                token_amount: 30,
+                price_per_token: 100,
+                payment_asset: 1984,
+            },
+            bvec![10, 10]
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyGovernance::sell_token_to_buyback(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            30
+        ));
+        assert_eq!(
+            pallet_real_estate_asset::PropertyAssetInfo::<Test>::get(0)
+                .unwrap()
+                .token_amount,
+            70
+        );
+        let income_1 = PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984);
+        let income_30 = PropertyManagement::claimable_income(&[30; 32].into(), 0, 1984);
+        let income_31 = PropertyManagement::claimable_income(&[31; 32].into(), 0, 1984);
+        assert_ok!(ForeignAssets::transfer(
+            RuntimeOrigin::signed([0; 32].into()),
+            codec::Compact(1984),
+            sp_runtime::MultiAddress::Id([4; 32].into()),
+            70_000
+        ));
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            70_000,
+            1984,
+        ));
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            income_1 + 36_000
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[30; 32].into(), 0, 1984),
+            income_30 + 27_000
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[31; 32].into(), 0, 1984),
+            income_31
+        );
+    });
+}
+
+#[test]
+fn propose_call_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        property_with_reserve_setup();
+        assert_noop!(
+            PropertyGovernance::propose_call(
+                RuntimeOrigin::signed([4; 32].into()),
+                0,
+                Box::new(RuntimeCall::System(frame_system::Call::remark {
+                    remark: vec![1, 2]
+                })),
+                bvec![10, 10]
+            ),
+            Error::<Test>::CallFiltered
+        );
+        assert_noop!(
+            PropertyGovernance::propose(
+                RuntimeOrigin::signed([4; 32].into()),
+                0,
+                ProposalKind::Dispatch { call_hash: [0; 32] },
+                bvec![10, 10]
+            ),
+            Error::<Test>::InvalidProposalKind
+        );
+        let call = RuntimeCall::Marketplace(pallet_marketplace::Call::set_ownership_cap {
+            asset_id: 0,
+            cap: Some(Permill::from_percent(60)),
+        });
+        let call_hash: [u8; 32] = BlakeTwo256::hash(&call.encode()).into();
+        assert_ok!(PropertyGovernance::propose_call(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            Box::new(call.clone()),
+            bvec![10, 10]
+        ));
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().kind,
+            ProposalKind::Dispatch { call_hash }
+        );
+        assert_eq!(
+            ProposalCalls::<Test>::get(0).unwrap().to_vec(),
+            call.encode()
+        );
+        assert_noop!(
+            PropertyGovernance::propose_call(
+                RuntimeOrigin::signed([4; 32].into()),
+                0,
+                Box::new(call),
+                bvec![10, 10]
+            ),
+            Error::<Test>::ProposalOngoing
+        );
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        System::assert_last_event(
+            Event::ProposalExecuted {
+                asset_id: 0,
+                kind: ProposalKind::Dispatch { call_hash },
+            }
+            .into(),
+        );
+        assert!(ProposalCalls::<Test>::get(0).is_none());
+        assert_eq!(
+            pallet_marketplace::OwnershipCap::<Test>::get(0),
+            Some(Permill::from_percent(60))
+        );
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 10_000);
+    });
+}
+
+#[test]
+fn propose_call_dismisses_letting_agent() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        property_with_reserve_setup();
+        assert_ok!(PropertyGovernance::propose_call(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            Box::new(RuntimeCall::Marketplace(
+                pallet_marketplace::Call::set_ownership_cap {
+                    asset_id: 1,
+                    cap: Some(Permill::from_percent(60)),
+                }
+            )),
+            bvec![10, 10]
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        System::assert_last_event(Event::ProposalExecutionFailed { proposal_id: 0 }.into());
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 10_000);
+        assert_ok!(PropertyGovernance::unfreeze_proposal_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(PropertyGovernance::unfreeze_proposal_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        // Only token holders of the property can propose next to the letting agent.
+        assert_noop!(
+            PropertyGovernance::propose_call(
+                RuntimeOrigin::signed([2; 32].into()),
+                0,
+                Box::new(RuntimeCall::PropertyManagement(
+                    pallet_property_management::Call::dismiss_letting_agent { asset_id: 0 }
+                )),
+                bvec![10, 10]
+            ),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(PropertyGovernance::propose_call(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            Box::new(RuntimeCall::PropertyManagement(
+                pallet_property_management::Call::dismiss_letting_agent { asset_id: 0 }
+            )),
+            bvec![10, 10]
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        assert_eq!(LettingStorage::<Test>::get(0), None);
+        assert!(Proposals::<Test>::get(1).is_none());
+    });
+}
+
+#[test]
+fn proposal_history_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        property_with_reserve_setup();
+        assert_ok!(PropertyGovernance::propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            ProposalKind::RentChange { rent: 2_000 },
+            bvec![10, 10]
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::No,
+            20
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        assert_eq!(Proposals::<Test>::get(0), None);
+        assert_eq!(
+            ProposalHistory::<Test>::get(0).into_inner(),
+            vec![ProposalRecord {
+                proposal_id: 0,
+                proposal_type: ProposalType::Proposal(ProposalCategory::RentChange),
+                outcome: ProposalOutcome::Executed,
+                yes_voting_power: 40,
+                no_voting_power: 20,
+                turnout: Permill::from_percent(60),
+                finished_at: expiry,
+            }]
+        );
+        assert_ok!(PropertyGovernance::challenge_against_letting_agent(
+            RuntimeOrigin::signed([31; 32].into()),
+            0
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyGovernance::propose_property_sale(
+            RuntimeOrigin::signed([31; 32].into()),
+            0
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertySaleVotingTime::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyGovernance::propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            ProposalKind::LettingAgentFeeChange {
+                fee: Percent::from_percent(5)
+            },
+            bvec![10, 10]
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
+        run_to_block(expiry);
+        let history = ProposalHistory::<Test>::get(0);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].proposal_id, 1);
+        assert_eq!(history[0].proposal_type, ProposalType::Challenge);
+        assert_eq!(history[0].outcome, ProposalOutcome::Rejected);
+        assert_eq!(history[1].proposal_id, 2);
+        assert_eq!(history[1].proposal_type, ProposalType::Sale);
+        assert_eq!(history[1].outcome, ProposalOutcome::Rejected);
+        assert_eq!(history[2].proposal_id, 3);
+        assert_eq!(
+            history[2].proposal_type,
+            ProposalType::Proposal(ProposalCategory::LettingAgentFeeChange)
+        );
+        assert_eq!(history[2].turnout, Permill::from_percent(0));
+        let records = PropertyGovernance::proposal_history(0, Some(3), 5);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].proposal_id, 2);
+        assert_eq!(records[1].proposal_id, 1);
+        assert_eq!(PropertyGovernance::proposal_history(1, None, 5), vec![]);
+    });
+}
+
 #[test]
 fn vote_on_proposal_fails() {
     new_test_ext().execute_with(|| {
@@ -1557,19 +2453,22 @@ fn vote_on_proposal_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1621,7 +2520,11 @@ fn vote_on_proposal_fails() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([0; 32].into()),
             0,
-            1000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
         assert_noop!(
@@ -1696,25 +2599,29 @@ fn unfreeze_proposal_token_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             15,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             15,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1756,7 +2663,11 @@ fn unfreeze_proposal_token_works() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([0; 32].into()),
             0,
-            1000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
         assert_ok!(PropertyGovernance::vote_on_proposal(
@@ -1805,6 +2716,7 @@ fn unfreeze_proposal_token_works() {
                 vote: crate::Vote::No,
                 asset_id: 0,
                 power: 30,
+                delegated: false,
             }
         );
         let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
@@ -1853,25 +2765,29 @@ fn unfreeze_proposal_token_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             15,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             15,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1913,7 +2829,11 @@ fn unfreeze_proposal_token_fails() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([0; 32].into()),
             0,
-            1000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
         assert_noop!(
@@ -1960,6 +2880,7 @@ fn unfreeze_proposal_token_fails() {
                 vote: crate::Vote::No,
                 asset_id: 0,
                 power: 30,
+                delegated: false,
             }
         );
         assert_noop!(
@@ -2011,25 +2932,29 @@ fn vote_on_challenge_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             20,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2086,6 +3011,7 @@ fn vote_on_challenge_works() {
                 vote: crate::Vote::Yes,
                 asset_id: 0,
                 power: 30,
+                delegated: false,
             }
         );
         assert_ok!(PropertyGovernance::vote_on_letting_agent_challenge(
@@ -2120,6 +3046,7 @@ fn vote_on_challenge_works() {
                 vote: crate::Vote::No,
                 asset_id: 0,
                 power: 40,
+                delegated: false,
             }
         );
         assert_eq!(
@@ -2185,19 +3112,22 @@ fn challenge_pass() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2257,7 +3187,7 @@ fn challenge_pass() {
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
-        assert_eq!(Challenges::<Test>::get(0).is_some(), true);
+        assert!(Challenges::<Test>::get(0).is_some());
         assert_ok!(PropertyGovernance::vote_on_letting_agent_challenge(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -2401,31 +3331,26 @@ fn challenge_pass() {
                 .unwrap()
                 .locations
                 .get(&bvec![10, 10])
-                .clone()
                 .unwrap()
                 .assigned_properties,
             1
         );
         run_to_block(211);
-        assert_eq!(
-            LettingInfo::<Test>::get::<AccountId>([0; 32].into())
-                .unwrap()
-                .active_strikes
-                .get(&0u32)
-                .is_none(),
-            true
-        );
+        assert!(LettingInfo::<Test>::get::<AccountId>([0; 32].into())
+            .unwrap()
+            .active_strikes
+            .get(&0u32)
+            .is_none());
         assert_eq!(
             LettingInfo::<Test>::get::<AccountId>([0; 32].into())
                 .unwrap()
                 .locations
                 .get(&bvec![10, 10])
-                .clone()
                 .unwrap()
                 .assigned_properties,
             1
         );
-        assert_eq!(LettingStorage::<Test>::get(0).is_none(), true);
+        assert!(LettingStorage::<Test>::get(0).is_none());
         assert_eq!(
             LettingInfo::<Test>::get::<AccountId>([0; 32].into())
                 .unwrap()
@@ -2433,7 +3358,7 @@ fn challenge_pass() {
                 .len(),
             1
         );
-        assert_eq!(Challenges::<Test>::get(0).is_none(), true);
+        assert!(Challenges::<Test>::get(0).is_none());
         assert_ok!(PropertyManagement::letting_agent_propose(
             RuntimeOrigin::signed([1; 32].into()),
             0
@@ -2527,25 +3452,32 @@ fn challenge_does_not_pass() {
             4_000,
             250,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             75,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             100,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             75,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2597,7 +3529,7 @@ fn challenge_does_not_pass() {
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
-        assert_eq!(Challenges::<Test>::get(0).is_some(), true);
+        assert!(Challenges::<Test>::get(0).is_some());
         assert_ok!(PropertyGovernance::vote_on_letting_agent_challenge(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -2645,7 +3577,7 @@ fn challenge_does_not_pass() {
             }
             .into(),
         ); */
-        assert_eq!(Challenges::<Test>::get(0).is_none(), true);
+        assert!(Challenges::<Test>::get(0).is_none());
     });
 }
 
@@ -2702,19 +3634,22 @@ fn challenge_pass_only_one_agent() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2766,7 +3701,7 @@ fn challenge_pass_only_one_agent() {
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
-        assert_eq!(Challenges::<Test>::get(0).is_some(), true);
+        assert!(Challenges::<Test>::get(0).is_some());
         assert_ok!(PropertyGovernance::vote_on_letting_agent_challenge(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -2837,8 +3772,8 @@ fn challenge_pass_only_one_agent() {
         ));
         assert_eq!(LettingStorage::<Test>::get(0).unwrap(), [0; 32].into());
         run_to_block(211);
-        assert_eq!(LettingStorage::<Test>::get(0).is_none(), true);
-        assert_eq!(Challenges::<Test>::get(0).is_none(), true);
+        assert!(LettingStorage::<Test>::get(0).is_none());
+        assert!(Challenges::<Test>::get(0).is_none());
     });
 }
 
@@ -2870,19 +3805,22 @@ fn challenge_not_pass() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2938,12 +3876,12 @@ fn challenge_not_pass() {
             crate::Vote::No,
             30
         ));
-        assert_eq!(Challenges::<Test>::get(0).is_some(), true);
+        assert!(Challenges::<Test>::get(0).is_some());
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
         System::assert_last_event(Event::ChallengeRejected { asset_id: 0 }.into());
-        assert_eq!(Challenges::<Test>::get(0).is_none(), true);
+        assert!(Challenges::<Test>::get(0).is_none());
     });
 }
 
@@ -2993,7 +3931,11 @@ fn challenge_not_pass2() {
             5_000,
             200,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
@@ -3004,19 +3946,22 @@ fn challenge_not_pass2() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             80,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             60,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             60,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -3072,12 +4017,12 @@ fn challenge_not_pass2() {
             crate::Vote::Yes,
             20
         ));
-        assert_eq!(Challenges::<Test>::get(0).is_some(), true);
+        assert!(Challenges::<Test>::get(0).is_some());
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
         System::assert_last_event(Event::ChallengeRejected { asset_id: 0 }.into());
-        assert_eq!(Challenges::<Test>::get(0).is_none(), true);
+        assert!(Challenges::<Test>::get(0).is_none());
     });
 }
 
@@ -3114,19 +4059,22 @@ fn vote_on_challenge_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -3233,25 +4181,29 @@ fn unfreeze_challenge_token_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             20,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -3320,6 +4272,7 @@ fn unfreeze_challenge_token_works() {
                 vote: crate::Vote::Yes,
                 asset_id: 0,
                 power: 30,
+                delegated: false,
             }
         );
         let expiry = frame_system::Pallet::<Test>::block_number() + PropertyVotingTime::get();
@@ -3368,25 +4321,29 @@ fn unfreeze_challenge_token_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             20,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -3453,6 +4410,7 @@ fn unfreeze_challenge_token_fails() {
                 vote: crate::Vote::Yes,
                 asset_id: 0,
                 power: 30,
+                delegated: false,
             }
         );
         assert_noop!(
@@ -3522,25 +4480,32 @@ fn different_proposals() {
             5_000,
             200,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             60,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             60,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             80,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -3583,7 +4548,11 @@ fn different_proposals() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            1000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
         assert_ok!(PropertyGovernance::vote_on_proposal(
@@ -3592,29 +4561,23 @@ fn different_proposals() {
             crate::Vote::Yes,
             60
         ));
-        assert_eq!(Proposals::<Test>::get(0).is_some(), true);
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 2000);
+        assert!(Proposals::<Test>::get(0).is_some());
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 2000);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             3000
         );
-        assert_eq!(
-            UserProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_some(),
-            true
-        );
+        assert!(UserProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_some());
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
+        assert!(UserProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_some());
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 2000);
         assert_eq!(
-            UserProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_some(),
-            true
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 2000);
-        assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             3000
         );
-        assert_eq!(Proposals::<Test>::get(0).is_none(), true);
+        assert!(Proposals::<Test>::get(0).is_none());
         assert_ok!(PropertyGovernance::unfreeze_proposal_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -3622,10 +4585,14 @@ fn different_proposals() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            3000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 3000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
-        assert_eq!(Proposals::<Test>::get(1).is_some(), true);
+        assert!(Proposals::<Test>::get(1).is_some());
         assert_ok!(PropertyGovernance::vote_on_proposal(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -3641,9 +4608,9 @@ fn different_proposals() {
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 2000);
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 2000);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             3000
         );
         assert_ok!(PropertyGovernance::unfreeze_proposal_token(
@@ -3657,10 +4624,14 @@ fn different_proposals() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            3000,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 3000,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
-        assert_eq!(Proposals::<Test>::get(2).is_some(), true);
+        assert!(Proposals::<Test>::get(2).is_some());
         assert_ok!(PropertyGovernance::vote_on_proposal(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -3682,9 +4653,9 @@ fn different_proposals() {
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 2000);
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 2000);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             3000
         );
         assert_ok!(PropertyManagement::distribute_income(
@@ -3702,10 +4673,14 @@ fn different_proposals() {
         assert_ok!(PropertyGovernance::propose(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            1500,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1500,
+                payment_asset: 1984,
+            },
             bvec![10, 10]
         ));
-        assert_eq!(Proposals::<Test>::get(3).is_some(), true);
+        assert!(Proposals::<Test>::get(3).is_some());
         assert_ok!(PropertyGovernance::unfreeze_proposal_token(
             RuntimeOrigin::signed([1; 32].into()),
             2,
@@ -3739,14 +4714,14 @@ fn different_proposals() {
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 300);
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 300);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             4700
         );
-        assert_eq!(ForeignAssets::balance(1337, &[4; 32].into()), 4700);
+        assert_eq!(ForeignAssets::balance(1337, AccountId::from([4; 32])), 4700);
         assert_eq!(
-            ForeignAssets::balance(1337, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1337, PropertyGovernance::property_account_id(0)),
             300
         );
     });
@@ -3780,19 +4755,22 @@ fn propose_property_sale_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -3830,8 +4808,8 @@ fn propose_property_sale_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
-        assert_eq!(SaleProposals::<Test>::get(0).is_some(), true);
-        assert_eq!(OngoingSaleProposalVotes::<Test>::get(0).is_some(), true);
+        assert!(SaleProposals::<Test>::get(0).is_some());
+        assert!(OngoingSaleProposalVotes::<Test>::get(0).is_some());
         assert_eq!(AssetSaleProposal::<Test>::get(0).unwrap(), 0);
     });
 }
@@ -3886,25 +4864,32 @@ fn propose_property_sale_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -3975,7 +4960,7 @@ fn propose_property_sale_fails() {
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
-        assert_eq!(PropertySale::<Test>::get(0).is_some(), true);
+        assert!(PropertySale::<Test>::get(0).is_some());
         assert_noop!(
             PropertyGovernance::propose_property_sale(RuntimeOrigin::signed([1; 32].into()), 0),
             Error::<Test>::SaleOngoing
@@ -4011,19 +4996,22 @@ fn vote_on_property_sale_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             35,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             25,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4061,8 +5049,8 @@ fn vote_on_property_sale_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
-        assert_eq!(SaleProposals::<Test>::get(0).is_some(), true);
-        assert_eq!(OngoingSaleProposalVotes::<Test>::get(0).is_some(), true);
+        assert!(SaleProposals::<Test>::get(0).is_some());
+        assert!(OngoingSaleProposalVotes::<Test>::get(0).is_some());
         assert_ok!(PropertyGovernance::vote_on_property_sale(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -4099,6 +5087,7 @@ fn vote_on_property_sale_works() {
                 vote: crate::Vote::Yes,
                 asset_id: 0,
                 power: 40,
+                delegated: false,
             }
         );
         assert_eq!(
@@ -4129,6 +5118,7 @@ fn vote_on_property_sale_works() {
                 vote: crate::Vote::No,
                 asset_id: 0,
                 power: 35,
+                delegated: false,
             }
         );
         assert_eq!(
@@ -4166,19 +5156,22 @@ fn vote_on_property_sale_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4274,19 +5267,22 @@ fn unfreeze_sale_proposal_token_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             35,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             25,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4347,6 +5343,7 @@ fn unfreeze_sale_proposal_token_works() {
                 vote: crate::Vote::Yes,
                 asset_id: 0,
                 power: 40,
+                delegated: false,
             }
         );
         assert_eq!(
@@ -4396,19 +5393,22 @@ fn unfreeze_sale_proposal_token_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             35,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             25,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4521,19 +5521,22 @@ fn auction_starts() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             48,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             5,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             47,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4571,8 +5574,8 @@ fn auction_starts() {
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
-        assert_eq!(SaleProposals::<Test>::get(0).is_some(), true);
-        assert_eq!(OngoingSaleProposalVotes::<Test>::get(0).is_some(), true);
+        assert!(SaleProposals::<Test>::get(0).is_some());
+        assert!(OngoingSaleProposalVotes::<Test>::get(0).is_some());
         assert_ok!(PropertyGovernance::vote_on_property_sale(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -4600,13 +5603,10 @@ fn auction_starts() {
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
-        assert_eq!(PropertySale::<Test>::get(0).is_some(), true);
-        assert_eq!(OngoingSaleProposalVotes::<Test>::get(0).is_some(), false);
-        assert_eq!(SaleProposals::<Test>::get(0).is_some(), false);
-        assert_eq!(
-            UserSaleProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_some(),
-            true
-        );
+        assert!(PropertySale::<Test>::get(0).is_some());
+        assert!(OngoingSaleProposalVotes::<Test>::get(0).is_none());
+        assert!(SaleProposals::<Test>::get(0).is_none());
+        assert!(UserSaleProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_some());
         assert_eq!(SaleAuctions::<Test>::get(0).unwrap().highest_bidder, None);
         assert_eq!(SaleAuctions::<Test>::get(0).unwrap().price, 0);
         assert_eq!(SaleAuctions::<Test>::get(0).unwrap().reserve, None);
@@ -4641,19 +5641,22 @@ fn proposal_does_not_pass() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             15,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4691,8 +5694,8 @@ fn proposal_does_not_pass() {
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
-        assert_eq!(SaleProposals::<Test>::get(0).is_some(), true);
-        assert_eq!(OngoingSaleProposalVotes::<Test>::get(0).is_some(), true);
+        assert!(SaleProposals::<Test>::get(0).is_some());
+        assert!(OngoingSaleProposalVotes::<Test>::get(0).is_some());
         assert_ok!(PropertyGovernance::vote_on_property_sale(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -4720,15 +5723,12 @@ fn proposal_does_not_pass() {
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
-        assert_eq!(PropertySale::<Test>::get(0).is_some(), false);
+        assert!(PropertySale::<Test>::get(0).is_none());
         System::assert_last_event(Event::PropertySaleProposalRejected { asset_id: 0 }.into());
-        assert_eq!(OngoingSaleProposalVotes::<Test>::get(0).is_some(), false);
-        assert_eq!(SaleProposals::<Test>::get(0).is_some(), false);
-        assert_eq!(
-            UserSaleProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_some(),
-            true
-        );
-        assert_eq!(SaleAuctions::<Test>::get(0).is_none(), true);
+        assert!(OngoingSaleProposalVotes::<Test>::get(0).is_none());
+        assert!(SaleProposals::<Test>::get(0).is_none());
+        assert!(UserSaleProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_some());
+        assert!(SaleAuctions::<Test>::get(0).is_none());
     });
 }
 
@@ -4768,7 +5768,11 @@ fn proposal_does_not_pass2() {
             5_000,
             200,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
@@ -4789,19 +5793,22 @@ fn proposal_does_not_pass2() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             90,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             80,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4839,8 +5846,8 @@ fn proposal_does_not_pass2() {
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
-        assert_eq!(SaleProposals::<Test>::get(0).is_some(), true);
-        assert_eq!(OngoingSaleProposalVotes::<Test>::get(0).is_some(), true);
+        assert!(SaleProposals::<Test>::get(0).is_some());
+        assert!(OngoingSaleProposalVotes::<Test>::get(0).is_some());
         assert_ok!(PropertyGovernance::vote_on_property_sale(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -4862,15 +5869,12 @@ fn proposal_does_not_pass2() {
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
-        assert_eq!(PropertySale::<Test>::get(0).is_some(), false);
+        assert!(PropertySale::<Test>::get(0).is_none());
         System::assert_last_event(Event::PropertySaleProposalRejected { asset_id: 0 }.into());
-        assert_eq!(OngoingSaleProposalVotes::<Test>::get(0).is_some(), false);
-        assert_eq!(SaleProposals::<Test>::get(0).is_some(), false);
-        assert_eq!(
-            UserSaleProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_some(),
-            true
-        );
-        assert_eq!(SaleAuctions::<Test>::get(0).is_none(), true);
+        assert!(OngoingSaleProposalVotes::<Test>::get(0).is_none());
+        assert!(SaleProposals::<Test>::get(0).is_none());
+        assert!(UserSaleProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_some());
+        assert!(SaleAuctions::<Test>::get(0).is_none());
     });
 }
 
@@ -4912,19 +5916,22 @@ fn bid_on_sale_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             48,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             5,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             47,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -4987,7 +5994,7 @@ fn bid_on_sale_works() {
         );
         let expiry = frame_system::Pallet::<Test>::block_number() + PropertySaleVotingTime::get();
         run_to_block(expiry);
-        assert_eq!(PropertySale::<Test>::get(0).is_some(), true);
+        assert!(PropertySale::<Test>::get(0).is_some());
         assert_eq!(PropertySale::<Test>::get(0).unwrap().price, None);
         assert_eq!(SaleAuctions::<Test>::get(0).unwrap().highest_bidder, None);
         assert_eq!(SaleAuctions::<Test>::get(0).unwrap().price, 0);
@@ -5114,19 +6121,22 @@ fn bid_on_sale_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             48,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             5,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             47,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -5239,19 +6249,22 @@ fn lawyer_claim_sale_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -5418,19 +6431,22 @@ fn lawyer_claim_sale_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -5704,19 +6720,22 @@ fn lawyer_claim_sale_fails_2() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             25,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             35,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -5873,7 +6892,7 @@ fn lawyer_claim_sale_fails_2() {
             RuntimeOrigin::signed([3; 32].into()),
             0,
         ));
-        assert_eq!(PropertySale::<Test>::get(0).is_some(), false);
+        assert!(PropertySale::<Test>::get(0).is_none());
         assert_noop!(
             PropertyGovernance::lawyer_claim_sale(
                 RuntimeOrigin::signed([0; 32].into()),
@@ -5943,7 +6962,7 @@ fn lawyer_claim_sale_fails_2() {
         ));
         let expiry = frame_system::Pallet::<Test>::block_number() + PropertySaleVotingTime::get();
         run_to_block(expiry);
-        assert_eq!(PropertySale::<Test>::get(0).is_some(), true);
+        assert!(PropertySale::<Test>::get(0).is_some());
         assert_noop!(
             PropertyGovernance::lawyer_claim_sale(
                 RuntimeOrigin::signed([10; 32].into()),
@@ -6006,19 +7025,22 @@ fn lawyer_confirm_sale_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -6108,7 +7130,7 @@ fn lawyer_confirm_sale_works() {
             PropertySale::<Test>::get(0).unwrap().buyer_lawyer_costs,
             2_500
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, false);
+        assert!(!PropertySale::<Test>::get(0).unwrap().lawyer_approved);
         assert_eq!(
             PropertySale::<Test>::get(0).unwrap().spv_status,
             crate::DocumentStatus::Pending
@@ -6131,7 +7153,7 @@ fn lawyer_confirm_sale_works() {
             PropertySale::<Test>::get(0).unwrap().buyer_status,
             crate::DocumentStatus::Approved
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, true);
+        assert!(PropertySale::<Test>::get(0).unwrap().lawyer_approved);
     });
 }
 
@@ -6168,19 +7190,22 @@ fn lawyer_confirm_sale_works_2() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -6262,7 +7287,7 @@ fn lawyer_confirm_sale_works_2() {
             PropertySale::<Test>::get(0).unwrap().spv_lawyer.unwrap(),
             [11; 32].into()
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, false);
+        assert!(!PropertySale::<Test>::get(0).unwrap().lawyer_approved);
         assert_eq!(
             PropertySale::<Test>::get(0).unwrap().spv_status,
             crate::DocumentStatus::Pending
@@ -6276,7 +7301,7 @@ fn lawyer_confirm_sale_works_2() {
             PropertySale::<Test>::get(0).unwrap().spv_status,
             crate::DocumentStatus::Approved
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().second_attempt, false);
+        assert!(!PropertySale::<Test>::get(0).unwrap().second_attempt);
         assert_ok!(PropertyGovernance::lawyer_confirm_sale(
             RuntimeOrigin::signed([10; 32].into()),
             0,
@@ -6290,7 +7315,7 @@ fn lawyer_confirm_sale_works_2() {
             PropertySale::<Test>::get(0).unwrap().buyer_status,
             crate::DocumentStatus::Pending
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().second_attempt, true);
+        assert!(PropertySale::<Test>::get(0).unwrap().second_attempt);
         assert_ok!(PropertyGovernance::lawyer_confirm_sale(
             RuntimeOrigin::signed([10; 32].into()),
             0,
@@ -6305,7 +7330,7 @@ fn lawyer_confirm_sale_works_2() {
             0,
             true
         ));
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, true);
+        assert!(PropertySale::<Test>::get(0).unwrap().lawyer_approved);
     });
 }
 
@@ -6342,19 +7367,22 @@ fn lawyer_confirm_sale_works_deny() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -6439,7 +7467,7 @@ fn lawyer_confirm_sale_works_deny() {
             PropertySale::<Test>::get(0).unwrap().buyer_lawyer.unwrap(),
             [10; 32].into()
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, false);
+        assert!(!PropertySale::<Test>::get(0).unwrap().lawyer_approved);
         assert_ok!(PropertyGovernance::lawyer_confirm_sale(
             RuntimeOrigin::signed([11; 32].into()),
             0,
@@ -6462,7 +7490,7 @@ fn lawyer_confirm_sale_works_deny() {
             AssetsHolder::total_balance_on_hold(1984, &[7; 32].into()),
             0
         );
-        assert_eq!(PropertySale::<Test>::get(0).is_none(), true);
+        assert!(PropertySale::<Test>::get(0).is_none());
     });
 }
 
@@ -6499,19 +7527,22 @@ fn lawyer_confirm_sale_works_deny_2() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -6596,7 +7627,7 @@ fn lawyer_confirm_sale_works_deny_2() {
             PropertySale::<Test>::get(0).unwrap().buyer_lawyer.unwrap(),
             [10; 32].into()
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, false);
+        assert!(!PropertySale::<Test>::get(0).unwrap().lawyer_approved);
         assert_ok!(PropertyGovernance::lawyer_confirm_sale(
             RuntimeOrigin::signed([11; 32].into()),
             0,
@@ -6629,7 +7660,7 @@ fn lawyer_confirm_sale_works_deny_2() {
             AssetsHolder::total_balance_on_hold(1984, &[7; 32].into()),
             0
         );
-        assert_eq!(PropertySale::<Test>::get(0).is_none(), true);
+        assert!(PropertySale::<Test>::get(0).is_none());
         assert_eq!(
             RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
                 .unwrap()
@@ -6678,19 +7709,22 @@ fn lawyer_confirm_sale_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -6809,7 +7843,7 @@ fn lawyer_confirm_sale_fails() {
             ),
             Error::<Test>::NotForSale
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, false);
+        assert!(!PropertySale::<Test>::get(0).unwrap().lawyer_approved);
         assert_ok!(PropertyGovernance::lawyer_confirm_sale(
             RuntimeOrigin::signed([11; 32].into()),
             0,
@@ -6867,19 +7901,22 @@ fn finalize_sale_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -6960,7 +7997,7 @@ fn finalize_sale_works() {
             PropertySale::<Test>::get(0).unwrap().spv_lawyer.unwrap(),
             [11; 32].into()
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, false);
+        assert!(!PropertySale::<Test>::get(0).unwrap().lawyer_approved);
         assert_ok!(PropertyGovernance::lawyer_confirm_sale(
             RuntimeOrigin::signed([11; 32].into()),
             0,
@@ -6971,31 +8008,40 @@ fn finalize_sale_works() {
             0,
             true
         ));
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, true);
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().finalized, false);
+        assert!(PropertySale::<Test>::get(0).unwrap().lawyer_approved);
+        assert!(!PropertySale::<Test>::get(0).unwrap().finalized);
         assert_ok!(PropertyGovernance::finalize_sale(
             RuntimeOrigin::signed([10; 32].into()),
             0,
             1337
         ));
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().finalized, true);
+        assert!(PropertySale::<Test>::get(0).unwrap().finalized);
         assert_eq!(PropertySaleFunds::<Test>::get(0, 1337), 264_000);
         assert_eq!(PropertySaleFunds::<Test>::get(0, 1984), 30_000);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             30_000
         );
         assert_eq!(
-            ForeignAssets::balance(1337, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1337, PropertyGovernance::property_account_id(0)),
             264_000
         );
-        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 1_000);
         assert_eq!(
-            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
+            ForeignAssets::balance(1337, AccountId::from([11; 32])),
+            1_000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1337, Marketplace::treasury_account_id()),
+            2_000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1337, AccountId::from([6; 32])),
             2_000
         );
-        assert_eq!(ForeignAssets::balance(1337, &[6; 32].into()), 2_000);
-        assert_eq!(ForeignAssets::balance(1337, &[10; 32].into()), 731_000);
+        assert_eq!(
+            ForeignAssets::balance(1337, AccountId::from([10; 32])),
+            731_000
+        );
     });
 }
 
@@ -7032,19 +8078,22 @@ fn finalize_sale_works_2() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -7136,24 +8185,30 @@ fn finalize_sale_works_2() {
             0,
             1337
         ));
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().finalized, true);
+        assert!(PropertySale::<Test>::get(0).unwrap().finalized);
         assert_eq!(PropertySaleFunds::<Test>::get(0, 1337), 264_000);
         assert_eq!(PropertySaleFunds::<Test>::get(0, 1984), 30_000);
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             30_000
         );
         assert_eq!(
-            ForeignAssets::balance(1337, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1337, PropertyGovernance::property_account_id(0)),
             264_000
         );
-        assert_eq!(ForeignAssets::balance(1337, &[11; 32].into()), 3_000);
         assert_eq!(
-            ForeignAssets::balance(1337, &Marketplace::treasury_account_id()),
+            ForeignAssets::balance(1337, AccountId::from([11; 32])),
+            3_000
+        );
+        assert_eq!(
+            ForeignAssets::balance(1337, Marketplace::treasury_account_id()),
             0
         );
-        assert_eq!(ForeignAssets::balance(1337, &[6; 32].into()), 0);
-        assert_eq!(ForeignAssets::balance(1337, &[10; 32].into()), 733_000);
+        assert_eq!(ForeignAssets::balance(1337, AccountId::from([6; 32])), 0);
+        assert_eq!(
+            ForeignAssets::balance(1337, AccountId::from([10; 32])),
+            733_000
+        );
     });
 }
 
@@ -7190,19 +8245,22 @@ fn finalize_sale_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -7291,7 +8349,7 @@ fn finalize_sale_fails() {
             PropertySale::<Test>::get(0).unwrap().spv_lawyer.unwrap(),
             [11; 32].into()
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, false);
+        assert!(!PropertySale::<Test>::get(0).unwrap().lawyer_approved);
         assert_noop!(
             PropertyGovernance::finalize_sale(RuntimeOrigin::signed([10; 32].into()), 0, 1984),
             Error::<Test>::SaleHasNotBeenApproved,
@@ -7306,12 +8364,12 @@ fn finalize_sale_fails() {
             0,
             true
         ));
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, true);
+        assert!(PropertySale::<Test>::get(0).unwrap().lawyer_approved);
         assert_noop!(
             PropertyGovernance::finalize_sale(RuntimeOrigin::signed([11; 32].into()), 0, 1984),
             Error::<Test>::NoPermission,
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().finalized, false);
+        assert!(!PropertySale::<Test>::get(0).unwrap().finalized);
         assert_noop!(
             PropertyGovernance::finalize_sale(RuntimeOrigin::signed([10; 32].into()), 0, 1),
             Error::<Test>::PaymentAssetNotSupported,
@@ -7365,19 +8423,22 @@ fn claim_sale_funds_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             49,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             41,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -7497,16 +8558,19 @@ fn claim_sale_funds_works() {
             0,
             1337
         ));
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().finalized, true);
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 990_400);
+        assert!(PropertySale::<Test>::get(0).unwrap().finalized);
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            990_400
+        );
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             30_000
         );
         assert_eq!(PropertySaleFunds::<Test>::get(0, 1337), 264_000);
         assert_eq!(PropertySaleFunds::<Test>::get(0, 1984), 30_000);
         assert_eq!(
-            LocalAssets::balance(0, &PropertyGovernance::property_account_id(0)),
+            LocalAssets::balance(0, PropertyGovernance::property_account_id(0)),
             0
         );
         assert_ok!(PropertyGovernance::claim_sale_funds(
@@ -7517,15 +8581,21 @@ fn claim_sale_funds_works() {
         assert_eq!(PropertySaleFunds::<Test>::get(0, 1337), 149_940);
         assert_eq!(PropertySaleFunds::<Test>::get(0, 1984), 0);
         assert_eq!(
-            LocalAssets::balance(0, &PropertyGovernance::property_account_id(0)),
+            LocalAssets::balance(0, PropertyGovernance::property_account_id(0)),
             49
         );
         assert_eq!(
-            ForeignAssets::balance(1337, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1337, PropertyGovernance::property_account_id(0)),
             149_940
         );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_020_400);
-        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 114_060);
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_020_400
+        );
+        assert_eq!(
+            ForeignAssets::balance(1337, AccountId::from([1; 32])),
+            114_060
+        );
         assert_eq!(LocalAssets::total_issuance(0), 100);
         assert_ok!(PropertyGovernance::claim_sale_funds(
             RuntimeOrigin::signed([2; 32].into()),
@@ -7533,21 +8603,27 @@ fn claim_sale_funds_works() {
             1337
         ));
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyGovernance::property_account_id(0)),
+            ForeignAssets::balance(1984, PropertyGovernance::property_account_id(0)),
             0
         );
-        assert_eq!(ForeignAssets::balance(1337, &[2; 32].into()), 29_400);
-        assert_eq!(ForeignAssets::balance(1984, &[2; 32].into()), 1_046_000);
+        assert_eq!(
+            ForeignAssets::balance(1337, AccountId::from([2; 32])),
+            29_400
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([2; 32])),
+            1_046_000
+        );
         assert_ok!(PropertyGovernance::claim_sale_funds(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             1337
         ));
-        assert_eq!(Nfts::owner(0, 0).is_none(), true);
-        assert_eq!(PropertyAssetInfo::<Test>::get(0).is_none(), true);
+        assert!(Nfts::owner(0, 0).is_none());
+        assert!(PropertyAssetInfo::<Test>::get(0).is_none());
         assert_eq!(PropertyOwner::<Test>::get(0).len(), 0);
         assert_eq!(LocalAssets::total_issuance(0), 0);
-        assert_eq!(PropertySale::<Test>::get(0).is_none(), true);
+        assert!(PropertySale::<Test>::get(0).is_none());
         assert_eq!(
             RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into())
                 .unwrap()
@@ -7596,19 +8672,22 @@ fn claim_sale_funds_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             10,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -7685,13 +8764,10 @@ fn claim_sale_funds_fails() {
             RuntimeOrigin::signed([2; 32].into()),
             0,
         ));
-        assert_eq!(PropertySale::<Test>::get(0).is_some(), true);
-        assert_eq!(OngoingSaleProposalVotes::<Test>::get(0).is_some(), false);
-        assert_eq!(SaleProposals::<Test>::get(0).is_some(), false);
-        assert_eq!(
-            UserSaleProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_some(),
-            false
-        );
+        assert!(PropertySale::<Test>::get(0).is_some());
+        assert!(OngoingSaleProposalVotes::<Test>::get(0).is_none());
+        assert!(SaleProposals::<Test>::get(0).is_none());
+        assert!(UserSaleProposalVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_none());
         assert_ok!(PropertyGovernance::bid_on_sale(
             RuntimeOrigin::signed([7; 32].into()),
             0,
@@ -7716,7 +8792,7 @@ fn claim_sale_funds_fails() {
             PropertySale::<Test>::get(0).unwrap().spv_lawyer.unwrap(),
             [11; 32].into()
         );
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, false);
+        assert!(!PropertySale::<Test>::get(0).unwrap().lawyer_approved);
         assert_ok!(PropertyGovernance::lawyer_confirm_sale(
             RuntimeOrigin::signed([11; 32].into()),
             0,
@@ -7727,8 +8803,8 @@ fn claim_sale_funds_fails() {
             0,
             true
         ));
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().lawyer_approved, true);
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().finalized, false);
+        assert!(PropertySale::<Test>::get(0).unwrap().lawyer_approved);
+        assert!(!PropertySale::<Test>::get(0).unwrap().finalized);
         assert_noop!(
             PropertyGovernance::claim_sale_funds(RuntimeOrigin::signed([1; 32].into()), 0, 1984),
             Error::<Test>::SaleNotFinalized,
@@ -7738,8 +8814,11 @@ fn claim_sale_funds_fails() {
             0,
             1337
         ));
-        assert_eq!(PropertySale::<Test>::get(0).unwrap().finalized, true);
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_032_000);
+        assert!(PropertySale::<Test>::get(0).unwrap().finalized);
+        assert_eq!(
+            ForeignAssets::balance(1984, AccountId::from([1; 32])),
+            1_032_000
+        );
         assert_noop!(
             PropertyGovernance::claim_sale_funds(RuntimeOrigin::signed([6; 32].into()), 0, 1984),
             Error::<Test>::NoFundsToClaim,
@@ -7755,3 +8834,420 @@ fn claim_sale_funds_fails() {
         );
     });
 }
+
+#[test]
+fn delegate_voting_power_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        listing_process();
+        for investor in [[1; 32], [30; 32], [31; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                investor.into(),
+                pallet_xcavate_whitelist::Role::RealEstateInvestor
+            ));
+        }
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        for investor in [[1; 32], [30; 32], [31; 32]] {
+            assert_ok!(Marketplace::claim_property_token(
+                RuntimeOrigin::signed(investor.into()),
+                0
+            ));
+        }
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        lawyer_process(vec![([1; 32].into(), 40), ([30; 32].into(), 30)]);
+        assert_ok!(PropertyGovernance::delegate_voting_power(
+            RuntimeOrigin::signed([30; 32].into()),
+            DelegationScope::Property(0),
+            [31; 32].into(),
+        ));
+        assert_ok!(PropertyGovernance::delegate_voting_power(
+            RuntimeOrigin::signed([30; 32].into()),
+            DelegationScope::Property(0),
+            [1; 32].into(),
+        ));
+        System::assert_last_event(
+            Event::VotingPowerDelegated {
+                delegator: [30; 32].into(),
+                delegate: [1; 32].into(),
+                scope: DelegationScope::Property(0),
+            }
+            .into(),
+        );
+        assert_eq!(
+            Delegations::<Test>::get::<AccountId, DelegationScope>(
+                [30; 32].into(),
+                DelegationScope::Property(0)
+            ),
+            Some([1; 32].into())
+        );
+        assert_eq!(
+            Delegators::<Test>::get::<AccountId, DelegationScope>(
+                [1; 32].into(),
+                DelegationScope::Property(0)
+            )
+            .into_inner(),
+            vec![[30; 32].into()]
+        );
+        assert!(Delegators::<Test>::get::<AccountId, DelegationScope>(
+            [31; 32].into(),
+            DelegationScope::Property(0)
+        )
+        .is_empty());
+        assert_ok!(PropertyGovernance::revoke_delegation(
+            RuntimeOrigin::signed([30; 32].into()),
+            DelegationScope::Property(0),
+        ));
+        System::assert_last_event(
+            Event::DelegationRevoked {
+                delegator: [30; 32].into(),
+                delegate: [1; 32].into(),
+                scope: DelegationScope::Property(0),
+            }
+            .into(),
+        );
+        assert!(Delegations::<Test>::get::<AccountId, DelegationScope>(
+            [30; 32].into(),
+            DelegationScope::Property(0)
+        )
+        .is_none());
+        assert!(Delegators::<Test>::get::<AccountId, DelegationScope>(
+            [1; 32].into(),
+            DelegationScope::Property(0)
+        )
+        .is_empty());
+    });
+}
+
+#[test]
+fn delegate_votes_on_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        listing_process();
+        for investor in [[1; 32], [30; 32], [31; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                investor.into(),
+                pallet_xcavate_whitelist::Role::RealEstateInvestor
+            ));
+        }
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        for investor in [[1; 32], [30; 32], [31; 32]] {
+            assert_ok!(Marketplace::claim_property_token(
+                RuntimeOrigin::signed(investor.into()),
+                0
+            ));
+        }
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        lawyer_process(vec![([1; 32].into(), 40), ([30; 32].into(), 30)]);
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        setting_letting_agent(
+            [4; 32].into(),
+            vec![([1; 32].into(), 40), ([30; 32].into(), 30)],
+        );
+        assert_ok!(PropertyGovernance::delegate_voting_power(
+            RuntimeOrigin::signed([30; 32].into()),
+            DelegationScope::Global,
+            [31; 32].into(),
+        ));
+        assert_ok!(PropertyGovernance::delegate_voting_power(
+            RuntimeOrigin::signed([30; 32].into()),
+            DelegationScope::Property(0),
+            [1; 32].into(),
+        ));
+        assert_eq!(
+            PropertyGovernance::delegators_of(&[1; 32].into(), 0),
+            vec![[30; 32].into()]
+        );
+        assert!(PropertyGovernance::delegators_of(&[31; 32].into(), 0).is_empty());
+        assert_ok!(PropertyGovernance::propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [4; 32].into(),
+                amount: 1000,
+                payment_asset: 1984,
+            },
+            bvec![10, 10]
+        ));
+        assert_noop!(
+            PropertyGovernance::vote_on_proposal(
+                RuntimeOrigin::signed([30; 32].into()),
+                0,
+                crate::Vote::No,
+                30
+            ),
+            Error::<Test>::VotingPowerDelegated
+        );
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        let current_vote = OngoingProposalVotes::<Test>::get(0).unwrap();
+        assert_eq!(current_vote.yes_voting_power, 70);
+        assert_eq!(
+            AssetsFreezer::balance_frozen(
+                0,
+                &MarketplaceFreezeReason::ProposalVoting,
+                &[30; 32].into()
+            ),
+            30
+        );
+        assert_eq!(
+            UserProposalVote::<Test>::get::<u64, AccountId>(0, [30; 32].into()).unwrap(),
+            VoteRecord {
+                vote: crate::Vote::Yes,
+                asset_id: 0,
+                power: 30,
+                delegated: true,
+            }
+        );
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::No,
+            40
+        ));
+        let current_vote = OngoingProposalVotes::<Test>::get(0).unwrap();
+        assert_eq!(current_vote.yes_voting_power, 0);
+        assert_eq!(current_vote.no_voting_power, 70);
+        assert_eq!(
+            AssetsFreezer::balance_frozen(
+                0,
+                &MarketplaceFreezeReason::ProposalVoting,
+                &[30; 32].into()
+            ),
+            30
+        );
+        assert_ok!(PropertyGovernance::vote_on_proposal(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Withdraw,
+            0
+        ));
+        let current_vote = OngoingProposalVotes::<Test>::get(0).unwrap();
+        assert_eq!(current_vote.yes_voting_power, 0);
+        assert_eq!(current_vote.no_voting_power, 0);
+        assert_eq!(
+            AssetsFreezer::balance_frozen(
+                0,
+                &MarketplaceFreezeReason::ProposalVoting,
+                &[30; 32].into()
+            ),
+            0
+        );
+        assert!(UserProposalVote::<Test>::get::<u64, AccountId>(0, [30; 32].into()).is_none());
+    });
+}
+
+#[test]
+fn delegate_voting_power_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        listing_process();
+        for investor in [[1; 32], [30; 32], [31; 32]] {
+            assert_ok!(XcavateWhitelist::assign_role(
+                RuntimeOrigin::signed([20; 32].into()),
+                investor.into(),
+                pallet_xcavate_whitelist::Role::RealEstateInvestor
+            ));
+        }
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        for investor in [[1; 32], [30; 32], [31; 32]] {
+            assert_ok!(Marketplace::claim_property_token(
+                RuntimeOrigin::signed(investor.into()),
+                0
+            ));
+        }
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        lawyer_process(vec![([1; 32].into(), 40), ([30; 32].into(), 30)]);
+        assert_noop!(
+            PropertyGovernance::delegate_voting_power(
+                RuntimeOrigin::signed([2; 32].into()),
+                DelegationScope::Property(0),
+                [1; 32].into(),
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            PropertyGovernance::delegate_voting_power(
+                RuntimeOrigin::signed([30; 32].into()),
+                DelegationScope::Property(0),
+                [30; 32].into(),
+            ),
+            Error::<Test>::CannotDelegateToSelf
+        );
+        assert_noop!(
+            PropertyGovernance::revoke_delegation(
+                RuntimeOrigin::signed([30; 32].into()),
+                DelegationScope::Global,
+            ),
+            Error::<Test>::NoDelegation
+        );
+    });
+}
+
+#[test]
+fn migration_to_v1_translates_proposals_and_vote_records() {
+    new_test_ext().execute_with(|| {
+        let voter: AccountId = [1; 32].into();
+        let old_vote = crate::migrations::v1::OldVoteRecord {
+            vote: crate::Vote::Yes,
+            asset_id: 0,
+            power: 20,
+        };
+        frame_support::storage::unhashed::put(
+            &UserProposalVote::<Test>::hashed_key_for(0, &voter),
+            &old_vote,
+        );
+        frame_support::storage::unhashed::put(
+            &UserChallengeVote::<Test>::hashed_key_for(0, &voter),
+            &old_vote,
+        );
+        frame_support::storage::unhashed::put(
+            &UserSaleProposalVote::<Test>::hashed_key_for(0, &voter),
+            &old_vote,
+        );
+        let old_proposal = crate::migrations::v1::OldProposal::<Test> {
+            proposer: [0; 32].into(),
+            amount: 1_000,
+            created_at: 1,
+            metadata: bvec![42, 42],
+        };
+        frame_support::storage::unhashed::put(&Proposals::<Test>::hashed_key_for(0), &old_proposal);
+        StorageVersion::new(0).put::<PropertyGovernance>();
+        crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(PropertyGovernance::on_chain_storage_version(), 1);
+        let proposal = Proposals::<Test>::get(0).unwrap();
+        assert_eq!(
+            proposal.kind,
+            ProposalKind::CapitalExpenditure {
+                beneficiary: [0; 32].into(),
+                amount: 1_000,
+                payment_asset: 1337
+            }
+        );
+        assert_eq!(proposal.proposer, [0; 32].into());
+        assert_eq!(proposal.created_at, 1);
+        let vote_record = VoteRecord {
+            vote: crate::Vote::Yes,
+            asset_id: 0,
+            power: 20,
+            delegated: false,
+        };
+        assert_eq!(
+            UserProposalVote::<Test>::get(0, &voter),
+            Some(vote_record.clone())
+        );
+        assert_eq!(
+            UserChallengeVote::<Test>::get(0, &voter),
+            Some(vote_record.clone())
+        );
+        assert_eq!(
+            UserSaleProposalVote::<Test>::get(0, &voter),
+            Some(vote_record)
+        );
+    })
+}
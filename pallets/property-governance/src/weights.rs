@@ -45,6 +45,12 @@ pub trait WeightInfo {
 	fn lawyer_confirm_sale() -> Weight;
 	fn finalize_sale() -> Weight;
 	fn claim_sale_funds() -> Weight;
+	fn delegate_voting_power() -> Weight;
+	fn revoke_delegation() -> Weight;
+	fn cast_delegated_vote() -> Weight;
+	fn set_proposal_requirements() -> Weight;
+	fn sell_token_to_buyback() -> Weight;
+	fn propose_call() -> Weight;
 }
 
 /// Weight functions for `pallet_property_governance`.
@@ -63,7 +69,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `PropertyGovernance::OngoingProposalVotes` (r:0 w:1)
 	/// Proof: `PropertyGovernance::OngoingProposalVotes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// Storage: `PropertyGovernance::Proposals` (r:0 w:1)
-	/// Proof: `PropertyGovernance::Proposals` (`max_values`: None, `max_size`: Some(127), added: 2602, mode: `MaxEncodedLen`)
+	/// Proof: `PropertyGovernance::Proposals` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
 	fn propose() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1747`
@@ -105,7 +111,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `PropertyGovernance::AssetProposal` (r:1 w:0)
 	/// Proof: `PropertyGovernance::AssetProposal` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `PropertyGovernance::Proposals` (r:1 w:0)
-	/// Proof: `PropertyGovernance::Proposals` (`max_values`: None, `max_size`: Some(127), added: 2602, mode: `MaxEncodedLen`)
+	/// Proof: `PropertyGovernance::Proposals` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
 	/// Storage: `RealEstateAsset::PropertyOwner` (r:1 w:0)
 	/// Proof: `RealEstateAsset::PropertyOwner` (`max_values`: None, `max_size`: Some(8022), added: 10497, mode: `MaxEncodedLen`)
 	/// Storage: `RealEstateAsset::PropertyOwnerToken` (r:1 w:0)
@@ -113,7 +119,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `PropertyGovernance::OngoingProposalVotes` (r:1 w:1)
 	/// Proof: `PropertyGovernance::OngoingProposalVotes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// Storage: `PropertyGovernance::UserProposalVote` (r:1 w:1)
-	/// Proof: `PropertyGovernance::UserProposalVote` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Proof: `PropertyGovernance::UserProposalVote` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
 	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
 	/// Storage: `RealEstateAssets::Account` (r:1 w:0)
@@ -131,9 +137,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `PropertyGovernance::UserProposalVote` (r:1 w:1)
-	/// Proof: `PropertyGovernance::UserProposalVote` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Proof: `PropertyGovernance::UserProposalVote` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
 	/// Storage: `PropertyGovernance::Proposals` (r:1 w:0)
-	/// Proof: `PropertyGovernance::Proposals` (`max_values`: None, `max_size`: Some(127), added: 2602, mode: `MaxEncodedLen`)
+	/// Proof: `PropertyGovernance::Proposals` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
 	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
 	/// Storage: `RealEstateAssets::Account` (r:1 w:0)
@@ -163,7 +169,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `PropertyGovernance::OngoingChallengeVotes` (r:1 w:1)
 	/// Proof: `PropertyGovernance::OngoingChallengeVotes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// Storage: `PropertyGovernance::UserChallengeVote` (r:1 w:1)
-	/// Proof: `PropertyGovernance::UserChallengeVote` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Proof: `PropertyGovernance::UserChallengeVote` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
 	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
 	/// Storage: `RealEstateAssets::Account` (r:1 w:0)
@@ -181,7 +187,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `PropertyGovernance::UserChallengeVote` (r:1 w:1)
-	/// Proof: `PropertyGovernance::UserChallengeVote` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Proof: `PropertyGovernance::UserChallengeVote` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
 	/// Storage: `PropertyGovernance::Challenges` (r:1 w:0)
 	/// Proof: `PropertyGovernance::Challenges` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
@@ -241,7 +247,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `PropertyGovernance::OngoingSaleProposalVotes` (r:1 w:1)
 	/// Proof: `PropertyGovernance::OngoingSaleProposalVotes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// Storage: `PropertyGovernance::UserSaleProposalVote` (r:1 w:1)
-	/// Proof: `PropertyGovernance::UserSaleProposalVote` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Proof: `PropertyGovernance::UserSaleProposalVote` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
 	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
 	/// Storage: `RealEstateAssets::Account` (r:1 w:0)
@@ -259,7 +265,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `PropertyGovernance::UserSaleProposalVote` (r:1 w:1)
-	/// Proof: `PropertyGovernance::UserSaleProposalVote` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Proof: `PropertyGovernance::UserSaleProposalVote` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
 	/// Storage: `PropertyGovernance::SaleProposals` (r:1 w:0)
 	/// Proof: `PropertyGovernance::SaleProposals` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
@@ -430,4 +436,125 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(27))
 			.saturating_add(T::DbWeight::get().writes(27))
 	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyGovernance::Delegations` (r:1 w:1)
+	/// Proof: `PropertyGovernance::Delegations` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyGovernance::Delegators` (r:1 w:1)
+	/// Proof: `PropertyGovernance::Delegators` (`max_values`: None, `max_size`: Some(1686), added: 4161, mode: `MaxEncodedLen`)
+	fn delegate_voting_power() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1812`
+		//  Estimated: `5151`
+		// Minimum execution time: 24_918_000 picoseconds.
+		Weight::from_parts(26_304_000, 0)
+			.saturating_add(Weight::from_parts(0, 5151))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `PropertyGovernance::Delegations` (r:1 w:1)
+	/// Proof: `PropertyGovernance::Delegations` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyGovernance::Delegators` (r:1 w:1)
+	/// Proof: `PropertyGovernance::Delegators` (`max_values`: None, `max_size`: Some(1686), added: 4161, mode: `MaxEncodedLen`)
+	fn revoke_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1904`
+		//  Estimated: `5151`
+		// Minimum execution time: 21_407_000 picoseconds.
+		Weight::from_parts(22_650_000, 0)
+			.saturating_add(Weight::from_parts(0, 5151))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `PropertyGovernance::Delegations` (r:1 w:0)
+	/// Proof: `PropertyGovernance::Delegations` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyOwnerToken` (r:1 w:0)
+	/// Proof: `RealEstateAsset::PropertyOwnerToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyGovernance::UserProposalVote` (r:1 w:1)
+	/// Proof: `PropertyGovernance::UserProposalVote` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAssets::Account` (r:1 w:0)
+	/// Proof: `RealEstateAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn cast_delegated_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2417`
+		//  Estimated: `3599`
+		// Minimum execution time: 38_215_000 picoseconds.
+		Weight::from_parts(40_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 3599))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `PropertyGovernance::ProposalRequirements` (r:0 w:1)
+	/// Proof: `PropertyGovernance::ProposalRequirements` (`max_values`: None, `max_size`: Some(19), added: 2494, mode: `MaxEncodedLen`)
+	fn set_proposal_requirements() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_812_000 picoseconds.
+		Weight::from_parts(8_290_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyGovernance::TokenBuybacks` (r:1 w:1)
+	/// Proof: `PropertyGovernance::TokenBuybacks` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::IncomePerToken` (r:1 w:0)
+	/// Proof: `PropertyManagement::IncomePerToken` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::IncomeCheckpoint` (r:1 w:1)
+	/// Proof: `PropertyManagement::IncomeCheckpoint` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyOwnerToken` (r:1 w:1)
+	/// Proof: `RealEstateAsset::PropertyOwnerToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyOwner` (r:1 w:1)
+	/// Proof: `RealEstateAsset::PropertyOwner` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAssets::Asset` (r:1 w:1)
+	/// Proof: `RealEstateAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAssets::Account` (r:2 w:2)
+	/// Proof: `RealEstateAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::PropertyReserve` (r:1 w:1)
+	/// Proof: `PropertyManagement::PropertyReserve` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn sell_token_to_buyback() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2874`
+		//  Estimated: `6687`
+		// Minimum execution time: 142_506_000 picoseconds.
+		Weight::from_parts(149_318_000, 0)
+			.saturating_add(Weight::from_parts(0, 6687))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::LettingStorage` (r:1 w:0)
+	/// Proof: `PropertyManagement::LettingStorage` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyGovernance::AssetProposal` (r:1 w:1)
+	/// Proof: `PropertyGovernance::AssetProposal` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyGovernance::ProposalCount` (r:1 w:1)
+	/// Proof: `PropertyGovernance::ProposalCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyGovernance::ProposalRoundsExpiring` (r:1 w:1)
+	/// Proof: `PropertyGovernance::ProposalRoundsExpiring` (`max_values`: None, `max_size`: Some(422), added: 2897, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyGovernance::OngoingProposalVotes` (r:0 w:1)
+	/// Proof: `PropertyGovernance::OngoingProposalVotes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyGovernance::Proposals` (r:0 w:1)
+	/// Proof: `PropertyGovernance::Proposals` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyGovernance::ProposalCalls` (r:0 w:1)
+	/// Proof: `PropertyGovernance::ProposalCalls` (`max_values`: None, `max_size`: Some(538), added: 3013, mode: `MaxEncodedLen`)
+	fn propose_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1747`
+		//  Estimated: `3887`
+		// Minimum execution time: 86_914_000 picoseconds.
+		Weight::from_parts(94_207_000, 0)
+			.saturating_add(Weight::from_parts(0, 3887))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
 }
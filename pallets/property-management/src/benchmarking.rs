@@ -11,7 +11,7 @@ use frame_support::traits::fungibles::InspectFreeze;
 use frame_support::BoundedVec;
 use frame_support::{assert_ok, traits::Get};
 use frame_system::RawOrigin;
-use pallet_marketplace::types::LegalProperty;
+use pallet_marketplace::types::{LegalProperty, SaleMode, SaleTerms};
 use pallet_marketplace::Pallet as Marketplace;
 use pallet_regions::Pallet as Regions;
 use pallet_regions::{RegionIdentifier, Vote};
@@ -143,10 +143,14 @@ fn list_and_sell_property<T: Config>(
         token_price,
         token_amount,
         metadata,
-        tax_paid_by_developer,
+        SaleTerms {
+            tax_paid_by_developer,
+            sale_mode: SaleMode::FixedPrice,
+            soft_cap: None
+        }
     ));
     let listing_id = 0;
-    let payment_asset = <T as pallet_marketplace::Config>::AcceptedAssets::get()[0];
+    let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
     let buyer: T::AccountId = account("buyer", 0, 0);
     assert_ok!(
         <T as pallet_marketplace::Config>::NativeCurrency::mint_into(
@@ -178,6 +182,7 @@ fn list_and_sell_property<T: Config>(
         listing_id,
         1,
         payment_asset,
+        None,
     ));
     claim_buyers_property_token::<T>(token_amount - 1, listing_id);
     assert_ok!(Marketplace::<T>::claim_property_token(
@@ -302,7 +307,7 @@ fn add_buyers_to_listing<T: Config + pallet_marketplace::Config>(
 
     for i in 1..=buyers {
         let buyer: T::AccountId = account("buyer", i, i);
-        let payment_asset_buyers = <T as pallet_marketplace::Config>::AcceptedAssets::get()[0];
+        let payment_asset_buyers = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
         assert_ok!(
             <T as pallet_marketplace::Config>::NativeCurrency::mint_into(
                 &buyer,
@@ -325,7 +330,8 @@ fn add_buyers_to_listing<T: Config + pallet_marketplace::Config>(
             RawOrigin::Signed(buyer).into(),
             0,
             1,
-            payment_asset_buyers
+            payment_asset_buyers,
+            None
         ));
     }
 }
@@ -340,6 +346,17 @@ fn claim_buyers_property_token<T: Config>(buyers: u32, listing_id: pallet_market
     }
 }
 
+fn assign_letting_agent<T: Config>(admin: T::AccountId, asset_id: u32) -> T::AccountId {
+    let letting_agent: T::AccountId = account("letting_agent", 0, 0);
+    assert_ok!(Whitelist::<T>::assign_role(
+        RawOrigin::Signed(admin).into(),
+        letting_agent.clone(),
+        Role::LettingAgent
+    ));
+    LettingStorage::<T>::insert(asset_id, letting_agent.clone());
+    letting_agent
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -780,14 +797,14 @@ mod benchmarks {
             0
         ));
 
-        let payment_asset = <T as pallet::Config>::AcceptedAssets::get()[0];
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
         assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
             payment_asset,
             &letting_agent,
             200_000_000_000u128.into()
         ));
 
-        let distribution_amount = 100_000u128.into();
+        let distribution_amount: <T as pallet::Config>::Balance = 100_000u128.into();
 
         #[extrinsic_call]
         distribute_income(
@@ -797,9 +814,12 @@ mod benchmarks {
             payment_asset,
         );
 
+        let reserve_amount =
+            <T as pallet::Config>::IncomeReserveShare::get().mul_floor(distribution_amount);
         assert_eq!(
-            InvestorFunds::<T>::get((&token_owner, asset_id, payment_asset)),
-            distribution_amount / <T as pallet_marketplace::Config>::MaxPropertyToken::get().into()
+            PropertyManagement::<T>::claimable_income(&token_owner, asset_id, payment_asset),
+            (distribution_amount - reserve_amount)
+                / <T as pallet_marketplace::Config>::MaxPropertyToken::get().into()
         );
     }
 
@@ -859,14 +879,14 @@ mod benchmarks {
             0
         ));
 
-        let payment_asset = <T as pallet::Config>::AcceptedAssets::get()[0];
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
         assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
             payment_asset,
             &letting_agent,
             200_000_000_000u128.into()
         ));
 
-        let distribution_amount = 100_000u128.into();
+        let distribution_amount: <T as pallet::Config>::Balance = 100_000u128.into();
 
         assert_ok!(PropertyManagement::<T>::distribute_income(
             RawOrigin::Signed(letting_agent.clone()).into(),
@@ -874,9 +894,12 @@ mod benchmarks {
             distribution_amount,
             payment_asset
         ));
+        let reserve_amount =
+            <T as pallet::Config>::IncomeReserveShare::get().mul_floor(distribution_amount);
         assert_eq!(
-            InvestorFunds::<T>::get((&token_owner, asset_id, payment_asset)),
-            distribution_amount / <T as pallet_marketplace::Config>::MaxPropertyToken::get().into()
+            PropertyManagement::<T>::claimable_income(&token_owner, asset_id, payment_asset),
+            (distribution_amount - reserve_amount)
+                / <T as pallet_marketplace::Config>::MaxPropertyToken::get().into()
         );
 
         #[extrinsic_call]
@@ -887,11 +910,177 @@ mod benchmarks {
         );
 
         assert_eq!(
-            InvestorFunds::<T>::get((&token_owner, asset_id, payment_asset)),
+            PropertyManagement::<T>::claimable_income(&token_owner, asset_id, payment_asset),
             0u32.into()
         );
     }
 
+    #[benchmark]
+    fn sweep_undistributed_income() -> Result<(), BenchmarkError> {
+        let (region_owner, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(region_owner.clone(), admin.clone());
+        let (_, asset_id) =
+            create_registered_property::<T>(region_owner, region_id, location, admin);
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
+        let amount: <T as pallet::Config>::Balance = 1_000u128.into();
+        UndistributedIncome::<T>::insert(asset_id, payment_asset, amount);
+        let origin =
+            <T as pallet::Config>::PropertyGovernanceOrigin::try_successful_origin(&asset_id)
+                .map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, payment_asset);
+
+        assert_eq!(PropertyReserve::<T>::get(asset_id, payment_asset), amount);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn record_income() {
+        let (region_owner, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(region_owner.clone(), admin.clone());
+        let (_, asset_id) =
+            create_registered_property::<T>(region_owner, region_id, location, admin.clone());
+        let letting_agent = assign_letting_agent::<T>(admin, asset_id);
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
+        let amount: <T as pallet::Config>::Balance = 100_000u128.into();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(letting_agent),
+            asset_id,
+            1,
+            payment_asset,
+            amount,
+        );
+
+        assert_eq!(
+            PropertyLedger::<T>::get(asset_id, 1).map(|ledger| ledger.income),
+            Some(amount)
+        );
+    }
+
+    #[benchmark]
+    fn record_expense() {
+        let (region_owner, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(region_owner.clone(), admin.clone());
+        let (_, asset_id) =
+            create_registered_property::<T>(region_owner, region_id, location, admin.clone());
+        let letting_agent = assign_letting_agent::<T>(admin, asset_id);
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
+        let amount: <T as pallet::Config>::Balance = 100_000u128.into();
+        assert_ok!(PropertyManagement::<T>::record_income(
+            RawOrigin::Signed(letting_agent.clone()).into(),
+            asset_id,
+            1,
+            payment_asset,
+            amount
+        ));
+        let expense: <T as pallet::Config>::Balance = 20_000u128.into();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(letting_agent),
+            asset_id,
+            1,
+            payment_asset,
+            ExpenseCategory::Maintenance,
+            expense,
+        );
+
+        assert_eq!(
+            PropertyLedger::<T>::get(asset_id, 1).map(|ledger| ledger.maintenance),
+            Some(expense)
+        );
+    }
+
+    #[benchmark]
+    fn close_ledger_period() {
+        let (region_owner, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(region_owner.clone(), admin.clone());
+        let (_, asset_id) =
+            create_registered_property::<T>(region_owner, region_id, location, admin.clone());
+        let letting_agent = assign_letting_agent::<T>(admin, asset_id);
+        let payment_asset = <T as pallet::Config>::AcceptedAssets::accepted_assets()[0];
+        assert_ok!(<T as pallet::Config>::ForeignCurrency::mint_into(
+            payment_asset,
+            &letting_agent,
+            200_000_000_000u128.into()
+        ));
+        assert_ok!(PropertyManagement::<T>::record_income(
+            RawOrigin::Signed(letting_agent.clone()).into(),
+            asset_id,
+            1,
+            payment_asset,
+            100_000u128.into()
+        ));
+        assert_ok!(PropertyManagement::<T>::record_expense(
+            RawOrigin::Signed(letting_agent.clone()).into(),
+            asset_id,
+            1,
+            payment_asset,
+            ExpenseCategory::Taxes,
+            20_000u128.into()
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(letting_agent), asset_id, 1);
+
+        assert_eq!(
+            PropertyLedger::<T>::get(asset_id, 1).map(|ledger| ledger.closed),
+            Some(true)
+        );
+    }
+
+    #[benchmark]
+    fn dismiss_letting_agent() -> Result<(), BenchmarkError> {
+        let (region_owner, admin): (T::AccountId, T::AccountId) = create_whitelisted_user::<T>();
+        let (region_id, location) = create_a_new_region::<T>(region_owner.clone(), admin.clone());
+        let (_, asset_id) = create_registered_property::<T>(
+            region_owner,
+            region_id,
+            location.clone(),
+            admin.clone(),
+        );
+        let letting_agent = assign_letting_agent::<T>(admin, asset_id);
+        let deposit = T::LettingAgentDeposit::get().saturating_mul(20u32.into());
+        assert_ok!(<T as pallet::Config>::NativeCurrency::mint_into(
+            &letting_agent,
+            deposit
+        ));
+        assert_ok!(PropertyManagement::<T>::add_letting_agent(
+            RawOrigin::Signed(letting_agent.clone()).into(),
+            region_id,
+            location.clone(),
+        ));
+        LettingInfo::<T>::mutate(&letting_agent, |maybe_info| {
+            if let Some(location_info) = maybe_info
+                .as_mut()
+                .and_then(|info| info.locations.get_mut(&location))
+            {
+                location_info.assigned_properties = 1;
+            }
+        });
+        let origin =
+            <T as pallet::Config>::PropertyGovernanceOrigin::try_successful_origin(&asset_id)
+                .map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id);
+
+        assert!(LettingStorage::<T>::get(asset_id).is_none());
+        assert_eq!(
+            LettingInfo::<T>::get(&letting_agent)
+                .unwrap()
+                .locations
+                .get(&location)
+                .unwrap()
+                .assigned_properties,
+            0
+        );
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(
         PropertyManagement,
         crate::mock::new_test_ext(),
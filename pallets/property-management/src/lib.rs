@@ -14,6 +14,7 @@ pub mod weights;
 pub use weights::*;
 
 use frame_support::{
+    dispatch::DispatchResult,
     traits::{
         fungible::MutateHold,
         fungibles::Mutate as FungiblesMutate,
@@ -27,18 +28,28 @@ use frame_support::{
 
 use frame_support::sp_runtime::{
     traits::{AccountIdConversion, Zero},
-    Percent, Saturating,
+    Percent, Permill, Saturating,
 };
 
 use codec::Codec;
 
-use pallet_real_estate_asset::traits::{PropertyTokenInspect, PropertyTokenSpvControl};
+use pallet_real_estate_asset::traits::{
+    OnPropertyTokenBalanceChange, PropertyTokenInspect, PropertyTokenSpvControl,
+};
 
 use primitives::MarketplaceFreezeReason;
 
+use pallet_marketplace::AcceptedAssetRegistry;
+
 use pallet_xcavate_whitelist::Role;
 
+use scale_info::prelude::vec::Vec;
+
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+
+/// Factor by which the income per token is scaled to keep the precision of the distribution.
+pub const INCOME_PRECISION: u128 = 1_000_000_000_000;
+
 pub type RuntimeHoldReasonOf<T> = <T as Config>::RuntimeHoldReason;
 
 pub type ForeignAssetIdOf<T> = <<T as Config>::ForeignCurrency as fungibles::Inspect<
@@ -124,6 +135,108 @@ pub mod pallet {
     pub enum Vote {
         Yes,
         No,
+        Withdraw,
+    }
+
+    /// Categories of property expenses.
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        RuntimeDebug,
+        TypeInfo,
+    )]
+    pub enum ExpenseCategory {
+        Maintenance,
+        Insurance,
+        Taxes,
+        AgentFee,
+    }
+
+    /// Income and expenses of a property recorded for a period.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct LedgerPeriod<T: Config> {
+        pub payment_asset: u32,
+        pub income: <T as pallet::Config>::Balance,
+        pub maintenance: <T as pallet::Config>::Balance,
+        pub insurance: <T as pallet::Config>::Balance,
+        pub taxes: <T as pallet::Config>::Balance,
+        pub agent_fee: <T as pallet::Config>::Balance,
+        pub closed: bool,
+    }
+
+    impl<T: Config> LedgerPeriod<T> {
+        /// Returns the sum of all recorded expenses.
+        pub fn total_expenses(&self) -> Option<<T as pallet::Config>::Balance> {
+            self.maintenance
+                .checked_add(&self.insurance)?
+                .checked_add(&self.taxes)?
+                .checked_add(&self.agent_fee)
+        }
+
+        /// Returns the income that remains after deducting the expenses.
+        pub fn net_income(&self) -> Option<<T as pallet::Config>::Balance> {
+            Some(self.income.saturating_sub(self.total_expenses()?))
+        }
+    }
+
+    /// Statement of a property for a period returned by the runtime api.
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct PropertyStatement<Balance> {
+        pub period: PeriodId,
+        pub payment_asset: u32,
+        pub income: Balance,
+        pub maintenance: Balance,
+        pub insurance: Balance,
+        pub taxes: Balance,
+        pub agent_fee: Balance,
+        pub total_expenses: Balance,
+        pub net_income: Balance,
+        pub closed: bool,
+    }
+
+    /// Financing terms of a property.
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        RuntimeDebug,
+        TypeInfo,
+    )]
+    pub struct Financing<Balance> {
+        pub principal: Balance,
+        pub interest_rate: Permill,
+    }
+
+    /// Terms of a property that the token holders decide on through governance.
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        Default,
+        MaxEncodedLen,
+        RuntimeDebug,
+        TypeInfo,
+    )]
+    pub struct PropertyTerms<Balance> {
+        pub rent: Option<Balance>,
+        pub letting_agent_fee: Option<Percent>,
+        pub reserve_share: Option<Percent>,
+        pub financing: Option<Financing<Balance>>,
     }
 
     #[pallet::config]
@@ -197,8 +310,8 @@ pub mod pallet {
         #[pallet::constant]
         type MaxLocations: Get<u32>;
 
-        #[pallet::constant]
-        type AcceptedAssets: Get<[u32; 2]>;
+        /// The registry of the accepted payment assets.
+        type AcceptedAssets: AcceptedAssetRegistry<<Self as pallet::Config>::Balance>;
 
         type PropertyToken: PropertyTokenInspect<Self> + PropertyTokenSpvControl<Self>;
 
@@ -214,9 +327,17 @@ pub mod pallet {
 
         #[pallet::constant]
         type MinVotingQuorum: Get<Percent>;
+
+        /// The origin that acts on behalf of the token holders of a property.
+        type PropertyGovernanceOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, u32>;
+
+        /// The share of distributed income that is added to the property reserve.
+        #[pallet::constant]
+        type IncomeReserveShare: Get<Percent>;
     }
 
     pub type ProposalId = u64;
+    pub type PeriodId = u32;
     pub type LocationId<T> = BoundedVec<u8, <T as pallet_regions::Config>::PostcodeLimit>;
 
     /// Mapping from the real estate object to the letting agent.
@@ -236,6 +357,82 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Mapping of asset id and payment asset to the cumulative income per property token,
+    /// scaled by `INCOME_PRECISION`.
+    #[pallet::storage]
+    pub type IncomePerToken<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        u32,
+        <T as pallet::Config>::Balance,
+        ValueQuery,
+    >;
+
+    /// Mapping of account, asset id and payment asset to the income per token at which the
+    /// income of the account has last been settled.
+    #[pallet::storage]
+    pub type IncomeCheckpoint<T> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, AccountIdOf<T>>,
+            NMapKey<Blake2_128Concat, u32>,
+            NMapKey<Blake2_128Concat, u32>,
+        ),
+        <T as pallet::Config>::Balance,
+        ValueQuery,
+    >;
+
+    /// Mapping of asset id and payment asset to the remainder of the distributed income that
+    /// could not be divided among the property token and is carried into the next
+    /// distribution.
+    #[pallet::storage]
+    pub type UndistributedIncome<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        u32,
+        <T as pallet::Config>::Balance,
+        ValueQuery,
+    >;
+
+    /// Mapping of asset id and payment asset to the reserve of a property that is held on the
+    /// property account.
+    #[pallet::storage]
+    pub type PropertyReserve<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        u32,
+        <T as pallet::Config>::Balance,
+        ValueQuery,
+    >;
+
+    /// Mapping of asset id and period to the ledger of the property for that period.
+    #[pallet::storage]
+    pub type PropertyLedger<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        PeriodId,
+        LedgerPeriod<T>,
+        OptionQuery,
+    >;
+
+    /// Mapping of asset id to the terms of the property.
+    #[pallet::storage]
+    pub type PropertyTermsStorage<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        PropertyTerms<<T as pallet::Config>::Balance>,
+        ValueQuery,
+    >;
+
     /// Mapping from account to letting agent info
     #[pallet::storage]
     pub type LettingInfo<T: Config> =
@@ -318,6 +515,50 @@ pub mod pallet {
             voter: AccountIdOf<T>,
             amount: u32,
         },
+        /// The letting agent recorded income of a property.
+        IncomeRecorded {
+            asset_id: u32,
+            period: PeriodId,
+            payment_asset: u32,
+            amount: <T as pallet::Config>::Balance,
+        },
+        /// The letting agent recorded an expense of a property.
+        ExpenseRecorded {
+            asset_id: u32,
+            period: PeriodId,
+            payment_asset: u32,
+            category: ExpenseCategory,
+            amount: <T as pallet::Config>::Balance,
+        },
+        /// A ledger period of a property has been closed and its net income distributed.
+        LedgerPeriodClosed {
+            asset_id: u32,
+            period: PeriodId,
+            net_income: <T as pallet::Config>::Balance,
+        },
+        /// The undistributed income of a property has been moved to its reserve.
+        UndistributedIncomeSwept {
+            asset_id: u32,
+            payment_asset: u32,
+            amount: <T as pallet::Config>::Balance,
+        },
+        /// Funds have been paid out of the reserve of a property.
+        ReserveWithdrawn {
+            asset_id: u32,
+            payment_asset: u32,
+            beneficiary: AccountIdOf<T>,
+            amount: <T as pallet::Config>::Balance,
+        },
+        /// The terms of a property have been updated.
+        PropertyTermsUpdated {
+            asset_id: u32,
+            terms: PropertyTerms<<T as pallet::Config>::Balance>,
+        },
+        /// The letting agent of a property has been dismissed.
+        LettingAgentDismissed {
+            asset_id: u32,
+            letting_agent: AccountIdOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -386,10 +627,26 @@ pub mod pallet {
         NoFrozenAmount,
         /// The amount for voting has to be higher than 0.
         ZeroVoteAmount,
+        /// There is no vote to withdraw.
+        NoVoteToWithdraw,
         /// Distribution amount can not be 0.
         ZeroDistributionAmount,
         /// Total supply of a property can not be 0.
         ZeroTokenSupply,
+        /// The property has no undistributed income in this payment asset.
+        NoUndistributedIncome,
+        /// Ledger entries can not be 0.
+        ZeroLedgerAmount,
+        /// No ledger has been recorded for this period.
+        LedgerPeriodNotFound,
+        /// The ledger period has already been closed.
+        LedgerPeriodClosed,
+        /// The ledger period is recorded in a different payment asset.
+        LedgerPaymentAssetMismatch,
+        /// The property reserve does not hold enough funds.
+        InsufficientReserve,
+        /// The agent fee exceeds the letting agent fee of the property.
+        AgentFeeExceeded,
     }
 
     #[pallet::call]
@@ -586,7 +843,7 @@ pub mod pallet {
         ///
         /// Parameters:
         /// - `asset_id`: The asset id of the property.
-        /// - `vote`: Must be either a Yes vote or a No vote.
+        /// - `vote`: A Yes or No vote, or Withdraw to take back a previous vote.
         /// - `amount`: The amount of property token that the investor is using for voting.
         ///
         /// Emits `VotedOnLettingAgent` event when successful.
@@ -611,9 +868,20 @@ pub mod pallet {
                 Error::<T>::VotingExpired
             );
 
-            ensure!(amount > 0, Error::<T>::ZeroVoteAmount);
+            ensure!(
+                vote == Vote::Withdraw || amount > 0,
+                Error::<T>::ZeroVoteAmount
+            );
             let voting_power = T::PropertyToken::get_token_balance(asset_id, &signer);
-            ensure!(voting_power >= amount, Error::<T>::NoPermission);
+            ensure!(
+                vote == Vote::Withdraw || voting_power >= amount,
+                Error::<T>::NoPermission
+            );
+            ensure!(
+                vote != Vote::Withdraw
+                    || UserLettingAgentVote::<T>::contains_key(proposal_id, &signer),
+                Error::<T>::NoVoteToWithdraw
+            );
             OngoingLettingAgentVoting::<T>::try_mutate(proposal_id, |maybe_current_vote| {
                 let current_vote = maybe_current_vote
                     .as_mut()
@@ -638,16 +906,10 @@ pub mod pallet {
                                     .no_voting_power
                                     .saturating_sub(previous_vote.power)
                             }
+                            Vote::Withdraw => {}
                         }
                     }
 
-                    T::AssetsFreezer::increase_frozen(
-                        asset_id,
-                        &MarketplaceFreezeReason::LettingAgentVoting,
-                        &signer,
-                        amount.into(),
-                    )?;
-
                     match vote {
                         Vote::Yes => {
                             current_vote.yes_voting_power =
@@ -657,8 +919,16 @@ pub mod pallet {
                             current_vote.no_voting_power =
                                 current_vote.no_voting_power.saturating_add(amount)
                         }
+                        Vote::Withdraw => return Ok(()),
                     }
 
+                    T::AssetsFreezer::increase_frozen(
+                        asset_id,
+                        &MarketplaceFreezeReason::LettingAgentVoting,
+                        &signer,
+                        amount.into(),
+                    )?;
+
                     *maybe_vote_record = Some(VoteRecord {
                         vote: vote.clone(),
                         asset_id,
@@ -826,51 +1096,12 @@ pub mod pallet {
                 origin,
                 &Role::LettingAgent,
             )?;
-            let letting_agent =
-                LettingStorage::<T>::get(asset_id).ok_or(Error::<T>::NoLettingAgentFound)?;
-            ensure!(letting_agent == signer, Error::<T>::NoPermission);
+            Self::ensure_letting_agent(&signer, asset_id)?;
             ensure!(
-                T::AcceptedAssets::get().contains(&payment_asset),
+                T::AcceptedAssets::is_accepted(payment_asset),
                 Error::<T>::PaymentAssetNotSupported
             );
-            ensure!(amount > Zero::zero(), Error::<T>::ZeroDistributionAmount);
-
-            let owner_list = T::PropertyToken::get_property_owner(asset_id);
-            let property_info = T::PropertyToken::get_property_asset_info(asset_id)
-                .ok_or(Error::<T>::NoObjectFound)?;
-            let total_supply = property_info.token_amount;
-            ensure!(total_supply > 0, Error::<T>::ZeroTokenSupply);
-
-            let scaled_amount = amount
-                .checked_mul(&1u128.into()) // Modify the scale factor if needed
-                .ok_or(Error::<T>::MultiplyError)?;
-
-            <T as pallet::Config>::ForeignCurrency::transfer(
-                payment_asset,
-                &signer,
-                &Self::property_account_id(asset_id),
-                scaled_amount,
-                Preservation::Expendable,
-            )
-            .map_err(|_| Error::<T>::NotEnoughFunds)?;
-
-            for owner in owner_list {
-                let token_amount = T::PropertyToken::get_token_balance(asset_id, &owner);
-                let amount_for_owner = Self::u64_to_balance_option(token_amount as u64)?
-                    .checked_mul(&amount)
-                    .ok_or(Error::<T>::MultiplyError)?
-                    .checked_div(&Self::u64_to_balance_option(total_supply.into())?)
-                    .ok_or(Error::<T>::DivisionError)?;
-                InvestorFunds::<T>::try_mutate((&owner, asset_id, payment_asset), |stored| {
-                    *stored = stored
-                        .checked_add(&amount_for_owner)
-                        .ok_or(Error::<T>::ArithmeticOverflow)?;
-                    Ok::<(), DispatchError>(())
-                })?;
-            }
-
-            Self::deposit_event(Event::<T>::IncomeDistributed { asset_id, amount });
-            Ok(())
+            Self::do_distribute_income(&signer, asset_id, amount, payment_asset)
         }
 
         /// Lets a property owner withdraw the distributed funds.
@@ -890,9 +1121,10 @@ pub mod pallet {
                 &Role::RealEstateInvestor,
             )?;
             ensure!(
-                T::AcceptedAssets::get().contains(&payment_asset),
+                T::AcceptedAssets::is_accepted(payment_asset),
                 Error::<T>::PaymentAssetNotSupported
             );
+            Self::settle_income(&signer, asset_id, payment_asset)?;
             let amount = InvestorFunds::<T>::take((&signer, asset_id, payment_asset));
             ensure!(!amount.is_zero(), Error::<T>::UserHasNoFundsStored);
             <T as pallet::Config>::ForeignCurrency::transfer(
@@ -909,6 +1141,217 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Moves the undistributed income of a property into the property reserve.
+        ///
+        /// The origin must be the PropertyGovernanceOrigin of the property.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset id of the property.
+        /// - `payment_asset`: The payment asset of the undistributed income.
+        ///
+        /// Emits `UndistributedIncomeSwept` event when successful.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::sweep_undistributed_income())]
+        pub fn sweep_undistributed_income(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            payment_asset: u32,
+        ) -> DispatchResult {
+            <T as pallet::Config>::PropertyGovernanceOrigin::ensure_origin(origin, &asset_id)?;
+            let amount = UndistributedIncome::<T>::take(asset_id, payment_asset);
+            ensure!(!amount.is_zero(), Error::<T>::NoUndistributedIncome);
+            PropertyReserve::<T>::try_mutate(asset_id, payment_asset, |reserve| {
+                *reserve = reserve
+                    .checked_add(&amount)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                Ok::<(), DispatchError>(())
+            })?;
+            Self::deposit_event(Event::<T>::UndistributedIncomeSwept {
+                asset_id,
+                payment_asset,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Lets the letting agent record income of a property for a period.
+        ///
+        /// The origin must be Signed and the sender must be the letting agent of the property.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset id of the property.
+        /// - `period`: The period of the ledger.
+        /// - `payment_asset`: The payment asset of the ledger.
+        /// - `amount`: The amount of income.
+        ///
+        /// Emits `IncomeRecorded` event when successful.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::record_income())]
+        pub fn record_income(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            period: PeriodId,
+            payment_asset: u32,
+            amount: <T as pallet::Config>::Balance,
+        ) -> DispatchResult {
+            let signer = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                origin,
+                &Role::LettingAgent,
+            )?;
+            Self::record_ledger_entry(
+                &signer,
+                asset_id,
+                period,
+                payment_asset,
+                amount,
+                |ledger, amount| {
+                    ledger.income = ledger
+                        .income
+                        .checked_add(&amount)
+                        .ok_or(Error::<T>::ArithmeticOverflow)?;
+                    Ok(())
+                },
+            )?;
+            Self::deposit_event(Event::<T>::IncomeRecorded {
+                asset_id,
+                period,
+                payment_asset,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Lets the letting agent record an expense of a property for a period.
+        ///
+        /// The origin must be Signed and the sender must be the letting agent of the property.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset id of the property.
+        /// - `period`: The period of the ledger.
+        /// - `payment_asset`: The payment asset of the ledger.
+        /// - `category`: The category of the expense.
+        /// - `amount`: The amount of the expense.
+        ///
+        /// Emits `ExpenseRecorded` event when successful.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::record_expense())]
+        pub fn record_expense(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            period: PeriodId,
+            payment_asset: u32,
+            category: ExpenseCategory,
+            amount: <T as pallet::Config>::Balance,
+        ) -> DispatchResult {
+            let signer = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                origin,
+                &Role::LettingAgent,
+            )?;
+            Self::record_ledger_entry(
+                &signer,
+                asset_id,
+                period,
+                payment_asset,
+                amount,
+                |ledger, amount| {
+                    let expense = match category {
+                        ExpenseCategory::Maintenance => &mut ledger.maintenance,
+                        ExpenseCategory::Insurance => &mut ledger.insurance,
+                        ExpenseCategory::Taxes => &mut ledger.taxes,
+                        ExpenseCategory::AgentFee => &mut ledger.agent_fee,
+                    };
+                    *expense = expense
+                        .checked_add(&amount)
+                        .ok_or(Error::<T>::ArithmeticOverflow)?;
+                    if let Some(letting_agent_fee) =
+                        PropertyTermsStorage::<T>::get(asset_id).letting_agent_fee
+                    {
+                        ensure!(
+                            ledger.agent_fee <= letting_agent_fee.mul_floor(ledger.income),
+                            Error::<T>::AgentFeeExceeded
+                        );
+                    }
+                    Ok(())
+                },
+            )?;
+            Self::deposit_event(Event::<T>::ExpenseRecorded {
+                asset_id,
+                period,
+                payment_asset,
+                category,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Lets the letting agent close a ledger period and distribute its net income.
+        ///
+        /// The origin must be Signed and the sender must be the letting agent of the property.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset id of the property.
+        /// - `period`: The period of the ledger.
+        ///
+        /// Emits `LedgerPeriodClosed` event when successful.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::close_ledger_period())]
+        pub fn close_ledger_period(
+            origin: OriginFor<T>,
+            asset_id: u32,
+            period: PeriodId,
+        ) -> DispatchResult {
+            let signer = <T as pallet::Config>::PermissionOrigin::ensure_origin(
+                origin,
+                &Role::LettingAgent,
+            )?;
+            Self::ensure_letting_agent(&signer, asset_id)?;
+            let net_income = PropertyLedger::<T>::try_mutate(asset_id, period, |maybe_ledger| {
+                let ledger = maybe_ledger
+                    .as_mut()
+                    .ok_or(Error::<T>::LedgerPeriodNotFound)?;
+                ensure!(!ledger.closed, Error::<T>::LedgerPeriodClosed);
+                ledger.closed = true;
+                let net_income = ledger.net_income().ok_or(Error::<T>::ArithmeticOverflow)?;
+                if !net_income.is_zero() {
+                    Self::do_distribute_income(
+                        &signer,
+                        asset_id,
+                        net_income,
+                        ledger.payment_asset,
+                    )?;
+                }
+                Ok::<_, DispatchError>(net_income)
+            })?;
+            Self::deposit_event(Event::<T>::LedgerPeriodClosed {
+                asset_id,
+                period,
+                net_income,
+            });
+            Ok(())
+        }
+
+        /// Dismisses the letting agent of a property so that a new one can be proposed.
+        ///
+        /// The origin must be the PropertyGovernanceOrigin of the property.
+        ///
+        /// Parameters:
+        /// - `asset_id`: The asset id of the property.
+        ///
+        /// Emits `LettingAgentDismissed` event when successful.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::dismiss_letting_agent())]
+        pub fn dismiss_letting_agent(origin: OriginFor<T>, asset_id: u32) -> DispatchResult {
+            <T as pallet::Config>::PropertyGovernanceOrigin::ensure_origin(origin, &asset_id)?;
+            let letting_agent =
+                LettingStorage::<T>::get(asset_id).ok_or(Error::<T>::NoLettingAgentFound)?;
+            Self::remove_bad_letting_agent(asset_id)?;
+            Self::deposit_event(Event::<T>::LettingAgentDismissed {
+                asset_id,
+                letting_agent,
+            });
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -924,6 +1367,272 @@ pub mod pallet {
             input.try_into().map_err(|_| Error::<T>::ConversionError)
         }
 
+        /// Returns the income of a property in a payment asset that an account can claim.
+        pub fn claimable_income(
+            account: &AccountIdOf<T>,
+            asset_id: u32,
+            payment_asset: u32,
+        ) -> <T as pallet::Config>::Balance {
+            let pending_income =
+                Self::pending_income(account, asset_id, payment_asset).unwrap_or_default();
+            InvestorFunds::<T>::get((account, asset_id, payment_asset))
+                .saturating_add(pending_income)
+        }
+
+        /// Returns the income that an account has accrued since its income has last been
+        /// settled.
+        fn pending_income(
+            account: &AccountIdOf<T>,
+            asset_id: u32,
+            payment_asset: u32,
+        ) -> Result<<T as pallet::Config>::Balance, DispatchError> {
+            let checkpoint = IncomeCheckpoint::<T>::get((account, asset_id, payment_asset));
+            let income_per_token =
+                IncomePerToken::<T>::get(asset_id, payment_asset).saturating_sub(checkpoint);
+            let token_amount = T::PropertyToken::get_token_balance(asset_id, account);
+            let pending_income = income_per_token
+                .checked_mul(&Self::u64_to_balance_option(token_amount.into())?)
+                .ok_or(Error::<T>::MultiplyError)?
+                .checked_div(&INCOME_PRECISION.into())
+                .ok_or(Error::<T>::DivisionError)?;
+            Ok(pending_income)
+        }
+
+        /// Adds the income that an account has accrued since its last settlement to its
+        /// stored funds.
+        pub(crate) fn settle_income(
+            account: &AccountIdOf<T>,
+            asset_id: u32,
+            payment_asset: u32,
+        ) -> DispatchResult {
+            let pending_income = Self::pending_income(account, asset_id, payment_asset)?;
+            if !pending_income.is_zero() {
+                InvestorFunds::<T>::try_mutate((account, asset_id, payment_asset), |stored| {
+                    *stored = stored
+                        .checked_add(&pending_income)
+                        .ok_or(Error::<T>::ArithmeticOverflow)?;
+                    Ok::<(), DispatchError>(())
+                })?;
+            }
+            IncomeCheckpoint::<T>::insert(
+                (account, asset_id, payment_asset),
+                IncomePerToken::<T>::get(asset_id, payment_asset),
+            );
+            Ok(())
+        }
+
+        /// Transfers income from the letting agent to the property account and adds it to the
+        /// income per token of the property.
+        fn do_distribute_income(
+            signer: &AccountIdOf<T>,
+            asset_id: u32,
+            amount: <T as pallet::Config>::Balance,
+            payment_asset: u32,
+        ) -> DispatchResult {
+            ensure!(amount > Zero::zero(), Error::<T>::ZeroDistributionAmount);
+
+            let property_info = T::PropertyToken::get_property_asset_info(asset_id)
+                .ok_or(Error::<T>::NoObjectFound)?;
+            let total_supply = property_info.token_amount;
+            ensure!(total_supply > 0, Error::<T>::ZeroTokenSupply);
+
+            let scaled_amount = amount
+                .checked_mul(&1u128.into()) // Modify the scale factor if needed
+                .ok_or(Error::<T>::MultiplyError)?;
+
+            <T as pallet::Config>::ForeignCurrency::transfer(
+                payment_asset,
+                signer,
+                &Self::property_account_id(asset_id),
+                scaled_amount,
+                Preservation::Expendable,
+            )
+            .map_err(|_| Error::<T>::NotEnoughFunds)?;
+
+            let reserve_amount = Self::reserve_share(asset_id).mul_floor(scaled_amount);
+            if !reserve_amount.is_zero() {
+                PropertyReserve::<T>::try_mutate(asset_id, payment_asset, |reserve| {
+                    *reserve = reserve
+                        .checked_add(&reserve_amount)
+                        .ok_or(Error::<T>::ArithmeticOverflow)?;
+                    Ok::<(), DispatchError>(())
+                })?;
+            }
+
+            let total_supply = Self::u64_to_balance_option(total_supply.into())?;
+            let distributable_amount = scaled_amount
+                .checked_sub(&reserve_amount)
+                .ok_or(Error::<T>::ArithmeticUnderflow)?
+                .checked_add(&UndistributedIncome::<T>::get(asset_id, payment_asset))
+                .ok_or(Error::<T>::ArithmeticOverflow)?
+                .checked_mul(&INCOME_PRECISION.into())
+                .ok_or(Error::<T>::MultiplyError)?;
+            let income_per_token = distributable_amount
+                .checked_div(&total_supply)
+                .ok_or(Error::<T>::DivisionError)?;
+            let remainder = distributable_amount
+                .checked_sub(
+                    &income_per_token
+                        .checked_mul(&total_supply)
+                        .ok_or(Error::<T>::MultiplyError)?,
+                )
+                .ok_or(Error::<T>::ArithmeticUnderflow)?
+                .checked_div(&INCOME_PRECISION.into())
+                .ok_or(Error::<T>::DivisionError)?;
+            if remainder.is_zero() {
+                UndistributedIncome::<T>::remove(asset_id, payment_asset);
+            } else {
+                UndistributedIncome::<T>::insert(asset_id, payment_asset, remainder);
+            }
+            IncomePerToken::<T>::try_mutate(asset_id, payment_asset, |stored| {
+                *stored = stored
+                    .checked_add(&income_per_token)
+                    .ok_or(Error::<T>::ArithmeticOverflow)?;
+                Ok::<(), DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::<T>::IncomeDistributed { asset_id, amount });
+            Ok(())
+        }
+
+        /// Pays funds out of the reserve of a property to the beneficiary.
+        pub fn withdraw_from_reserve(
+            asset_id: u32,
+            payment_asset: u32,
+            beneficiary: &AccountIdOf<T>,
+            amount: <T as pallet::Config>::Balance,
+        ) -> DispatchResult {
+            PropertyReserve::<T>::try_mutate(asset_id, payment_asset, |reserve| {
+                *reserve = reserve
+                    .checked_sub(&amount)
+                    .ok_or(Error::<T>::InsufficientReserve)?;
+                <T as pallet::Config>::ForeignCurrency::transfer(
+                    payment_asset,
+                    &Self::property_account_id(asset_id),
+                    beneficiary,
+                    amount,
+                    Preservation::Expendable,
+                )
+                .map_err(|_| Error::<T>::NotEnoughFunds)?;
+                Ok::<(), DispatchError>(())
+            })?;
+            Self::deposit_event(Event::<T>::ReserveWithdrawn {
+                asset_id,
+                payment_asset,
+                beneficiary: beneficiary.clone(),
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Returns the share of the income of a property that is added to its reserve.
+        pub fn reserve_share(asset_id: u32) -> Percent {
+            PropertyTermsStorage::<T>::get(asset_id)
+                .reserve_share
+                .unwrap_or_else(<T as pallet::Config>::IncomeReserveShare::get)
+        }
+
+        /// Updates the terms of a property.
+        pub fn update_property_terms(
+            asset_id: u32,
+            update: impl FnOnce(&mut PropertyTerms<<T as pallet::Config>::Balance>),
+        ) {
+            let terms = PropertyTermsStorage::<T>::mutate(asset_id, |terms| {
+                update(terms);
+                terms.clone()
+            });
+            Self::deposit_event(Event::<T>::PropertyTermsUpdated { asset_id, terms });
+        }
+
+        /// Ensures that the account is the letting agent of the property.
+        fn ensure_letting_agent(account: &AccountIdOf<T>, asset_id: u32) -> DispatchResult {
+            let letting_agent =
+                LettingStorage::<T>::get(asset_id).ok_or(Error::<T>::NoLettingAgentFound)?;
+            ensure!(&letting_agent == account, Error::<T>::NoPermission);
+            Ok(())
+        }
+
+        /// Adds an entry to the ledger of a property for a period.
+        fn record_ledger_entry(
+            signer: &AccountIdOf<T>,
+            asset_id: u32,
+            period: PeriodId,
+            payment_asset: u32,
+            amount: <T as pallet::Config>::Balance,
+            entry: impl FnOnce(
+                &mut LedgerPeriod<T>,
+                <T as pallet::Config>::Balance,
+            ) -> Result<(), DispatchError>,
+        ) -> DispatchResult {
+            Self::ensure_letting_agent(signer, asset_id)?;
+            ensure!(!amount.is_zero(), Error::<T>::ZeroLedgerAmount);
+            ensure!(
+                T::AcceptedAssets::is_accepted(payment_asset),
+                Error::<T>::PaymentAssetNotSupported
+            );
+            PropertyLedger::<T>::try_mutate(asset_id, period, |maybe_ledger| {
+                let ledger = maybe_ledger.get_or_insert_with(|| LedgerPeriod {
+                    payment_asset,
+                    income: Zero::zero(),
+                    maintenance: Zero::zero(),
+                    insurance: Zero::zero(),
+                    taxes: Zero::zero(),
+                    agent_fee: Zero::zero(),
+                    closed: false,
+                });
+                ensure!(!ledger.closed, Error::<T>::LedgerPeriodClosed);
+                ensure!(
+                    ledger.payment_asset == payment_asset,
+                    Error::<T>::LedgerPaymentAssetMismatch
+                );
+                entry(ledger, amount)
+            })
+        }
+
+        /// Returns the statement of a property for a period.
+        pub fn property_statement(
+            asset_id: u32,
+            period: PeriodId,
+        ) -> Option<PropertyStatement<<T as pallet::Config>::Balance>> {
+            PropertyLedger::<T>::get(asset_id, period)
+                .map(|ledger| Self::statement_from_ledger(period, ledger))
+        }
+
+        /// Returns up to `limit` statements of a property ordered by period, starting after the
+        /// period `start`.
+        pub fn property_statements(
+            asset_id: u32,
+            start: Option<PeriodId>,
+            limit: u32,
+        ) -> Vec<PropertyStatement<<T as pallet::Config>::Balance>> {
+            let mut statements: Vec<_> = PropertyLedger::<T>::iter_prefix(asset_id)
+                .filter(|(period, _)| start.map_or(true, |start| *period > start))
+                .map(|(period, ledger)| Self::statement_from_ledger(period, ledger))
+                .collect();
+            statements.sort_by_key(|statement| statement.period);
+            statements.truncate(limit as usize);
+            statements
+        }
+
+        fn statement_from_ledger(
+            period: PeriodId,
+            ledger: LedgerPeriod<T>,
+        ) -> PropertyStatement<<T as pallet::Config>::Balance> {
+            let total_expenses = ledger.total_expenses().unwrap_or_default();
+            PropertyStatement {
+                period,
+                payment_asset: ledger.payment_asset,
+                income: ledger.income,
+                maintenance: ledger.maintenance,
+                insurance: ledger.insurance,
+                taxes: ledger.taxes,
+                agent_fee: ledger.agent_fee,
+                total_expenses,
+                net_income: ledger.income.saturating_sub(total_expenses),
+                closed: ledger.closed,
+            }
+        }
+
         /// Removes bad letting agents.
         pub fn remove_bad_letting_agent(asset_id: u32) -> DispatchResult {
             let letting_agent =
@@ -948,6 +1657,17 @@ pub mod pallet {
     }
 }
 
+impl<T: Config> OnPropertyTokenBalanceChange<AccountIdOf<T>> for Pallet<T> {
+    /// Settles the income of the account in all payment assets before its token balance
+    /// changes.
+    fn before_balance_change(asset_id: u32, account: &AccountIdOf<T>) -> DispatchResult {
+        for payment_asset in IncomePerToken::<T>::iter_key_prefix(asset_id) {
+            Self::settle_income(account, asset_id, payment_asset)?;
+        }
+        Ok(())
+    }
+}
+
 sp_api::decl_runtime_apis! {
     pub trait PropertyManagementApi<AccountId>
     where
@@ -955,4 +1675,19 @@ sp_api::decl_runtime_apis! {
     {
         fn get_management_account_id() -> AccountId;
     }
+
+    pub trait PropertyLedgerApi<Balance>
+    where
+        Balance: Codec,
+    {
+        /// Returns the statement of a property for a period.
+        fn property_statement(asset_id: u32, period: PeriodId) -> Option<PropertyStatement<Balance>>;
+
+        /// Returns the statements of a property ordered by period after the period `start`.
+        fn property_statements(
+            asset_id: u32,
+            start: Option<PeriodId>,
+            limit: u32,
+        ) -> Vec<PropertyStatement<Balance>>;
+    }
 }
@@ -4,12 +4,13 @@ use crate as pallet_property_management;
 use frame_support::{
     derive_impl, parameter_types,
     traits::{AsEnsureOriginWithArg, OriginTrait},
+    weights::Weight,
     BoundedVec,
 };
-use sp_core::{ConstU128, ConstU32};
+use sp_core::{ConstU128, ConstU32, ConstU64};
 use sp_runtime::{
     traits::{AccountIdLookup, BlakeTwo256, IdentifyAccount, Verify},
-    MultiSignature, Percent,
+    MultiSignature, Percent, Permill,
 };
 
 use frame_system::EnsureRoot;
@@ -235,6 +236,14 @@ impl pallet_xcavate_whitelist::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_xcavate_whitelist::weights::SubstrateWeight<Test>;
     type WhitelistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type RoleGrantPeriod = ConstU64<100_000>;
+    type ExpiryNoticePeriod = ConstU64<10>;
+    type MaxExpiriesPerBlock = ConstU32<100>;
+    type MaxJurisdictions = ConstU32<10>;
+    type PendingActionLifetime = ConstU64<20>;
+    type MaxApprovals = ConstU32<5>;
+    type MaxRoleAssignments = ConstU32<100>;
+    type MaxLinkedAccounts = ConstU32<5>;
 }
 
 use pallet_xcavate_whitelist::{self as whitelist, RolePermission};
@@ -295,6 +304,34 @@ impl<T: whitelist::Config> EnsureOriginWithArg<T::RuntimeOrigin, whitelist::Role
     }
 }
 
+impl<T: whitelist::Config> EnsureOriginWithArg<T::RuntimeOrigin, whitelist::InvestmentEligibility>
+    for EnsureCompliant<T>
+{
+    type Success = T::AccountId;
+
+    fn try_origin(
+        origin: T::RuntimeOrigin,
+        eligibility: &whitelist::InvestmentEligibility,
+    ) -> Result<Self::Success, T::RuntimeOrigin> {
+        let Some(who) = origin.clone().into_signer() else {
+            return Err(origin);
+        };
+        if whitelist::Pallet::<T>::is_eligible(&who, eligibility) {
+            Ok(who)
+        } else {
+            Err(origin)
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(
+        _eligibility: &whitelist::InvestmentEligibility,
+    ) -> Result<T::RuntimeOrigin, ()> {
+        let account = frame_benchmarking::whitelisted_caller();
+        Ok(frame_system::RawOrigin::Signed(account).into())
+    }
+}
+
 parameter_types! {
     pub const Postcode: u32 = 10;
     pub const MaximumListingDuration: u64 = 10_000;
@@ -353,6 +390,7 @@ impl pallet_real_estate_asset::Config for Test {
     type AssetId = <Self as pallet_assets::Config<Instance1>>::AssetId;
     type PropertyAccountFundingAmount = ConstU128<100>;
     type MaxPropertyToken = MaxPropertyTokens;
+    type OnBalanceChange = PropertyManagement;
 }
 
 parameter_types! {
@@ -361,13 +399,19 @@ parameter_types! {
     pub const MaxPropertyTokens: u32 = 1000;
     pub const MaxNftsInCollection: u32 = 100;
     pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
-    pub const AcceptedPaymentAssets: [u32; 2] = [1337, 1984];
     pub const MaximumAcceptedAssets: u32 = 2;
+    pub const PriceReferenceDecimals: u8 = 6;
     pub const LawyerVotingDuration: BlockNumber = 30;
     pub const LegalProcessDuration: BlockNumber = 150;
     pub const MinimumVotingQuorum: Percent = Percent::from_percent(50);
+    pub const PropertyIncomeReserveShare: Percent = Percent::zero();
     pub const ClaimWindowTime: BlockNumber = 100;
     pub const MaximumRelistAttempts: u8 = 1;
+    pub const MaxOrdersPerProperty: u32 = 100;
+    pub const MaxOrdersPerInvestor: u32 = 5;
+    pub const MaxListingForBlock: u32 = 100;
+    pub const ExpiryProcessingWeight: Weight = Weight::MAX;
+    pub const DefaultPropertyOwnershipCap: Permill = Permill::from_percent(50);
 }
 
 /// Configure the pallet-xcavate-staking in pallets/xcavate-staking.
@@ -388,8 +432,10 @@ impl pallet_marketplace::Config for Test {
     type AssetId = <Self as pallet_assets::Config<Instance1>>::AssetId;
     type ListingDeposit = ConstU128<10>;
     type MarketplaceFeePercentage = ConstU128<1>;
-    type AcceptedAssets = AcceptedPaymentAssets;
+    type AssetRegistryOrigin = EnsureRoot<Self::AccountId>;
     type MaxAcceptedAssets = MaximumAcceptedAssets;
+    type ReferenceDecimals = PriceReferenceDecimals;
+    type ExchangeRates = ();
     type PropertyToken = RealEstateAsset;
     type LawyerVotingTime = LawyerVotingDuration;
     type LegalProcessTime = LegalProcessDuration;
@@ -399,6 +445,13 @@ impl pallet_marketplace::Config for Test {
     type MinVotingQuorum = MinimumVotingQuorum;
     type ClaimWindow = ClaimWindowTime;
     type MaxRelistAttempts = MaximumRelistAttempts;
+    type MaxOrdersPerAsset = MaxOrdersPerProperty;
+    type MaxOrdersPerAccount = MaxOrdersPerInvestor;
+    type OrderDeposit = ConstU128<100>;
+    type MaxListingsForBlock = MaxListingForBlock;
+    type ExpiryProcessingWeight = ExpiryProcessingWeight;
+    type DefaultOwnershipCap = DefaultPropertyOwnershipCap;
+    type OwnershipCapOrigin = AsEnsureOriginWithArg<EnsureRoot<Self::AccountId>>;
 }
 
 parameter_types! {
@@ -423,11 +476,13 @@ impl pallet_property_management::Config for Test {
     type MaxProperties = MaxProperty;
     type MaxLettingAgents = MaxLettingAgent;
     type MaxLocations = MaxLocation;
-    type AcceptedAssets = AcceptedPaymentAssets;
+    type AcceptedAssets = Marketplace;
     type PropertyToken = RealEstateAsset;
     type LettingAgentVotingTime = LettingAgentVotingDuration;
     type PermissionOrigin = EnsureHasRole<Self>;
     type MinVotingQuorum = MinimumVotingQuorum;
+    type PropertyGovernanceOrigin = AsEnsureOriginWithArg<EnsureRoot<Self::AccountId>>;
+    type IncomeReserveShare = PropertyIncomeReserveShare;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -465,7 +520,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
             true,
             1,
         )], // Genesis assets: id, owner, is_sufficient, min_balance
-        metadata: vec![(1984, "USDT".into(), "USDT".into(), 0)], // Genesis metadata: id, name, symbol, decimals
+        metadata: vec![(1984, "USDT".into(), "USDT".into(), 6)], // Genesis metadata: id, name, symbol, decimals
         accounts: vec![
             (1984, [0; 32].into(), 20_000_000),
             (1984, [1; 32].into(), 1_500_000),
@@ -490,12 +545,18 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
             true,
             1,
         )], // Genesis assets: id, owner, is_sufficient, min_balance
-        metadata: vec![(1337, "USDT".into(), "USDT".into(), 0)], // Genesis metadata: id, name, symbol, decimals
+        metadata: vec![(1337, "USDT".into(), "USDT".into(), 6)], // Genesis metadata: id, name, symbol, decimals
         accounts: vec![(1337, [4; 32].into(), 5_000)], // Genesis accounts: id, account_id, balance
         next_asset_id: None,
     }
     .assimilate_storage(&mut test)
     .unwrap();
 
+    pallet_marketplace::GenesisConfig::<Test> {
+        accepted_assets: vec![(1337, 1, false), (1984, 1, false)], // Accepted payment assets: id, min_amount, uses_exchange_rate
+    }
+    .assimilate_storage(&mut test)
+    .unwrap();
+
     test.into()
 }
@@ -8,17 +8,19 @@ use frame_support::{
 use primitives::MarketplaceFreezeReason;
 
 use crate::{
-    AssetLettingProposal, HoldReason, InvestorFunds, LettingAgentProposal, LettingInfo,
-    LettingStorage, OngoingLettingAgentVoting, ProposalCounter, UserLettingAgentVote, VoteRecord,
+    AssetLettingProposal, ExpenseCategory, HoldReason, IncomeCheckpoint, IncomePerToken,
+    InvestorFunds, LettingAgentProposal, LettingInfo, LettingStorage, OngoingLettingAgentVoting,
+    PropertyLedger, PropertyReserve, PropertyStatement, PropertyTerms, ProposalCounter,
+    UndistributedIncome, UserLettingAgentVote, VoteRecord, INCOME_PRECISION,
 };
 
-use sp_runtime::{traits::BadOrigin, Permill, TokenError};
+use sp_runtime::{traits::BadOrigin, Percent, Permill, TokenError};
 
-use pallet_marketplace::types::LegalProperty;
+use pallet_marketplace::types::{LegalProperty, SaleMode, SaleTerms};
 
 use pallet_regions::RegionIdentifier;
 
-use pallet_real_estate_asset::Error as RealEstateAssetError;
+use pallet_real_estate_asset::{Error as RealEstateAssetError, PropertyOwnerToken};
 
 macro_rules! bvec {
 	($( $x:tt )*) => {
@@ -669,25 +671,32 @@ fn letting_agent_propose_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -801,25 +810,32 @@ fn letting_agent_propose_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -973,25 +989,32 @@ fn vote_on_letting_agent_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1106,6 +1129,37 @@ fn vote_on_letting_agent_works() {
             ),
             25
         );
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Withdraw,
+            0,
+        ));
+        assert_eq!(
+            OngoingLettingAgentVoting::<Test>::get(0).unwrap(),
+            crate::VoteStats {
+                yes_voting_power: 20,
+                no_voting_power: 0,
+            },
+        );
+        assert!(UserLettingAgentVote::<Test>::get::<u64, AccountId>(0, [1; 32].into()).is_none());
+        assert_noop!(
+            PropertyManagement::vote_on_letting_agent(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                crate::Vote::Withdraw,
+                0
+            ),
+            Error::<Test>::NoVoteToWithdraw
+        );
+        assert_eq!(
+            AssetsFreezer::balance_frozen(
+                0,
+                &MarketplaceFreezeReason::LettingAgentVoting,
+                &[1; 32].into()
+            ),
+            0
+        );
     });
 }
 
@@ -1179,25 +1233,32 @@ fn vote_on_letting_agent_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1342,7 +1403,11 @@ fn finalize_letting_agent_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -1351,7 +1416,11 @@ fn finalize_letting_agent_works() {
             1_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
@@ -1360,61 +1429,74 @@ fn finalize_letting_agent_works() {
             1_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             1,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             1,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             1,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             2,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             2,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             2,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1686,25 +1768,32 @@ fn finalize_letting_agent_works_2() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -1910,25 +1999,32 @@ fn finalize_letting_agent_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_noop!(
             PropertyManagement::finalize_letting_agent(RuntimeOrigin::signed([2; 32].into()), 0,),
@@ -2005,25 +2101,32 @@ fn finalize_letting_agent_fails() {
                 1_000,
                 100,
                 bvec![22, 22],
-                false
+                SaleTerms {
+                    tax_paid_by_developer: false,
+                    sale_mode: SaleMode::FixedPrice,
+                    soft_cap: None
+                }
             ));
             assert_ok!(Marketplace::buy_property_token(
                 RuntimeOrigin::signed([0; 32].into()),
                 x,
                 40,
-                1984
+                1984,
+                None
             ));
             assert_ok!(Marketplace::buy_property_token(
                 RuntimeOrigin::signed([15; 32].into()),
                 x,
                 30,
-                1984
+                1984,
+                None
             ));
             assert_ok!(Marketplace::buy_property_token(
                 RuntimeOrigin::signed([16; 32].into()),
                 x,
                 30,
-                1984
+                1984,
+                None
             ));
             assert_ok!(Marketplace::claim_property_token(
                 RuntimeOrigin::signed([0; 32].into()),
@@ -2164,25 +2267,32 @@ fn unfreeze_letting_voting_token_works() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2350,25 +2460,32 @@ fn unfreeze_letting_voting_token_fails() {
             10_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             40,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([30; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([31; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2503,25 +2620,32 @@ fn distribute_income_works() {
             9_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
             25,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([3; 32].into()),
             0,
             45,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2630,15 +2754,15 @@ fn distribute_income_works() {
             1984,
         ));
         assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([1; 32].into(), 0, 1984)),
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
             800
         );
         assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([2; 32].into(), 0, 1984)),
+            PropertyManagement::claimable_income(&[2; 32].into(), 0, 1984),
             960
         );
         assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([3; 32].into(), 0, 1984)),
+            PropertyManagement::claimable_income(&[3; 32].into(), 0, 1984),
             1440
         );
         assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 1800);
@@ -2646,7 +2770,7 @@ fn distribute_income_works() {
 }
 
 #[test]
-fn distribute_income_fails() {
+fn distribute_income_carries_remainder() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -2681,80 +2805,77 @@ fn distribute_income_fails() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
+            [3; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [4; 32].into(),
-            pallet_xcavate_whitelist::Role::LettingAgent
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
         ));
         assert_ok!(Marketplace::list_property(
             RuntimeOrigin::signed([0; 32].into()),
             3,
             bvec![10, 10],
-            10_000,
+            9_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
+            25,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([3; 32].into()),
             0,
-            30,
-            1984
+            45,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
-            0
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::LettingAgent
-        ));
-        assert_noop!(
-            PropertyManagement::distribute_income(
-                RuntimeOrigin::signed([5; 32].into()),
-                0,
-                200,
-                1984
-            ),
-            Error::<Test>::NoLettingAgentFound
-        );
-        assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([1; 32].into(), 0, 1984)),
+            RuntimeOrigin::signed([3; 32].into()),
             0
-        );
-        assert_ok!(PropertyManagement::add_letting_agent(
-            RuntimeOrigin::signed([4; 32].into()),
-            3,
-            bvec![10, 10],
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
@@ -2765,66 +2886,2351 @@ fn distribute_income_fails() {
             RuntimeOrigin::signed([5; 32].into()),
             0,
         ));
-        lawyer_process_helper(
-            [0; 32].into(),
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
             0,
-            vec![([1; 32].into(), 40), ([30; 32].into(), 30)],
-        );
-        assert_ok!(PropertyManagement::letting_agent_propose(
-            RuntimeOrigin::signed([4; 32].into()),
-            0
+            LegalProperty::RealEstateDeveloperSide,
+            4_000,
         ));
-        assert_ok!(PropertyManagement::vote_on_letting_agent(
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            crate::Vote::Yes,
-            40
+            pallet_marketplace::types::Vote::Yes,
+            20,
         ));
-        assert_ok!(PropertyManagement::vote_on_letting_agent(
-            RuntimeOrigin::signed([30; 32].into()),
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([3; 32].into()),
             0,
-            crate::Vote::Yes,
-            30
+            pallet_marketplace::types::Vote::Yes,
+            40,
         ));
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
-        assert_ok!(PropertyManagement::finalize_letting_agent(
+        assert_ok!(Marketplace::finalize_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
         ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        assert_ok!(PropertyManagement::add_letting_agent(
+            RuntimeOrigin::signed([4; 32].into()),
+            3,
+            bvec![10, 10],
+        ));
+        assert_ok!(PropertyManagement::letting_agent_propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            25
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            crate::Vote::Yes,
+            45
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyManagement::finalize_letting_agent(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+        ));
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            2050,
+            1984,
+        ));
+        assert_eq!(
+            IncomePerToken::<Test>::get(0, 1984),
+            20 * INCOME_PRECISION + INCOME_PRECISION / 2
+        );
+        assert_eq!(UndistributedIncome::<Test>::get(0, 1984), 0);
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            512
+        );
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1950,
+            1984,
+        ));
+        assert_eq!(IncomePerToken::<Test>::get(0, 1984), 40 * INCOME_PRECISION);
+        assert_eq!(UndistributedIncome::<Test>::get(0, 1984), 0);
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            1000
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[3; 32].into(), 0, 1984),
+            1800
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, PropertyManagement::property_account_id(0)),
+            4000
+        );
+    });
+}
+
+#[test]
+fn sweep_undistributed_income_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [6; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([6; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [3; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            9_000,
+            100,
+            bvec![22, 22],
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            25,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            45,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([3; 32].into()),
+            0
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            20,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            40,
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        assert_ok!(PropertyManagement::add_letting_agent(
+            RuntimeOrigin::signed([4; 32].into()),
+            3,
+            bvec![10, 10],
+        ));
+        assert_ok!(PropertyManagement::letting_agent_propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            25
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            crate::Vote::Yes,
+            45
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyManagement::finalize_letting_agent(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+        ));
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            2050,
+            1984,
+        ));
+        assert_noop!(
+            PropertyManagement::sweep_undistributed_income(
+                RuntimeOrigin::signed([1; 32].into()),
+                0,
+                1984
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            PropertyManagement::sweep_undistributed_income(RuntimeOrigin::root(), 0, 1337),
+            Error::<Test>::NoUndistributedIncome
+        );
+        assert_noop!(
+            PropertyManagement::sweep_undistributed_income(RuntimeOrigin::root(), 0, 1984),
+            Error::<Test>::NoUndistributedIncome
+        );
+        UndistributedIncome::<Test>::insert(0, 1984, 50);
+        assert_ok!(PropertyManagement::sweep_undistributed_income(
+            RuntimeOrigin::root(),
+            0,
+            1984
+        ));
+        System::assert_last_event(
+            crate::Event::UndistributedIncomeSwept {
+                asset_id: 0,
+                payment_asset: 1984,
+                amount: 50,
+            }
+            .into(),
+        );
+        assert_eq!(UndistributedIncome::<Test>::get(0, 1984), 0);
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 50);
+        assert_noop!(
+            PropertyManagement::sweep_undistributed_income(RuntimeOrigin::root(), 0, 1984),
+            Error::<Test>::NoUndistributedIncome
+        );
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1950,
+            1984,
+        ));
+        assert_eq!(IncomePerToken::<Test>::get(0, 1984), 40 * INCOME_PRECISION);
+        assert_eq!(UndistributedIncome::<Test>::get(0, 1984), 0);
+    });
+}
+
+#[test]
+fn distribute_income_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [6; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([6; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            10_000,
+            100,
+            bvec![22, 22],
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        assert_noop!(
+            PropertyManagement::distribute_income(
+                RuntimeOrigin::signed([5; 32].into()),
+                0,
+                200,
+                1984
+            ),
+            Error::<Test>::NoLettingAgentFound
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            0
+        );
+        assert_ok!(PropertyManagement::add_letting_agent(
+            RuntimeOrigin::signed([4; 32].into()),
+            3,
+            bvec![10, 10],
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        lawyer_process_helper(
+            [0; 32].into(),
+            0,
+            vec![([1; 32].into(), 40), ([30; 32].into(), 30)],
+        );
+        assert_ok!(PropertyManagement::letting_agent_propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyManagement::finalize_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_noop!(
+            PropertyManagement::distribute_income(
+                RuntimeOrigin::signed([5; 32].into()),
+                0,
+                200,
+                1984
+            ),
+            Error::<Test>::NoPermission
+        );
+        assert_noop!(
+            PropertyManagement::distribute_income(
+                RuntimeOrigin::signed([4; 32].into()),
+                0,
+                20000,
+                1984
+            ),
+            Error::<Test>::NotEnoughFunds
+        );
+        assert_noop!(
+            PropertyManagement::distribute_income(
+                RuntimeOrigin::signed([4; 32].into()),
+                0,
+                2000,
+                1
+            ),
+            Error::<Test>::PaymentAssetNotSupported
+        );
+    });
+}
+
+#[test]
+fn claim_income_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [6; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([6; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            9_000,
+            100,
+            bvec![22, 22],
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + LawyerVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(PropertyManagement::add_letting_agent(
+            RuntimeOrigin::signed([4; 32].into()),
+            3,
+            bvec![10, 10],
+        ));
+        assert_ok!(PropertyManagement::letting_agent_propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyManagement::finalize_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            2200,
+            1984,
+        ));
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1000,
+            1337,
+        ));
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            880
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1337),
+            400
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[30; 32].into(), 0, 1984),
+            660
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[30; 32].into(), 0, 1337),
+            300
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[31; 32].into(), 0, 1984),
+            660
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[31; 32].into(), 0, 1337),
+            300
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 2800);
+        assert_eq!(Balances::free_balance(&([4; 32].into())), 4000);
+        assert_eq!(
+            Balances::free_balance(&PropertyManagement::property_account_id(0)),
+            5085
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, &PropertyManagement::property_account_id(0)),
+            2200
+        );
+        assert_eq!(
+            ForeignAssets::balance(1337, &PropertyManagement::property_account_id(0)),
+            1000
+        );
+        assert_ok!(PropertyManagement::claim_income(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            1337
+        ));
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            880
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, &PropertyManagement::property_account_id(0)),
+            2200
+        );
+        assert_eq!(
+            ForeignAssets::balance(1337, &PropertyManagement::property_account_id(0)),
+            600
+        );
+        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_125_600);
+        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 400);
+    });
+}
+
+#[test]
+fn income_is_settled_on_token_transfer() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [6; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([6; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            9_000,
+            100,
+            bvec![22, 22],
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + LawyerVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(PropertyManagement::add_letting_agent(
+            RuntimeOrigin::signed([4; 32].into()),
+            3,
+            bvec![10, 10],
+        ));
+        assert_ok!(PropertyManagement::letting_agent_propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyManagement::finalize_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            2200,
+            1984,
+        ));
+        assert_eq!(IncomePerToken::<Test>::get(0, 1984), 22 * INCOME_PRECISION);
+        assert_eq!(
+            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([31; 32].into(), 0, 1984)),
+            0
+        );
+        assert_ok!(Marketplace::send_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            [30; 32].into(),
+            10
+        ));
+        assert_eq!(
+            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([31; 32].into(), 0, 1984)),
+            660
+        );
+        assert_eq!(
+            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([30; 32].into(), 0, 1984)),
+            660
+        );
+        assert_eq!(
+            IncomeCheckpoint::<Test>::get::<(AccountId, u32, u32)>(([31; 32].into(), 0, 1984)),
+            22 * INCOME_PRECISION
+        );
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1000,
+            1984,
+        ));
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            1280
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[30; 32].into(), 0, 1984),
+            1060
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[31; 32].into(), 0, 1984),
+            860
+        );
+        assert_ok!(PropertyManagement::claim_income(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            1984
+        ));
+        assert_eq!(
+            PropertyManagement::claimable_income(&[31; 32].into(), 0, 1984),
+            0
+        );
+        assert_eq!(
+            ForeignAssets::balance(1984, PropertyManagement::property_account_id(0)),
+            2340
+        );
+    });
+}
+
+#[test]
+fn income_of_escrowed_ask_stays_with_seller() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [6; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([6; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            9_000,
+            100,
+            bvec![22, 22],
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            40,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            40
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            30
+        ));
+        let expiry = frame_system::Pallet::<Test>::block_number() + LawyerVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(PropertyManagement::add_letting_agent(
+            RuntimeOrigin::signed([4; 32].into()),
+            3,
+            bvec![10, 10],
+        ));
+        assert_ok!(PropertyManagement::letting_agent_propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            40
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            30
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyManagement::finalize_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::place_ask(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            1000,
+            10
+        ));
+        assert_eq!(LocalAssets::balance(0, AccountId::from([31; 32])), 20);
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            2200,
+            1984,
+        ));
+        assert_eq!(
+            PropertyManagement::claimable_income(&[31; 32].into(), 0, 1984),
+            660
+        );
+        assert_ok!(Marketplace::place_bid(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            1000,
+            10,
+            1984
+        ));
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [31; 32].into()),
+            20
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[31; 32].into(), 0, 1984),
+            660
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            880
+        );
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            2200,
+            1984,
+        ));
+        assert_eq!(
+            PropertyManagement::claimable_income(&[31; 32].into(), 0, 1984),
+            1100
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            1980
+        );
+        assert_ok!(Marketplace::place_ask(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            1000,
+            5
+        ));
+        assert_ok!(Marketplace::cancel_order(
+            RuntimeOrigin::signed([31; 32].into()),
+            2
+        ));
+        assert_eq!(LocalAssets::balance(0, AccountId::from([31; 32])), 20);
+        assert_eq!(
+            PropertyManagement::claimable_income(&[31; 32].into(), 0, 1984),
+            1100
+        );
+    });
+}
+
+#[test]
+fn claim_income_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [6; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([6; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [30; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [31; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            900,
+            1000,
+            bvec![22, 22],
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            400,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            300,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0,
+            300,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([30; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([31; 32].into()),
+            0
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            400
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            300
+        ));
+        run_to_block(91);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_eq!(LocalAssets::total_supply(0), 1000);
+        assert_ok!(PropertyManagement::add_letting_agent(
+            RuntimeOrigin::signed([4; 32].into()),
+            3,
+            bvec![10, 10],
+        ));
+        assert_ok!(PropertyManagement::letting_agent_propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            400
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([30; 32].into()),
+            0,
+            crate::Vote::Yes,
+            300
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyManagement::finalize_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            3200,
+            1984,
+        ));
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            1280
+        );
+        assert_noop!(
+            PropertyManagement::claim_income(RuntimeOrigin::signed([2; 32].into()), 0, 1984),
+            Error::<Test>::UserHasNoFundsStored
+        );
+        assert_noop!(
+            PropertyManagement::claim_income(RuntimeOrigin::signed([1; 32].into()), 0, 1),
+            Error::<Test>::PaymentAssetNotSupported
+        );
+    });
+}
+
+#[test]
+fn ledger_distributes_net_income() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [6; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([6; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [3; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            9_000,
+            100,
+            bvec![22, 22],
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            25,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            45,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([3; 32].into()),
+            0
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            20,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            40,
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        assert_ok!(PropertyManagement::add_letting_agent(
+            RuntimeOrigin::signed([4; 32].into()),
+            3,
+            bvec![10, 10],
+        ));
+        assert_ok!(PropertyManagement::letting_agent_propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            25
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            crate::Vote::Yes,
+            45
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyManagement::finalize_letting_agent(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+        ));
+        assert_ok!(PropertyManagement::record_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+            1984,
+            4000,
+        ));
+        assert_ok!(PropertyManagement::record_expense(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+            1984,
+            ExpenseCategory::Maintenance,
+            500,
+        ));
+        assert_ok!(PropertyManagement::record_expense(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+            1984,
+            ExpenseCategory::Insurance,
+            200,
+        ));
+        assert_ok!(PropertyManagement::record_expense(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+            1984,
+            ExpenseCategory::Taxes,
+            300,
+        ));
+        assert_ok!(PropertyManagement::record_expense(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+            1984,
+            ExpenseCategory::AgentFee,
+            200,
+        ));
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 5000);
+        assert_ok!(PropertyManagement::close_ledger_period(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+        ));
+        System::assert_last_event(
+            crate::Event::LedgerPeriodClosed {
+                asset_id: 0,
+                period: 1,
+                net_income: 2800,
+            }
+            .into(),
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            700
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[2; 32].into(), 0, 1984),
+            840
+        );
+        assert_eq!(
+            PropertyManagement::claimable_income(&[3; 32].into(), 0, 1984),
+            1260
+        );
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 2200);
+        assert_eq!(
+            PropertyManagement::property_statement(0, 1),
+            Some(PropertyStatement {
+                period: 1,
+                payment_asset: 1984,
+                income: 4000,
+                maintenance: 500,
+                insurance: 200,
+                taxes: 300,
+                agent_fee: 200,
+                total_expenses: 1200,
+                net_income: 2800,
+                closed: true,
+            })
+        );
+        assert_ok!(PropertyManagement::record_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            0,
+            1337,
+            1000,
+        ));
+        let statements = PropertyManagement::property_statements(0, None, 10);
+        assert_eq!(statements.len(), 2);
+        assert_eq!(statements[0].period, 0);
+        assert_eq!(statements[1].period, 1);
+        assert_eq!(
+            PropertyManagement::property_statements(0, Some(0), 10).len(),
+            1
+        );
+        assert!(PropertyManagement::property_statement(0, 2).is_none());
+    });
+}
+
+#[test]
+fn close_ledger_period_without_net_income() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [6; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([6; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [3; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(
+            RuntimeOrigin::signed([0; 32].into()),
+            3,
+            bvec![10, 10],
+            9_000,
+            100,
+            bvec![22, 22],
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            25,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            45,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([3; 32].into()),
+            0
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            20,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            40,
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        assert_ok!(PropertyManagement::add_letting_agent(
+            RuntimeOrigin::signed([4; 32].into()),
+            3,
+            bvec![10, 10],
+        ));
+        assert_ok!(PropertyManagement::letting_agent_propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            25
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            crate::Vote::Yes,
+            45
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyManagement::finalize_letting_agent(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+        ));
+        assert_ok!(PropertyManagement::record_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+            1984,
+            1000,
+        ));
+        assert_ok!(PropertyManagement::record_expense(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+            1984,
+            ExpenseCategory::Maintenance,
+            1500,
+        ));
+        assert_ok!(PropertyManagement::close_ledger_period(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+        ));
+        assert_eq!(
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            0
+        );
+        assert_eq!(ForeignAssets::balance(1984, AccountId::from([4; 32])), 5000);
+        assert_eq!(
+            PropertyLedger::<Test>::get(0, 1).map(|ledger| ledger.closed),
+            Some(true)
+        );
+    });
+}
+
+#[test]
+fn ledger_entries_fail() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [8; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [6; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        new_region_helper();
+        assert_ok!(Regions::create_new_location(
+            RuntimeOrigin::signed([6; 32].into()),
+            3,
+            bvec![10, 10]
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateDeveloper
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [3; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [10; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [11; 32].into(),
+            pallet_xcavate_whitelist::Role::Lawyer
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([10; 32].into()),
+            3,
+        ));
+        assert_ok!(Regions::register_lawyer(
+            RuntimeOrigin::signed([11; 32].into()),
+            3,
+        ));
+        assert_ok!(Marketplace::list_property(RuntimeOrigin::signed([0; 32].into()), 3, bvec![10, 10], 9_000, 100, bvec![22, 22], SaleTerms { tax_paid_by_developer: false, sale_mode: SaleMode::FixedPrice, soft_cap: None }));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            25,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0,
+            30,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::buy_property_token(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            45,
+            1984,
+            None
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([1; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([2; 32].into()),
+            0
+        ));
+        assert_ok!(Marketplace::claim_property_token(
+            RuntimeOrigin::signed([3; 32].into()),
+            0
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            LegalProperty::RealEstateDeveloperSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::approve_developer_lawyer(
+            RuntimeOrigin::signed([0; 32].into()),
+            0,
+            true
+        ));
+        assert_ok!(Marketplace::lawyer_claim_property(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            LegalProperty::SpvSide,
+            4_000,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            20,
+        ));
+        assert_ok!(Marketplace::vote_on_spv_lawyer(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            pallet_marketplace::types::Vote::Yes,
+            40,
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(Marketplace::finalize_spv_lawyer(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([10; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(Marketplace::lawyer_confirm_documents(
+            RuntimeOrigin::signed([11; 32].into()),
+            0,
+            true,
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
+        assert_ok!(PropertyManagement::add_letting_agent(
+            RuntimeOrigin::signed([4; 32].into()),
+            3,
+            bvec![10, 10],
+        ));
+        assert_ok!(PropertyManagement::letting_agent_propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([1; 32].into()),
+            0,
+            crate::Vote::Yes,
+            25
+        ));
+        assert_ok!(PropertyManagement::vote_on_letting_agent(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+            crate::Vote::Yes,
+            45
+        ));
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
+        assert_ok!(PropertyManagement::finalize_letting_agent(
+            RuntimeOrigin::signed([3; 32].into()),
+            0,
+        ));
         assert_noop!(
-            PropertyManagement::distribute_income(
-                RuntimeOrigin::signed([5; 32].into()),
+            PropertyManagement::record_income(
+                RuntimeOrigin::signed([4; 32].into()),
+                1,
+                1,
+                1984,
+                1000,
+            ),
+            Error::<Test>::NoLettingAgentFound
+        );
+        assert_noop!(
+            PropertyManagement::record_income(
+                RuntimeOrigin::signed([4; 32].into()),
+                0,
+                1,
+                1984,
                 0,
-                200,
-                1984
             ),
-            Error::<Test>::NoPermission
+            Error::<Test>::ZeroLedgerAmount
+        );
+        assert_noop!(
+            PropertyManagement::record_income(
+                RuntimeOrigin::signed([4; 32].into()),
+                0,
+                1,
+                1,
+                1000,
+            ),
+            Error::<Test>::PaymentAssetNotSupported
+        );
+        assert_noop!(
+            PropertyManagement::close_ledger_period(RuntimeOrigin::signed([4; 32].into()), 0, 1),
+            Error::<Test>::LedgerPeriodNotFound
         );
+        assert_ok!(PropertyManagement::record_income(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+            1984,
+            1000,
+        ));
         assert_noop!(
-            PropertyManagement::distribute_income(
+            PropertyManagement::record_expense(
                 RuntimeOrigin::signed([4; 32].into()),
                 0,
-                20000,
-                1984
+                1,
+                1337,
+                ExpenseCategory::Taxes,
+                100,
             ),
-            Error::<Test>::NotEnoughFunds
+            Error::<Test>::LedgerPaymentAssetMismatch
         );
+        assert_ok!(PropertyManagement::close_ledger_period(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+        ));
         assert_noop!(
-            PropertyManagement::distribute_income(
+            PropertyManagement::record_expense(
                 RuntimeOrigin::signed([4; 32].into()),
                 0,
-                2000,
-                1
+                1,
+                1984,
+                ExpenseCategory::Taxes,
+                100,
             ),
-            Error::<Test>::PaymentAssetNotSupported
+            Error::<Test>::LedgerPeriodClosed
+        );
+        assert_noop!(
+            PropertyManagement::close_ledger_period(RuntimeOrigin::signed([4; 32].into()), 0, 1),
+            Error::<Test>::LedgerPeriodClosed
         );
     });
 }
 
 #[test]
-fn claim_income_works() {
+fn property_terms_are_applied() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -2859,19 +5265,14 @@ fn claim_income_works() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
+            [2; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
+            [3; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [4; 32].into(),
-            pallet_xcavate_whitelist::Role::LettingAgent
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [10; 32].into(),
@@ -2897,38 +5298,54 @@ fn claim_income_works() {
             9_000,
             100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            40,
-            1984
+            25,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
             30,
-            1984
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([3; 32].into()),
             0,
-            30,
-            1984
+            45,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([3; 32].into()),
             0
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [5; 32].into(),
+            pallet_xcavate_whitelist::Role::SpvConfirmation
+        ));
+        assert_ok!(Marketplace::create_spv(
+            RuntimeOrigin::signed([5; 32].into()),
+            0,
+        ));
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([10; 32].into()),
             0,
@@ -2940,15 +5357,6 @@ fn claim_income_works() {
             0,
             true
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [5; 32].into(),
-            pallet_xcavate_whitelist::Role::SpvConfirmation
-        ));
-        assert_ok!(Marketplace::create_spv(
-            RuntimeOrigin::signed([5; 32].into()),
-            0,
-        ));
         assert_ok!(Marketplace::lawyer_claim_property(
             RuntimeOrigin::signed([11; 32].into()),
             0,
@@ -2959,15 +5367,16 @@ fn claim_income_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             pallet_marketplace::types::Vote::Yes,
-            40
+            20,
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([3; 32].into()),
             0,
             pallet_marketplace::types::Vote::Yes,
-            30
+            40,
         ));
-        let expiry = frame_system::Pallet::<Test>::block_number() + LawyerVotingDuration::get();
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
         assert_ok!(Marketplace::finalize_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
@@ -2983,6 +5392,11 @@ fn claim_income_works() {
             0,
             true,
         ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
         assert_ok!(PropertyManagement::add_letting_agent(
             RuntimeOrigin::signed([4; 32].into()),
             3,
@@ -2996,95 +5410,100 @@ fn claim_income_works() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             crate::Vote::Yes,
-            40
+            25
         ));
         assert_ok!(PropertyManagement::vote_on_letting_agent(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([3; 32].into()),
             0,
             crate::Vote::Yes,
-            30
+            45
         ));
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
         assert_ok!(PropertyManagement::finalize_letting_agent(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([3; 32].into()),
             0,
         ));
-        assert_ok!(PropertyManagement::distribute_income(
+        assert_ok!(PropertyManagement::record_income(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            2200,
+            1,
             1984,
+            1000,
         ));
-        assert_ok!(PropertyManagement::distribute_income(
+        assert_ok!(PropertyManagement::record_expense(
             RuntimeOrigin::signed([4; 32].into()),
             0,
-            1000,
-            1337,
-        ));
-        assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([1; 32].into(), 0, 1984)),
-            880
-        );
-        assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([1; 32].into(), 0, 1337)),
-            400
-        );
-        assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([30; 32].into(), 0, 1984)),
-            660
-        );
-        assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([30; 32].into(), 0, 1337)),
-            300
-        );
-        assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([31; 32].into(), 0, 1984)),
-            660
-        );
-        assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([31; 32].into(), 0, 1337)),
-            300
-        );
-        assert_eq!(ForeignAssets::balance(1984, &[4; 32].into()), 2800);
-        assert_eq!(Balances::free_balance(&([4; 32].into())), 4000);
-        assert_eq!(
-            Balances::free_balance(&PropertyManagement::property_account_id(0)),
-            5085
+            1,
+            1984,
+            ExpenseCategory::AgentFee,
+            150,
+        ));
+        PropertyManagement::update_property_terms(0, |terms| {
+            terms.letting_agent_fee = Some(Percent::from_percent(20));
+            terms.reserve_share = Some(Percent::from_percent(25));
+        });
+        System::assert_last_event(
+            crate::Event::PropertyTermsUpdated {
+                asset_id: 0,
+                terms: PropertyTerms {
+                    rent: None,
+                    letting_agent_fee: Some(Percent::from_percent(20)),
+                    reserve_share: Some(Percent::from_percent(25)),
+                    financing: None,
+                },
+            }
+            .into(),
         );
-        assert_eq!(
-            ForeignAssets::balance(1984, &PropertyManagement::property_account_id(0)),
-            2200
+        assert_noop!(
+            PropertyManagement::record_expense(
+                RuntimeOrigin::signed([4; 32].into()),
+                0,
+                1,
+                1984,
+                ExpenseCategory::AgentFee,
+                51,
+            ),
+            Error::<Test>::AgentFeeExceeded
         );
+        assert_ok!(PropertyManagement::record_expense(
+            RuntimeOrigin::signed([4; 32].into()),
+            0,
+            1,
+            1984,
+            ExpenseCategory::AgentFee,
+            50,
+        ));
+        assert_eq!(PropertyLedger::<Test>::get(0, 1).unwrap().agent_fee, 200);
         assert_eq!(
-            ForeignAssets::balance(1337, &PropertyManagement::property_account_id(0)),
-            1000
+            PropertyManagement::reserve_share(0),
+            Percent::from_percent(25)
         );
-        assert_ok!(PropertyManagement::claim_income(
-            RuntimeOrigin::signed([1; 32].into()),
+        assert_ok!(PropertyManagement::distribute_income(
+            RuntimeOrigin::signed([4; 32].into()),
             0,
-            1337
+            3200,
+            1984,
         ));
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 800);
         assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([1; 32].into(), 0, 1984)),
-            880
+            PropertyManagement::claimable_income(&[1; 32].into(), 0, 1984),
+            600
         );
         assert_eq!(
-            ForeignAssets::balance(1984, &PropertyManagement::property_account_id(0)),
-            2200
+            PropertyManagement::claimable_income(&[2; 32].into(), 0, 1984),
+            720
         );
         assert_eq!(
-            ForeignAssets::balance(1337, &PropertyManagement::property_account_id(0)),
-            600
+            PropertyManagement::claimable_income(&[3; 32].into(), 0, 1984),
+            1080
         );
-        assert_eq!(ForeignAssets::balance(1984, &[1; 32].into()), 1_125_600);
-        assert_eq!(ForeignAssets::balance(1337, &[1; 32].into()), 400);
     });
 }
 
 #[test]
-fn claim_income_fails() {
+fn property_governance_calls_work() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         assert_ok!(XcavateWhitelist::add_admin(
@@ -3117,16 +5536,6 @@ fn claim_income_fails() {
             [1; 32].into(),
             pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [30; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
-        assert_ok!(XcavateWhitelist::assign_role(
-            RuntimeOrigin::signed([20; 32].into()),
-            [31; 32].into(),
-            pallet_xcavate_whitelist::Role::RealEstateInvestor
-        ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
             [2; 32].into(),
@@ -3134,8 +5543,8 @@ fn claim_income_fails() {
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
-            [4; 32].into(),
-            pallet_xcavate_whitelist::Role::LettingAgent
+            [3; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
         ));
         assert_ok!(XcavateWhitelist::assign_role(
             RuntimeOrigin::signed([20; 32].into()),
@@ -3159,39 +5568,46 @@ fn claim_income_fails() {
             RuntimeOrigin::signed([0; 32].into()),
             3,
             bvec![10, 10],
-            900,
-            1000,
+            9_000,
+            100,
             bvec![22, 22],
-            false
+            SaleTerms {
+                tax_paid_by_developer: false,
+                sale_mode: SaleMode::FixedPrice,
+                soft_cap: None
+            }
         ));
         assert_ok!(Marketplace::buy_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0,
-            400,
-            1984
+            25,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0,
-            300,
-            1984
+            30,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::buy_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([3; 32].into()),
             0,
-            300,
-            1984
+            45,
+            1984,
+            None
         ));
         assert_ok!(Marketplace::claim_property_token(
             RuntimeOrigin::signed([1; 32].into()),
             0
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([2; 32].into()),
             0
         ));
         assert_ok!(Marketplace::claim_property_token(
-            RuntimeOrigin::signed([31; 32].into()),
+            RuntimeOrigin::signed([3; 32].into()),
             0
         ));
         assert_ok!(XcavateWhitelist::assign_role(
@@ -3224,15 +5640,17 @@ fn claim_income_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             pallet_marketplace::types::Vote::Yes,
-            400
+            20,
         ));
         assert_ok!(Marketplace::vote_on_spv_lawyer(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([3; 32].into()),
             0,
             pallet_marketplace::types::Vote::Yes,
-            300
+            40,
         ));
-        run_to_block(91);
+        let expiry =
+            frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
+        run_to_block(expiry);
         assert_ok!(Marketplace::finalize_spv_lawyer(
             RuntimeOrigin::signed([1; 32].into()),
             0,
@@ -3247,7 +5665,11 @@ fn claim_income_fails() {
             0,
             true,
         ));
-        assert_eq!(LocalAssets::total_supply(0), 1000);
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [4; 32].into(),
+            pallet_xcavate_whitelist::Role::LettingAgent
+        ));
         assert_ok!(PropertyManagement::add_letting_agent(
             RuntimeOrigin::signed([4; 32].into()),
             3,
@@ -3261,38 +5683,83 @@ fn claim_income_fails() {
             RuntimeOrigin::signed([1; 32].into()),
             0,
             crate::Vote::Yes,
-            400
+            25
         ));
         assert_ok!(PropertyManagement::vote_on_letting_agent(
-            RuntimeOrigin::signed([30; 32].into()),
+            RuntimeOrigin::signed([3; 32].into()),
             0,
             crate::Vote::Yes,
-            300
+            45
         ));
         let expiry =
             frame_system::Pallet::<Test>::block_number() + LettingAgentVotingDuration::get();
         run_to_block(expiry);
         assert_ok!(PropertyManagement::finalize_letting_agent(
-            RuntimeOrigin::signed([1; 32].into()),
+            RuntimeOrigin::signed([3; 32].into()),
             0,
         ));
+        PropertyManagement::update_property_terms(0, |terms| {
+            terms.reserve_share = Some(Percent::from_percent(25));
+        });
         assert_ok!(PropertyManagement::distribute_income(
             RuntimeOrigin::signed([4; 32].into()),
             0,
             3200,
             1984,
         ));
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 800);
+        assert_noop!(
+            PropertyManagement::withdraw_from_reserve(0, 1984, &[7; 32].into(), 900),
+            Error::<Test>::InsufficientReserve
+        );
+        let balance = ForeignAssets::balance(1984, AccountId::from([7; 32]));
+        assert_ok!(PropertyManagement::withdraw_from_reserve(
+            0,
+            1984,
+            &[7; 32].into(),
+            500
+        ));
+        System::assert_last_event(
+            crate::Event::ReserveWithdrawn {
+                asset_id: 0,
+                payment_asset: 1984,
+                beneficiary: [7; 32].into(),
+                amount: 500,
+            }
+            .into(),
+        );
+        assert_eq!(PropertyReserve::<Test>::get(0, 1984), 300);
         assert_eq!(
-            InvestorFunds::<Test>::get::<(AccountId, u32, u32)>(([1; 32].into(), 0, 1984)),
-            1280
+            ForeignAssets::balance(1984, AccountId::from([7; 32])),
+            balance + 500
         );
         assert_noop!(
-            PropertyManagement::claim_income(RuntimeOrigin::signed([2; 32].into()), 0, 1984),
-            Error::<Test>::UserHasNoFundsStored
+            PropertyManagement::dismiss_letting_agent(RuntimeOrigin::signed([1; 32].into()), 0),
+            BadOrigin
         );
+        assert_ok!(PropertyManagement::dismiss_letting_agent(
+            RuntimeOrigin::root(),
+            0
+        ));
+        System::assert_last_event(
+            crate::Event::LettingAgentDismissed {
+                asset_id: 0,
+                letting_agent: [4; 32].into(),
+            }
+            .into(),
+        );
+        assert_eq!(LettingStorage::<Test>::get(0), None);
+        let location: BoundedVec<u8, Postcode> = bvec![10, 10];
+        let letting_info = LettingInfo::<Test>::get::<AccountId>([4; 32].into()).unwrap();
+        let location_info = letting_info.locations.get(&location).unwrap();
+        assert_eq!(location_info.assigned_properties, 0);
         assert_noop!(
-            PropertyManagement::claim_income(RuntimeOrigin::signed([1; 32].into()), 0, 1),
-            Error::<Test>::PaymentAssetNotSupported
+            PropertyManagement::dismiss_letting_agent(RuntimeOrigin::root(), 0),
+            Error::<Test>::NoLettingAgentFound
         );
+        assert_ok!(PropertyManagement::letting_agent_propose(
+            RuntimeOrigin::signed([4; 32].into()),
+            0
+        ));
     });
 }
@@ -39,6 +39,11 @@ pub trait WeightInfo {
 	fn unfreeze_letting_voting_token() -> Weight;
 	fn distribute_income() -> Weight;
 	fn claim_income() -> Weight;
+	fn sweep_undistributed_income() -> Weight;
+	fn record_income() -> Weight;
+	fn record_expense() -> Weight;
+	fn close_ledger_period() -> Weight;
+	fn dismiss_letting_agent() -> Weight;
 }
 
 /// Weight functions for `pallet_property_management`.
@@ -186,8 +191,6 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
 	/// Storage: `PropertyManagement::LettingStorage` (r:1 w:0)
 	/// Proof: `PropertyManagement::LettingStorage` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-	/// Storage: `RealEstateAsset::PropertyOwner` (r:1 w:0)
-	/// Proof: `RealEstateAsset::PropertyOwner` (`max_values`: None, `max_size`: Some(8022), added: 10497, mode: `MaxEncodedLen`)
 	/// Storage: `RealEstateAsset::PropertyAssetInfo` (r:1 w:0)
 	/// Proof: `RealEstateAsset::PropertyAssetInfo` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -198,22 +201,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `RealEstateAsset::PropertyOwnerToken` (r:250 w:0)
-	/// Proof: `RealEstateAsset::PropertyOwnerToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
-	/// Storage: `PropertyManagement::InvestorFunds` (r:250 w:250)
-	/// Proof: `PropertyManagement::InvestorFunds` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::PropertyReserve` (r:1 w:1)
+	/// Proof: `PropertyManagement::PropertyReserve` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::UndistributedIncome` (r:1 w:1)
+	/// Proof: `PropertyManagement::UndistributedIncome` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::IncomePerToken` (r:1 w:1)
+	/// Proof: `PropertyManagement::IncomePerToken` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::PropertyTermsStorage` (r:1 w:0)
+	/// Proof: `PropertyManagement::PropertyTermsStorage` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
 	fn distribute_income() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `25977`
-		//  Estimated: `645740`
-		// Minimum execution time: 3_468_232_000 picoseconds.
-		Weight::from_parts(3_845_561_000, 0)
-			.saturating_add(Weight::from_parts(0, 645740))
-			.saturating_add(T::DbWeight::get().reads(509))
-			.saturating_add(T::DbWeight::get().writes(254))
+		//  Measured:  `1563`
+		//  Estimated: `6208`
+		// Minimum execution time: 98_412_000 picoseconds.
+		Weight::from_parts(107_215_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
 	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::IncomePerToken` (r:1 w:0)
+	/// Proof: `PropertyManagement::IncomePerToken` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::IncomeCheckpoint` (r:1 w:1)
+	/// Proof: `PropertyManagement::IncomeCheckpoint` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyOwnerToken` (r:1 w:0)
+	/// Proof: `RealEstateAsset::PropertyOwnerToken` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	/// Storage: `PropertyManagement::InvestorFunds` (r:1 w:1)
 	/// Proof: `PropertyManagement::InvestorFunds` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -227,9 +240,107 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Measured:  `4044`
 		//  Estimated: `6208`
 		// Minimum execution time: 174_392_000 picoseconds.
-		Weight::from_parts(220_350_000, 0)
+		Weight::from_parts(231_904_000, 0)
 			.saturating_add(Weight::from_parts(0, 6208))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `PropertyManagement::UndistributedIncome` (r:1 w:1)
+	/// Proof: `PropertyManagement::UndistributedIncome` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::PropertyReserve` (r:1 w:1)
+	/// Proof: `PropertyManagement::PropertyReserve` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn sweep_undistributed_income() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212`
+		//  Estimated: `3521`
+		// Minimum execution time: 12_874_000 picoseconds.
+		Weight::from_parts(13_402_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::LettingStorage` (r:1 w:0)
+	/// Proof: `PropertyManagement::LettingStorage` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::PropertyLedger` (r:1 w:1)
+	/// Proof: `PropertyManagement::PropertyLedger` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	fn record_income() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `462`
+		//  Estimated: `3587`
+		// Minimum execution time: 21_305_000 picoseconds.
+		Weight::from_parts(22_714_000, 0)
+			.saturating_add(Weight::from_parts(0, 3587))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::LettingStorage` (r:1 w:0)
+	/// Proof: `PropertyManagement::LettingStorage` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::PropertyLedger` (r:1 w:1)
+	/// Proof: `PropertyManagement::PropertyLedger` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::PropertyTermsStorage` (r:1 w:0)
+	/// Proof: `PropertyManagement::PropertyTermsStorage` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	fn record_expense() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `541`
+		//  Estimated: `3587`
+		// Minimum execution time: 21_847_000 picoseconds.
+		Weight::from_parts(23_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 3587))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::LettingStorage` (r:1 w:0)
+	/// Proof: `PropertyManagement::LettingStorage` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::PropertyLedger` (r:1 w:1)
+	/// Proof: `PropertyManagement::PropertyLedger` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyAssetInfo` (r:1 w:0)
+	/// Proof: `RealEstateAsset::PropertyAssetInfo` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::BalancesOnHold` (r:1 w:0)
+	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::PropertyReserve` (r:1 w:1)
+	/// Proof: `PropertyManagement::PropertyReserve` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::UndistributedIncome` (r:1 w:1)
+	/// Proof: `PropertyManagement::UndistributedIncome` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::IncomePerToken` (r:1 w:1)
+	/// Proof: `PropertyManagement::IncomePerToken` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::PropertyTermsStorage` (r:1 w:0)
+	/// Proof: `PropertyManagement::PropertyTermsStorage` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	fn close_ledger_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1702`
+		//  Estimated: `6208`
+		// Minimum execution time: 104_632_000 picoseconds.
+		Weight::from_parts(112_518_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `PropertyManagement::LettingStorage` (r:1 w:1)
+	/// Proof: `PropertyManagement::LettingStorage` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `RealEstateAsset::PropertyAssetInfo` (r:1 w:0)
+	/// Proof: `RealEstateAsset::PropertyAssetInfo` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `PropertyManagement::LettingInfo` (r:1 w:1)
+	/// Proof: `PropertyManagement::LettingInfo` (`max_values`: None, `max_size`: Some(6603), added: 9078, mode: `MaxEncodedLen`)
+	fn dismiss_letting_agent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `876`
+		//  Estimated: `10068`
+		// Minimum execution time: 24_118_000 picoseconds.
+		Weight::from_parts(25_361_000, 0)
+			.saturating_add(Weight::from_parts(0, 10068))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 }
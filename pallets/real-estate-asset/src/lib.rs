@@ -31,6 +31,10 @@ use pallet_nfts::{CollectionConfig, ItemConfig, ItemSettings};
 
 use pallet_regions::LocationId;
 
+use pallet_xcavate_whitelist::{Role, RolePermission};
+
+use traits::OnPropertyTokenBalanceChange;
+
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
 pub type LocalAssetIdOf<T> = <<T as Config>::LocalCurrency as fungibles::Inspect<
@@ -138,6 +142,9 @@ pub mod pallet {
         /// The maximum amount of token of a property.
         #[pallet::constant]
         type MaxPropertyToken: Get<u32>;
+
+        /// Handler that is called before the property token balance of an account changes.
+        type OnBalanceChange: OnPropertyTokenBalanceChange<Self::AccountId>;
     }
 
     pub type FractionalizedAssetId<T> = <T as Config>::AssetId;
@@ -236,6 +243,10 @@ pub mod pallet {
         SpvNotCreated,
         /// The property has not been finalized yet.
         PropertyNotFinalized,
+        /// The sender is not a compliant investor.
+        SenderNotCompliant,
+        /// The receiver is not a compliant investor.
+        ReceiverNotCompliant,
     }
 
     impl<T: Config> Pallet<T> {
@@ -383,6 +394,9 @@ pub mod pallet {
             receiver: &AccountIdOf<T>,
             token_amount: u32,
         ) -> DispatchResult {
+            Self::ensure_transfer_compliant(sender, receiver)?;
+            T::OnBalanceChange::before_balance_change(asset_id, sender)?;
+            T::OnBalanceChange::before_balance_change(asset_id, receiver)?;
             let sender_balance = PropertyOwnerToken::<T>::get(asset_id, sender);
             let updated_sender_balance = sender_balance
                 .checked_sub(token_amount)
@@ -434,11 +448,34 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Property token can only be transferred between compliant investors.
+        pub(crate) fn ensure_transfer_compliant(
+            sender: &AccountIdOf<T>,
+            receiver: &AccountIdOf<T>,
+        ) -> DispatchResult {
+            ensure!(
+                pallet_xcavate_whitelist::Pallet::<T>::is_compliant(
+                    sender,
+                    Role::RealEstateInvestor
+                ),
+                Error::<T>::SenderNotCompliant
+            );
+            ensure!(
+                pallet_xcavate_whitelist::Pallet::<T>::is_compliant(
+                    receiver,
+                    Role::RealEstateInvestor
+                ),
+                Error::<T>::ReceiverNotCompliant
+            );
+            Ok(())
+        }
+
         pub(crate) fn do_distribute_property_token_to_owner(
             asset_id: u32,
             investor: &AccountIdOf<T>,
             token_amount: u32,
         ) -> DispatchResult {
+            T::OnBalanceChange::before_balance_change(asset_id, investor)?;
             let property_account = Self::property_account_id(asset_id);
 
             <T as pallet::Config>::LocalCurrency::transfer(
@@ -463,14 +500,108 @@ pub mod pallet {
             Ok(())
         }
 
-        pub(crate) fn do_take_property_token(asset_id: u32, owner: &AccountIdOf<T>) -> u32 {
-            PropertyOwnerToken::<T>::take(asset_id, owner)
+        pub(crate) fn do_take_property_token(
+            asset_id: u32,
+            owner: &AccountIdOf<T>,
+        ) -> Result<u32, DispatchError> {
+            T::OnBalanceChange::before_balance_change(asset_id, owner)?;
+            Ok(PropertyOwnerToken::<T>::take(asset_id, owner))
+        }
+
+        pub(crate) fn do_redeem_property_token(
+            asset_id: u32,
+            owner: &AccountIdOf<T>,
+            token_amount: u32,
+        ) -> DispatchResult {
+            T::OnBalanceChange::before_balance_change(asset_id, owner)?;
+            let owner_balance = PropertyOwnerToken::<T>::get(asset_id, owner);
+            let updated_owner_balance = owner_balance
+                .checked_sub(token_amount)
+                .ok_or(Error::<T>::NotEnoughToken)?;
+
+            <T as pallet::Config>::LocalCurrency::transfer(
+                asset_id,
+                owner,
+                &Self::property_account_id(asset_id),
+                token_amount.into(),
+                Preservation::Expendable,
+            )
+            .map_err(|_| Error::<T>::NotEnoughToken)?;
+
+            if updated_owner_balance == 0 {
+                PropertyOwnerToken::<T>::remove(asset_id, owner);
+                PropertyOwner::<T>::try_mutate(asset_id, |owner_list| {
+                    let index = owner_list
+                        .iter()
+                        .position(|x| x == owner)
+                        .ok_or(Error::<T>::InvalidIndex)?;
+                    owner_list.swap_remove(index);
+                    Ok::<(), DispatchError>(())
+                })?;
+            } else {
+                PropertyOwnerToken::<T>::insert(asset_id, owner, updated_owner_balance);
+            }
+            // The redeemed token stay in the property account so that the property nft can still
+            // be unified, but they no longer count towards the supply of the property.
+            PropertyAssetInfo::<T>::try_mutate(asset_id, |maybe_asset_details| {
+                let asset_details = maybe_asset_details
+                    .as_mut()
+                    .ok_or(Error::<T>::PropertyAssetNotRegistered)?;
+                asset_details.token_amount = asset_details
+                    .token_amount
+                    .checked_sub(token_amount)
+                    .ok_or(Error::<T>::NotEnoughToken)?;
+                Ok::<(), DispatchError>(())
+            })?;
+            Ok(())
+        }
+
+        pub(crate) fn do_escrow_property_token(
+            asset_id: u32,
+            owner: &AccountIdOf<T>,
+            token_amount: u32,
+        ) -> DispatchResult {
+            ensure!(
+                pallet_xcavate_whitelist::Pallet::<T>::is_compliant(
+                    owner,
+                    Role::RealEstateInvestor
+                ),
+                Error::<T>::SenderNotCompliant
+            );
+            T::OnBalanceChange::before_balance_change(asset_id, owner)?;
+            // The escrowed token stay assigned to the owner, so that the owner keeps receiving
+            // the income of the token until they are transferred.
+            <T as pallet::Config>::LocalCurrency::transfer(
+                asset_id,
+                owner,
+                &Self::property_account_id(asset_id),
+                token_amount.into(),
+                Preservation::Expendable,
+            )?;
+            Ok(())
+        }
+
+        pub(crate) fn do_release_escrowed_property_token(
+            asset_id: u32,
+            owner: &AccountIdOf<T>,
+            token_amount: u32,
+        ) -> DispatchResult {
+            T::OnBalanceChange::before_balance_change(asset_id, owner)?;
+            <T as pallet::Config>::LocalCurrency::transfer(
+                asset_id,
+                &Self::property_account_id(asset_id),
+                owner,
+                token_amount.into(),
+                Preservation::Expendable,
+            )?;
+            Ok(())
         }
 
         pub(crate) fn do_remove_property_token_ownership(
             asset_id: u32,
             account: &AccountIdOf<T>,
         ) -> DispatchResult {
+            T::OnBalanceChange::before_balance_change(asset_id, account)?;
             PropertyOwnerToken::<T>::remove(asset_id, account);
             Ok(())
         }
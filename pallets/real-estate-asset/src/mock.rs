@@ -5,7 +5,9 @@ use frame_support::{
     traits::{AsEnsureOriginWithArg, EnsureOriginWithArg, OriginTrait},
 };
 use sp_runtime::{
-    traits::{AccountIdLookup, BlakeTwo256, ConstU128, ConstU32, IdentifyAccount, Verify},
+    traits::{
+        AccountIdLookup, BlakeTwo256, ConstU128, ConstU32, ConstU64, IdentifyAccount, Verify,
+    },
     BuildStorage, MultiSignature, Percent,
 };
 
@@ -201,6 +203,14 @@ impl pallet_xcavate_whitelist::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_xcavate_whitelist::weights::SubstrateWeight<Test>;
     type WhitelistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type RoleGrantPeriod = ConstU64<100_000>;
+    type ExpiryNoticePeriod = ConstU64<10>;
+    type MaxExpiriesPerBlock = ConstU32<100>;
+    type MaxJurisdictions = ConstU32<10>;
+    type PendingActionLifetime = ConstU64<20>;
+    type MaxApprovals = ConstU32<5>;
+    type MaxRoleAssignments = ConstU32<100>;
+    type MaxLinkedAccounts = ConstU32<5>;
 }
 
 use pallet_xcavate_whitelist::{self as whitelist, RolePermission};
@@ -300,6 +310,7 @@ impl crate::Config for Test {
     type AssetId = <Self as pallet_assets::Config<Instance1>>::AssetId;
     type PropertyAccountFundingAmount = ConstU128<100>;
     type MaxPropertyToken = MaxPropertyTokens;
+    type OnBalanceChange = ();
 }
 
 // Build genesis storage according to the mock runtime.
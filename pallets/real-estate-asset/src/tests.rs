@@ -265,6 +265,26 @@ fn transfer_property_token_works() {
             [20; 32].into(),
         ));
         new_region_helper();
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [0; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [3; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(RealEstateAsset::create_property_token(
             &[0; 32].into(),
             3,
@@ -342,6 +362,21 @@ fn transfer_property_token_fails() {
             [20; 32].into(),
         ));
         new_region_helper();
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [2; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
+        assert_ok!(XcavateWhitelist::assign_role(
+            RuntimeOrigin::signed([20; 32].into()),
+            [3; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor
+        ));
         assert_ok!(RealEstateAsset::create_property_token(
             &[0; 32].into(),
             3,
@@ -380,6 +415,42 @@ fn transfer_property_token_fails() {
             ),
             Error::<Test>::NotEnoughToken
         );
+        assert_noop!(
+            RealEstateAsset::transfer_property_token(
+                0,
+                &[2; 32].into(),
+                &[2; 32].into(),
+                &[4; 32].into(),
+                1
+            ),
+            Error::<Test>::ReceiverNotCompliant
+        );
+        assert_ok!(XcavateWhitelist::set_permission(
+            RuntimeOrigin::signed([20; 32].into()),
+            [1; 32].into(),
+            pallet_xcavate_whitelist::Role::RealEstateInvestor,
+            pallet_xcavate_whitelist::AccessPermission::Revoked,
+        ));
+        assert_noop!(
+            RealEstateAsset::transfer_property_token(
+                0,
+                &[1; 32].into(),
+                &[1; 32].into(),
+                &[3; 32].into(),
+                1
+            ),
+            Error::<Test>::SenderNotCompliant
+        );
+        assert_noop!(
+            RealEstateAsset::transfer_property_token(
+                0,
+                &[2; 32].into(),
+                &[2; 32].into(),
+                &[1; 32].into(),
+                1
+            ),
+            Error::<Test>::ReceiverNotCompliant
+        );
         assert_eq!(LocalAssets::balance(0, &[1; 32].into()), 4);
         assert_eq!(LocalAssets::balance(0, &[2; 32].into()), 6);
     })
@@ -410,7 +481,10 @@ fn take_property_token_works() {
             PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
             4
         );
-        assert_eq!(RealEstateAsset::take_property_token(0, &[1; 32].into()), 4);
+        assert_eq!(
+            RealEstateAsset::take_property_token(0, &[1; 32].into()),
+            Ok(4)
+        );
         assert_eq!(
             PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
             0
@@ -418,6 +492,56 @@ fn take_property_token_works() {
     })
 }
 
+#[test]
+fn redeem_property_token_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(XcavateWhitelist::add_admin(
+            RuntimeOrigin::root(),
+            [20; 32].into(),
+        ));
+        new_region_helper();
+        assert_ok!(RealEstateAsset::create_property_token(
+            &[0; 32].into(),
+            3,
+            bvec![10, 10],
+            10,
+            1_000,
+            bvec![22, 22]
+        ));
+        assert_ok!(RealEstateAsset::distribute_property_token_to_owner(
+            0,
+            &[1; 32].into(),
+            4
+        ));
+        let property_balance = LocalAssets::balance(0, RealEstateAsset::property_account_id(0));
+        assert_ok!(RealEstateAsset::redeem_property_token(
+            0,
+            &[1; 32].into(),
+            3
+        ));
+        assert_eq!(
+            PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
+            1
+        );
+        assert_eq!(LocalAssets::balance(0, AccountId::from([1; 32])), 1);
+        assert_eq!(
+            LocalAssets::balance(0, RealEstateAsset::property_account_id(0)),
+            property_balance + 3
+        );
+        assert_eq!(PropertyAssetInfo::<Test>::get(0).unwrap().token_amount, 7);
+        assert_noop!(
+            RealEstateAsset::redeem_property_token(0, &[1; 32].into(), 2),
+            Error::<Test>::NotEnoughToken
+        );
+        assert_ok!(RealEstateAsset::redeem_property_token(
+            0,
+            &[1; 32].into(),
+            1
+        ));
+        assert!(!PropertyOwner::<Test>::get(0).contains(&[1; 32].into()));
+    })
+}
+
 #[test]
 fn remove_token_ownership_works() {
     new_test_ext().execute_with(|| {
@@ -443,7 +567,10 @@ fn remove_token_ownership_works() {
             PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
             4
         );
-        assert_eq!(RealEstateAsset::take_property_token(0, &[1; 32].into()), 4);
+        assert_eq!(
+            RealEstateAsset::take_property_token(0, &[1; 32].into()),
+            Ok(4)
+        );
         assert_eq!(
             PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()),
             0
@@ -601,12 +728,18 @@ fn getter_function_works() {
             ])
             .unwrap()
         );
-        assert_eq!(RealEstateAsset::take_property_token(0, &[1; 32].into()), 4);
+        assert_eq!(
+            RealEstateAsset::take_property_token(0, &[1; 32].into()),
+            Ok(4)
+        );
         assert_eq!(RealEstateAsset::get_property_asset_info(1).is_none(), true);
         assert_eq!(
             PropertyOwner::<Test>::get(1),
             frame_support::BoundedVec::<_, MaxPropertyTokens>::try_from(vec![]).unwrap()
         );
-        assert_eq!(RealEstateAsset::take_property_token(1, &[3; 32].into()), 0);
+        assert_eq!(
+            RealEstateAsset::take_property_token(1, &[3; 32].into()),
+            Ok(0)
+        );
     })
 }
@@ -30,7 +30,29 @@ pub trait PropertyTokenOwnership<T: Config> {
         token_amount: u32,
     ) -> DispatchResult;
 
-    fn take_property_token(asset_id: u32, owner: &AccountIdOf<T>) -> u32;
+    fn take_property_token(asset_id: u32, owner: &AccountIdOf<T>) -> Result<u32, DispatchError>;
+
+    /// Moves token of an owner to the property account without assigning them to a new owner
+    /// and removes them from the token supply of the property.
+    fn redeem_property_token(
+        asset_id: u32,
+        owner: &AccountIdOf<T>,
+        token_amount: u32,
+    ) -> DispatchResult;
+
+    /// Moves token of an owner to the property account while they stay assigned to the owner.
+    fn escrow_property_token(
+        asset_id: u32,
+        owner: &AccountIdOf<T>,
+        token_amount: u32,
+    ) -> DispatchResult;
+
+    /// Returns escrowed token from the property account to their owner.
+    fn release_escrowed_property_token(
+        asset_id: u32,
+        owner: &AccountIdOf<T>,
+        token_amount: u32,
+    ) -> DispatchResult;
 
     fn remove_property_token_ownership(asset_id: u32, account: &AccountIdOf<T>) -> DispatchResult;
 
@@ -87,6 +109,17 @@ pub trait PropertyTokenInspect<T: Config> {
     fn get_token_balance(asset_id: u32, owner: &AccountIdOf<T>) -> u32;
 }
 
+/// Handler that is called before the property token balance of an account changes.
+pub trait OnPropertyTokenBalanceChange<AccountId> {
+    fn before_balance_change(asset_id: u32, account: &AccountId) -> DispatchResult;
+}
+
+impl<AccountId> OnPropertyTokenBalanceChange<AccountId> for () {
+    fn before_balance_change(_asset_id: u32, _account: &AccountId) -> DispatchResult {
+        Ok(())
+    }
+}
+
 impl<T: Config> PropertyTokenManage<T> for Pallet<T> {
     fn create_property_token(
         funding_account: &AccountIdOf<T>,
@@ -130,10 +163,34 @@ impl<T: Config> PropertyTokenOwnership<T> for Pallet<T> {
         Self::do_distribute_property_token_to_owner(asset_id, investor, token_amount)
     }
 
-    fn take_property_token(asset_id: u32, owner: &AccountIdOf<T>) -> u32 {
+    fn take_property_token(asset_id: u32, owner: &AccountIdOf<T>) -> Result<u32, DispatchError> {
         Self::do_take_property_token(asset_id, owner)
     }
 
+    fn redeem_property_token(
+        asset_id: u32,
+        owner: &AccountIdOf<T>,
+        token_amount: u32,
+    ) -> DispatchResult {
+        Self::do_redeem_property_token(asset_id, owner, token_amount)
+    }
+
+    fn escrow_property_token(
+        asset_id: u32,
+        owner: &AccountIdOf<T>,
+        token_amount: u32,
+    ) -> DispatchResult {
+        Self::do_escrow_property_token(asset_id, owner, token_amount)
+    }
+
+    fn release_escrowed_property_token(
+        asset_id: u32,
+        owner: &AccountIdOf<T>,
+        token_amount: u32,
+    ) -> DispatchResult {
+        Self::do_release_escrowed_property_token(asset_id, owner, token_amount)
+    }
+
     fn remove_property_token_ownership(asset_id: u32, account: &AccountIdOf<T>) -> DispatchResult {
         Self::do_remove_property_token_ownership(asset_id, account)
     }
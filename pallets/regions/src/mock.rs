@@ -2,7 +2,9 @@ use super::*;
 
 use frame_support::{derive_impl, parameter_types, traits::AsEnsureOriginWithArg};
 use sp_runtime::{
-    traits::{AccountIdLookup, BlakeTwo256, ConstU128, ConstU32, IdentifyAccount, Verify},
+    traits::{
+        AccountIdLookup, BlakeTwo256, ConstU128, ConstU32, ConstU64, IdentifyAccount, Verify,
+    },
     BuildStorage, MultiSignature,
 };
 
@@ -142,6 +144,14 @@ impl pallet_xcavate_whitelist::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_xcavate_whitelist::weights::SubstrateWeight<Test>;
     type WhitelistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type RoleGrantPeriod = ConstU64<100_000>;
+    type ExpiryNoticePeriod = ConstU64<10>;
+    type MaxExpiriesPerBlock = ConstU32<100>;
+    type MaxJurisdictions = ConstU32<10>;
+    type PendingActionLifetime = ConstU64<20>;
+    type MaxApprovals = ConstU32<5>;
+    type MaxRoleAssignments = ConstU32<100>;
+    type MaxLinkedAccounts = ConstU32<5>;
 }
 
 use pallet_xcavate_whitelist::{self as whitelist, RolePermission};
@@ -6,7 +6,12 @@ use super::*;
 use crate::Pallet as Whitelist;
 use frame_benchmarking::v2::*;
 use frame_support::assert_ok;
+use frame_support::traits::Get;
+use frame_support::BoundedVec;
 use frame_system::RawOrigin;
+use scale_info::prelude::vec;
+extern crate alloc;
+use alloc::vec::Vec;
 
 #[benchmarks]
 mod benchmarks {
@@ -47,6 +52,16 @@ mod benchmarks {
             RawOrigin::Root.into(),
             admin.clone()
         ));
+        // Assigns a role again after the previous assignment expired.
+        assert_ok!(Whitelist::<T>::assign_role(
+            RawOrigin::Signed(admin.clone()).into(),
+            user.clone(),
+            Role::LettingAgent
+        ));
+        let previous_expiry = RoleGrantExpiries::<T>::get(&user, Role::LettingAgent).unwrap();
+        frame_system::Pallet::<T>::set_block_number(previous_expiry + 1u32.into());
+        assert!(!Whitelist::<T>::role_valid(&user, &Role::LettingAgent));
+
         #[extrinsic_call]
         assign_role(
             RawOrigin::Signed(admin.clone()),
@@ -58,6 +73,7 @@ mod benchmarks {
             AccountRoles::<T>::get(&user, Role::LettingAgent).unwrap(),
             AccessPermission::Compliant
         );
+        assert!(Whitelist::<T>::role_valid(&user, &Role::LettingAgent));
     }
 
     #[benchmark]
@@ -121,5 +137,292 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn attest_role() {
+        let admin: T::AccountId = account("admin", 0, 0);
+        let user: T::AccountId = account("user", 0, 0);
+
+        assert_ok!(Whitelist::<T>::add_admin(
+            RawOrigin::Root.into(),
+            admin.clone()
+        ));
+        // Attests an assigned role whose expiry events share their blocks with other expiries.
+        let previous_expiry = frame_system::Pallet::<T>::block_number() + T::RoleGrantPeriod::get();
+        for block in [
+            previous_expiry - T::ExpiryNoticePeriod::get(),
+            previous_expiry,
+        ] {
+            let expiries: Vec<(T::AccountId, Role)> = (1..T::MaxExpiriesPerBlock::get())
+                .map(|i| (account("expiring", i, 0), Role::Lawyer))
+                .collect();
+            AttestationExpiries::<T>::insert(block, BoundedVec::truncate_from(expiries));
+        }
+        assert_ok!(Whitelist::<T>::assign_role(
+            RawOrigin::Signed(admin.clone()).into(),
+            user.clone(),
+            Role::RealEstateInvestor
+        ));
+        assert_eq!(
+            RoleGrantExpiries::<T>::get(&user, Role::RealEstateInvestor),
+            Some(previous_expiry)
+        );
+        let expires_at = frame_system::Pallet::<T>::block_number()
+            + T::ExpiryNoticePeriod::get()
+            + 1_000u32.into();
+        let evidence_hash = T::Hash::default();
+
+        #[extrinsic_call]
+        attest_role(
+            RawOrigin::Signed(admin.clone()),
+            user.clone(),
+            Role::RealEstateInvestor,
+            expires_at,
+            evidence_hash,
+        );
+
+        assert_eq!(
+            AccountRoles::<T>::get(&user, Role::RealEstateInvestor).unwrap(),
+            AccessPermission::Compliant
+        );
+        let attestation = RoleAttestations::<T>::get(&user, Role::RealEstateInvestor).unwrap();
+        assert_eq!(attestation.issuer, admin);
+        assert_eq!(attestation.expires_at, expires_at);
+        assert_eq!(attestation.evidence_hash, evidence_hash);
+        assert!(!AttestationExpiries::<T>::get(previous_expiry)
+            .contains(&(user, Role::RealEstateInvestor)));
+    }
+
+    #[benchmark]
+    fn set_investor_profile() {
+        let admin: T::AccountId = account("admin", 0, 0);
+        let user: T::AccountId = account("user", 0, 0);
+
+        assert_ok!(Whitelist::<T>::add_admin(
+            RawOrigin::Root.into(),
+            admin.clone()
+        ));
+
+        #[extrinsic_call]
+        set_investor_profile(RawOrigin::Signed(admin.clone()), user.clone(), *b"DE", true);
+
+        assert_eq!(
+            InvestorProfiles::<T>::get(&user),
+            Some(InvestorProfile {
+                jurisdiction: *b"DE",
+                accredited: true,
+            })
+        );
+    }
+
+    #[benchmark]
+    fn set_region_policy() {
+        let allowed_jurisdictions: BoundedVec<Jurisdiction, T::MaxJurisdictions> =
+            BoundedVec::truncate_from(vec![*b"DE"; T::MaxJurisdictions::get() as usize]);
+
+        #[extrinsic_call]
+        set_region_policy(
+            RawOrigin::Root,
+            3,
+            allowed_jurisdictions,
+            true,
+            Some(1_000_000),
+        );
+
+        assert!(RegionEligibility::<T>::contains_key(3));
+    }
+
+    #[benchmark]
+    fn remove_region_policy() {
+        let allowed_jurisdictions: BoundedVec<Jurisdiction, T::MaxJurisdictions> =
+            BoundedVec::truncate_from(vec![*b"DE"; T::MaxJurisdictions::get() as usize]);
+
+        assert_ok!(Whitelist::<T>::set_region_policy(
+            RawOrigin::Root.into(),
+            3,
+            allowed_jurisdictions,
+            true,
+            Some(1_000_000)
+        ));
+
+        #[extrinsic_call]
+        remove_region_policy(RawOrigin::Root, 3);
+
+        assert!(!RegionEligibility::<T>::contains_key(3));
+    }
+
+    #[benchmark]
+    fn set_approval_threshold() {
+        let threshold = T::MaxApprovals::get();
+
+        #[extrinsic_call]
+        set_approval_threshold(RawOrigin::Root, threshold);
+
+        assert_eq!(ApprovalThreshold::<T>::get(), threshold);
+    }
+
+    #[benchmark]
+    fn approve_action() {
+        let threshold = T::MaxApprovals::get();
+        let user: T::AccountId = account("user", 0, 0);
+        let admins: Vec<T::AccountId> = (0..threshold).map(|i| account("admin", i, i)).collect();
+
+        for admin in &admins {
+            assert_ok!(Whitelist::<T>::add_admin(
+                RawOrigin::Root.into(),
+                admin.clone()
+            ));
+        }
+        // Approves assigning a role again after the previous assignment expired.
+        assert_ok!(Whitelist::<T>::assign_role(
+            RawOrigin::Signed(admins[0].clone()).into(),
+            user.clone(),
+            Role::RegionalOperator
+        ));
+        let previous_expiry = RoleGrantExpiries::<T>::get(&user, Role::RegionalOperator).unwrap();
+        frame_system::Pallet::<T>::set_block_number(previous_expiry + 1u32.into());
+        assert_ok!(Whitelist::<T>::set_approval_threshold(
+            RawOrigin::Root.into(),
+            threshold
+        ));
+        assert_ok!(Whitelist::<T>::assign_role(
+            RawOrigin::Signed(admins[0].clone()).into(),
+            user.clone(),
+            Role::RegionalOperator
+        ));
+        for admin in &admins[1..admins.len() - 1] {
+            assert_ok!(Whitelist::<T>::approve_action(
+                RawOrigin::Signed(admin.clone()).into(),
+                0
+            ));
+        }
+
+        assert!(!Whitelist::<T>::role_valid(&user, &Role::RegionalOperator));
+
+        #[extrinsic_call]
+        approve_action(RawOrigin::Signed(admins[admins.len() - 1].clone()), 0);
+
+        assert!(Whitelist::<T>::role_valid(&user, &Role::RegionalOperator));
+        assert!(!PendingActions::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn remove_expired_action() {
+        let admin: T::AccountId = account("admin", 0, 0);
+        let caller: T::AccountId = account("caller", 0, 0);
+        let user: T::AccountId = account("user", 0, 0);
+
+        assert_ok!(Whitelist::<T>::add_admin(
+            RawOrigin::Root.into(),
+            admin.clone()
+        ));
+        assert_ok!(Whitelist::<T>::set_approval_threshold(
+            RawOrigin::Root.into(),
+            2
+        ));
+        assert_ok!(Whitelist::<T>::assign_role(
+            RawOrigin::Signed(admin.clone()).into(),
+            user.clone(),
+            Role::RegionalOperator
+        ));
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + T::PendingActionLifetime::get(),
+        );
+
+        #[extrinsic_call]
+        remove_expired_action(RawOrigin::Signed(caller), 0);
+
+        assert!(!PendingActions::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn assign_roles(n: Linear<1, { T::MaxRoleAssignments::get() }>) {
+        let admin: T::AccountId = account("admin", 0, 0);
+        let users: Vec<T::AccountId> = (0..n).map(|i| account("user", i, i)).collect();
+
+        assert_ok!(Whitelist::<T>::add_admin(
+            RawOrigin::Root.into(),
+            admin.clone()
+        ));
+        // Assigns roles again after the previous assignments expired.
+        assert_ok!(Whitelist::<T>::assign_roles(
+            RawOrigin::Signed(admin.clone()).into(),
+            BoundedVec::truncate_from(users.clone()),
+            Role::RealEstateInvestor
+        ));
+        let previous_expiry = users
+            .iter()
+            .filter_map(|user| RoleGrantExpiries::<T>::get(user, Role::RealEstateInvestor))
+            .max()
+            .unwrap();
+        frame_system::Pallet::<T>::set_block_number(previous_expiry + 1u32.into());
+
+        #[extrinsic_call]
+        assign_roles(
+            RawOrigin::Signed(admin.clone()),
+            BoundedVec::truncate_from(users.clone()),
+            Role::RealEstateInvestor,
+        );
+
+        for user in users {
+            assert_eq!(
+                AccountRoles::<T>::get(&user, Role::RealEstateInvestor).unwrap(),
+                AccessPermission::Compliant
+            );
+            assert!(Whitelist::<T>::role_valid(&user, &Role::RealEstateInvestor));
+        }
+    }
+
+    #[benchmark]
+    fn link_account() {
+        let admin: T::AccountId = account("admin", 0, 0);
+        let primary: T::AccountId = account("primary", 0, 0);
+        let user: T::AccountId = account("user", 0, 0);
+
+        assert_ok!(Whitelist::<T>::add_admin(
+            RawOrigin::Root.into(),
+            admin.clone()
+        ));
+        for i in 1..T::MaxLinkedAccounts::get() {
+            assert_ok!(Whitelist::<T>::link_account(
+                RawOrigin::Signed(admin.clone()).into(),
+                primary.clone(),
+                account("user", i, i)
+            ));
+        }
+
+        #[extrinsic_call]
+        link_account(
+            RawOrigin::Signed(admin.clone()),
+            primary.clone(),
+            user.clone(),
+        );
+
+        assert_eq!(PrimaryAccount::<T>::get(&user), Some(primary));
+    }
+
+    #[benchmark]
+    fn unlink_account() {
+        let admin: T::AccountId = account("admin", 0, 0);
+        let primary: T::AccountId = account("primary", 0, 0);
+        let user: T::AccountId = account("user", 0, 0);
+
+        assert_ok!(Whitelist::<T>::add_admin(
+            RawOrigin::Root.into(),
+            admin.clone()
+        ));
+        for i in 0..T::MaxLinkedAccounts::get() {
+            assert_ok!(Whitelist::<T>::link_account(
+                RawOrigin::Signed(admin.clone()).into(),
+                primary.clone(),
+                account("user", i, i)
+            ));
+        }
+
+        #[extrinsic_call]
+        unlink_account(RawOrigin::Signed(admin.clone()), user.clone());
+
+        assert!(!PrimaryAccount::<T>::contains_key(&user));
+    }
+
     impl_benchmark_test_suite!(Whitelist, crate::mock::new_test_ext(), crate::mock::Test);
 }
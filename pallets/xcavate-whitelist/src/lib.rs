@@ -10,18 +10,35 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
+use scale_info::prelude::vec::Vec;
+
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
+/// ISO 3166-1 alpha-2 country code of an investor's jurisdiction.
+pub type Jurisdiction = [u8; 2];
+
+pub type ActionId = u32;
+
+pub type RegionId = u16;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use frame_support::{
+        pallet_prelude::*,
+        sp_runtime::traits::{One, Saturating},
+    };
     use frame_system::pallet_prelude::*;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Role enum.
@@ -64,6 +81,82 @@ pub mod pallet {
         Compliant,
     }
 
+    /// Attestation of the off-chain KYC/AML checks that back a role assignment.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RoleAttestation<T: Config> {
+        /// The admin that issued the attestation.
+        pub issuer: AccountIdOf<T>,
+        /// The block from which on the role is no longer compliant.
+        pub expires_at: BlockNumberFor<T>,
+        /// Hash of the off-chain KYC/AML evidence.
+        pub evidence_hash: T::Hash,
+    }
+
+    /// Compliance profile of an investor.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+    pub struct InvestorProfile {
+        /// The jurisdiction of the investor.
+        pub jurisdiction: Jurisdiction,
+        /// Whether the investor is an accredited investor.
+        pub accredited: bool,
+    }
+
+    /// Rules that an investor has to fulfill to invest into properties of a region.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct EligibilityPolicy<T: Config> {
+        /// The investor jurisdictions that are allowed to invest, empty if all are allowed.
+        pub allowed_jurisdictions: BoundedVec<Jurisdiction, T::MaxJurisdictions>,
+        /// Whether only accredited investors are allowed to invest.
+        pub accredited_only: bool,
+        /// The maximum investment of an investor into a property in the reference unit.
+        pub max_investment: Option<u128>,
+    }
+
+    /// An investment into a property of a region that is checked against the eligibility policy
+    /// of the region.
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+    pub struct InvestmentEligibility {
+        /// The region of the property.
+        pub region: RegionId,
+        /// The total investment of the investor into the property in the reference unit.
+        pub investment: u128,
+    }
+
+    /// A sensitive admin action that can require the approval of multiple admins.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub enum AdminAction<T: Config> {
+        AssignRole {
+            user: AccountIdOf<T>,
+            role: Role,
+        },
+        RemoveRole {
+            user: AccountIdOf<T>,
+            role: Role,
+        },
+        SetPermission {
+            user: AccountIdOf<T>,
+            role: Role,
+            permission: AccessPermission,
+        },
+    }
+
+    /// An admin action that is waiting for the approval of other admins.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PendingAction<T: Config> {
+        /// The action that gets executed once enough admins approved it.
+        pub action: AdminAction<T>,
+        /// The admin that proposed the action.
+        pub proposer: AccountIdOf<T>,
+        /// The admins that approved the action, including the proposer.
+        pub approvals: BoundedVec<AccountIdOf<T>, T::MaxApprovals>,
+        /// The block from which on the action can no longer be approved.
+        pub expires_at: BlockNumberFor<T>,
+    }
+
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -73,6 +166,30 @@ pub mod pallet {
         type WeightInfo: WeightInfo;
         /// Origin who can add and remove users to the whitelist.
         type WhitelistOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The period before the expiry of an attestation in which the expiry is announced.
+        #[pallet::constant]
+        type ExpiryNoticePeriod: Get<BlockNumberFor<Self>>;
+        /// The period after which a role that has been assigned without an attestation expires.
+        #[pallet::constant]
+        type RoleGrantPeriod: Get<BlockNumberFor<Self>>;
+        /// The maximum amount of attestation events that can be scheduled for one block.
+        #[pallet::constant]
+        type MaxExpiriesPerBlock: Get<u32>;
+        /// The maximum amount of jurisdictions in an eligibility policy.
+        #[pallet::constant]
+        type MaxJurisdictions: Get<u32>;
+        /// The period in which a pending admin action can be approved.
+        #[pallet::constant]
+        type PendingActionLifetime: Get<BlockNumberFor<Self>>;
+        /// The maximum amount of admin approvals for a pending action.
+        #[pallet::constant]
+        type MaxApprovals: Get<u32>;
+        /// The maximum amount of users that can be assigned a role in one batch.
+        #[pallet::constant]
+        type MaxRoleAssignments: Get<u32>;
+        /// The maximum amount of accounts that can be linked to a primary account.
+        #[pallet::constant]
+        type MaxLinkedAccounts: Get<u32>;
     }
 
     /// Mapping of the admin accounts.
@@ -92,6 +209,80 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Mapping of the accounts and roles to the KYC attestation of the role assignment.
+    #[pallet::storage]
+    pub type RoleAttestations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        Role,
+        RoleAttestation<T>,
+        OptionQuery,
+    >;
+
+    /// Mapping of the accounts and roles to the expiry of a role assigned without an attestation.
+    #[pallet::storage]
+    pub type RoleGrantExpiries<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        Role,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Mapping of the investor accounts to their compliance profile.
+    #[pallet::storage]
+    pub type InvestorProfiles<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, InvestorProfile, OptionQuery>;
+
+    /// Mapping of the primary accounts to the accounts that are linked to them.
+    #[pallet::storage]
+    pub type LinkedAccounts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        BoundedVec<AccountIdOf<T>, T::MaxLinkedAccounts>,
+        ValueQuery,
+    >;
+
+    /// Mapping of the linked accounts to their primary account.
+    #[pallet::storage]
+    pub type PrimaryAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, AccountIdOf<T>, OptionQuery>;
+
+    /// Mapping of the regions to the eligibility policy for investors.
+    #[pallet::storage]
+    pub type RegionEligibility<T: Config> =
+        StorageMap<_, Blake2_128Concat, RegionId, EligibilityPolicy<T>, OptionQuery>;
+
+    /// The amount of admin approvals that sensitive admin actions require. Sensitive actions are
+    /// executed directly if the threshold is one or lower.
+    #[pallet::storage]
+    pub type ApprovalThreshold<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Number of pending admin actions that have been proposed.
+    #[pallet::storage]
+    pub type NextActionId<T: Config> = StorageValue<_, ActionId, ValueQuery>;
+
+    /// Mapping of the action id to the pending admin action.
+    #[pallet::storage]
+    pub type PendingActions<T: Config> =
+        StorageMap<_, Blake2_128Concat, ActionId, PendingAction<T>, OptionQuery>;
+
+    /// Stores the role assignments whose attestation or grant expires or gets announced in a
+    /// block.
+    #[pallet::storage]
+    pub type AttestationExpiries<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(AccountIdOf<T>, Role), T::MaxExpiriesPerBlock>,
+        ValueQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -109,6 +300,65 @@ pub mod pallet {
             role: Role,
             permission: AccessPermission,
         },
+        /// A KYC attestation has been recorded for a role of a user.
+        RoleAttested {
+            user: T::AccountId,
+            role: Role,
+            issuer: T::AccountId,
+            expires_at: BlockNumberFor<T>,
+            evidence_hash: T::Hash,
+        },
+        /// The attestation or the grant of a role of a user is about to expire.
+        RoleExpiring {
+            user: T::AccountId,
+            role: Role,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// The attestation or the grant of a role of a user expired.
+        RoleExpired { user: T::AccountId, role: Role },
+        /// The compliance profile of an investor has been set.
+        InvestorProfileSet {
+            user: T::AccountId,
+            jurisdiction: Jurisdiction,
+            accredited: bool,
+        },
+        /// The eligibility policy of a region has been set.
+        RegionPolicySet {
+            region: RegionId,
+            allowed_jurisdictions: BoundedVec<Jurisdiction, T::MaxJurisdictions>,
+            accredited_only: bool,
+            max_investment: Option<u128>,
+        },
+        /// The eligibility policy of a region has been removed.
+        RegionPolicyRemoved { region: RegionId },
+        /// The approval threshold for sensitive admin actions has been set.
+        ApprovalThresholdSet { threshold: u32 },
+        /// A sensitive admin action has been proposed and awaits approval.
+        AdminActionProposed {
+            action_id: ActionId,
+            proposer: T::AccountId,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// An admin approved a pending admin action.
+        AdminActionApproved {
+            action_id: ActionId,
+            admin: T::AccountId,
+            approvals: u32,
+        },
+        /// A pending admin action reached the approval threshold and has been executed.
+        AdminActionExecuted { action_id: ActionId },
+        /// An expired pending admin action has been removed.
+        AdminActionExpired { action_id: ActionId },
+        /// An account has been linked to a primary account.
+        AccountLinked {
+            primary: T::AccountId,
+            account: T::AccountId,
+        },
+        /// An account has been unlinked from its primary account.
+        AccountUnlinked {
+            primary: T::AccountId,
+            account: T::AccountId,
+        },
     }
 
     // Errors inform users that something went wrong.
@@ -124,6 +374,62 @@ pub mod pallet {
         AccountNotAdmin,
         /// This permission has already been set.
         PermissionAlreadySet,
+        /// The expiry of the attestation has to be in the future.
+        InvalidExpiry,
+        /// Too many attestations expire in the same block.
+        TooManyExpiries,
+        /// The region has no eligibility policy.
+        NoRegionPolicy,
+        /// The threshold exceeds the maximum amount of approvals.
+        InvalidThreshold,
+        /// The pending admin action does not exist.
+        ActionNotFound,
+        /// The pending admin action has expired.
+        ActionExpired,
+        /// The pending admin action has not expired yet.
+        ActionNotExpired,
+        /// The admin already approved this action.
+        AlreadyApproved,
+        /// The pending admin action has reached the maximum amount of approvals.
+        TooManyApprovals,
+        /// The action requires the approval of multiple admins.
+        ApprovalRequired,
+        /// An arithmetic operation overflowed.
+        ArithmeticOverflow,
+        /// The account is already linked to an account.
+        AlreadyLinked,
+        /// The account is not linked to a primary account.
+        AccountNotLinked,
+        /// An account can not be linked to itself.
+        CannotLinkSelf,
+        /// The primary account has reached the maximum amount of linked accounts.
+        TooManyLinkedAccounts,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+            let scheduled = AttestationExpiries::<T>::take(n);
+            // Announces upcoming expiries and emits an event for expired roles.
+            scheduled.into_iter().for_each(|(user, role)| {
+                weight = weight.saturating_add(T::DbWeight::get().reads(2));
+                let Some(expires_at) = Self::role_expiry(&user, &role) else {
+                    return;
+                };
+                if expires_at == n {
+                    Self::deposit_event(Event::<T>::RoleExpired { user, role });
+                } else if expires_at == n.saturating_add(T::ExpiryNoticePeriod::get()) {
+                    Self::deposit_event(Event::<T>::RoleExpiring {
+                        user,
+                        role,
+                        expires_at,
+                    });
+                }
+            });
+            weight
+        }
     }
 
     #[pallet::call]
@@ -137,7 +443,7 @@ pub mod pallet {
         ///
         /// Emits `RoleAssigned` event when successful.
         #[pallet::call_index(0)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::add_admin())]
         pub fn add_admin(origin: OriginFor<T>, admin: AccountIdOf<T>) -> DispatchResult {
             T::WhitelistOrigin::ensure_origin(origin)?;
             ensure!(
@@ -158,7 +464,7 @@ pub mod pallet {
         ///
         /// Emits `RoleAssigned` event when successful.
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_admin())]
         pub fn remove_admin(origin: OriginFor<T>, admin: AccountIdOf<T>) -> DispatchResult {
             T::WhitelistOrigin::ensure_origin(origin)?;
             ensure!(
@@ -170,7 +476,9 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Assign a role to a user with default 'Compliant' permission.
+        /// Assign a role to a user with default 'Compliant' permission. Assigning the
+        /// 'RegionalOperator' or 'SpvConfirmation' role is proposed as a pending action if an
+        /// approval threshold is set. An expired role can be assigned again.
         ///
         /// The origin must be an admin.
         ///
@@ -178,9 +486,9 @@ pub mod pallet {
         /// - `user`: The address of the account that gets a new role.
         /// - `role`: The role that is getting assigned to the user.
         ///
-        /// Emits `RoleAssigned` event when successful.
+        /// Emits `RoleAssigned` or `AdminActionProposed` event when successful.
         #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::assign_role())]
         pub fn assign_role(
             origin: OriginFor<T>,
             user: AccountIdOf<T>,
@@ -191,16 +499,11 @@ pub mod pallet {
                 AdminAccounts::<T>::contains_key(&signer),
                 Error::<T>::AccountNotAdmin
             );
-            ensure!(
-                !AccountRoles::<T>::contains_key(&user, &role),
-                Error::<T>::RoleAlreadyAssigned
-            );
-            AccountRoles::<T>::insert(&user, role.clone(), AccessPermission::Compliant);
-            Self::deposit_event(Event::<T>::RoleAssigned { user, role });
-            Ok(())
+            Self::execute_or_propose(signer, AdminAction::AssignRole { user, role })
         }
 
-        /// Remove a role from a user.
+        /// Remove a role from a user. The removal is proposed as a pending action if an approval
+        /// threshold is set.
         ///
         /// The origin must be an admin.
         ///
@@ -208,9 +511,9 @@ pub mod pallet {
         /// - `user`: The address of the account that gets a role removed.
         /// - `role`: The role that is getting removed from the user.
         ///
-        /// Emits `RoleRemoved` event when successful.
+        /// Emits `RoleRemoved` or `AdminActionProposed` event when successful.
         #[pallet::call_index(3)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_role())]
         pub fn remove_role(
             origin: OriginFor<T>,
             user: AccountIdOf<T>,
@@ -221,16 +524,12 @@ pub mod pallet {
                 AdminAccounts::<T>::contains_key(&signer),
                 Error::<T>::AccountNotAdmin
             );
-            ensure!(
-                AccountRoles::<T>::contains_key(&user, &role),
-                Error::<T>::RoleNotAssigned
-            );
-            AccountRoles::<T>::remove(&user, role.clone());
-            Self::deposit_event(Event::<T>::RoleRemoved { user, role });
-            Ok(())
+            Self::execute_or_propose(signer, AdminAction::RemoveRole { user, role })
         }
 
-        /// Update a user's permission for a role.
+        /// Update a user's permission for a role. Revoking a permission or updating the
+        /// permission of a 'RegionalOperator' or 'SpvConfirmation' role is proposed as a pending
+        /// action if an approval threshold is set.
         ///
         /// The origin must be an admin.
         ///
@@ -239,9 +538,9 @@ pub mod pallet {
         /// - `role`: The role that is getting the permission updated.
         /// - `permission`: The new permission state.
         ///
-        /// Emits `PermissionUpdated` event when successful.
+        /// Emits `PermissionUpdated` or `AdminActionProposed` event when successful.
         #[pallet::call_index(4)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1,1))]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_permission())]
         pub fn set_permission(
             origin: OriginFor<T>,
             user: AccountIdOf<T>,
@@ -253,18 +552,603 @@ pub mod pallet {
                 AdminAccounts::<T>::contains_key(&signer),
                 Error::<T>::AccountNotAdmin
             );
-            let current_role =
-                AccountRoles::<T>::get(&user, &role).ok_or(Error::<T>::RoleNotAssigned)?;
-            ensure!(current_role != permission, Error::<T>::PermissionAlreadySet);
+            Self::execute_or_propose(
+                signer,
+                AdminAction::SetPermission {
+                    user,
+                    role,
+                    permission,
+                },
+            )
+        }
 
-            AccountRoles::<T>::insert(&user, role.clone(), permission.clone());
-            Self::deposit_event(Event::<T>::PermissionUpdated {
+        /// Record the KYC attestation of a role assignment. Assigns the role with 'Compliant'
+        /// permission if the user does not have it yet, otherwise renews the attestation.
+        /// The expiry moves to the next block with room for its events if the block is full.
+        ///
+        /// The origin must be an admin.
+        ///
+        /// Parameters:
+        /// - `user`: The address of the account that gets the attestation.
+        /// - `role`: The role that the attestation is recorded for.
+        /// - `expires_at`: The block from which on the role is no longer compliant.
+        /// - `evidence_hash`: The hash of the off-chain KYC/AML evidence.
+        ///
+        /// Emits `RoleAttested` event when successful.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::attest_role())]
+        pub fn attest_role(
+            origin: OriginFor<T>,
+            user: AccountIdOf<T>,
+            role: Role,
+            expires_at: BlockNumberFor<T>,
+            evidence_hash: T::Hash,
+        ) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            ensure!(
+                AdminAccounts::<T>::contains_key(&signer),
+                Error::<T>::AccountNotAdmin
+            );
+            let current_block_number = <frame_system::Pallet<T>>::block_number();
+            ensure!(expires_at > current_block_number, Error::<T>::InvalidExpiry);
+            if !AccountRoles::<T>::contains_key(&user, &role) {
+                ensure!(
+                    !Self::requires_approval(&AdminAction::AssignRole {
+                        user: user.clone(),
+                        role: role.clone(),
+                    }),
+                    Error::<T>::ApprovalRequired
+                );
+                AccountRoles::<T>::insert(&user, role.clone(), AccessPermission::Compliant);
+                Self::deposit_event(Event::<T>::RoleAssigned {
+                    user: user.clone(),
+                    role: role.clone(),
+                });
+            }
+
+            if let Some(previous_expiry) = Self::role_expiry(&user, &role) {
+                if previous_expiry > current_block_number {
+                    Self::unschedule_role_expiry(&user, &role, previous_expiry);
+                }
+            }
+            let expires_at = Self::schedule_role_expiry(&user, &role, expires_at)?;
+            RoleAttestations::<T>::insert(
+                &user,
+                role.clone(),
+                RoleAttestation {
+                    issuer: signer.clone(),
+                    expires_at,
+                    evidence_hash,
+                },
+            );
+            Self::deposit_event(Event::<T>::RoleAttested {
                 user,
                 role,
-                permission,
+                issuer: signer,
+                expires_at,
+                evidence_hash,
             });
             Ok(())
         }
+
+        /// Set the compliance profile of an investor.
+        ///
+        /// The origin must be an admin.
+        ///
+        /// Parameters:
+        /// - `user`: The address of the investor.
+        /// - `jurisdiction`: The jurisdiction of the investor.
+        /// - `accredited`: Whether the investor is an accredited investor.
+        ///
+        /// Emits `InvestorProfileSet` event when successful.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_investor_profile())]
+        pub fn set_investor_profile(
+            origin: OriginFor<T>,
+            user: AccountIdOf<T>,
+            jurisdiction: Jurisdiction,
+            accredited: bool,
+        ) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            ensure!(
+                AdminAccounts::<T>::contains_key(&signer),
+                Error::<T>::AccountNotAdmin
+            );
+            InvestorProfiles::<T>::insert(
+                &user,
+                InvestorProfile {
+                    jurisdiction,
+                    accredited,
+                },
+            );
+            Self::deposit_event(Event::<T>::InvestorProfileSet {
+                user,
+                jurisdiction,
+                accredited,
+            });
+            Ok(())
+        }
+
+        /// Set the eligibility policy for investors of a region.
+        ///
+        /// The origin must be the WhitelistOrigin.
+        ///
+        /// Parameters:
+        /// - `region`: The region that the policy applies to.
+        /// - `allowed_jurisdictions`: The investor jurisdictions that are allowed to invest, empty
+        ///   if all are allowed.
+        /// - `accredited_only`: Whether only accredited investors are allowed to invest.
+        /// - `max_investment`: The maximum investment of an investor into a property.
+        ///
+        /// Emits `RegionPolicySet` event when successful.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_region_policy())]
+        pub fn set_region_policy(
+            origin: OriginFor<T>,
+            region: RegionId,
+            allowed_jurisdictions: BoundedVec<Jurisdiction, T::MaxJurisdictions>,
+            accredited_only: bool,
+            max_investment: Option<u128>,
+        ) -> DispatchResult {
+            T::WhitelistOrigin::ensure_origin(origin)?;
+            RegionEligibility::<T>::insert(
+                region,
+                EligibilityPolicy {
+                    allowed_jurisdictions: allowed_jurisdictions.clone(),
+                    accredited_only,
+                    max_investment,
+                },
+            );
+            Self::deposit_event(Event::<T>::RegionPolicySet {
+                region,
+                allowed_jurisdictions,
+                accredited_only,
+                max_investment,
+            });
+            Ok(())
+        }
+
+        /// Remove the eligibility policy of a region.
+        ///
+        /// The origin must be the WhitelistOrigin.
+        ///
+        /// Parameters:
+        /// - `region`: The region that the policy gets removed from.
+        ///
+        /// Emits `RegionPolicyRemoved` event when successful.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_region_policy())]
+        pub fn remove_region_policy(origin: OriginFor<T>, region: RegionId) -> DispatchResult {
+            T::WhitelistOrigin::ensure_origin(origin)?;
+            ensure!(
+                RegionEligibility::<T>::contains_key(region),
+                Error::<T>::NoRegionPolicy
+            );
+            RegionEligibility::<T>::remove(region);
+            Self::deposit_event(Event::<T>::RegionPolicyRemoved { region });
+            Ok(())
+        }
+
+        /// Set the amount of admin approvals that sensitive admin actions require.
+        ///
+        /// The origin must be the WhitelistOrigin.
+        ///
+        /// Parameters:
+        /// - `threshold`: The amount of required approvals, one or lower disables the approval
+        ///   flow.
+        ///
+        /// Emits `ApprovalThresholdSet` event when successful.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_approval_threshold())]
+        pub fn set_approval_threshold(origin: OriginFor<T>, threshold: u32) -> DispatchResult {
+            T::WhitelistOrigin::ensure_origin(origin)?;
+            ensure!(
+                threshold <= T::MaxApprovals::get(),
+                Error::<T>::InvalidThreshold
+            );
+            ApprovalThreshold::<T>::put(threshold);
+            Self::deposit_event(Event::<T>::ApprovalThresholdSet { threshold });
+            Ok(())
+        }
+
+        /// Approve a pending admin action. The action gets executed once the amount of
+        /// approvals of current admins reaches the approval threshold.
+        ///
+        /// The origin must be an admin.
+        ///
+        /// Parameters:
+        /// - `action_id`: The id of the pending action.
+        ///
+        /// Emits `AdminActionApproved` event when successful.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::approve_action())]
+        pub fn approve_action(origin: OriginFor<T>, action_id: ActionId) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            ensure!(
+                AdminAccounts::<T>::contains_key(&signer),
+                Error::<T>::AccountNotAdmin
+            );
+            let mut pending_action =
+                PendingActions::<T>::get(action_id).ok_or(Error::<T>::ActionNotFound)?;
+            ensure!(
+                pending_action.expires_at > <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::ActionExpired
+            );
+            ensure!(
+                !pending_action.approvals.contains(&signer),
+                Error::<T>::AlreadyApproved
+            );
+            pending_action
+                .approvals
+                .try_push(signer.clone())
+                .map_err(|_| Error::<T>::TooManyApprovals)?;
+            let approvals = pending_action
+                .approvals
+                .iter()
+                .filter(|admin| AdminAccounts::<T>::contains_key(admin))
+                .count() as u32;
+            Self::deposit_event(Event::<T>::AdminActionApproved {
+                action_id,
+                admin: signer,
+                approvals,
+            });
+            if approvals >= ApprovalThreshold::<T>::get() {
+                PendingActions::<T>::remove(action_id);
+                Self::execute_action(pending_action.action)?;
+                Self::deposit_event(Event::<T>::AdminActionExecuted { action_id });
+            } else {
+                PendingActions::<T>::insert(action_id, pending_action);
+            }
+            Ok(())
+        }
+
+        /// Remove an expired pending admin action.
+        ///
+        /// The origin must be Signed and the sender must have sufficient funds free.
+        ///
+        /// Parameters:
+        /// - `action_id`: The id of the expired action.
+        ///
+        /// Emits `AdminActionExpired` event when successful.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_expired_action())]
+        pub fn remove_expired_action(origin: OriginFor<T>, action_id: ActionId) -> DispatchResult {
+            ensure_signed(origin)?;
+            let pending_action =
+                PendingActions::<T>::get(action_id).ok_or(Error::<T>::ActionNotFound)?;
+            ensure!(
+                pending_action.expires_at <= <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::ActionNotExpired
+            );
+            PendingActions::<T>::remove(action_id);
+            Self::deposit_event(Event::<T>::AdminActionExpired { action_id });
+            Ok(())
+        }
+
+        /// Assign a role to a batch of users with default 'Compliant' permission.
+        ///
+        /// The origin must be an admin.
+        ///
+        /// Parameters:
+        /// - `users`: The addresses of the accounts that get the new role.
+        /// - `role`: The role that is getting assigned to the users.
+        ///
+        /// Emits `RoleAssigned` event for every user when successful.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::assign_roles(users.len() as u32))]
+        pub fn assign_roles(
+            origin: OriginFor<T>,
+            users: BoundedVec<AccountIdOf<T>, T::MaxRoleAssignments>,
+            role: Role,
+        ) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            ensure!(
+                AdminAccounts::<T>::contains_key(&signer),
+                Error::<T>::AccountNotAdmin
+            );
+            for user in users {
+                let action = AdminAction::AssignRole {
+                    user,
+                    role: role.clone(),
+                };
+                ensure!(
+                    !Self::requires_approval(&action),
+                    Error::<T>::ApprovalRequired
+                );
+                Self::execute_action(action)?;
+            }
+            Ok(())
+        }
+
+        /// Link an account to a primary account. Linked accounts are treated as one investor
+        /// for ownership limits.
+        ///
+        /// The origin must be an admin.
+        ///
+        /// Parameters:
+        /// - `primary`: The primary account of the investor.
+        /// - `account`: The account that gets linked to the primary account.
+        ///
+        /// Emits `AccountLinked` event when successful.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::link_account())]
+        pub fn link_account(
+            origin: OriginFor<T>,
+            primary: AccountIdOf<T>,
+            account: AccountIdOf<T>,
+        ) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            ensure!(
+                AdminAccounts::<T>::contains_key(&signer),
+                Error::<T>::AccountNotAdmin
+            );
+            ensure!(primary != account, Error::<T>::CannotLinkSelf);
+            ensure!(
+                !PrimaryAccount::<T>::contains_key(&primary)
+                    && !PrimaryAccount::<T>::contains_key(&account)
+                    && LinkedAccounts::<T>::get(&account).is_empty(),
+                Error::<T>::AlreadyLinked
+            );
+            LinkedAccounts::<T>::try_mutate(&primary, |linked_accounts| {
+                linked_accounts
+                    .try_push(account.clone())
+                    .map_err(|_| Error::<T>::TooManyLinkedAccounts)
+            })?;
+            PrimaryAccount::<T>::insert(&account, &primary);
+            Self::deposit_event(Event::<T>::AccountLinked { primary, account });
+            Ok(())
+        }
+
+        /// Unlink an account from its primary account.
+        ///
+        /// The origin must be an admin.
+        ///
+        /// Parameters:
+        /// - `account`: The account that gets unlinked.
+        ///
+        /// Emits `AccountUnlinked` event when successful.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unlink_account())]
+        pub fn unlink_account(origin: OriginFor<T>, account: AccountIdOf<T>) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            ensure!(
+                AdminAccounts::<T>::contains_key(&signer),
+                Error::<T>::AccountNotAdmin
+            );
+            let primary =
+                PrimaryAccount::<T>::take(&account).ok_or(Error::<T>::AccountNotLinked)?;
+            LinkedAccounts::<T>::mutate_exists(&primary, |maybe_linked_accounts| {
+                if let Some(linked_accounts) = maybe_linked_accounts {
+                    linked_accounts.retain(|linked_account| linked_account != &account);
+                    if linked_accounts.is_empty() {
+                        *maybe_linked_accounts = None;
+                    }
+                }
+            });
+            Self::deposit_event(Event::<T>::AccountUnlinked { primary, account });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Executes the action directly or stores it as a pending action if it requires the
+        /// approval of multiple admins.
+        fn execute_or_propose(proposer: AccountIdOf<T>, action: AdminAction<T>) -> DispatchResult {
+            if !Self::requires_approval(&action) {
+                return Self::execute_action(action);
+            }
+            Self::validate_action(&action)?;
+            let action_id = NextActionId::<T>::get();
+            let next_action_id = action_id
+                .checked_add(1)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            let expires_at = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::PendingActionLifetime::get());
+            let mut approvals = BoundedVec::<AccountIdOf<T>, T::MaxApprovals>::new();
+            approvals
+                .try_push(proposer.clone())
+                .map_err(|_| Error::<T>::TooManyApprovals)?;
+            PendingActions::<T>::insert(
+                action_id,
+                PendingAction {
+                    action,
+                    proposer: proposer.clone(),
+                    approvals,
+                    expires_at,
+                },
+            );
+            NextActionId::<T>::put(next_action_id);
+            Self::deposit_event(Event::<T>::AdminActionProposed {
+                action_id,
+                proposer,
+                expires_at,
+            });
+            Ok(())
+        }
+
+        /// Returns true if the action is sensitive and an approval threshold is set.
+        fn requires_approval(action: &AdminAction<T>) -> bool {
+            if ApprovalThreshold::<T>::get() <= 1 {
+                return false;
+            }
+            let sensitive_role =
+                |role: &Role| matches!(role, Role::RegionalOperator | Role::SpvConfirmation);
+            match action {
+                AdminAction::AssignRole { role, .. } => sensitive_role(role),
+                AdminAction::RemoveRole { .. } => true,
+                AdminAction::SetPermission {
+                    role, permission, ..
+                } => *permission == AccessPermission::Revoked || sensitive_role(role),
+            }
+        }
+
+        fn validate_action(action: &AdminAction<T>) -> DispatchResult {
+            match action {
+                AdminAction::AssignRole { user, role } => {
+                    ensure!(
+                        !AccountRoles::<T>::contains_key(user, role)
+                            || !Self::role_valid(user, role),
+                        Error::<T>::RoleAlreadyAssigned
+                    );
+                }
+                AdminAction::RemoveRole { user, role } => {
+                    ensure!(
+                        AccountRoles::<T>::contains_key(user, role),
+                        Error::<T>::RoleNotAssigned
+                    );
+                }
+                AdminAction::SetPermission {
+                    user,
+                    role,
+                    permission,
+                } => {
+                    let current_role =
+                        AccountRoles::<T>::get(user, role).ok_or(Error::<T>::RoleNotAssigned)?;
+                    ensure!(
+                        current_role != *permission,
+                        Error::<T>::PermissionAlreadySet
+                    );
+                }
+            }
+            Ok(())
+        }
+
+        fn execute_action(action: AdminAction<T>) -> DispatchResult {
+            Self::validate_action(&action)?;
+            match action {
+                AdminAction::AssignRole { user, role } => {
+                    AccountRoles::<T>::insert(&user, role.clone(), AccessPermission::Compliant);
+                    RoleAttestations::<T>::remove(&user, role.clone());
+                    let expires_at = Self::schedule_role_expiry(
+                        &user,
+                        &role,
+                        <frame_system::Pallet<T>>::block_number()
+                            .saturating_add(T::RoleGrantPeriod::get()),
+                    )?;
+                    RoleGrantExpiries::<T>::insert(&user, role.clone(), expires_at);
+                    Self::deposit_event(Event::<T>::RoleAssigned { user, role });
+                }
+                AdminAction::RemoveRole { user, role } => {
+                    AccountRoles::<T>::remove(&user, role.clone());
+                    RoleAttestations::<T>::remove(&user, role.clone());
+                    RoleGrantExpiries::<T>::remove(&user, role.clone());
+                    Self::deposit_event(Event::<T>::RoleRemoved { user, role });
+                }
+                AdminAction::SetPermission {
+                    user,
+                    role,
+                    permission,
+                } => {
+                    AccountRoles::<T>::insert(&user, role.clone(), permission.clone());
+                    Self::deposit_event(Event::<T>::PermissionUpdated {
+                        user,
+                        role,
+                        permission,
+                    });
+                }
+            }
+            Ok(())
+        }
+
+        fn schedule_attestation_event(
+            block: BlockNumberFor<T>,
+            user: &AccountIdOf<T>,
+            role: &Role,
+        ) -> DispatchResult {
+            AttestationExpiries::<T>::try_mutate(block, |keys| {
+                keys.try_push((user.clone(), role.clone()))
+                    .map_err(|_| Error::<T>::TooManyExpiries)?;
+                Ok::<(), DispatchError>(())
+            })
+        }
+
+        /// Schedules the expiry notice and the expiry of a role in the first block from `earliest`
+        /// on that has room for both events and returns the block of the expiry.
+        pub(crate) fn schedule_role_expiry(
+            user: &AccountIdOf<T>,
+            role: &Role,
+            earliest: BlockNumberFor<T>,
+        ) -> Result<BlockNumberFor<T>, DispatchError> {
+            let current_block_number = <frame_system::Pallet<T>>::block_number();
+            let has_room = |block: BlockNumberFor<T>| {
+                AttestationExpiries::<T>::decode_len(block).unwrap_or_default()
+                    < T::MaxExpiriesPerBlock::get() as usize
+            };
+            let mut expires_at = earliest;
+            loop {
+                let notice_block = expires_at.saturating_sub(T::ExpiryNoticePeriod::get());
+                let with_notice = notice_block > current_block_number;
+                if has_room(expires_at) && (!with_notice || has_room(notice_block)) {
+                    if with_notice {
+                        Self::schedule_attestation_event(notice_block, user, role)?;
+                    }
+                    Self::schedule_attestation_event(expires_at, user, role)?;
+                    return Ok(expires_at);
+                }
+                expires_at = expires_at.saturating_add(One::one());
+            }
+        }
+
+        /// Removes the scheduled expiry notice and expiry of a role that expires at `expires_at`.
+        fn unschedule_role_expiry(
+            user: &AccountIdOf<T>,
+            role: &Role,
+            expires_at: BlockNumberFor<T>,
+        ) {
+            let notice_block = expires_at.saturating_sub(T::ExpiryNoticePeriod::get());
+            for block in [notice_block, expires_at] {
+                AttestationExpiries::<T>::mutate_exists(block, |maybe_keys| {
+                    if let Some(keys) = maybe_keys {
+                        keys.retain(|(account, scheduled_role)| {
+                            account != user || scheduled_role != role
+                        });
+                        if keys.is_empty() {
+                            *maybe_keys = None;
+                        }
+                    }
+                });
+            }
+        }
+
+        /// Checks an investment of the account against the eligibility policy of the region.
+        pub fn investment_eligible(
+            account: &AccountIdOf<T>,
+            region: RegionId,
+            investment: u128,
+        ) -> bool {
+            let Some(policy) = RegionEligibility::<T>::get(region) else {
+                return true;
+            };
+            let profile = InvestorProfiles::<T>::get(account);
+            if !policy.allowed_jurisdictions.is_empty()
+                && !profile.as_ref().map_or(false, |profile| {
+                    policy.allowed_jurisdictions.contains(&profile.jurisdiction)
+                })
+            {
+                return false;
+            }
+            if policy.accredited_only && !profile.map_or(false, |profile| profile.accredited) {
+                return false;
+            }
+            policy
+                .max_investment
+                .map_or(true, |max_investment| investment <= max_investment)
+        }
+
+        /// Returns the block in which the role of the account expires. The expiry of an
+        /// attestation takes precedence over the expiry of the role assignment.
+        pub fn role_expiry(account: &AccountIdOf<T>, role: &Role) -> Option<BlockNumberFor<T>> {
+            RoleAttestations::<T>::get(account, role)
+                .map(|attestation| attestation.expires_at)
+                .or_else(|| RoleGrantExpiries::<T>::get(account, role))
+        }
+
+        /// Returns false if the role of the account expired.
+        pub fn role_valid(account: &AccountIdOf<T>, role: &Role) -> bool {
+            Self::role_expiry(account, role).map_or(true, |expires_at| {
+                expires_at > <frame_system::Pallet<T>>::block_number()
+            })
+        }
     }
 }
 
@@ -274,19 +1158,37 @@ pub trait RolePermission<AccountId> {
     fn is_compliant(account: &AccountId, role: Role) -> bool;
 
     fn is_admin(account: &AccountId) -> bool;
+
+    fn is_eligible(account: &AccountId, eligibility: &InvestmentEligibility) -> bool;
+
+    /// Returns all accounts that are linked with the account, including the account itself.
+    fn linked_accounts(account: &AccountId) -> Vec<AccountId>;
 }
 
 impl<T: Config> RolePermission<T::AccountId> for Pallet<T> {
     fn has_role(account: &T::AccountId, role: Role) -> bool {
-        AccountRoles::<T>::contains_key(account, role)
+        AccountRoles::<T>::contains_key(account, &role) && Self::role_valid(account, &role)
     }
 
     fn is_compliant(account: &T::AccountId, role: Role) -> bool {
-        AccountRoles::<T>::get(account, role)
+        AccountRoles::<T>::get(account, &role)
             .map_or(false, |access| access == AccessPermission::Compliant)
+            && Self::role_valid(account, &role)
     }
 
     fn is_admin(account: &T::AccountId) -> bool {
         AdminAccounts::<T>::contains_key(account)
     }
+
+    fn is_eligible(account: &T::AccountId, eligibility: &InvestmentEligibility) -> bool {
+        Self::is_compliant(account, Role::RealEstateInvestor)
+            && Self::investment_eligible(account, eligibility.region, eligibility.investment)
+    }
+
+    fn linked_accounts(account: &T::AccountId) -> Vec<T::AccountId> {
+        let primary = PrimaryAccount::<T>::get(account).unwrap_or_else(|| account.clone());
+        let mut accounts = LinkedAccounts::<T>::get(&primary).into_inner();
+        accounts.push(primary);
+        accounts
+    }
 }
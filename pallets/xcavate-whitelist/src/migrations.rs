@@ -0,0 +1,51 @@
+//! Storage migrations for the whitelist pallet.
+
+use super::*;
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+pub mod v1 {
+    use super::*;
+    use frame_support::sp_runtime::traits::Saturating;
+
+    /// Lets the roles that have been assigned without an attestation before role grants expired
+    /// expire one grant period after the upgrade. The expiry and its notice are scheduled like
+    /// the ones of newly assigned roles.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads = 1u64;
+            let mut writes = 0u64;
+            let earliest =
+                <frame_system::Pallet<T>>::block_number().saturating_add(T::RoleGrantPeriod::get());
+            let roles: Vec<(AccountIdOf<T>, Role)> = AccountRoles::<T>::iter_keys().collect();
+            for (user, role) in roles {
+                reads.saturating_accrue(3);
+                if RoleAttestations::<T>::contains_key(&user, &role)
+                    || RoleGrantExpiries::<T>::contains_key(&user, &role)
+                {
+                    continue;
+                }
+                let Ok(expires_at) = Pallet::<T>::schedule_role_expiry(&user, &role, earliest)
+                else {
+                    continue;
+                };
+                RoleGrantExpiries::<T>::insert(&user, role, expires_at);
+                reads.saturating_accrue(2);
+                writes.saturating_accrue(3);
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+
+    /// Migrates the whitelist from storage version 0 to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
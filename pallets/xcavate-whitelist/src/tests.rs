@@ -1,6 +1,16 @@
-use crate::{mock::*, AccessPermission, AccountRoles, AdminAccounts, Error, Role, RolePermission};
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::traits::BadOrigin;
+use crate::{
+    mock::*, AccessPermission, AccountRoles, AdminAccounts, AdminAction, ApprovalThreshold,
+    AttestationExpiries, Error, Event, InvestmentEligibility, InvestorProfile, InvestorProfiles,
+    LinkedAccounts, PendingActions, PrimaryAccount, RegionEligibility, Role, RoleAttestation,
+    RoleAttestations, RoleGrantExpiries, RolePermission,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+    BoundedVec,
+};
+use sp_core::H256;
+use sp_runtime::{bounded_vec, traits::BadOrigin};
 
 #[test]
 fn add_admin_works() {
@@ -236,3 +246,893 @@ fn set_permission_fails() {
         );
     });
 }
+
+#[test]
+fn assigned_role_expires_without_attestation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor
+        ));
+        assert_eq!(
+            RoleGrantExpiries::<Test>::get(1, Role::RealEstateInvestor),
+            Some(101)
+        );
+        assert!(Whitelist::has_role(&1, Role::RealEstateInvestor));
+        assert!(Whitelist::is_compliant(&1, Role::RealEstateInvestor));
+        System::set_block_number(91);
+        Whitelist::on_initialize(91);
+        System::assert_last_event(
+            Event::RoleExpiring {
+                user: 1,
+                role: Role::RealEstateInvestor,
+                expires_at: 101,
+            }
+            .into(),
+        );
+        System::set_block_number(101);
+        Whitelist::on_initialize(101);
+        System::assert_last_event(
+            Event::RoleExpired {
+                user: 1,
+                role: Role::RealEstateInvestor,
+            }
+            .into(),
+        );
+        assert!(!Whitelist::has_role(&1, Role::RealEstateInvestor));
+        assert!(!Whitelist::is_compliant(&1, Role::RealEstateInvestor));
+        assert_ok!(Whitelist::attest_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor,
+            200,
+            H256::repeat_byte(1)
+        ));
+        assert!(Whitelist::has_role(&1, Role::RealEstateInvestor));
+        assert!(Whitelist::is_compliant(&1, Role::RealEstateInvestor));
+        assert_ok!(Whitelist::remove_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor
+        ));
+        assert!(RoleGrantExpiries::<Test>::get(1, Role::RealEstateInvestor).is_none());
+    })
+}
+
+#[test]
+fn role_grant_expiry_moves_to_next_free_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        let full_block: BoundedVec<(u64, Role), <Test as crate::Config>::MaxExpiriesPerBlock> =
+            (100..200)
+                .map(|user| (user, Role::Lawyer))
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+        AttestationExpiries::<Test>::insert(101, full_block);
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor
+        ));
+        assert_eq!(
+            RoleGrantExpiries::<Test>::get(1, Role::RealEstateInvestor),
+            Some(102)
+        );
+        assert!(AttestationExpiries::<Test>::get(92).contains(&(1, Role::RealEstateInvestor)));
+        assert!(AttestationExpiries::<Test>::get(102).contains(&(1, Role::RealEstateInvestor)));
+    })
+}
+
+#[test]
+fn expired_role_can_be_assigned_again() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor
+        ));
+        assert_ok!(Whitelist::attest_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::Lawyer,
+            50,
+            H256::repeat_byte(1)
+        ));
+        System::set_block_number(50);
+        assert!(!Whitelist::has_role(&1, Role::Lawyer));
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::Lawyer
+        ));
+        assert!(RoleAttestations::<Test>::get(1, Role::Lawyer).is_none());
+        assert_eq!(RoleGrantExpiries::<Test>::get(1, Role::Lawyer), Some(150));
+        assert!(Whitelist::is_compliant(&1, Role::Lawyer));
+        assert_noop!(
+            Whitelist::assign_role(RuntimeOrigin::signed(3), 1, Role::RealEstateInvestor),
+            Error::<Test>::RoleAlreadyAssigned
+        );
+        System::set_block_number(101);
+        assert!(!Whitelist::has_role(&1, Role::RealEstateInvestor));
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor
+        ));
+        assert_eq!(
+            RoleGrantExpiries::<Test>::get(1, Role::RealEstateInvestor),
+            Some(201)
+        );
+        assert!(Whitelist::is_compliant(&1, Role::RealEstateInvestor));
+    })
+}
+
+#[test]
+fn migration_to_v1_lets_assigned_roles_expire() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        AccountRoles::<Test>::insert(1, Role::RealEstateInvestor, AccessPermission::Compliant);
+        AccountRoles::<Test>::insert(2, Role::Lawyer, AccessPermission::Compliant);
+        RoleAttestations::<Test>::insert(
+            2,
+            Role::Lawyer,
+            RoleAttestation {
+                issuer: 3,
+                expires_at: 50,
+                evidence_hash: H256::repeat_byte(1),
+            },
+        );
+        StorageVersion::new(0).put::<Whitelist>();
+        crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(Whitelist::on_chain_storage_version(), 1);
+        assert_eq!(
+            RoleGrantExpiries::<Test>::get(1, Role::RealEstateInvestor),
+            Some(101)
+        );
+        assert!(AttestationExpiries::<Test>::get(101).contains(&(1, Role::RealEstateInvestor)));
+        assert_eq!(RoleGrantExpiries::<Test>::get(2, Role::Lawyer), None);
+        System::set_block_number(101);
+        assert!(!Whitelist::has_role(&1, Role::RealEstateInvestor));
+    })
+}
+
+#[test]
+fn attest_role_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::attest_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor,
+            50,
+            H256::repeat_byte(1)
+        ));
+        assert_eq!(
+            AccountRoles::<Test>::get(1, Role::RealEstateInvestor).unwrap(),
+            AccessPermission::Compliant
+        );
+        assert_eq!(
+            RoleAttestations::<Test>::get(1, Role::RealEstateInvestor).unwrap(),
+            RoleAttestation {
+                issuer: 3,
+                expires_at: 50,
+                evidence_hash: H256::repeat_byte(1),
+            }
+        );
+        assert_eq!(
+            AttestationExpiries::<Test>::get(40).into_inner(),
+            vec![(1, Role::RealEstateInvestor)]
+        );
+        assert_eq!(
+            AttestationExpiries::<Test>::get(50).into_inner(),
+            vec![(1, Role::RealEstateInvestor)]
+        );
+        assert!(Whitelist::is_compliant(&1, Role::RealEstateInvestor));
+        System::assert_last_event(
+            Event::RoleAttested {
+                user: 1,
+                role: Role::RealEstateInvestor,
+                issuer: 3,
+                expires_at: 50,
+                evidence_hash: H256::repeat_byte(1),
+            }
+            .into(),
+        );
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::Lawyer
+        ));
+        assert_ok!(Whitelist::attest_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::Lawyer,
+            5,
+            H256::repeat_byte(2)
+        ));
+        assert!(AttestationExpiries::<Test>::get(0).is_empty());
+        assert_eq!(
+            AttestationExpiries::<Test>::get(5).into_inner(),
+            vec![(1, Role::Lawyer)]
+        );
+    });
+}
+
+#[test]
+fn attest_role_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(10);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_noop!(
+            Whitelist::attest_role(
+                RuntimeOrigin::signed(2),
+                1,
+                Role::RealEstateInvestor,
+                50,
+                H256::repeat_byte(1)
+            ),
+            Error::<Test>::AccountNotAdmin
+        );
+        assert_noop!(
+            Whitelist::attest_role(
+                RuntimeOrigin::signed(3),
+                1,
+                Role::RealEstateInvestor,
+                10,
+                H256::repeat_byte(1)
+            ),
+            Error::<Test>::InvalidExpiry
+        );
+    });
+}
+
+#[test]
+fn role_attestation_expires() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::attest_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor,
+            50,
+            H256::repeat_byte(1)
+        ));
+        System::set_block_number(40);
+        Whitelist::on_initialize(40);
+        System::assert_last_event(
+            Event::RoleExpiring {
+                user: 1,
+                role: Role::RealEstateInvestor,
+                expires_at: 50,
+            }
+            .into(),
+        );
+        assert!(Whitelist::is_compliant(&1, Role::RealEstateInvestor));
+        System::set_block_number(50);
+        Whitelist::on_initialize(50);
+        System::assert_last_event(
+            Event::RoleExpired {
+                user: 1,
+                role: Role::RealEstateInvestor,
+            }
+            .into(),
+        );
+        assert!(!Whitelist::has_role(&1, Role::RealEstateInvestor));
+        assert!(!Whitelist::is_compliant(&1, Role::RealEstateInvestor));
+        assert!(AttestationExpiries::<Test>::get(50).is_empty());
+
+        // Renewing the attestation restores compliance
+        assert_ok!(Whitelist::attest_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor,
+            100,
+            H256::repeat_byte(2)
+        ));
+        assert!(Whitelist::is_compliant(&1, Role::RealEstateInvestor));
+        assert_ok!(Whitelist::remove_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor
+        ));
+        assert_eq!(
+            RoleAttestations::<Test>::get(1, Role::RealEstateInvestor),
+            None
+        );
+    });
+}
+
+#[test]
+fn role_attestation_expiry_moves_to_next_free_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        let full_block: BoundedVec<(u64, Role), <Test as crate::Config>::MaxExpiriesPerBlock> =
+            (100..200)
+                .map(|user| (user, Role::Lawyer))
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+        AttestationExpiries::<Test>::insert(50, full_block);
+        assert_ok!(Whitelist::attest_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor,
+            50,
+            H256::repeat_byte(1)
+        ));
+        assert_eq!(
+            RoleAttestations::<Test>::get(1, Role::RealEstateInvestor)
+                .unwrap()
+                .expires_at,
+            51
+        );
+        assert!(AttestationExpiries::<Test>::get(41).contains(&(1, Role::RealEstateInvestor)));
+        assert!(AttestationExpiries::<Test>::get(51).contains(&(1, Role::RealEstateInvestor)));
+        System::assert_last_event(
+            Event::RoleAttested {
+                user: 1,
+                role: Role::RealEstateInvestor,
+                issuer: 3,
+                expires_at: 51,
+                evidence_hash: H256::repeat_byte(1),
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn renewing_attestation_removes_previous_expiry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            2,
+            Role::RealEstateInvestor
+        ));
+        assert_ok!(Whitelist::attest_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor,
+            101,
+            H256::repeat_byte(1)
+        ));
+        assert_ok!(Whitelist::attest_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor,
+            150,
+            H256::repeat_byte(2)
+        ));
+        assert_eq!(
+            AttestationExpiries::<Test>::get(91).into_inner(),
+            vec![(2, Role::RealEstateInvestor)]
+        );
+        assert_eq!(
+            AttestationExpiries::<Test>::get(101).into_inner(),
+            vec![(2, Role::RealEstateInvestor)]
+        );
+        assert_eq!(
+            AttestationExpiries::<Test>::get(140).into_inner(),
+            vec![(1, Role::RealEstateInvestor)]
+        );
+        assert_eq!(
+            AttestationExpiries::<Test>::get(150).into_inner(),
+            vec![(1, Role::RealEstateInvestor)]
+        );
+    })
+}
+
+#[test]
+fn set_investor_profile_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::set_investor_profile(
+            RuntimeOrigin::signed(3),
+            1,
+            *b"DE",
+            true
+        ));
+        assert_eq!(
+            InvestorProfiles::<Test>::get(1).unwrap(),
+            InvestorProfile {
+                jurisdiction: *b"DE",
+                accredited: true,
+            }
+        );
+        System::assert_last_event(
+            Event::InvestorProfileSet {
+                user: 1,
+                jurisdiction: *b"DE",
+                accredited: true,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn set_investor_profile_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            Whitelist::set_investor_profile(RuntimeOrigin::signed(3), 1, *b"DE", true),
+            Error::<Test>::AccountNotAdmin
+        );
+    });
+}
+
+#[test]
+fn set_region_policy_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::set_region_policy(
+            RuntimeOrigin::root(),
+            3,
+            bounded_vec![*b"DE", *b"FR"],
+            true,
+            Some(100_000)
+        ));
+        let policy = RegionEligibility::<Test>::get(3).unwrap();
+        assert_eq!(policy.allowed_jurisdictions.len(), 2);
+        assert!(policy.accredited_only);
+        assert_eq!(policy.max_investment, Some(100_000));
+        assert_noop!(
+            Whitelist::set_region_policy(
+                RuntimeOrigin::signed(1),
+                3,
+                bounded_vec![*b"DE"],
+                false,
+                None
+            ),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn remove_region_policy_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::set_region_policy(
+            RuntimeOrigin::root(),
+            3,
+            bounded_vec![*b"DE"],
+            false,
+            None
+        ));
+        assert_ok!(Whitelist::remove_region_policy(RuntimeOrigin::root(), 3));
+        assert!(RegionEligibility::<Test>::get(3).is_none());
+        System::assert_last_event(Event::RegionPolicyRemoved { region: 3 }.into());
+    });
+}
+
+#[test]
+fn remove_region_policy_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            Whitelist::remove_region_policy(RuntimeOrigin::root(), 3),
+            Error::<Test>::NoRegionPolicy
+        );
+        assert_ok!(Whitelist::set_region_policy(
+            RuntimeOrigin::root(),
+            3,
+            bounded_vec![*b"DE"],
+            false,
+            None
+        ));
+        assert_noop!(
+            Whitelist::remove_region_policy(RuntimeOrigin::signed(1), 3),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn region_policy_restricts_eligibility() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor
+        ));
+        let eligibility = InvestmentEligibility {
+            region: 3,
+            investment: 50_000,
+        };
+        // Regions without a policy accept every compliant investor
+        assert!(Whitelist::is_eligible(&1, &eligibility));
+        assert_ok!(Whitelist::set_region_policy(
+            RuntimeOrigin::root(),
+            3,
+            bounded_vec![*b"DE"],
+            true,
+            Some(100_000)
+        ));
+        assert!(!Whitelist::is_eligible(&1, &eligibility));
+        assert_ok!(Whitelist::set_investor_profile(
+            RuntimeOrigin::signed(3),
+            1,
+            *b"FR",
+            true
+        ));
+        assert!(!Whitelist::is_eligible(&1, &eligibility));
+        assert_ok!(Whitelist::set_investor_profile(
+            RuntimeOrigin::signed(3),
+            1,
+            *b"DE",
+            false
+        ));
+        assert!(!Whitelist::is_eligible(&1, &eligibility));
+        assert_ok!(Whitelist::set_investor_profile(
+            RuntimeOrigin::signed(3),
+            1,
+            *b"DE",
+            true
+        ));
+        assert!(Whitelist::is_eligible(&1, &eligibility));
+        assert!(!Whitelist::is_eligible(
+            &1,
+            &InvestmentEligibility {
+                region: 3,
+                investment: 100_001,
+            }
+        ));
+        // Other regions are unaffected
+        assert!(Whitelist::is_eligible(
+            &1,
+            &InvestmentEligibility {
+                region: 4,
+                investment: 100_001,
+            }
+        ));
+    });
+}
+
+#[test]
+fn set_approval_threshold_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::set_approval_threshold(RuntimeOrigin::root(), 2));
+        assert_eq!(ApprovalThreshold::<Test>::get(), 2);
+        System::assert_last_event(Event::ApprovalThresholdSet { threshold: 2 }.into());
+    });
+}
+
+#[test]
+fn set_approval_threshold_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            Whitelist::set_approval_threshold(RuntimeOrigin::signed(1), 2),
+            BadOrigin
+        );
+        assert_noop!(
+            Whitelist::set_approval_threshold(RuntimeOrigin::root(), 6),
+            Error::<Test>::InvalidThreshold
+        );
+    });
+}
+
+#[test]
+fn sensitive_role_assignment_requires_approvals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 4));
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 5));
+        assert_ok!(Whitelist::set_approval_threshold(RuntimeOrigin::root(), 3));
+        // Other roles are assigned directly
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor
+        ));
+        assert!(Whitelist::has_role(&1, Role::RealEstateInvestor));
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RegionalOperator
+        ));
+        System::assert_last_event(
+            Event::AdminActionProposed {
+                action_id: 0,
+                proposer: 3,
+                expires_at: 21,
+            }
+            .into(),
+        );
+        assert!(!Whitelist::has_role(&1, Role::RegionalOperator));
+        let pending_action = PendingActions::<Test>::get(0).unwrap();
+        assert_eq!(
+            pending_action.action,
+            AdminAction::AssignRole {
+                user: 1,
+                role: Role::RegionalOperator
+            }
+        );
+        assert_eq!(pending_action.approvals.into_inner(), vec![3]);
+        assert_ok!(Whitelist::approve_action(RuntimeOrigin::signed(4), 0));
+        System::assert_last_event(
+            Event::AdminActionApproved {
+                action_id: 0,
+                admin: 4,
+                approvals: 2,
+            }
+            .into(),
+        );
+        assert!(!Whitelist::has_role(&1, Role::RegionalOperator));
+        assert_ok!(Whitelist::approve_action(RuntimeOrigin::signed(5), 0));
+        System::assert_last_event(Event::AdminActionExecuted { action_id: 0 }.into());
+        assert!(Whitelist::has_role(&1, Role::RegionalOperator));
+        assert!(PendingActions::<Test>::get(0).is_none());
+    });
+}
+
+#[test]
+fn revoking_compliance_requires_approvals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 4));
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor
+        ));
+        assert_ok!(Whitelist::set_approval_threshold(RuntimeOrigin::root(), 2));
+        assert_ok!(Whitelist::set_permission(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RealEstateInvestor,
+            AccessPermission::Revoked
+        ));
+        assert!(Whitelist::is_compliant(&1, Role::RealEstateInvestor));
+        assert_ok!(Whitelist::remove_role(
+            RuntimeOrigin::signed(4),
+            1,
+            Role::RealEstateInvestor
+        ));
+        assert_ok!(Whitelist::approve_action(RuntimeOrigin::signed(4), 0));
+        assert!(!Whitelist::is_compliant(&1, Role::RealEstateInvestor));
+        assert!(Whitelist::has_role(&1, Role::RealEstateInvestor));
+        // The role removal has been proposed before the permission update was executed
+        assert_ok!(Whitelist::approve_action(RuntimeOrigin::signed(3), 1));
+        assert!(!Whitelist::has_role(&1, Role::RealEstateInvestor));
+    });
+}
+
+#[test]
+fn approve_action_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 4));
+        assert_ok!(Whitelist::set_approval_threshold(RuntimeOrigin::root(), 2));
+        assert_noop!(
+            Whitelist::approve_action(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::ActionNotFound
+        );
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::SpvConfirmation
+        ));
+        assert_noop!(
+            Whitelist::approve_action(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::AccountNotAdmin
+        );
+        assert_noop!(
+            Whitelist::approve_action(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::AlreadyApproved
+        );
+        assert_noop!(
+            Whitelist::attest_role(
+                RuntimeOrigin::signed(3),
+                1,
+                Role::SpvConfirmation,
+                50,
+                H256::repeat_byte(1)
+            ),
+            Error::<Test>::ApprovalRequired
+        );
+        System::set_block_number(21);
+        assert_noop!(
+            Whitelist::approve_action(RuntimeOrigin::signed(4), 0),
+            Error::<Test>::ActionExpired
+        );
+        assert!(!Whitelist::has_role(&1, Role::SpvConfirmation));
+    });
+}
+
+#[test]
+fn remove_expired_action_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::set_approval_threshold(RuntimeOrigin::root(), 2));
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            1,
+            Role::RegionalOperator
+        ));
+        assert_noop!(
+            Whitelist::remove_expired_action(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::ActionNotExpired
+        );
+        System::set_block_number(21);
+        assert_ok!(Whitelist::remove_expired_action(
+            RuntimeOrigin::signed(1),
+            0
+        ));
+        System::assert_last_event(Event::AdminActionExpired { action_id: 0 }.into());
+        assert!(PendingActions::<Test>::get(0).is_none());
+        assert_noop!(
+            Whitelist::remove_expired_action(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::ActionNotFound
+        );
+    });
+}
+
+#[test]
+fn assign_roles_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::assign_roles(
+            RuntimeOrigin::signed(3),
+            bounded_vec![1, 2, 4],
+            Role::RealEstateInvestor
+        ));
+        assert!(Whitelist::has_role(&1, Role::RealEstateInvestor));
+        assert!(Whitelist::has_role(&2, Role::RealEstateInvestor));
+        assert!(Whitelist::has_role(&4, Role::RealEstateInvestor));
+        System::assert_last_event(
+            Event::RoleAssigned {
+                user: 4,
+                role: Role::RealEstateInvestor,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn assign_roles_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_noop!(
+            Whitelist::assign_roles(
+                RuntimeOrigin::signed(1),
+                bounded_vec![1, 2],
+                Role::RealEstateInvestor
+            ),
+            Error::<Test>::AccountNotAdmin
+        );
+        assert_ok!(Whitelist::assign_role(
+            RuntimeOrigin::signed(3),
+            2,
+            Role::RealEstateInvestor
+        ));
+        assert_noop!(
+            Whitelist::assign_roles(
+                RuntimeOrigin::signed(3),
+                bounded_vec![1, 2],
+                Role::RealEstateInvestor
+            ),
+            Error::<Test>::RoleAlreadyAssigned
+        );
+        assert!(!Whitelist::has_role(&1, Role::RealEstateInvestor));
+        assert_ok!(Whitelist::set_approval_threshold(RuntimeOrigin::root(), 2));
+        assert_noop!(
+            Whitelist::assign_roles(
+                RuntimeOrigin::signed(3),
+                bounded_vec![1],
+                Role::RegionalOperator
+            ),
+            Error::<Test>::ApprovalRequired
+        );
+    });
+}
+
+#[test]
+fn link_account_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::link_account(RuntimeOrigin::signed(3), 1, 2));
+        System::assert_last_event(
+            Event::AccountLinked {
+                primary: 1,
+                account: 2,
+            }
+            .into(),
+        );
+        assert_ok!(Whitelist::link_account(RuntimeOrigin::signed(3), 1, 4));
+        assert_eq!(LinkedAccounts::<Test>::get(1).into_inner(), vec![2, 4]);
+        assert_eq!(PrimaryAccount::<Test>::get(2), Some(1));
+        assert_eq!(Whitelist::linked_accounts(&1), vec![2, 4, 1]);
+        assert_eq!(Whitelist::linked_accounts(&4), vec![2, 4, 1]);
+        assert_eq!(Whitelist::linked_accounts(&5), vec![5]);
+    });
+}
+
+#[test]
+fn link_account_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_noop!(
+            Whitelist::link_account(RuntimeOrigin::signed(1), 1, 2),
+            Error::<Test>::AccountNotAdmin
+        );
+        assert_noop!(
+            Whitelist::link_account(RuntimeOrigin::signed(3), 1, 1),
+            Error::<Test>::CannotLinkSelf
+        );
+        assert_ok!(Whitelist::link_account(RuntimeOrigin::signed(3), 1, 2));
+        assert_noop!(
+            Whitelist::link_account(RuntimeOrigin::signed(3), 4, 2),
+            Error::<Test>::AlreadyLinked
+        );
+        assert_noop!(
+            Whitelist::link_account(RuntimeOrigin::signed(3), 2, 4),
+            Error::<Test>::AlreadyLinked
+        );
+        assert_noop!(
+            Whitelist::link_account(RuntimeOrigin::signed(3), 4, 1),
+            Error::<Test>::AlreadyLinked
+        );
+        for account in 4..8 {
+            assert_ok!(Whitelist::link_account(
+                RuntimeOrigin::signed(3),
+                1,
+                account
+            ));
+        }
+        assert_noop!(
+            Whitelist::link_account(RuntimeOrigin::signed(3), 1, 8),
+            Error::<Test>::TooManyLinkedAccounts
+        );
+    });
+}
+
+#[test]
+fn unlink_account_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::root(), 3));
+        assert_ok!(Whitelist::link_account(RuntimeOrigin::signed(3), 1, 2));
+        assert_noop!(
+            Whitelist::unlink_account(RuntimeOrigin::signed(3), 4),
+            Error::<Test>::AccountNotLinked
+        );
+        assert_ok!(Whitelist::unlink_account(RuntimeOrigin::signed(3), 2));
+        System::assert_last_event(
+            Event::AccountUnlinked {
+                primary: 1,
+                account: 2,
+            }
+            .into(),
+        );
+        assert!(!LinkedAccounts::<Test>::contains_key(1));
+        assert_eq!(PrimaryAccount::<Test>::get(2), None);
+        assert_eq!(Whitelist::linked_accounts(&2), vec![2]);
+    });
+}
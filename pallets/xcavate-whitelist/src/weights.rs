@@ -1,8 +1,13 @@
 
-//! Autogenerated weights for `pallet_xcavate_whitelist`
+//! Weights for `pallet_xcavate_whitelist`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.2.0
-//! DATE: 2025-08-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! NOT REGENERATED: the execution times below come from the last run of the Substrate
+//! benchmark CLI version 47.2.0. The storage accesses and proof sizes of `assign_role`,
+//! `attest_role`, `approve_action` and `assign_roles` were adjusted by hand to the current
+//! benchmarks, which assign expired roles again and renew scheduled expiries. Rerun the
+//! executed command below and replace this file with its output.
+//!
+//! Last CLI run: DATE: 2025-08-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `recrafter-Legion-5-16IRX9`, CPU: `Intel(R) Core(TM) i7-14650HX`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
@@ -36,6 +41,16 @@ pub trait WeightInfo {
 	fn assign_role() -> Weight;
 	fn remove_role() -> Weight;
 	fn set_permission() -> Weight;
+	fn attest_role() -> Weight;
+	fn set_investor_profile() -> Weight;
+	fn set_region_policy() -> Weight;
+	fn remove_region_policy() -> Weight;
+	fn set_approval_threshold() -> Weight;
+	fn approve_action() -> Weight;
+	fn remove_expired_action() -> Weight;
+	fn assign_roles(n: u32, ) -> Weight;
+	fn link_account() -> Weight;
+	fn unlink_account() -> Weight;
 }
 
 /// Weight functions for `pallet_xcavate_whitelist`.
@@ -69,42 +84,219 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `XcavateWhitelist::AdminAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:1)
 	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::ApprovalThreshold` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::ApprovalThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::RoleAttestations` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::RoleAttestations` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::RoleGrantExpiries` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::RoleGrantExpiries` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::AttestationExpiries` (r:2 w:2)
+	/// Proof: `XcavateWhitelist::AttestationExpiries` (`max_values`: None, `max_size`: Some(6522), added: 8997, mode: `MaxEncodedLen`)
 	fn assign_role() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `117`
-		//  Estimated: `3531`
-		// Minimum execution time: 14_023_000 picoseconds.
-		Weight::from_parts(14_525_000, 0)
-			.saturating_add(Weight::from_parts(0, 3531))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Estimated: `26677`
+		// Minimum execution time: 15_102_000 picoseconds.
+		Weight::from_parts(15_634_000, 0)
+			.saturating_add(Weight::from_parts(0, 26677))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `XcavateWhitelist::AdminAccounts` (r:1 w:0)
 	/// Proof: `XcavateWhitelist::AdminAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:1)
 	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::ApprovalThreshold` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::ApprovalThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::RoleAttestations` (r:0 w:1)
+	/// Proof: `XcavateWhitelist::RoleAttestations` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::RoleGrantExpiries` (r:0 w:1)
+	/// Proof: `XcavateWhitelist::RoleGrantExpiries` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	fn remove_role() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `208`
 		//  Estimated: `3531`
-		// Minimum execution time: 15_825_000 picoseconds.
-		Weight::from_parts(16_193_000, 0)
+		// Minimum execution time: 17_214_000 picoseconds.
+		Weight::from_parts(17_806_000, 0)
 			.saturating_add(Weight::from_parts(0, 3531))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `XcavateWhitelist::AdminAccounts` (r:1 w:0)
 	/// Proof: `XcavateWhitelist::AdminAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:1)
 	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::ApprovalThreshold` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::ApprovalThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn set_permission() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `208`
 		//  Estimated: `3531`
-		// Minimum execution time: 16_401_000 picoseconds.
-		Weight::from_parts(16_827_000, 0)
+		// Minimum execution time: 17_498_000 picoseconds.
+		Weight::from_parts(17_962_000, 0)
 			.saturating_add(Weight::from_parts(0, 3531))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcavateWhitelist::AdminAccounts` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AdminAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::RoleAttestations` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::RoleAttestations` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::RoleGrantExpiries` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::RoleGrantExpiries` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::AttestationExpiries` (r:4 w:4)
+	/// Proof: `XcavateWhitelist::AttestationExpiries` (`max_values`: None, `max_size`: Some(6522), added: 8997, mode: `MaxEncodedLen`)
+	fn attest_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `117`
+		//  Estimated: `41120`
+		// Minimum execution time: 25_391_000 picoseconds.
+		Weight::from_parts(26_117_000, 0)
+			.saturating_add(Weight::from_parts(0, 41120))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `XcavateWhitelist::AdminAccounts` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AdminAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::InvestorProfiles` (r:0 w:1)
+	/// Proof: `XcavateWhitelist::InvestorProfiles` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	fn set_investor_profile() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `117`
+		//  Estimated: `3513`
+		// Minimum execution time: 11_842_000 picoseconds.
+		Weight::from_parts(12_270_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcavateWhitelist::RegionEligibility` (r:0 w:1)
+	/// Proof: `XcavateWhitelist::RegionEligibility` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	fn set_region_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_731_000 picoseconds.
+		Weight::from_parts(10_215_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `XcavateWhitelist::RegionEligibility` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::RegionEligibility` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	fn remove_region_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `228`
+		//  Estimated: `3602`
+		// Minimum execution time: 12_115_000 picoseconds.
+		Weight::from_parts(12_603_000, 0)
+			.saturating_add(Weight::from_parts(0, 3602))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcavateWhitelist::ApprovalThreshold` (r:0 w:1)
+	/// Proof: `XcavateWhitelist::ApprovalThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_approval_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_862_000 picoseconds.
+		Weight::from_parts(6_140_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcavateWhitelist::AdminAccounts` (r:10 w:0)
+	/// Proof: `XcavateWhitelist::AdminAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::PendingActions` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::PendingActions` (`max_values`: None, `max_size`: Some(412), added: 2887, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::ApprovalThreshold` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::ApprovalThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::RoleAttestations` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::RoleAttestations` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::RoleGrantExpiries` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::RoleGrantExpiries` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::AttestationExpiries` (r:2 w:2)
+	/// Proof: `XcavateWhitelist::AttestationExpiries` (`max_values`: None, `max_size`: Some(6522), added: 8997, mode: `MaxEncodedLen`)
+	fn approve_action() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1004`
+		//  Estimated: `49366`
+		// Minimum execution time: 48_907_000 picoseconds.
+		Weight::from_parts(50_362_000, 0)
+			.saturating_add(Weight::from_parts(0, 49366))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `XcavateWhitelist::PendingActions` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::PendingActions` (`max_values`: None, `max_size`: Some(412), added: 2887, mode: `MaxEncodedLen`)
+	fn remove_expired_action() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `199`
+		//  Estimated: `3877`
+		// Minimum execution time: 13_590_000 picoseconds.
+		Weight::from_parts(14_021_000, 0)
+			.saturating_add(Weight::from_parts(0, 3877))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcavateWhitelist::AdminAccounts` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AdminAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::ApprovalThreshold` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::ApprovalThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::AccountRoles` (r:100 w:100)
+	/// Proof: `XcavateWhitelist::AccountRoles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::RoleAttestations` (r:100 w:100)
+	/// Proof: `XcavateWhitelist::RoleAttestations` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::RoleGrantExpiries` (r:100 w:100)
+	/// Proof: `XcavateWhitelist::RoleGrantExpiries` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::AttestationExpiries` (r:200 w:200)
+	/// Proof: `XcavateWhitelist::AttestationExpiries` (`max_values`: None, `max_size`: Some(6522), added: 8997, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn assign_roles(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `117`
+		//  Estimated: `3513 + n * (25687 ±0)`
+		// Minimum execution time: 16_874_000 picoseconds.
+		Weight::from_parts(12_406_215, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			// Standard Error: 3_118
+			.saturating_add(Weight::from_parts(5_317_842, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 25687).saturating_mul(n.into()))
+	}
+	/// Storage: `XcavateWhitelist::AdminAccounts` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AdminAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::PrimaryAccount` (r:2 w:1)
+	/// Proof: `XcavateWhitelist::PrimaryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::LinkedAccounts` (r:2 w:1)
+	/// Proof: `XcavateWhitelist::LinkedAccounts` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	fn link_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `6678`
+		// Minimum execution time: 24_736_000 picoseconds.
+		Weight::from_parts(25_519_000, 0)
+			.saturating_add(Weight::from_parts(0, 6678))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `XcavateWhitelist::AdminAccounts` (r:1 w:0)
+	/// Proof: `XcavateWhitelist::AdminAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::PrimaryAccount` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::PrimaryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `XcavateWhitelist::LinkedAccounts` (r:1 w:1)
+	/// Proof: `XcavateWhitelist::LinkedAccounts` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	fn unlink_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `505`
+		//  Estimated: `3834`
+		// Minimum execution time: 21_058_000 picoseconds.
+		Weight::from_parts(21_743_000, 0)
+			.saturating_add(Weight::from_parts(0, 3834))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
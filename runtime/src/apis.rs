@@ -42,10 +42,16 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-    Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-    SLOT_DURATION, VERSION,
+    AccountId, Balance, Block, BlockNumber, ConsensusHook, Executive, InherentDataExt, Marketplace,
+    Nonce, ParachainSystem, PropertyGovernance, PropertyManagement, Runtime, RuntimeCall,
+    RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, SLOT_DURATION, VERSION,
 };
+use pallet_marketplace::{
+    types::{ListingFunding, ListingInfo, OfferInfo, PurchaseInfo, TokenListingInfo},
+    ListingId,
+};
+use pallet_property_governance::{ProposalId, ProposalRecord};
+use pallet_property_management::{PeriodId, PropertyStatement};
 
 // we move some impls outside so we can easily use them with `docify`.
 impl Runtime {
@@ -210,6 +216,66 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_marketplace::MarketplaceApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn ongoing_listings(
+            start: Option<ListingId>,
+            limit: u32,
+        ) -> Vec<ListingInfo<AccountId, Balance, BlockNumber>> {
+            Marketplace::ongoing_listings(start, limit)
+        }
+
+        fn token_listings(
+            start: Option<ListingId>,
+            limit: u32,
+        ) -> Vec<TokenListingInfo<AccountId, Balance>> {
+            Marketplace::token_listings(start, limit)
+        }
+
+        fn listing_funding(listing_id: ListingId) -> Option<ListingFunding<Balance>> {
+            Marketplace::listing_funding(listing_id)
+        }
+
+        fn listing_offers(
+            listing_id: ListingId,
+            start: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<OfferInfo<AccountId, Balance>> {
+            Marketplace::listing_offers(listing_id, start, limit)
+        }
+
+        fn account_purchases(
+            account: AccountId,
+            start: Option<ListingId>,
+            limit: u32,
+        ) -> Vec<PurchaseInfo<Balance>> {
+            Marketplace::account_purchases(account, start, limit)
+        }
+    }
+
+    impl pallet_property_management::PropertyLedgerApi<Block, Balance> for Runtime {
+        fn property_statement(asset_id: u32, period: PeriodId) -> Option<PropertyStatement<Balance>> {
+            PropertyManagement::property_statement(asset_id, period)
+        }
+
+        fn property_statements(
+            asset_id: u32,
+            start: Option<PeriodId>,
+            limit: u32,
+        ) -> Vec<PropertyStatement<Balance>> {
+            PropertyManagement::property_statements(asset_id, start, limit)
+        }
+    }
+
+    impl pallet_property_governance::PropertyGovernanceHistoryApi<Block, BlockNumber> for Runtime {
+        fn proposal_history(
+            asset_id: u32,
+            start: Option<ProposalId>,
+            limit: u32,
+        ) -> Vec<ProposalRecord<BlockNumber>> {
+            PropertyGovernance::proposal_history(asset_id, start, limit)
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             ParachainSystem::collect_collation_info(header)
@@ -38,4 +38,5 @@ frame_benchmarking::define_benchmarks!(
     [pallet_marketplace, Marketplace]
     [pallet_property_management, PropertyManagement]
     [pallet_property_governance, PropertyGovernance]
+    [pallet_price_oracle, PriceOracle]
 );
@@ -34,7 +34,7 @@ use frame_support::{
     instances::Instance1,
     parameter_types,
     traits::{
-        AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse,
+        AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, ConstU8, Contains, EitherOfDiverse,
         EnsureOriginWithArg, InstanceFilter, OriginTrait, TransformOrigin, VariantCountOf,
     },
     weights::{ConstantMultiplier, Weight},
@@ -55,7 +55,7 @@ use polkadot_runtime_common::{
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_runtime::{
     traits::{BlakeTwo256, ConvertInto, Verify},
-    MultiSignature, Perbill, Percent, RuntimeDebug,
+    MultiSignature, Perbill, Percent, Permill, RuntimeDebug,
 };
 use sp_version::RuntimeVersion;
 use xcm::latest::prelude::BodyId;
@@ -65,13 +65,14 @@ use super::{
     deposit,
     weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
     AccountId, Assets, AssetsFreezer, AssetsHolder, Aura, Balance, Balances, Block, BlockNumber,
-    CollatorSelection, ConsensusHook, Hash, MessageQueue, Nfts, Nonce, OriginCaller, PalletInfo,
-    ParachainSystem, RealEstateAsset, RealEstateAssets, Runtime, RuntimeCall, RuntimeEvent,
-    RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session, SessionKeys,
-    System, WeightToFee, XcavateWhitelist, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, DAYS,
-    EXISTENTIAL_DEPOSIT, HOURS, MAXIMUM_BLOCK_WEIGHT, MICROUNIT, NORMAL_DISPATCH_RATIO,
-    SLOT_DURATION, UNIT, VERSION,
+    CollatorSelection, ConsensusHook, Hash, Marketplace, MessageQueue, Nfts, Nonce, OriginCaller,
+    PalletInfo, ParachainSystem, PriceOracle, PropertyManagement, RealEstateAsset,
+    RealEstateAssets, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason,
+    RuntimeOrigin, RuntimeTask, Session, SessionKeys, System, WeightToFee, XcavateWhitelist,
+    XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, DAYS, EXISTENTIAL_DEPOSIT, HOURS, MAXIMUM_BLOCK_WEIGHT,
+    MICROUNIT, MILLIUNIT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, UNIT, VERSION,
 };
+use alloc::{vec, vec::Vec};
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use pallet_nfts::PalletFeatures;
 use primitives::{MarketplaceFreezeReason, MarketplaceHoldReason};
@@ -111,6 +112,27 @@ parameter_types! {
     pub const SS58Prefix: u16 = 42;
 }
 
+/// Blocks the calls that move property token directly in `RealEstateAssets`. Property token can
+/// only be transferred through the marketplace, which checks the compliance of sender and
+/// receiver and keeps the token ownership of the real estate asset pallet in sync.
+pub struct PropertyTokenTransferFilter;
+
+impl Contains<RuntimeCall> for PropertyTokenTransferFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        !matches!(
+            call,
+            RuntimeCall::RealEstateAssets(
+                pallet_assets::Call::transfer { .. }
+                    | pallet_assets::Call::transfer_keep_alive { .. }
+                    | pallet_assets::Call::transfer_all { .. }
+                    | pallet_assets::Call::force_transfer { .. }
+                    | pallet_assets::Call::approve_transfer { .. }
+                    | pallet_assets::Call::transfer_approved { .. }
+            )
+        )
+    }
+}
+
 /// The default types are being injected by [`derive_impl`](`frame_support::derive_impl`) from
 /// [`ParaChainDefaultConfig`](`struct@frame_system::config_preludes::ParaChainDefaultConfig`),
 /// but overridden as needed.
@@ -140,6 +162,8 @@ impl frame_system::Config for Runtime {
     type SS58Prefix = SS58Prefix;
     /// The action to take on a Runtime Upgrade
     type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
+    /// Property token can not be transferred outside of the marketplace.
+    type BaseCallFilter = PropertyTokenTransferFilter;
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
@@ -612,13 +636,22 @@ parameter_types! {
     pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
     pub const PropertyFundingAmount: Balance = 10 * UNIT;
     pub const MarketplaceFeePercent: Balance = 1;
-    pub const MaximumAcceptedAssets: u32 = 2;
-    pub const AcceptedPaymentAssets: [u32; 2] = [1337, 1984];
+    pub const MaximumAcceptedAssets: u32 = 5;
+    pub InitialPaymentAssets: Vec<(u32, Balance, bool)> = vec![(1337, 1, false), (1984, 1, false)];
+    pub const PriceReferenceDecimals: u8 = 6;
     pub const LawyerVotingDuration: BlockNumber = 20;
     pub const LegalProcessDuration: BlockNumber = 30;
     pub const MinimumVotingQuorum: Percent = Percent::from_percent(50);
     pub const ClaimWindowTime: BlockNumber = 100;
     pub const MaximumRelistAttempts: u8 = 1;
+    pub const MaxOrdersPerProperty: u32 = 100;
+    pub const MaxOrdersPerInvestor: u32 = 5;
+    pub const OrderDepositAmount: Balance = MILLIUNIT;
+    // A full round of fixed price listings, claim windows or legal processes fits the expiry
+    // weight, auction settlements and further rounds are carried over to the next block.
+    pub const MaxListingForBlock: u32 = 20;
+    pub MarketplaceExpiryWeight: Weight = Perbill::from_percent(25) * RuntimeBlockWeights::get().max_block;
+    pub const DefaultPropertyOwnershipCap: Permill = Permill::from_percent(50);
 }
 
 /// Configure the pallet-marketplace in pallets/marketplace.
@@ -639,8 +672,10 @@ impl pallet_marketplace::Config for Runtime {
     type AssetId = <Self as pallet_assets::Config<Instance1>>::AssetId;
     type ListingDeposit = ListingDepositAmount;
     type MarketplaceFeePercentage = MarketplaceFeePercent;
-    type AcceptedAssets = AcceptedPaymentAssets;
+    type AssetRegistryOrigin = EnsureRoot<Self::AccountId>;
     type MaxAcceptedAssets = MaximumAcceptedAssets;
+    type ReferenceDecimals = PriceReferenceDecimals;
+    type ExchangeRates = PriceOracle;
     type PropertyToken = RealEstateAsset;
     type LawyerVotingTime = LawyerVotingDuration;
     type LegalProcessTime = LegalProcessDuration;
@@ -650,6 +685,24 @@ impl pallet_marketplace::Config for Runtime {
     type MinVotingQuorum = MinimumVotingQuorum;
     type ClaimWindow = ClaimWindowTime;
     type MaxRelistAttempts = MaximumRelistAttempts;
+    type MaxOrdersPerAsset = MaxOrdersPerProperty;
+    type MaxOrdersPerAccount = MaxOrdersPerInvestor;
+    type OrderDeposit = OrderDepositAmount;
+    type MaxListingsForBlock = MaxListingForBlock;
+    type ExpiryProcessingWeight = MarketplaceExpiryWeight;
+    type DefaultOwnershipCap = DefaultPropertyOwnershipCap;
+    type OwnershipCapOrigin = pallet_property_governance::EnsurePropertyCollective;
+}
+
+parameter_types! {
+    pub const AttestationExpiryNotice: BlockNumber = 30 * DAYS;
+    pub const RoleGrantPeriod: BlockNumber = 365 * DAYS;
+    pub const MaxAttestationExpiriesPerBlock: u32 = 100;
+    pub const MaxInvestorJurisdictions: u32 = 50;
+    pub const AdminActionLifetime: BlockNumber = 7 * DAYS;
+    pub const MaxAdminApprovals: u32 = 10;
+    pub const MaxRoleAssignmentsPerCall: u32 = 100;
+    pub const MaxInvestorLinkedAccounts: u32 = 10;
 }
 
 /// Configure the pallet-xcavate-whitelist in pallets/xcavate-whitelist.
@@ -657,6 +710,14 @@ impl pallet_xcavate_whitelist::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_xcavate_whitelist::weights::SubstrateWeight<Runtime>;
     type WhitelistOrigin = EnsureRoot<Self::AccountId>;
+    type RoleGrantPeriod = RoleGrantPeriod;
+    type ExpiryNoticePeriod = AttestationExpiryNotice;
+    type MaxExpiriesPerBlock = MaxAttestationExpiriesPerBlock;
+    type MaxJurisdictions = MaxInvestorJurisdictions;
+    type PendingActionLifetime = AdminActionLifetime;
+    type MaxApprovals = MaxAdminApprovals;
+    type MaxRoleAssignments = MaxRoleAssignmentsPerCall;
+    type MaxLinkedAccounts = MaxInvestorLinkedAccounts;
 }
 
 use pallet_xcavate_whitelist::{self as whitelist, RolePermission};
@@ -717,12 +778,41 @@ impl<T: whitelist::Config> EnsureOriginWithArg<T::RuntimeOrigin, whitelist::Role
     }
 }
 
+impl<T: whitelist::Config> EnsureOriginWithArg<T::RuntimeOrigin, whitelist::InvestmentEligibility>
+    for EnsureCompliant<T>
+{
+    type Success = T::AccountId;
+
+    fn try_origin(
+        origin: T::RuntimeOrigin,
+        eligibility: &whitelist::InvestmentEligibility,
+    ) -> Result<Self::Success, T::RuntimeOrigin> {
+        let Some(who) = origin.clone().into_signer() else {
+            return Err(origin);
+        };
+        if whitelist::Pallet::<T>::is_eligible(&who, eligibility) {
+            Ok(who)
+        } else {
+            Err(origin)
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(
+        _eligibility: &whitelist::InvestmentEligibility,
+    ) -> Result<T::RuntimeOrigin, ()> {
+        let account = frame_benchmarking::whitelisted_caller();
+        Ok(frame_system::RawOrigin::Signed(account).into())
+    }
+}
+
 parameter_types! {
     pub const MinimumStakingAmount: Balance = 1000 * UNIT;
     pub const MaxProperty: u32 = 1000;
     pub const MaxLettingAgent: u32 = 100;
     pub const MaxLocation: u32 = 50;
     pub const LettingAgentVotingDuration: BlockNumber = 20;
+    pub const PropertyIncomeReserveShare: Percent = Percent::from_percent(5);
 }
 
 /// Configure the pallet-property-management in pallets/property-management.
@@ -740,11 +830,13 @@ impl pallet_property_management::Config for Runtime {
     type MaxProperties = MaxProperty;
     type MaxLettingAgents = MaxLettingAgent;
     type MaxLocations = MaxLocation;
-    type AcceptedAssets = AcceptedPaymentAssets;
+    type AcceptedAssets = Marketplace;
     type PropertyToken = RealEstateAsset;
     type LettingAgentVotingTime = LettingAgentVotingDuration;
     type PermissionOrigin = EnsureHasRole<Self>;
     type MinVotingQuorum = MinimumVotingQuorum;
+    type PropertyGovernanceOrigin = pallet_property_governance::EnsurePropertyCollective;
+    type IncomeReserveShare = PropertyIncomeReserveShare;
 }
 
 parameter_types! {
@@ -754,11 +846,27 @@ parameter_types! {
     pub const MinimumSlashingAmount: Balance = 10 * UNIT;
     pub const VotingThreshold: Percent = Percent::from_percent(51);
     pub const HighVotingThreshold: Percent = Percent::from_percent(67);
-    pub const LowProposal: Balance = 500 * UNIT;
     pub const HighProposal: Balance = 10_000 * UNIT;
     pub const SalesProposalThreshold: Percent = Percent::from_percent(90);
     pub const AuctionDuration: BlockNumber = 28;
     pub const MinimumPropertySaleQuorum: Percent = Percent::from_percent(75);
+    pub const MaxPropertyDelegators: u32 = 50;
+    pub const MaxProposalCallLength: u32 = 512;
+    pub const MaxArchivedProposals: u32 = 100;
+}
+
+/// Calls that passed property proposals can dispatch with the property collective origin.
+pub struct PropertyCollectiveCalls;
+impl Contains<RuntimeCall> for PropertyCollectiveCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::PropertyManagement(
+                pallet_property_management::Call::sweep_undistributed_income { .. }
+                    | pallet_property_management::Call::dismiss_letting_agent { .. }
+            ) | RuntimeCall::Marketplace(pallet_marketplace::Call::set_ownership_cap { .. })
+        )
+    }
 }
 
 /// Configure the pallet-property-governance in pallets/property-governance.
@@ -776,18 +884,24 @@ impl pallet_property_governance::Config for Runtime {
     type MaxVotesForBlock = MaxVoteForBlock;
     type MinSlashingAmount = MinimumSlashingAmount;
     type HighThreshold = HighVotingThreshold;
-    type LowProposal = LowProposal;
     type HighProposal = HighProposal;
     type MarketplacePalletId = MarketplacePalletId;
     type SaleApprovalYesThreshold = SalesProposalThreshold;
     type AuctionTime = AuctionDuration;
     type Slash = ();
-    type AcceptedAssets = AcceptedPaymentAssets;
+    type AcceptedAssets = Marketplace;
     type TreasuryId = TreasuryPalletId;
     type PropertyToken = RealEstateAsset;
     type PermissionOrigin = EnsureHasRole<Self>;
     type MinVotingQuorum = MinimumVotingQuorum;
     type MinPropertySaleQuorum = MinimumPropertySaleQuorum;
+    type MaxDelegators = MaxPropertyDelegators;
+    type ProposalRequirementsOrigin = EnsureRoot<AccountId>;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type CallFilter = PropertyCollectiveCalls;
+    type MaxCallLength = MaxProposalCallLength;
+    type MaxArchivedProposals = MaxArchivedProposals;
 }
 
 parameter_types! {
@@ -859,4 +973,17 @@ impl pallet_real_estate_asset::Config for Runtime {
     type AssetId = <Self as pallet_assets::Config<Instance1>>::AssetId;
     type PropertyAccountFundingAmount = PropertyFundingAmount;
     type MaxPropertyToken = MaxPropertyTokens;
+    type OnBalanceChange = PropertyManagement;
+}
+
+parameter_types! {
+    pub const MaxExchangeRateAge: BlockNumber = HOURS;
+}
+
+/// Configure the pallet-price-oracle in pallets/price-oracle.
+impl pallet_price_oracle::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_price_oracle::weights::SubstrateWeight<Runtime>;
+    type FeederOrigin = EnsureRoot<Self::AccountId>;
+    type MaxRateAge = MaxExchangeRateAge;
 }
@@ -89,6 +89,14 @@ pub type SignedExtra = (
 pub type UncheckedExtrinsic =
     generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+    pallet_marketplace::migrations::v1::MigrateV0ToV1<Runtime, configs::InitialPaymentAssets>,
+    pallet_marketplace::migrations::v2::MigrateV1ToV2<Runtime>,
+    pallet_property_governance::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_xcavate_whitelist::migrations::v1::MigrateV0ToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -96,6 +104,7 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
+    Migrations,
 >;
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -339,6 +348,8 @@ mod runtime {
     pub type Regions = pallet_regions;
     #[runtime::pallet_index(55)]
     pub type RealEstateAsset = pallet_real_estate_asset;
+    #[runtime::pallet_index(56)]
+    pub type PriceOracle = pallet_price_oracle;
 }
 
 #[docify::export(register_validate_block)]